@@ -1,15 +1,90 @@
 //! A collection of basic converters.
 
+mod ascii_case;
+mod bitorder;
+mod bits;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+mod buf;
 mod chained;
+#[cfg(feature = "alloc")]
+mod composed;
 mod exact;
+mod fixed_array;
+mod interleave;
 mod into;
 mod iter;
 mod map;
+mod map_indexed;
+mod nibble;
+mod piped;
+mod range_map;
+#[cfg(feature = "std")]
+mod sink;
+mod stats;
+#[cfg(feature = "alloc")]
+mod str_map;
+mod tee;
+#[cfg(feature = "alloc")]
+mod tokenize;
+mod transducer;
+mod try_adapt;
 
+pub mod binary;
+pub mod checksum;
+pub mod cipher;
+#[cfg(feature = "compression")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "compression")))]
+pub mod compress;
 pub mod encoding;
+pub mod escape;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod fmt;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod framing;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod mail;
+pub mod url;
 
+pub use ascii_case::{AsciiCaseFoldConverter, AsciiLowercaseConverter, AsciiUppercaseConverter};
+pub use bitorder::BitEndianConverter;
+pub use bits::{BitByteUnpacker, BitOrder, BitPacker, BitUnpacker, PartialByteError, TailPolicy};
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use boxed::BoxConverter;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use buf::BatchExtend;
 pub use chained::ChainedConverter;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use composed::ComposedConverter;
 pub use exact::ExactConverter;
+pub use fixed_array::{FixedArrayDecoder, FixedArrayEncoder, IncompleteArrayError};
+pub use interleave::InterleavedConverter;
 pub use into::IntoConverter;
 pub use iter::{IterConverter, TryIterConverter};
 pub use map::{MapConverter, TryMapConverter};
+pub use map_indexed::IndexedMapConverter;
+pub use nibble::{
+    ByteToNibbles, NibbleError, NibbleJoiner, NibbleOrder, NibbleSplitter, NibblesToByte,
+};
+pub use piped::PipedConverter;
+pub use range_map::{Clamp, RangeMapConverter, RangeMapError, RangeValue};
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub use sink::SinkConverter;
+pub use stats::{RunningMeanConverter, RunningVarianceConverter};
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use str_map::{StrConverter, TryStrConverter};
+pub use tee::TeeConverter;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use tokenize::WordTokenizer;
+pub use transducer::TransducerConverter;
+pub use try_adapt::TryAdaptedConverter;
@@ -0,0 +1,152 @@
+use core::convert::Infallible;
+
+use crate::{Converter, ExactSizeConverter};
+
+macro_rules! ascii_case_converter {
+    ($(#[$attr:meta])* $name:ident, $f:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $name;
+
+        impl $name {
+            /// Creating a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self
+            }
+        }
+
+        impl Converter for $name {
+            type Item = u8;
+            type Output = u8;
+            type Error = Infallible;
+
+            #[inline]
+            fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                buf.extend([item.$f()]);
+                Ok(1)
+            }
+
+            fn convert_slice<E>(
+                &mut self,
+                items: &[Self::Item],
+                buf: &mut E,
+            ) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                buf.extend(items.iter().map(u8::$f));
+                Ok(items.len())
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (1, Some(1))
+            }
+        }
+
+        impl crate::sealed::Sealed for $name {}
+
+        impl ExactSizeConverter for $name {}
+    };
+}
+
+ascii_case_converter!(
+    /// Converting ASCII letters in bytes to lowercase.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::AsciiLowercaseConverter;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"Content-Type".iter().cloned();
+    /// let lowered = ConvertedIterator::new(iter, AsciiLowercaseConverter::new());
+    ///
+    /// assert_eq!(Ok(b"content-type".to_vec()), lowered.collect());
+    /// ```
+    AsciiLowercaseConverter,
+    to_ascii_lowercase
+);
+
+ascii_case_converter!(
+    /// Converting ASCII letters in bytes to uppercase.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::AsciiUppercaseConverter;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"deadbeef".iter().cloned();
+    /// let uppered = ConvertedIterator::new(iter, AsciiUppercaseConverter::new());
+    ///
+    /// assert_eq!(Ok(b"DEADBEEF".to_vec()), uppered.collect());
+    /// ```
+    AsciiUppercaseConverter,
+    to_ascii_uppercase
+);
+
+ascii_case_converter!(
+    /// Folding cases of ASCII letters in bytes, for case-insensitive comparisons.
+    ///
+    /// Only `A`-`Z` are lowercased and all other bytes are passed through, so UTF-8 byte
+    /// streams stay valid. (bytes of multi-byte sequences are never ASCII letters)
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Validator;
+    /// use conversion::converter::AsciiCaseFoldConverter;
+    /// use conversion::iter::ConvertedIterator;
+    /// use std::io::Write;
+    ///
+    /// let iter = "STRAẞE Straße".bytes();
+    /// let validated = ConvertedIterator::new(iter, UTF8Validator::new()).map(Result::unwrap);
+    /// let folded = ConvertedIterator::new(validated, AsciiCaseFoldConverter::new());
+    ///
+    /// let mut writer = Vec::new();
+    /// for byte in folded {
+    ///     writer.write_all(&[byte.unwrap()]).unwrap();
+    /// }
+    /// assert_eq!(Ok("straẞe straße"), std::str::from_utf8(&writer));
+    /// ```
+    AsciiCaseFoldConverter,
+    to_ascii_lowercase
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn preserve_utf8() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        // every 7th code point, covering 1- to 4-byte sequences.
+        let text = (0..0x11000)
+            .step_by(7)
+            .filter_map(char::from_u32)
+            .collect::<String>();
+
+        let lower = ConvertedIterator::new(text.bytes(), AsciiLowercaseConverter::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(Ok(text.to_ascii_lowercase()), String::from_utf8(lower));
+
+        let upper = ConvertedIterator::new(text.bytes(), AsciiUppercaseConverter::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(Ok(text.to_ascii_uppercase()), String::from_utf8(upper));
+
+        let mut folded = Vec::new();
+        assert_eq!(
+            Ok(text.len()),
+            AsciiCaseFoldConverter::new().convert_slice(text.as_bytes(), &mut folded)
+        );
+        assert_eq!(Ok(text.to_ascii_lowercase()), String::from_utf8(folded));
+    }
+}
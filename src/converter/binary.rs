@@ -0,0 +1,56 @@
+//! Converters between bytes and the fields of binary formats: integers, bits, nibbles, packed BCD
+//! and varints.
+//!
+//! The converters are the same as the ones in [`encoding`] and this module, gathered by layout.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::binary::int::{BigEndian, U32Decoder};
+//! use conversion::converter::binary::varint::SignedVarintEncoder;
+//! use conversion::iter::ConvertedIterator;
+//!
+//! let ints = ConvertedIterator::new(*b"\xFF\xFF\xFF\xFF", U32Decoder::<BigEndian>::new())
+//!     .map(|int| int.unwrap() as i32 as i64);
+//! let bytes = ConvertedIterator::new(ints, SignedVarintEncoder::new())
+//!     .collect::<Result<Vec<_>, _>>();
+//! assert_eq!(Ok(vec![0x01]), bytes);
+//! ```
+//!
+//! [`encoding`]: super::encoding
+
+/// Fixed-width integers, from [`encoding::int`] and [`encoding::word`].
+///
+/// [`encoding::int`]: crate::converter::encoding::int
+/// [`encoding::word`]: crate::converter::encoding::word
+pub mod int {
+    pub use super::super::encoding::int::*;
+    pub use super::super::encoding::word::{BigEndian, LittleEndian, NativeEndian};
+}
+
+/// Unpacking bytes into bits, and packing them back.
+pub mod bits {
+    pub use super::super::{
+        BitByteUnpacker, BitOrder, BitPacker, BitUnpacker, PartialByteError, TailPolicy,
+    };
+}
+
+/// Splitting bytes into nibbles, and joining them back.
+pub mod nibble {
+    pub use super::super::{
+        ByteToNibbles, NibbleError, NibbleJoiner, NibbleOrder, NibbleSplitter, NibblesToByte,
+    };
+}
+
+/// Packed BCD, from [`encoding::bcd`].
+///
+/// [`encoding::bcd`]: crate::converter::encoding::bcd
+pub mod bcd {
+    pub use super::super::encoding::bcd::*;
+}
+
+/// Varints with ZigZag encoding, from [`encoding::proto_varint`].
+///
+/// [`encoding::proto_varint`]: crate::converter::encoding::proto_varint
+pub mod varint {
+    pub use super::super::encoding::proto_varint::*;
+}
@@ -0,0 +1,87 @@
+use core::convert::Infallible;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// Reversing the bit order within each byte, to convert packed bit streams between LSB-first and
+/// MSB-first orders.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::BitEndianConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0b0000_0001, 0b1100_1010, 0xFF].into_iter();
+/// let reversed = ConvertedIterator::new(iter, BitEndianConverter::new());
+///
+/// assert_eq!(Ok(vec![0b1000_0000, 0b0101_0011, 0xFF]), reversed.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BitEndianConverter;
+
+impl BitEndianConverter {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for BitEndianConverter {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([item.reverse_bits()]);
+        Ok(1)
+    }
+
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend(items.iter().map(|b| b.reverse_bits()));
+        Ok(items.len())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for BitEndianConverter {}
+
+impl ExactSizeConverter for BitEndianConverter {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn involution() {
+        use alloc::vec::Vec;
+
+        let bytes = (0..=0xFF).collect::<Vec<u8>>();
+        let mut reversed = Vec::new();
+        assert_eq!(
+            Ok(256),
+            BitEndianConverter::new().convert_slice(&bytes, &mut reversed)
+        );
+        assert_eq!(0b1011_0000, reversed[0b0000_1101]);
+
+        let mut restored = Vec::new();
+        for &byte in reversed.iter() {
+            assert_eq!(
+                Ok(1),
+                BitEndianConverter::new().convert(byte, &mut restored)
+            );
+        }
+        assert_eq!(bytes, restored);
+    }
+}
@@ -0,0 +1,357 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// Orders of bits within a byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The most significant bit first.
+    #[default]
+    MsbFirst,
+    /// The least significant bit first.
+    LsbFirst,
+}
+
+impl BitOrder {
+    // bits of a byte in the order.
+    #[inline]
+    fn bits(self, byte: u8) -> impl Iterator<Item = bool> {
+        (0..8).map(move |i| match self {
+            Self::MsbFirst => byte & (0x80 >> i) != 0,
+            Self::LsbFirst => byte & (1 << i) != 0,
+        })
+    }
+}
+
+/// Unpacking each byte into 8 bits.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{BitOrder, BitUnpacker};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let bits = ConvertedIterator::new([0b1000_0011], BitUnpacker::new());
+/// assert_eq!(
+///     Ok(vec![true, false, false, false, false, false, true, true]),
+///     bits.collect(),
+/// );
+///
+/// let bits = ConvertedIterator::new([0b1000_0011], BitUnpacker::with_order(BitOrder::LsbFirst));
+/// assert_eq!(
+///     Ok(vec![true, true, false, false, false, false, false, true]),
+///     bits.collect(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BitUnpacker {
+    order: BitOrder,
+}
+
+impl BitUnpacker {
+    /// Creating a new instance, emits the most significant bit first.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a bit order.
+    #[inline]
+    pub fn with_order(order: BitOrder) -> Self {
+        Self { order }
+    }
+}
+
+impl Converter for BitUnpacker {
+    type Item = u8;
+    type Output = bool;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend(self.order.bits(item));
+        Ok(8)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+impl crate::sealed::Sealed for BitUnpacker {}
+
+impl ExactSizeConverter for BitUnpacker {}
+
+/// Unpacking each byte into 8 bits, as bytes of `0` or `1`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{BitOrder, BitByteUnpacker};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let bits = ConvertedIterator::new([0x0F], BitByteUnpacker::with_order(BitOrder::LsbFirst));
+/// assert_eq!(Ok(vec![1, 1, 1, 1, 0, 0, 0, 0]), bits.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BitByteUnpacker {
+    order: BitOrder,
+}
+
+impl BitByteUnpacker {
+    /// Creating a new instance, emits the most significant bit first.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a bit order.
+    #[inline]
+    pub fn with_order(order: BitOrder) -> Self {
+        Self { order }
+    }
+}
+
+impl Converter for BitByteUnpacker {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend(self.order.bits(item).map(u8::from));
+        Ok(8)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+impl crate::sealed::Sealed for BitByteUnpacker {}
+
+impl ExactSizeConverter for BitByteUnpacker {}
+
+/// How to handle the last bits which do not fill a byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TailPolicy {
+    /// Padding the byte with zeros.
+    #[default]
+    Pad,
+    /// Returning [`PartialByteError`].
+    ///
+    /// [`PartialByteError`]: crate::converter::PartialByteError
+    Error,
+}
+
+/// An error for inputs which ended inside a byte, with [`TailPolicy::Error`].
+///
+/// [`TailPolicy::Error`]: crate::converter::TailPolicy::Error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialByteError {
+    bits: usize,
+}
+
+impl PartialByteError {
+    /// Returns the number of the last bits.
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+}
+
+impl fmt::Display for PartialByteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input ended with {} bits of a byte.", self.bits)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PartialByteError {}
+
+/// Packing each 8 bits into a byte.
+///
+/// This is the inverse of [`BitUnpacker`]. The last bits which do not fill a byte are handled by
+/// [`TailPolicy`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{BitOrder, BitPacker, TailPolicy};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let bits = [true, false, false, false, false, false, true, true, true, true];
+/// let bytes = ConvertedIterator::new(bits, BitPacker::new());
+/// assert_eq!(Ok(vec![0b1000_0011, 0b1100_0000]), bytes.collect());
+///
+/// let packer = BitPacker::with_order(BitOrder::LsbFirst).tail_policy(TailPolicy::Error);
+/// let mut bytes = ConvertedIterator::new(bits, packer);
+/// assert_eq!(Some(Ok(0b1100_0001)), bytes.next());
+/// assert_eq!(Some(2), bytes.next().and_then(|r| r.err()).map(|e| e.bits()));
+/// assert_eq!(None, bytes.next());
+/// ```
+///
+/// [`BitUnpacker`]: crate::converter::BitUnpacker
+/// [`TailPolicy`]: crate::converter::TailPolicy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BitPacker {
+    order: BitOrder,
+    policy: TailPolicy,
+    byte: u8,
+    count: usize,
+}
+
+impl BitPacker {
+    /// Creating a new instance, takes the most significant bit first and pads the last byte.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a bit order.
+    #[inline]
+    pub fn with_order(order: BitOrder) -> Self {
+        Self {
+            order,
+            ..Self::default()
+        }
+    }
+
+    /// Setting how to handle the last bits.
+    #[inline]
+    pub fn tail_policy(self, policy: TailPolicy) -> Self {
+        Self { policy, ..self }
+    }
+}
+
+impl Converter for BitPacker {
+    type Item = bool;
+    type Output = u8;
+    type Error = PartialByteError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item {
+            self.byte |= match self.order {
+                BitOrder::MsbFirst => 0x80 >> self.count,
+                BitOrder::LsbFirst => 1 << self.count,
+            };
+        }
+        self.count += 1;
+        if self.count < 8 {
+            return Ok(0);
+        }
+
+        buf.extend([core::mem::take(&mut self.byte)]);
+        self.count = 0;
+        Ok(1)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let byte = core::mem::take(&mut self.byte);
+        match (core::mem::take(&mut self.count), self.policy) {
+            (0, _) => Ok(0),
+            (_, TailPolicy::Pad) => {
+                buf.extend([byte]);
+                Ok(1)
+            }
+            (bits, TailPolicy::Error) => Err(PartialByteError { bits }),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(usize::from(self.count != 0))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn orders() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let bytes = [0b1010_0000, 0b0000_0110, 0xFF, 0x00];
+        let msb = "10100000_00000110_11111111_00000000";
+        let lsb = "00000101_01100000_11111111_00000000";
+        let expected = |s: &str| {
+            s.bytes()
+                .filter(|&b| b != b'_')
+                .map(|b| b - b'0')
+                .collect::<Vec<_>>()
+        };
+
+        for (order, pattern) in [(BitOrder::MsbFirst, msb), (BitOrder::LsbFirst, lsb)] {
+            let bits = ConvertedIterator::new(bytes, BitByteUnpacker::with_order(order));
+            assert_eq!((32, Some(32)), bits.size_hint());
+            assert_eq!(Ok(expected(pattern)), bits.collect::<Result<Vec<_>, _>>());
+
+            let bits = ConvertedIterator::new(bytes, BitUnpacker::with_order(order));
+            let ones = expected(pattern).into_iter().map(|b| b == 1).collect();
+            assert_eq!(Ok(ones), bits.collect::<Result<Vec<_>, _>>());
+        }
+
+        let mut bits = ConvertedIterator::new(bytes, BitUnpacker::new());
+        bits.nth(12);
+        assert_eq!((19, Some(19)), bits.size_hint());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pack() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let bytes = [0x00, 0xA5, 0x3C, 0xFF];
+        for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+            let bits = ConvertedIterator::new(bytes, BitUnpacker::with_order(order))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            for policy in [TailPolicy::Pad, TailPolicy::Error] {
+                let packer = BitPacker::with_order(order).tail_policy(policy);
+                let packed = ConvertedIterator::new(bits.iter().cloned(), packer)
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(bytes.to_vec()), packed);
+            }
+        }
+
+        let tail = [true, false, true];
+        let mut packer = BitPacker::new();
+        let mut buf = Vec::new();
+        for bit in tail {
+            assert_eq!(Ok(0), packer.convert(bit, &mut buf));
+        }
+        assert_eq!(Ok(1), packer.finish(&mut buf));
+        assert_eq!(vec![0b1010_0000], buf);
+
+        let mut packer = BitPacker::new().tail_policy(TailPolicy::Error);
+        for bit in tail {
+            assert_eq!(Ok(0), packer.convert(bit, &mut buf));
+        }
+        assert_eq!(Err(PartialByteError { bits: 3 }), packer.finish(&mut buf));
+        assert_eq!(Ok(0), packer.finish(&mut buf));
+        assert_eq!(vec![0b1010_0000], buf);
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::Converter;
+
+// an `Extend` implementation calling a function for each output.
+struct FnExtend<'a, O>(&'a mut dyn FnMut(O));
+
+impl<O> Extend<O> for FnExtend<'_, O> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = O>>(&mut self, iter: T) {
+        iter.into_iter().for_each(&mut *self.0)
+    }
+}
+
+// an object-safe version of `Converter`.
+trait DynConverter<I, O, E> {
+    fn convert_dyn(&mut self, item: I, buf: &mut dyn FnMut(O)) -> Result<usize, E>;
+    fn is_ended_dyn(&self) -> bool;
+    fn finalize_dyn(&mut self) -> Result<(), E>;
+    fn finish_dyn(&mut self, buf: &mut dyn FnMut(O)) -> Result<usize, E>;
+    fn size_hint_dyn(&self) -> (usize, Option<usize>);
+    fn finish_hint_dyn(&self) -> Option<usize>;
+}
+
+impl<C, E> DynConverter<C::Item, C::Output, E> for C
+where
+    C: Converter,
+    C::Error: Into<E>,
+{
+    #[inline]
+    fn convert_dyn(&mut self, item: C::Item, buf: &mut dyn FnMut(C::Output)) -> Result<usize, E> {
+        self.convert(item, &mut FnExtend(buf)).map_err(Into::into)
+    }
+
+    #[inline]
+    fn is_ended_dyn(&self) -> bool {
+        self.is_ended()
+    }
+
+    #[inline]
+    fn finalize_dyn(&mut self) -> Result<(), E> {
+        self.finalize().map_err(Into::into)
+    }
+
+    #[inline]
+    fn finish_dyn(&mut self, buf: &mut dyn FnMut(C::Output)) -> Result<usize, E> {
+        self.finish(&mut FnExtend(buf)).map_err(Into::into)
+    }
+
+    #[inline]
+    fn size_hint_dyn(&self) -> (usize, Option<usize>) {
+        self.size_hint()
+    }
+
+    #[inline]
+    fn finish_hint_dyn(&self) -> Option<usize> {
+        self.finish_hint()
+    }
+}
+
+/// A type-erased converter, to choose converters at runtime.
+///
+/// Errors of the inner converter are converted into `E` with [`Into`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf16::{UTF16EncodingError, UTF16LEDecoder};
+/// use conversion::converter::encoding::utf8::{UTF8DecodeError, UTF8Decoder};
+/// use conversion::converter::BoxConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct DecodeError;
+///
+/// impl From<UTF8DecodeError> for DecodeError {
+///     fn from(_: UTF8DecodeError) -> Self {
+///         DecodeError
+///     }
+/// }
+///
+/// impl From<UTF16EncodingError> for DecodeError {
+///     fn from(_: UTF16EncodingError) -> Self {
+///         DecodeError
+///     }
+/// }
+///
+/// let utf16 = false;
+/// let decoder: BoxConverter<u8, char, DecodeError> = if utf16 {
+///     BoxConverter::new(UTF16LEDecoder::new())
+/// } else {
+///     BoxConverter::new(UTF8Decoder::new())
+/// };
+/// let decoded = ConvertedIterator::new(b"stra\xc3\x9fe".into_iter().cloned(), decoder);
+///
+/// assert_eq!(Ok(String::from("straße")), decoded.collect());
+/// ```
+///
+/// [`Into`]: core::convert::Into
+pub struct BoxConverter<I, O, E> {
+    inner: Box<dyn DynConverter<I, O, E>>,
+}
+
+impl<I, O, E> fmt::Debug for BoxConverter<I, O, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BoxConverter").finish()
+    }
+}
+
+impl<I, O, E> BoxConverter<I, O, E> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new<C>(converter: C) -> Self
+    where
+        C: Converter<Item = I, Output = O> + 'static,
+        C::Error: Into<E>,
+    {
+        Self {
+            inner: Box::new(converter),
+        }
+    }
+}
+
+impl<I, O, E> Converter for BoxConverter<I, O, E> {
+    type Item = I;
+    type Output = O;
+    type Error = E;
+
+    #[inline]
+    fn convert<B>(&mut self, item: Self::Item, buf: &mut B) -> Result<usize, Self::Error>
+    where
+        B: Extend<Self::Output>,
+    {
+        self.inner.convert_dyn(item, &mut |o| buf.extend([o]))
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.inner.is_ended_dyn()
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.inner.finalize_dyn()
+    }
+
+    #[inline]
+    fn finish<B>(&mut self, buf: &mut B) -> Result<usize, Self::Error>
+    where
+        B: Extend<Self::Output>,
+    {
+        self.inner.finish_dyn(&mut |o| buf.extend([o]))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint_dyn()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint_dyn()
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+/// A FIFO buffer for outputs of converters, backed by [`Vec`]s.
+///
+/// Outputs are extended in batches and popped one by one from the front, like iterators in
+/// [`iter`] do. Extended items are appended to a vector in order, and moved to another vector in
+/// reverse order only when it is drained, so extending is a plain vector extension, popping is a
+/// plain vector pop, and each item is moved once.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Encoder;
+/// use conversion::converter::BatchExtend;
+/// use conversion::Converter;
+///
+/// let mut encoder = UTF8Encoder::new();
+/// let mut buf = BatchExtend::with_capacity(4);
+/// encoder.convert('ß', &mut buf).unwrap();
+/// encoder.convert('!', &mut buf).unwrap();
+///
+/// assert_eq!(3, buf.len());
+/// assert_eq!(Some(0xC3), buf.pop_front());
+/// assert_eq!(vec![0x9F, 0x21], Vec::from(buf));
+/// ```
+///
+/// [`Vec`]: alloc::vec::Vec
+/// [`iter`]: crate::iter
+#[derive(Clone)]
+pub struct BatchExtend<O> {
+    // items to be popped first, in reverse order, the front at the end.
+    front: Vec<O>,
+    // items extended after them, in order.
+    back: Vec<O>,
+}
+
+impl<O> Default for BatchExtend<O> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            front: Vec::new(),
+            back: Vec::new(),
+        }
+    }
+}
+
+impl<O: fmt::Debug> fmt::Debug for BatchExtend<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<O: PartialEq> PartialEq for BatchExtend<O> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<O: Eq> Eq for BatchExtend<O> {}
+
+impl<O: Hash> Hash for BatchExtend<O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<O> BatchExtend<O> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a capacity, like [`size_hint`] of converters.
+    ///
+    /// [`size_hint`]: crate::Converter::size_hint
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            front: Vec::new(),
+            back: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of buffered items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns `true` if no items are buffered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Returns the number of items the buffer can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.front.capacity() + self.back.capacity()
+    }
+
+    /// Removing the first item.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<O> {
+        if self.front.is_empty() {
+            // the drained vector keeps its capacity for following extensions.
+            self.back.reverse();
+            core::mem::swap(&mut self.front, &mut self.back);
+        }
+        self.front.pop()
+    }
+
+    /// Removing all items, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.front.clear();
+        self.back.clear();
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &O> {
+        self.front.iter().rev().chain(self.back.iter())
+    }
+}
+
+impl<O> Extend<O> for BatchExtend<O> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = O>>(&mut self, iter: T) {
+        self.back.extend(iter);
+    }
+}
+
+impl<'a, O: Copy + 'a> Extend<&'a O> for BatchExtend<O> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = &'a O>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<O> From<BatchExtend<O>> for Vec<O> {
+    #[inline]
+    fn from(buf: BatchExtend<O>) -> Self {
+        let mut items = buf.front;
+        items.reverse();
+        items.extend(buf.back);
+        items
+    }
+}
+
+impl<O> From<BatchExtend<O>> for VecDeque<O> {
+    #[inline]
+    fn from(buf: BatchExtend<O>) -> Self {
+        VecDeque::from(Vec::from(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fifo() {
+        let mut buf = BatchExtend::new();
+        let mut expected = VecDeque::new();
+        for n in 0..50u32 {
+            let batch = (0..n % 7).map(|i| n * 10 + i);
+            buf.extend(batch.clone());
+            expected.extend(batch);
+            for _ in 0..n % 3 {
+                assert_eq!(expected.pop_front(), buf.pop_front());
+            }
+            assert_eq!(expected.len(), buf.len());
+        }
+        buf.extend(&[1, 2]);
+        expected.extend(&[1, 2]);
+        assert_eq!(expected, VecDeque::from(buf));
+    }
+
+    #[test]
+    fn eq_after_pop() {
+        let mut popped = BatchExtend::new();
+        popped.extend([0, 1, 2]);
+        assert_eq!(Some(0), popped.pop_front());
+        popped.extend([3]);
+
+        let mut extended = BatchExtend::new();
+        extended.extend([1, 2, 3]);
+        assert_eq!(extended, popped);
+        assert_eq!("[1, 2, 3]", alloc::format!("{:?}", popped));
+    }
+}
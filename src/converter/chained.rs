@@ -1,37 +1,6 @@
-use core::fmt;
-
+use crate::error::ChainedError;
 use crate::Converter;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ChainedError<E, F> {
-    First(E),
-    Second(F),
-}
-
-impl<E: fmt::Display, F: fmt::Display> fmt::Display for ChainedError<E, F> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::First(e) => write!(f, "{}", e),
-            Self::Second(e) => write!(f, "{}", e),
-        }
-    }
-}
-
-#[cfg(feature = "std")]
-impl<E, F> std::error::Error for ChainedError<E, F>
-where
-    E: std::error::Error + 'static,
-    F: std::error::Error + 'static,
-{
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            Self::First(e) => Some(e),
-            Self::Second(e) => Some(e),
-        }
-    }
-}
-
 /// A converter for [`chain`] method.
 ///
 /// [`chain`]: crate::Converter::chain
@@ -75,8 +44,12 @@ where
         match self.first.convert(item.clone(), buf) {
             Ok(0) if self.first.is_ended() => {
                 self.first_ended = true;
-                self.first.finalize().map_err(ChainedError::First)?;
-                self.second.convert(item, buf).map_err(ChainedError::Second)
+                let first = self.first.finish(buf).map_err(ChainedError::First)?;
+                let second = self
+                    .second
+                    .convert(item, buf)
+                    .map_err(ChainedError::Second)?;
+                Ok(first + second)
             }
             other => other.map_err(ChainedError::First),
         }
@@ -92,8 +65,61 @@ where
         self.second.finalize().map_err(ChainedError::Second)
     }
 
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        if !self.first_ended {
+            self.first_ended = true;
+            len += self.first.finish(buf).map_err(ChainedError::First)?;
+        }
+        Ok(len + self.second.finish(buf).map_err(ChainedError::Second)?)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        let second = self.second.finish_hint();
+        if self.first_ended {
+            second
+        } else {
+            self.first.finish_hint().zip(second).map(|(x, y)| x + y)
+        }
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(0))
+        if self.first_ended {
+            return self.second.size_hint();
+        }
+        // the first converter may end on this item, emitting outputs of its `finish` and of the
+        // second converter instead.
+        let (min, first_max) = self.first.size_hint();
+        let (_, second_max) = self.second.size_hint();
+        let ending_max = self.first.finish_hint().zip(second_max).map(|(x, y)| x + y);
+        (min, first_max.zip(ending_max).map(|(x, y)| x.max(y)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn size_hint() {
+        use crate::converter::encoding::utf8::UTF8Decoder;
+        use crate::converter::ExactConverter;
+        use alloc::vec::Vec;
+
+        // the first converter ends on `a`, which the second converter decodes.
+        let mut conv = ExactConverter::new([0xEF, 0xBB, 0xBF]).chain(UTF8Decoder::new());
+        let mut buf = Vec::new();
+        for item in [0xEF, 0xBB, 0xBF, b'a', b'b'] {
+            let (min, max) = conv.size_hint();
+            let len = conv.convert(item, &mut buf).unwrap();
+            assert!(min <= len && max.is_none_or(|max| len <= max), "{}", item);
+        }
+        assert_eq!(2, buf.len());
     }
 }
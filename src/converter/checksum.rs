@@ -0,0 +1,9 @@
+//! Converters appending/verifying checksums.
+
+mod crc32;
+mod digest;
+mod luhn;
+
+pub use self::digest::{Adler32, Digest, DigestConverter, Fletcher16};
+pub use crc32::{CRC32Appender, CRC32Error, CRC32Verifier, CRC32};
+pub use luhn::{LuhnAppender, LuhnError, LuhnVerifier};
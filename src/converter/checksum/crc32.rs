@@ -0,0 +1,404 @@
+use super::Digest;
+use crate::converter::encoding::Endianness;
+use crate::Converter;
+use core::fmt;
+
+// the reversed IEEE 802.3 polynomial.
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+const TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// The CRC-32 checksum, with the IEEE polynomial as Ethernet, gzip and PNG use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CRC32(u32);
+
+impl CRC32 {
+    const INIT: Self = Self(0xFFFF_FFFF);
+
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::INIT
+    }
+
+    #[inline]
+    fn push(&mut self, byte: u8) {
+        self.0 = TABLE[((self.0 ^ byte as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+    }
+
+    #[inline]
+    fn value(self) -> u32 {
+        !self.0
+    }
+}
+
+impl Default for CRC32 {
+    #[inline]
+    fn default() -> Self {
+        Self::INIT
+    }
+}
+
+impl Digest for CRC32 {
+    type Output = u32;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|&byte| self.push(byte));
+    }
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        self.value()
+    }
+}
+
+#[inline]
+fn to_bytes(crc: u32, endianness: Endianness) -> [u8; 4] {
+    match endianness {
+        Endianness::Big => crc.to_be_bytes(),
+        Endianness::Little => crc.to_le_bytes(),
+    }
+}
+
+/// An error while verifying CRC-32 checksums.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CRC32Error {
+    /// The checksum did not match, with the expected (trailing) one and the actual (computed)
+    /// one.
+    Mismatch(u32, u32),
+    /// The input was shorter than a checksum, with the length of it.
+    TooShort(usize),
+}
+
+impl fmt::Display for CRC32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch(expected, actual) => write!(
+                f,
+                "CRC-32 mismatch: expected 0x{:08X}, actual 0x{:08X}.",
+                expected, actual
+            ),
+            Self::TooShort(len) => write!(f, "the input is too short: {} bytes.", len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CRC32Error {}
+
+/// A converter passing bytes through, and appending the CRC-32 (IEEE) checksum of them at the end.
+///
+/// The checksum is appended in little-endian by default, as Ethernet and gzip do.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::checksum::CRC32Appender;
+/// use conversion::converter::encoding::Endianness;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let appended = ConvertedIterator::new(*b"123456789", CRC32Appender::new());
+/// assert_eq!(
+///     Ok(b"123456789\x26\x39\xF4\xCB".to_vec()),
+///     appended.collect::<Result<Vec<_>, _>>(),
+/// );
+///
+/// let appender = CRC32Appender::with_endianness(Endianness::Big);
+/// let appended = ConvertedIterator::new(*b"123456789", appender);
+/// assert_eq!(
+///     Ok(b"123456789\xCB\xF4\x39\x26".to_vec()),
+///     appended.collect::<Result<Vec<_>, _>>(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CRC32Appender {
+    endianness: Endianness,
+    state: CRC32,
+}
+
+impl CRC32Appender {
+    /// Creating a new instance, appends checksums in little-endian.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_endianness(Endianness::Little)
+    }
+
+    /// Creating a new instance with a byte order of checksums.
+    #[inline]
+    pub fn with_endianness(endianness: Endianness) -> Self {
+        Self {
+            endianness,
+            state: CRC32::INIT,
+        }
+    }
+
+    /// Returning the checksum of bytes passed so far.
+    #[inline]
+    pub fn checksum(&self) -> u32 {
+        self.state.value()
+    }
+}
+
+impl Default for CRC32Appender {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for CRC32Appender {
+    type Item = u8;
+    type Output = u8;
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.state.push(item);
+        buf.extend([item]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let crc = core::mem::take(&mut self.state).value();
+        buf.extend(to_bytes(crc, self.endianness));
+        Ok(4)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+/// A converter verifying and stripping the trailing CRC-32 (IEEE) checksum of bytes.
+///
+/// The last 4 bytes are held back until more bytes come, since they may be the checksum, so
+/// outputs lag 4 bytes behind inputs. A mismatch is reported at the end of inputs, after all
+/// payload bytes are emitted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::checksum::{CRC32Error, CRC32Verifier};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let verified = ConvertedIterator::new(*b"123456789\x26\x39\xF4\xCB", CRC32Verifier::new());
+/// assert_eq!(Ok(b"123456789".to_vec()), verified.collect::<Result<Vec<_>, _>>());
+///
+/// let verified = ConvertedIterator::new(*b"123456780\x26\x39\xF4\xCB", CRC32Verifier::new());
+/// assert_eq!(
+///     Err(CRC32Error::Mismatch(0xCBF43926, 0xB2288182)),
+///     verified.collect::<Result<Vec<_>, _>>(),
+/// );
+///
+/// let verified = ConvertedIterator::new(*b"\x00\x00\x00", CRC32Verifier::new());
+/// assert_eq!(Err(CRC32Error::TooShort(3)), verified.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CRC32Verifier {
+    endianness: Endianness,
+    state: CRC32,
+    // the last bytes, which may be the checksum. (a ring buffer starting at `head`)
+    tail: [u8; 4],
+    head: usize,
+    len: usize,
+}
+
+impl CRC32Verifier {
+    /// Creating a new instance, expects checksums in little-endian.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_endianness(Endianness::Little)
+    }
+
+    /// Creating a new instance with a byte order of checksums.
+    #[inline]
+    pub fn with_endianness(endianness: Endianness) -> Self {
+        Self {
+            endianness,
+            state: CRC32::INIT,
+            tail: [0; 4],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl Default for CRC32Verifier {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter for CRC32Verifier {
+    type Item = u8;
+    type Output = u8;
+    type Error = CRC32Error;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.len < 4 {
+            self.tail[self.len] = item;
+            self.len += 1;
+            return Ok(0);
+        }
+        // the oldest byte can no longer be a part of the checksum.
+        let byte = core::mem::replace(&mut self.tail[self.head], item);
+        self.head = (self.head + 1) % 4;
+        self.state.push(byte);
+        buf.extend([byte]);
+        Ok(1)
+    }
+
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        let Self {
+            endianness,
+            state,
+            tail,
+            head,
+            len,
+        } = core::mem::replace(self, Self::with_endianness(self.endianness));
+        if len < 4 {
+            return Err(CRC32Error::TooShort(len));
+        }
+        let mut bytes = tail;
+        bytes.rotate_left(head);
+        let expected = match endianness {
+            Endianness::Big => u32::from_be_bytes(bytes),
+            Endianness::Little => u32::from_le_bytes(bytes),
+        };
+        let actual = state.value();
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(CRC32Error::Mismatch(expected, actual))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random_bytes;
+        use alloc::vec::Vec;
+
+        for endianness in [Endianness::Big, Endianness::Little] {
+            for len in [0, 1, 3, 4, 5, 8, 100, 1000] {
+                let payload = random_bytes(len);
+                let appender = CRC32Appender::with_endianness(endianness);
+                let verifier = CRC32Verifier::with_endianness(endianness);
+                let framed = ConvertedIterator::new(payload.iter().cloned(), appender)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+                assert_eq!(len + 4, framed.len());
+                let verified =
+                    ConvertedIterator::new(framed, verifier).collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(payload), verified);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn corrupted() {
+        use crate::test_util::random_bytes;
+        use alloc::vec::Vec;
+
+        let payload = random_bytes(64);
+        let mut appender = CRC32Appender::new();
+        let mut framed = Vec::new();
+        for byte in payload.iter().cloned() {
+            appender.convert(byte, &mut framed).unwrap();
+        }
+        let checksum = appender.checksum();
+        appender.finish(&mut framed).unwrap();
+
+        // flipping each bit of the payload and the checksum.
+        for i in 0..framed.len() * 8 {
+            let mut corrupted = framed.clone();
+            corrupted[i / 8] ^= 1 << (i % 8);
+            let mut verifier = CRC32Verifier::new();
+            let mut verified = Vec::new();
+            for byte in corrupted.iter().cloned() {
+                assert_eq!(Ok(()), verifier.convert(byte, &mut verified).map(|_| ()));
+            }
+            assert_eq!(&corrupted[..64], &verified[..]);
+            match verifier.finalize() {
+                Err(CRC32Error::Mismatch(expected, actual)) if i < 64 * 8 => {
+                    assert_eq!(checksum, expected);
+                    assert_ne!(checksum, actual);
+                }
+                Err(CRC32Error::Mismatch(expected, actual)) => {
+                    assert_ne!(checksum, expected);
+                    assert_eq!(checksum, actual);
+                }
+                result => panic!("unexpected result at bit {}: {:?}", i, result),
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn too_short() {
+        use alloc::vec::Vec;
+
+        let mut verifier = CRC32Verifier::new();
+        let mut buf = Vec::new();
+        assert_eq!(Err(CRC32Error::TooShort(0)), verifier.finalize());
+        for len in 1..4 {
+            assert_eq!(Ok(0), verifier.convert(0, &mut buf));
+            assert_eq!(Err(CRC32Error::TooShort(len)), verifier.clone().finalize());
+        }
+        assert_eq!(Ok(0), verifier.convert(0, &mut buf));
+        // the CRC-32 of an empty input is zero.
+        assert_eq!(Ok(()), verifier.finalize());
+        assert!(buf.is_empty());
+
+        // the state is reset after finalizing.
+        assert_eq!(Ok(0), verifier.convert(0, &mut buf));
+        assert_eq!(Err(CRC32Error::TooShort(1)), verifier.finalize());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::Converter;
+use core::convert::Infallible;
+
+/// Algorithms computing digests of bytes incrementally.
+pub trait Digest {
+    /// The type of digests.
+    type Output;
+
+    /// Feeding bytes to the digest.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returning the digest of bytes fed so far.
+    fn finish(&self) -> Self::Output;
+}
+
+/// Bridging hash functions of the [`digest`](::digest) crate, like SHA-256 in `sha2`.
+///
+/// The state is cloned to finalize, so that bytes can be fed after retrieving the digest.
+#[cfg(feature = "digest")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "digest")))]
+impl<D: ::digest::Digest + Clone> Digest for D {
+    type Output = ::digest::Output<D>;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        ::digest::Digest::update(self, bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> Self::Output {
+        self.clone().finalize()
+    }
+}
+
+/// The Adler-32 checksum, as zlib uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MODULUS: u32 = 65521;
+
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Digest for Adler32 {
+    type Output = u32;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.a = (self.a + byte as u32) % Self::MODULUS;
+            self.b = (self.b + self.a) % Self::MODULUS;
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+/// The Fletcher-16 checksum.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct Fletcher16 {
+    sum1: u16,
+    sum2: u16,
+}
+
+impl Fletcher16 {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Digest for Fletcher16 {
+    type Output = u16;
+
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.sum1 = (self.sum1 + byte as u16) % 255;
+            self.sum2 = (self.sum2 + self.sum1) % 255;
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u16 {
+        (self.sum2 << 8) | self.sum1
+    }
+}
+
+/// Passing bytes through unchanged, while computing the digest of them.
+///
+/// The digest is not reset at the end of inputs, so it can be retrieved by [`digest`] after
+/// converting.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::checksum::{Adler32, DigestConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut iter = ConvertedIterator::new(*b"Wikipedia", DigestConverter::new(Adler32::new()));
+/// let passed = iter.by_ref().collect::<Result<Vec<_>, _>>();
+/// assert_eq!(Ok(b"Wikipedia".to_vec()), passed);
+///
+/// let (_, converter) = iter.into_parts();
+/// assert_eq!(0x11E60398, converter.digest());
+/// ```
+///
+/// [`digest`]: Self::digest
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct DigestConverter<D> {
+    digest: D,
+}
+
+impl<D: Digest> DigestConverter<D> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(digest: D) -> Self {
+        Self { digest }
+    }
+
+    /// Returning the digest of bytes passed so far.
+    #[inline]
+    pub fn digest(&self) -> D::Output {
+        self.digest.finish()
+    }
+
+    /// Returning the inner digest.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.digest
+    }
+}
+
+impl<D: Digest> Converter for DigestConverter<D> {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.digest.update(&[item]);
+        buf.extend([item]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::CRC32;
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    fn check<D>(digest: D, vectors: &[(&[u8], D::Output)])
+    where
+        D: Digest + Clone,
+        D::Output: PartialEq + core::fmt::Debug,
+    {
+        use crate::iter::ConvertedIterator;
+
+        for (input, expected) in vectors {
+            let mut iter =
+                ConvertedIterator::new(input.iter().cloned(), DigestConverter::new(digest.clone()));
+            assert!(iter.by_ref().map(Result::unwrap).eq(input.iter().cloned()));
+            let (_, converter) = iter.into_parts();
+            assert_eq!(*expected, converter.digest(), "{:?}", input);
+
+            // feeding all bytes at once.
+            let mut digest = digest.clone();
+            digest.update(input);
+            assert_eq!(*expected, digest.finish());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vectors() {
+        check(
+            CRC32::new(),
+            &[
+                (b"", 0),
+                (b"a", 0xE8B7BE43),
+                (b"123456789", 0xCBF43926),
+                (b"The quick brown fox jumps over the lazy dog", 0x414FA339),
+            ],
+        );
+        check(
+            Adler32::new(),
+            &[
+                (b"", 1),
+                (b"a", 0x00620062),
+                (b"Wikipedia", 0x11E60398),
+                (&[0xFF; 5553], 0x8E299C8B),
+            ],
+        );
+        check(
+            Fletcher16::new(),
+            &[
+                (b"", 0),
+                (b"abcde", 0xC8F0),
+                (b"abcdef", 0x2057),
+                (b"abcdefgh", 0x0627),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "digest"))]
+    fn digest_crate() {
+        use sha2::{Digest as _, Sha256};
+
+        let inputs: [&[u8]; 3] = [b"", b"abc", b"The quick brown fox jumps over the lazy dog"];
+        for input in inputs {
+            check(Sha256::new(), &[(input, Sha256::digest(input))]);
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::Converter;
+use core::fmt;
+
+/// An error while computing/verifying Luhn check digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LuhnError {
+    /// A value which is not a decimal digit.
+    InvalidDigit(u8),
+    /// The check digit did not match, with the expected (trailing) one and the actual (computed)
+    /// one.
+    Mismatch(u8, u8),
+    /// The input was empty, without a check digit.
+    Empty,
+}
+
+impl fmt::Display for LuhnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(d) => write!(f, "invalid decimal digit: {}", d),
+            Self::Mismatch(expected, actual) => write!(
+                f,
+                "Luhn check digit mismatch: expected {}, actual {}.",
+                expected, actual
+            ),
+            Self::Empty => write!(f, "the input is empty."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LuhnError {}
+
+// the running sums of the Luhn algorithm.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Sums {
+    // the sum (mod 10) if the last digit is doubled, and the one if it is not.
+    doubled: u8,
+    single: u8,
+}
+
+impl Sums {
+    #[inline]
+    fn push(&mut self, digit: u8) -> Result<(), LuhnError> {
+        if digit > 9 {
+            return Err(LuhnError::InvalidDigit(digit));
+        }
+        let doubled = match digit * 2 {
+            d if d > 9 => d - 9,
+            d => d,
+        };
+        *self = Self {
+            doubled: (self.single + doubled) % 10,
+            single: (self.doubled + digit) % 10,
+        };
+        Ok(())
+    }
+
+    // the check digit to be appended.
+    #[inline]
+    fn check_digit(self) -> u8 {
+        (10 - self.doubled) % 10
+    }
+}
+
+/// A converter passing decimal digits through, and appending the Luhn check digit at the end.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::checksum::{LuhnAppender, LuhnError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let appended = ConvertedIterator::new([7, 9, 9, 2, 7, 3, 9, 8, 7, 1], LuhnAppender::new());
+/// assert_eq!(Ok(vec![7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]), appended.collect());
+///
+/// let mut appended = ConvertedIterator::new([1, 10], LuhnAppender::new());
+/// assert_eq!(Some(Ok(1)), appended.next());
+/// assert_eq!(Some(Err(LuhnError::InvalidDigit(10))), appended.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LuhnAppender {
+    sums: Sums,
+}
+
+impl LuhnAppender {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returning the check digit of digits passed so far.
+    #[inline]
+    pub fn check_digit(&self) -> u8 {
+        self.sums.check_digit()
+    }
+}
+
+impl Converter for LuhnAppender {
+    type Item = u8;
+    type Output = u8;
+    type Error = LuhnError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.sums.push(item)?;
+        buf.extend([item]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([core::mem::take(&mut self.sums).check_digit()]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A converter verifying and stripping the trailing Luhn check digit of decimal digits.
+///
+/// The last digit is held back until the next digit comes, since it may be the check digit. A
+/// mismatch is reported at the end of inputs, after all other digits are emitted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::checksum::{LuhnError, LuhnVerifier};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let verified = ConvertedIterator::new([7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3], LuhnVerifier::new());
+/// assert_eq!(Ok(vec![7, 9, 9, 2, 7, 3, 9, 8, 7, 1]), verified.collect());
+///
+/// let verified = ConvertedIterator::new([7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 4], LuhnVerifier::new());
+/// assert_eq!(Err(LuhnError::Mismatch(4, 3)), verified.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LuhnVerifier {
+    sums: Sums,
+    // the last digit, which may be the check digit.
+    last: Option<u8>,
+}
+
+impl LuhnVerifier {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for LuhnVerifier {
+    type Item = u8;
+    type Output = u8;
+    type Error = LuhnError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item > 9 {
+            return Err(LuhnError::InvalidDigit(item));
+        }
+        match self.last.replace(item) {
+            Some(digit) => {
+                self.sums.push(digit)?;
+                buf.extend([digit]);
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        let Self { sums, last } = core::mem::take(self);
+        let expected = last.ok_or(LuhnError::Empty)?;
+        let actual = sums.check_digit();
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(LuhnError::Mismatch(expected, actual))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vectors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        // well-known test card numbers, and IMEI.
+        let vectors = [
+            "4111111111111111",
+            "5500005555555559",
+            "378282246310005",
+            "6011111111111117",
+            "490154203237518",
+            "0",
+            "18",
+        ];
+        for number in vectors {
+            let digits = number.bytes().map(|b| b - b'0').collect::<Vec<_>>();
+            let (payload, check) = digits.split_at(digits.len() - 1);
+
+            let appended = ConvertedIterator::new(payload.iter().cloned(), LuhnAppender::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(digits.clone()), appended, "{}", number);
+            let verified = ConvertedIterator::new(digits.iter().cloned(), LuhnVerifier::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(payload.to_vec()), verified, "{}", number);
+
+            // every single-digit error is detected.
+            for i in 0..digits.len() {
+                for d in (0..10).filter(|&d| d != digits[i]) {
+                    let mut corrupted = digits.clone();
+                    corrupted[i] = d;
+                    let verified =
+                        ConvertedIterator::new(corrupted, LuhnVerifier::new()).collect::<Vec<_>>();
+                    match verified.last() {
+                        Some(Err(LuhnError::Mismatch(expected, _))) => {
+                            assert_eq!(if i + 1 == digits.len() { d } else { check[0] }, *expected)
+                        }
+                        result => panic!("undetected error in {}: {:?}", number, result),
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use alloc::vec::Vec;
+
+        let mut verifier = LuhnVerifier::new();
+        let mut buf = Vec::new();
+        assert_eq!(Err(LuhnError::Empty), verifier.finalize());
+        assert_eq!(
+            Err(LuhnError::InvalidDigit(b'1')),
+            verifier.convert(b'1', &mut buf)
+        );
+        assert_eq!(Ok(0), verifier.convert(1, &mut buf));
+        assert_eq!(Ok(1), verifier.convert(8, &mut buf));
+        assert_eq!(Ok(()), verifier.finalize());
+        assert_eq!(&[1], &buf[..]);
+        // the state is reset after finalizing.
+        assert_eq!(Err(LuhnError::Empty), verifier.finalize());
+
+        let mut appender = LuhnAppender::new();
+        assert_eq!(Ok(1), appender.convert(1, &mut buf));
+        assert_eq!(8, appender.check_digit());
+        assert_eq!(Ok(1), appender.finish(&mut buf));
+        assert_eq!(0, appender.check_digit());
+        assert_eq!(&[1, 1, 8], &buf[..]);
+    }
+}
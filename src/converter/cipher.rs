@@ -0,0 +1,12 @@
+//! Converters for simple ciphers.
+//!
+//! These are meant for obfuscation and legacy protocols, not for security.
+
+#[cfg(feature = "alloc")]
+mod vigenere;
+mod xor;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use vigenere::{VigenereDecoder, VigenereEncoder, VigenereKeyError};
+pub use xor::{KeystreamExhaustedError, XorConverter};
@@ -0,0 +1,242 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// An error for invalid keys of the Vigenère cipher.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VigenereKeyError {
+    /// The key is empty.
+    Empty,
+    /// The key contains a character which is not an ASCII letter.
+    InvalidChar(char),
+}
+
+impl fmt::Display for VigenereKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the key is empty."),
+            Self::InvalidChar(c) => write!(f, "the key contains a non-letter: {:?}", c),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VigenereKeyError {}
+
+// shifts of the key, with the position of the next shift.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    shifts: Vec<u8>,
+    position: usize,
+}
+
+impl Key {
+    fn new(key: &str) -> Result<Self, VigenereKeyError> {
+        if key.is_empty() {
+            return Err(VigenereKeyError::Empty);
+        }
+        let shifts = key
+            .chars()
+            .map(|c| match c {
+                'A'..='Z' => Ok(c as u8 - b'A'),
+                'a'..='z' => Ok(c as u8 - b'a'),
+                _ => Err(VigenereKeyError::InvalidChar(c)),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            shifts,
+            position: 0,
+        })
+    }
+
+    // shifting a letter forward by `shift(key)`, and advancing the key.
+    fn apply(&mut self, c: char, shift: impl Fn(u8) -> u8) -> char {
+        let base = match c {
+            'A'..='Z' => b'A',
+            'a'..='z' => b'a',
+            _ => return c,
+        };
+        let k = shift(self.shifts[self.position]);
+        self.position = (self.position + 1) % self.shifts.len();
+        (base + (c as u8 - base + k) % 26) as char
+    }
+}
+
+/// Encrypting ASCII letters with the Vigenère cipher.
+///
+/// Each letter is shifted by the position of the next key letter in the alphabet, keeping its
+/// case. The key advances only on letters, and other characters are passed through unchanged.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::cipher::VigenereEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoder = VigenereEncoder::new("LEMON").unwrap();
+/// let encrypted = ConvertedIterator::new("Attack at dawn!".chars(), encoder);
+/// assert_eq!(Ok(String::from("Lxfopv ef rnhr!")), encrypted.collect());
+/// ```
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VigenereEncoder {
+    key: Key,
+}
+
+impl VigenereEncoder {
+    /// Creating a new instance.
+    ///
+    /// The key must be non-empty ASCII letters, which are case-insensitive.
+    #[inline]
+    pub fn new(key: &str) -> Result<Self, VigenereKeyError> {
+        Key::new(key).map(|key| Self { key })
+    }
+
+    /// Restarting the key from the beginning, to process another message.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.key.position = 0;
+    }
+}
+
+impl Converter for VigenereEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([self.key.apply(item, |k| k)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for VigenereEncoder {}
+
+impl ExactSizeConverter for VigenereEncoder {}
+
+/// Decrypting ASCII letters with the Vigenère cipher.
+///
+/// This is the inverse of [`VigenereEncoder`] with the same key.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::cipher::{VigenereDecoder, VigenereKeyError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoder = VigenereDecoder::new("lemon").unwrap();
+/// let decrypted = ConvertedIterator::new("Lxfopv ef rnhr!".chars(), decoder);
+/// assert_eq!(Ok(String::from("Attack at dawn!")), decrypted.collect());
+///
+/// assert_eq!(Err(VigenereKeyError::InvalidChar(' ')), VigenereDecoder::new("two words"));
+/// ```
+///
+/// [`VigenereEncoder`]: crate::converter::cipher::VigenereEncoder
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VigenereDecoder {
+    key: Key,
+}
+
+impl VigenereDecoder {
+    /// Creating a new instance.
+    ///
+    /// The key must be non-empty ASCII letters, which are case-insensitive.
+    #[inline]
+    pub fn new(key: &str) -> Result<Self, VigenereKeyError> {
+        Key::new(key).map(|key| Self { key })
+    }
+
+    /// Restarting the key from the beginning, to process another message.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.key.position = 0;
+    }
+}
+
+impl Converter for VigenereDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([self.key.apply(item, |k| 26 - k)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for VigenereDecoder {}
+
+impl ExactSizeConverter for VigenereDecoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let vectors = [
+            ("LEMON", "ATTACKATDAWN", "LXFOPVEFRNHR"),
+            ("key", "Hello, World!", "Rijvs, Uyvjn!"),
+            ("a", "unchanged", "unchanged"),
+            ("Z", "abc xyz", "zab wxy"),
+            ("key", "", ""),
+        ];
+        for (key, plain, cipher) in vectors {
+            let encoder = VigenereEncoder::new(key).unwrap();
+            let encrypted =
+                ConvertedIterator::new(plain.chars(), encoder).collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(cipher)), encrypted);
+
+            let decoder = VigenereDecoder::new(key).unwrap();
+            let decrypted =
+                ConvertedIterator::new(cipher.chars(), decoder).collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(plain)), decrypted);
+        }
+    }
+
+    #[test]
+    fn key() {
+        use alloc::string::String;
+
+        assert_eq!(Err(VigenereKeyError::Empty), VigenereEncoder::new(""));
+        assert_eq!(
+            Err(VigenereKeyError::InvalidChar('1')),
+            VigenereEncoder::new("abc1")
+        );
+        assert_eq!(
+            Err(VigenereKeyError::InvalidChar('é')),
+            VigenereDecoder::new("clé")
+        );
+
+        let mut encoder = VigenereEncoder::new("bc").unwrap();
+        let mut buf = String::new();
+        assert_eq!(Ok(1), encoder.convert('a', &mut buf));
+        encoder.reset();
+        assert_eq!(Ok(1), encoder.convert('a', &mut buf));
+        assert_eq!(Ok(1), encoder.convert('-', &mut buf));
+        assert_eq!(Ok(1), encoder.convert('a', &mut buf));
+        assert_eq!("bb-c", buf);
+    }
+}
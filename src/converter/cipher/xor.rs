@@ -0,0 +1,235 @@
+use core::fmt;
+use core::iter::{Copied, Cycle};
+use core::slice;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// An error for inputs after a finite keystream ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeystreamExhaustedError;
+
+impl fmt::Display for KeystreamExhaustedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The keystream is exhausted.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeystreamExhaustedError {}
+
+/// XORing each byte with the next byte of a keystream.
+///
+/// The keystream is a repeating key, or any iterator of bytes, like outputs of a PRNG. Applying
+/// the converter twice with the same keystream restores the input.
+///
+/// The converter keeps the original keystream for [`reset`], so keystream iterators must be
+/// [`Clone`], and cloning must restart the same sequence from that point. If a finite keystream
+/// ends, each of the rest of the input is reported as [`KeystreamExhaustedError`] instead of
+/// being passed through unencrypted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::cipher::XorConverter;
+/// use conversion::iter::ConvertedIterator;
+/// use conversion::Converter;
+///
+/// let iter = b"Hello".iter().cloned();
+/// let encrypted = ConvertedIterator::new(iter, XorConverter::with_static_key(b"key"));
+/// assert_eq!(Ok(vec![0x23, 0x00, 0x15, 0x07, 0x0A]), encrypted.collect());
+///
+/// // a keystream of a linear congruential generator.
+/// let lcg = core::iter::successors(Some(1u32), |x| {
+///     Some(x.wrapping_mul(1103515245).wrapping_add(12345))
+/// });
+/// let mut xor = XorConverter::from_keystream(lcg.map(|x| (x >> 16) as u8));
+/// let mut encrypted = Vec::new();
+/// for b in *b"secret" {
+///     xor.convert(b, &mut encrypted).unwrap();
+/// }
+///
+/// xor.reset();
+/// let decrypted = ConvertedIterator::new(encrypted, xor);
+/// assert_eq!(Ok(b"secret".to_vec()), decrypted.collect());
+/// ```
+///
+/// [`reset`]: Self::reset
+/// [`Clone`]: core::clone::Clone
+/// [`KeystreamExhaustedError`]: self::KeystreamExhaustedError
+#[derive(Clone, Debug)]
+pub struct XorConverter<I> {
+    origin: I,
+    keystream: I,
+    position: usize,
+}
+
+impl<I: Iterator<Item = u8> + Clone> XorConverter<I> {
+    /// Creating a new instance with a keystream.
+    #[inline]
+    pub fn from_keystream(keystream: I) -> Self {
+        Self {
+            origin: keystream.clone(),
+            keystream,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes converted since the keystream started.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Restarting the keystream from the beginning, to process another message.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.keystream = self.origin.clone();
+        self.position = 0;
+    }
+}
+
+impl XorConverter<Cycle<Copied<slice::Iter<'static, u8>>>> {
+    /// Creating a new instance with a repeating key.
+    ///
+    /// # Panics
+    /// Panics if `key` is empty.
+    #[inline]
+    pub fn with_static_key(key: &'static [u8]) -> Self {
+        assert!(!key.is_empty(), "the key must not be empty");
+        Self::from_keystream(key.iter().copied().cycle())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl XorConverter<Cycle<alloc::vec::IntoIter<u8>>> {
+    /// Creating a new instance with a repeating key.
+    ///
+    /// # Panics
+    /// Panics if `key` is empty.
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn new(key: alloc::vec::Vec<u8>) -> Self {
+        assert!(!key.is_empty(), "the key must not be empty");
+        Self::from_keystream(key.into_iter().cycle())
+    }
+}
+
+impl<I: Iterator<Item = u8>> Converter for XorConverter<I> {
+    type Item = u8;
+    type Output = u8;
+    type Error = KeystreamExhaustedError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let key = self.keystream.next().ok_or(KeystreamExhaustedError)?;
+        buf.extend([item ^ key]);
+        self.position += 1;
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<I> crate::sealed::Sealed for XorConverter<I> {}
+
+impl<I: Iterator<Item = u8>> ExactSizeConverter for XorConverter<I> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn known_answer() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors: [(&[u8], &[u8], &[u8]); 3] = [
+            (
+                b"key",
+                b"Hello, World!",
+                &[
+                    0x23, 0x00, 0x15, 0x07, 0x0A, 0x55, 0x4B, 0x32, 0x16, 0x19, 0x09, 0x1D, 0x4A,
+                ],
+            ),
+            (
+                b"\x5a",
+                b"firmware",
+                &[0x3C, 0x33, 0x28, 0x37, 0x2D, 0x3B, 0x28, 0x3F],
+            ),
+            (b"key", b"", b""),
+        ];
+        for (key, plain, cipher) in vectors {
+            let xor = XorConverter::new(key.to_vec());
+            let encrypted =
+                ConvertedIterator::new(plain.iter().cloned(), xor).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(cipher.to_vec()), encrypted);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn involution() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let plain = (0..=255).collect::<Vec<u8>>();
+        let encrypted =
+            ConvertedIterator::new(plain.iter().cloned(), XorConverter::new(b"secret".to_vec()))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        assert_ne!(plain, encrypted);
+        let decrypted = ConvertedIterator::new(encrypted, XorConverter::with_static_key(b"secret"))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(plain), decrypted);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reset() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut xor = XorConverter::from_keystream([1, 2, 3].into_iter());
+        let mut buf = Vec::new();
+        assert_eq!(Ok(1), xor.convert(0, &mut buf));
+        assert_eq!(Ok(1), xor.convert(0, &mut buf));
+        xor.reset();
+        for _ in 0..3 {
+            assert_eq!(Ok(1), xor.convert(0, &mut buf));
+        }
+        // the finite keystream ended.
+        assert_eq!(Err(KeystreamExhaustedError), xor.convert(0, &mut buf));
+        assert_eq!(Err(KeystreamExhaustedError), xor.convert(0, &mut buf));
+        assert_eq!(vec![1, 2, 1, 2, 3], buf);
+        assert_eq!(3, xor.position());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn position() {
+        use alloc::vec::Vec;
+
+        let plain = b"a longer message than the key";
+        let mut xor = XorConverter::new(b"k3y".to_vec());
+        let mut encrypted = Vec::new();
+        for &b in plain.iter() {
+            assert_eq!(Ok(1), xor.convert(b, &mut encrypted));
+        }
+        assert_eq!(plain.len(), xor.position());
+
+        xor.reset();
+        assert_eq!(0, xor.position());
+        let mut decrypted = Vec::new();
+        for b in encrypted {
+            assert_eq!(Ok(1), xor.convert(b, &mut decrypted));
+        }
+        assert_eq!(plain.to_vec(), decrypted);
+        assert_eq!(plain.len(), xor.position());
+    }
+}
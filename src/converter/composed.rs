@@ -0,0 +1,175 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Converter;
+
+/// Lifting an iterator transformation into a converter.
+///
+/// Items are buffered, and fed to the transformation `f` in batches. By default the whole input
+/// is one batch, which is transformed at [`finish`]. With [`with_batch_size`], each batch is
+/// transformed as soon as it is filled, and state of the transformation (like skipped items of
+/// [`Iterator::skip`]) does not cross batches.
+///
+/// This is an escape hatch for iterator adapters which do not fit in [`Converter`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::ComposedConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let reversed = ComposedConverter::new(|iter: Box<dyn Iterator<Item = i32>>| {
+///     Box::new(iter.collect::<Vec<_>>().into_iter().rev()) as Box<dyn Iterator<Item = i32>>
+/// });
+/// let converted = ConvertedIterator::new(1..=5, reversed);
+/// assert_eq!(Ok(vec![5, 4, 3, 2, 1]), converted.collect());
+///
+/// let pairs = ComposedConverter::with_batch_size(2, |iter: Box<dyn Iterator<Item = i32>>| {
+///     Box::new(core::iter::once(iter.sum())) as Box<dyn Iterator<Item = i32>>
+/// });
+/// let converted = ConvertedIterator::new(1..=5, pairs);
+/// assert_eq!(Ok(vec![3, 7, 5]), converted.collect());
+/// ```
+///
+/// [`finish`]: crate::Converter::finish
+/// [`with_batch_size`]: Self::with_batch_size
+/// [`Iterator::skip`]: core::iter::Iterator::skip
+/// [`Converter`]: crate::Converter
+pub struct ComposedConverter<I, O, F> {
+    f: F,
+    batch_size: Option<usize>,
+    batch: Vec<I>,
+    _phantom: PhantomData<fn() -> O>,
+}
+
+impl<I, O, F> fmt::Debug for ComposedConverter<I, O, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComposedConverter")
+            .field("batch_size", &self.batch_size)
+            .field("buffered", &self.batch.len())
+            .finish()
+    }
+}
+
+impl<I, O, F> ComposedConverter<I, O, F>
+where
+    F: FnMut(Box<dyn Iterator<Item = I>>) -> Box<dyn Iterator<Item = O>>,
+{
+    /// Creating a new instance, transforms the whole input at once.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            batch_size: None,
+            batch: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creating a new instance, transforms each `batch_size` items.
+    ///
+    /// # Panics
+    /// Panics if `batch_size` is zero.
+    #[inline]
+    pub fn with_batch_size(batch_size: usize, f: F) -> Self {
+        assert!(batch_size > 0, "the batch size must not be zero");
+        Self {
+            batch_size: Some(batch_size),
+            batch: Vec::with_capacity(batch_size),
+            ..Self::new(f)
+        }
+    }
+}
+
+impl<I: 'static, O, F> ComposedConverter<I, O, F>
+where
+    F: FnMut(Box<dyn Iterator<Item = I>>) -> Box<dyn Iterator<Item = O>>,
+{
+    // transforming the buffered items.
+    fn flush<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<O>,
+    {
+        let batch = core::mem::take(&mut self.batch);
+        let mut len = 0;
+        buf.extend((self.f)(Box::new(batch.into_iter())).inspect(|_| len += 1));
+        len
+    }
+}
+
+impl<I: 'static, O, F> Converter for ComposedConverter<I, O, F>
+where
+    F: FnMut(Box<dyn Iterator<Item = I>>) -> Box<dyn Iterator<Item = O>>,
+{
+    type Item = I;
+    type Output = O;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.batch.push(item);
+        match self.batch_size {
+            Some(size) if self.batch.len() >= size => Ok(self.flush(buf)),
+            _ => Ok(0),
+        }
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.batch.is_empty() {
+            return Ok(0);
+        }
+        Ok(self.flush(buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn batches() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+
+        let windows = |iter: Box<dyn Iterator<Item = u8>>| {
+            let items = iter.collect::<Vec<_>>();
+            let sums = items.windows(2).map(|w| w[0] + w[1]).collect::<Vec<_>>();
+            Box::new(sums.into_iter()) as Box<dyn Iterator<Item = u8>>
+        };
+
+        let converted = ConvertedIterator::new(1..=6, ComposedConverter::new(windows))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![3, 5, 7, 9, 11]), converted);
+
+        // windows do not cross batches.
+        let converted =
+            ConvertedIterator::new(1..=6, ComposedConverter::with_batch_size(3, windows))
+                .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![3, 5, 9, 11]), converted);
+
+        let mut converter = ComposedConverter::with_batch_size(2, windows);
+        let mut buf = Vec::new();
+        assert_eq!(Ok(0), converter.convert(1, &mut buf));
+        assert_eq!(Ok(1), converter.convert(2, &mut buf));
+        assert_eq!(Ok(0), converter.convert(3, &mut buf));
+        // a batch of one item has no windows.
+        assert_eq!(Ok(0), converter.finish(&mut buf));
+        assert_eq!(Ok(0), converter.finish(&mut buf));
+        assert_eq!(vec![3], buf);
+    }
+}
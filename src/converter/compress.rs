@@ -0,0 +1,22 @@
+//! Converters compressing byte streams, and decompressing them.
+//!
+//! The converters are the same as the ones in [`encoding`], gathered by purpose.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::compress::rle::{RLEDecoder, RLEEncoder};
+//! use conversion::iter::ConvertedIterator;
+//!
+//! let encoded = ConvertedIterator::new(*b"aaabccc", RLEEncoder::packbits()).map(Result::unwrap);
+//! let decoded = ConvertedIterator::new(encoded, RLEDecoder::packbits());
+//! assert_eq!(Ok(b"aaabccc".to_vec()), decoded.collect());
+//! ```
+//!
+//! [`encoding`]: super::encoding
+
+/// Run-length encoding, from [`encoding::rle`].
+///
+/// [`encoding::rle`]: crate::converter::encoding::rle
+pub mod rle {
+    pub use super::super::encoding::rle::*;
+}
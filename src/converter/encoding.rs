@@ -1,6 +1,109 @@
-//! ASCII/UTF-8/UTF-16/UTF-32 encoders and decoders as an converter.
+//! Encoders and decoders as converters.
 
+#[cfg(feature = "compression")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "compression")))]
+pub mod arithmetic;
 pub mod ascii;
+pub mod base32;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod base58;
+pub mod base64;
+pub mod base85;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod base_convert;
+pub mod bcd;
+pub mod bom;
+pub mod braille;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod cbor;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod delimited;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod detect;
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+pub mod duration;
+#[cfg(feature = "unicode-segmentation")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode-segmentation")))]
+pub mod grapheme;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod grpc;
+pub mod html_strip;
+pub mod http_quoted;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod huffman;
+pub mod int;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod intel_hex;
+#[cfg(feature = "cjk")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "cjk")))]
+pub mod iso2022jp;
+pub mod iso8859;
+pub mod json_escape;
+pub mod koi8r;
+pub mod latin9;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod length_prefix;
+pub mod mime_base64;
+pub mod morse;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod msgpack;
+pub mod null_term;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod pascal_str;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod pem;
+pub mod phonetics;
+pub mod proto_varint;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod punycode;
+pub mod radix;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod registry;
+#[cfg(feature = "compression")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "compression")))]
+pub mod rle;
+pub mod rust_escape;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod sci_notation;
+pub mod sql_escape;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod sse;
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub mod stuffing;
+#[cfg(feature = "unicode-names")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode-names")))]
+pub mod unicode_name;
 pub mod utf16;
 pub mod utf32;
 pub mod utf8;
+pub mod uuid;
+pub mod windows1252;
+pub mod word;
+pub mod xml_escape;
+
+/// Byte orders of multi-byte encodings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Big-endian. (the most significant byte first)
+    Big,
+    /// Little-endian. (the least significant byte first)
+    Little,
+}
@@ -0,0 +1,337 @@
+//! Arithmetic coding with user-supplied probability models.
+//!
+//! The coder uses 32-bit integer intervals, emitting bits as soon as the interval narrows enough
+//! to determine them. (most significant bit first)
+
+use crate::Converter;
+use core::fmt;
+
+/// The maximum total frequency which [`ProbabilityModel`]s can return.
+///
+/// [`ProbabilityModel`]: self::ProbabilityModel
+pub const MAX_TOTAL: u64 = 1 << 30;
+
+const HALF: u64 = 1 << 31;
+const QUARTER: u64 = 1 << 30;
+const MASK: u64 = (1 << 32) - 1;
+
+/// Probabilities of symbols for [`ArithmeticEncoder`].
+///
+/// [`ArithmeticEncoder`]: self::ArithmeticEncoder
+pub trait ProbabilityModel {
+    /// The type of symbols.
+    type Symbol;
+
+    /// Returns the frequency range of `symbol` as `(low, high, total)`, which means the
+    /// probability of `symbol` is `(high - low) / total`.
+    ///
+    /// Ranges of symbols must not overlap, and `low < high <= total <= MAX_TOTAL` should be
+    /// satisfied.
+    fn probability(&self, symbol: &Self::Symbol) -> (u64, u64, u64);
+}
+
+/// An error while arithmetic coding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidProbabilityError {
+    probability: (u64, u64, u64),
+}
+
+impl InvalidProbabilityError {
+    /// Returns the invalid range returned by the model.
+    #[inline]
+    pub fn probability(&self) -> (u64, u64, u64) {
+        self.probability
+    }
+}
+
+impl fmt::Display for InvalidProbabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (low, high, total) = self.probability;
+        write!(f, "invalid probability: {}..{} of {}", low, high, total)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidProbabilityError {}
+
+/// An arithmetic encoder, emits the compressed bits as bytes.
+///
+/// Since the number of symbols is not encoded, it should be stored separately or a model should
+/// have an end-of-stream symbol. The remaining interval is flushed at the end of the input, and
+/// the last byte is padded with `0`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::arithmetic::{ArithmeticEncoder, ProbabilityModel};
+/// use conversion::iter::ConvertedIterator;
+///
+/// // `a` appears 6 times as often as `b` or `c`.
+/// struct Model;
+///
+/// impl ProbabilityModel for Model {
+///     type Symbol = char;
+///
+///     fn probability(&self, symbol: &char) -> (u64, u64, u64) {
+///         match symbol {
+///             'a' => (0, 6, 8),
+///             'b' => (6, 7, 8),
+///             _ => (7, 8, 8),
+///         }
+///     }
+/// }
+///
+/// let encoded = ConvertedIterator::new("aaaaaaab".chars(), ArithmeticEncoder::new(Model));
+/// // 8 symbols are compressed into 8 bits.
+/// assert_eq!(Ok(vec![0x1A]), encoded.collect());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArithmeticEncoder<M> {
+    model: M,
+    low: u64,
+    high: u64,
+    // the number of bits which will be the opposite of the next bit.
+    pending: u64,
+    byte: u8,
+    bits: u8,
+}
+
+impl<M> ArithmeticEncoder<M> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            low: 0,
+            high: MASK,
+            pending: 0,
+            byte: 0,
+            bits: 0,
+        }
+    }
+
+    /// Getting a reference to the model.
+    #[inline]
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    fn push<E>(&mut self, bit: bool, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        self.byte = self.byte << 1 | bit as u8;
+        self.bits += 1;
+        if self.bits == 8 {
+            buf.extend([self.byte]);
+            self.byte = 0;
+            self.bits = 0;
+            1
+        } else {
+            0
+        }
+    }
+
+    // emits a bit followed by pending opposite bits.
+    fn emit<E>(&mut self, bit: bool, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        let mut len = self.push(bit, buf);
+        for _ in 0..core::mem::take(&mut self.pending) {
+            len += self.push(!bit, buf);
+        }
+        len
+    }
+}
+
+impl<M: ProbabilityModel> Converter for ArithmeticEncoder<M> {
+    type Item = M::Symbol;
+    type Output = u8;
+    type Error = InvalidProbabilityError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (low, high, total) = self.model.probability(&item);
+        if !(low < high && high <= total && total <= MAX_TOTAL) {
+            return Err(InvalidProbabilityError {
+                probability: (low, high, total),
+            });
+        }
+
+        let range = self.high - self.low + 1;
+        self.high = self.low + range * high / total - 1;
+        self.low += range * low / total;
+
+        let mut len = 0;
+        loop {
+            if self.high < HALF {
+                len += self.emit(false, buf);
+            } else if self.low >= HALF {
+                len += self.emit(true, buf);
+                self.low -= HALF;
+                self.high -= HALF;
+            } else if self.low >= QUARTER && self.high < HALF + QUARTER {
+                self.pending += 1;
+                self.low -= QUARTER;
+                self.high -= QUARTER;
+            } else {
+                break;
+            }
+            self.low <<= 1;
+            self.high = self.high << 1 | 1;
+        }
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        // two more bits select a value inside the interval.
+        self.pending += 1;
+        let mut len = self.emit(self.low >= QUARTER, buf);
+        if self.bits != 0 {
+            buf.extend([self.byte << (8 - self.bits)]);
+            len += 1;
+        }
+        self.low = 0;
+        self.high = MASK;
+        self.byte = 0;
+        self.bits = 0;
+        Ok(len)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    // frequencies of bytes, in proportion to `byte + 1`.
+    struct Linear;
+
+    impl Linear {
+        fn cumulative(byte: u64) -> u64 {
+            byte * (byte + 1) / 2
+        }
+    }
+
+    impl ProbabilityModel for Linear {
+        type Symbol = u8;
+
+        fn probability(&self, symbol: &u8) -> (u64, u64, u64) {
+            let byte = *symbol as u64;
+            (
+                Self::cumulative(byte),
+                Self::cumulative(byte + 1),
+                Self::cumulative(256),
+            )
+        }
+    }
+
+    fn decode(bytes: &[u8], count: usize) -> Vec<u8> {
+        let mut bits = bytes
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i & 1) as u64))
+            .chain(core::iter::repeat(0));
+        let mut value = 0;
+        for _ in 0..32 {
+            value = value << 1 | bits.next().unwrap();
+        }
+        let (mut low, mut high) = (0, MASK);
+        let mut symbols = Vec::new();
+        let total = Linear::cumulative(256);
+        for _ in 0..count {
+            let range = high - low + 1;
+            let target = ((value - low + 1) * total - 1) / range;
+            let symbol = (0..=255)
+                .find(|&b| Linear::cumulative(b as u64 + 1) > target)
+                .unwrap();
+            let (l, h, _) = Linear.probability(&symbol);
+            high = low + range * h / total - 1;
+            low += range * l / total;
+            loop {
+                if high < HALF {
+                } else if low >= HALF {
+                    low -= HALF;
+                    high -= HALF;
+                    value -= HALF;
+                } else if low >= QUARTER && high < HALF + QUARTER {
+                    low -= QUARTER;
+                    high -= QUARTER;
+                    value -= QUARTER;
+                } else {
+                    break;
+                }
+                low <<= 1;
+                high = high << 1 | 1;
+                value = value << 1 | bits.next().unwrap();
+            }
+            symbols.push(symbol);
+        }
+        symbols
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+
+        let inputs: [&[u8]; 4] = [b"", b"\xff", b"\x00\x00\x00", b"hello, world!"];
+        for input in inputs
+            .iter()
+            .cloned()
+            .chain([&(0..=255).rev().collect::<Vec<_>>()[..]])
+        {
+            let encoded =
+                ConvertedIterator::new(input.iter().cloned(), ArithmeticEncoder::new(Linear))
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+            assert_eq!(input, decode(&encoded, input.len()));
+        }
+
+        // frequent symbols are encoded into fewer bits.
+        let encode = |byte: u8| {
+            ConvertedIterator::new([byte; 100], ArithmeticEncoder::new(Linear))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        assert!(encode(0xFF).len() < 100);
+        assert!(encode(0xFF).len() * 2 < encode(0x00).len());
+    }
+
+    #[test]
+    fn invalid() {
+        struct Broken;
+
+        impl ProbabilityModel for Broken {
+            type Symbol = u64;
+
+            fn probability(&self, symbol: &u64) -> (u64, u64, u64) {
+                (0, *symbol, 4)
+            }
+        }
+
+        let mut encoder = ArithmeticEncoder::new(Broken);
+        let mut buf = Vec::new();
+        assert_eq!(Ok(0), encoder.convert(1, &mut buf));
+        assert_eq!(
+            Err(InvalidProbabilityError {
+                probability: (0, 5, 4)
+            }),
+            encoder.convert(5, &mut buf)
+        );
+        assert_eq!(
+            Err(InvalidProbabilityError {
+                probability: (0, 0, 4)
+            }),
+            encoder.convert(0, &mut buf)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! 7-bit ASCII Decoder/Encoder.
 
-use crate::Converter;
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
 use core::fmt;
 
 /// An error while encoding/decoding ASCII characters.
@@ -42,7 +43,7 @@ impl ASCIIDecoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -63,12 +64,32 @@ impl Converter for ASCIIDecoder {
         }
     }
 
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = items
+            .iter()
+            .position(|b| !b.is_ascii())
+            .unwrap_or(items.len());
+        buf.extend(items[..len].iter().map(|&b| b as char));
+        if len == items.len() {
+            Ok(len)
+        } else {
+            Err(ASCIIEncodingError)
+        }
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (1, Some(1))
     }
 }
 
+impl crate::sealed::Sealed for ASCIIDecoder {}
+
+impl ExactSizeConverter for ASCIIDecoder {}
+
 /// An encoder for 7-bit ASCII.
 ///
 /// # Examples
@@ -94,7 +115,7 @@ impl ASCIIEncoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -115,8 +136,341 @@ impl Converter for ASCIIEncoder {
         }
     }
 
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = items
+            .iter()
+            .position(|c| !c.is_ascii())
+            .unwrap_or(items.len());
+        buf.extend(items[..len].iter().map(|&c| c as u8));
+        if len == items.len() {
+            Ok(len)
+        } else {
+            Err(ASCIIEncodingError)
+        }
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (1, Some(1))
     }
 }
+
+impl crate::sealed::Sealed for ASCIIEncoder {}
+
+impl ExactSizeConverter for ASCIIEncoder {}
+
+/// A lossy encoder for 7-bit ASCII, replaces non-ASCII characters with a substitute byte.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::ascii::ASCIILossyEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new("straße".chars(), ASCIILossyEncoder::new());
+/// assert_eq!(Ok(b"stra?e".to_vec()), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new("straße".chars(), ASCIILossyEncoder::skip());
+/// assert_eq!(Ok(b"strae".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ASCIILossyEncoder {
+    // `None` for skipping.
+    substitute: Option<u8>,
+}
+
+impl Default for ASCIILossyEncoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            substitute: Some(b'?'),
+        }
+    }
+}
+
+impl ASCIILossyEncoder {
+    /// Create a new instance, substituting `b'?'`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a custom substitute byte.
+    ///
+    /// Returns [`ASCIIEncodingError`] if `substitute` is not ASCII.
+    ///
+    /// [`ASCIIEncodingError`]: self::ASCIIEncodingError
+    #[inline]
+    pub fn with_substitute(substitute: u8) -> Result<Self, ASCIIEncodingError> {
+        if substitute.is_ascii() {
+            Ok(Self {
+                substitute: Some(substitute),
+            })
+        } else {
+            Err(ASCIIEncodingError)
+        }
+    }
+
+    /// Create a new instance which drops non-ASCII characters.
+    #[inline]
+    pub fn skip() -> Self {
+        Self { substitute: None }
+    }
+}
+
+impl Converter for ASCIILossyEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match (item.is_ascii(), self.substitute) {
+            (true, _) => buf.extend([item as u8]),
+            (false, Some(substitute)) => buf.extend([substitute]),
+            (false, None) => return Ok(0),
+        }
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.substitute {
+            Some(_) => (1, Some(1)),
+            None => (0, Some(1)),
+        }
+    }
+}
+
+/// Escape styles of [`ASCIIEscapeEncoder`].
+///
+/// [`ASCIIEscapeEncoder`]: self::ASCIIEscapeEncoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ASCIIEscapeStyle {
+    /// Rust-style escapes, like `\u{1F4A3}`.
+    Rust,
+    /// Java/JSON-style escapes with surrogate pairs, like `\uD83D\uDCA3`.
+    Java,
+    /// Percent-encoded UTF-8 bytes, like `%F0%9F%92%A3`.
+    Percent,
+}
+
+/// An encoder for 7-bit ASCII, escapes non-ASCII characters instead of failing.
+///
+/// The escape character itself (`\` or `%`) is also escaped, so that the output can be
+/// unescaped losslessly. Other ASCII characters are emitted as they are.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::ascii::{ASCIIEscapeEncoder, ASCIIEscapeStyle};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encode = |style| {
+///     ConvertedIterator::new("aß💣".chars(), ASCIIEscapeEncoder::new(style))
+///         .collect::<Result<Vec<_>, _>>()
+///         .unwrap()
+/// };
+///
+/// assert_eq!(b"a\\u{DF}\\u{1F4A3}".to_vec(), encode(ASCIIEscapeStyle::Rust));
+/// assert_eq!(b"a\\u00DF\\uD83D\\uDCA3".to_vec(), encode(ASCIIEscapeStyle::Java));
+/// assert_eq!(b"a%C3%9F%F0%9F%92%A3".to_vec(), encode(ASCIIEscapeStyle::Percent));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ASCIIEscapeEncoder {
+    style: ASCIIEscapeStyle,
+}
+
+impl ASCIIEscapeEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(style: ASCIIEscapeStyle) -> Self {
+        Self { style }
+    }
+}
+
+impl Converter for ASCIIEscapeEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        let hex = |code: u32, digits: usize| {
+            (0..digits)
+                .rev()
+                .map(move |i| HEX[(code >> (i * 4) & 0xF) as usize])
+        };
+
+        match self.style {
+            ASCIIEscapeStyle::Rust | ASCIIEscapeStyle::Java if item == '\\' => {
+                buf.extend(*b"\\\\");
+                Ok(2)
+            }
+            ASCIIEscapeStyle::Percent if item == '%' => {
+                buf.extend(*b"%25");
+                Ok(3)
+            }
+            _ if item.is_ascii() => {
+                buf.extend([item as u8]);
+                Ok(1)
+            }
+            ASCIIEscapeStyle::Rust => {
+                let code = item as u32;
+                // at least 2 digits, since non-ASCII characters are above U+007F.
+                let digits = (32 - code.leading_zeros() as usize).div_ceil(4);
+                buf.extend(*b"\\u{");
+                buf.extend(hex(code, digits));
+                buf.extend([b'}']);
+                Ok(digits + 4)
+            }
+            ASCIIEscapeStyle::Java => {
+                let mut units = [0; 2];
+                let units = item.encode_utf16(&mut units);
+                for &unit in units.iter() {
+                    buf.extend(*b"\\u");
+                    buf.extend(hex(unit as u32, 4));
+                }
+                Ok(units.len() * 6)
+            }
+            ASCIIEscapeStyle::Percent => {
+                let mut bytes = [0; 4];
+                let bytes = item.encode_utf8(&mut bytes);
+                for &byte in bytes.as_bytes() {
+                    buf.extend([b'%']);
+                    buf.extend(hex(byte as u32, 2));
+                }
+                Ok(bytes.len() * 3)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.style {
+            // `\u{10FFFF}`
+            ASCIIEscapeStyle::Rust => (1, Some(10)),
+            // `\uDBFF\uDFFF`
+            ASCIIEscapeStyle::Java => (1, Some(12)),
+            // `%F4%8F%BF%BF`
+            ASCIIEscapeStyle::Percent => (1, Some(12)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn convert_slice() {
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let mut decoded = String::new();
+        assert_eq!(Ok(3), ASCIIDecoder.convert_slice(b"abc", &mut decoded));
+        assert_eq!(
+            Err(ASCIIEncodingError),
+            ASCIIDecoder.convert_slice(b"de\xC3\x9F", &mut decoded)
+        );
+        assert_eq!("abcde", decoded);
+
+        let mut encoded = Vec::new();
+        let chars = "straße".chars().collect::<Vec<_>>();
+        assert_eq!(Ok(1), ASCIIEncoder.convert_slice(&chars[5..], &mut encoded));
+        assert_eq!(
+            Err(ASCIIEncodingError),
+            ASCIIEncoder.convert_slice(&chars, &mut encoded)
+        );
+        assert_eq!(b"estra".to_vec(), encoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lossy() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        assert_eq!(
+            Err(ASCIIEncodingError),
+            ASCIILossyEncoder::with_substitute(0x80)
+        );
+
+        let text = "💣 naïve café 日本";
+        let encoder = ASCIILossyEncoder::with_substitute(b'_').unwrap();
+        let encoded = ConvertedIterator::new(text.chars(), encoder).collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(b"_ na_ve caf_ __".to_vec()), encoded);
+
+        let mut encoded = ConvertedIterator::new(text.chars(), ASCIILossyEncoder::skip());
+        assert_eq!((0, Some(text.len())), encoded.size_hint());
+        assert_eq!(
+            Ok(b" nave caf ".to_vec()),
+            encoded.by_ref().collect::<Result<Vec<_>, _>>()
+        );
+        assert_eq!(None, encoded.next());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn escape() {
+        use super::super::json_escape::JsonStringUnescapeConverter;
+        use super::super::rust_escape::RustStringUnescapeConverter;
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let encode = |text: &str, style| {
+            ConvertedIterator::new(text.chars(), ASCIIEscapeEncoder::new(style))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+
+        let plain = "Hello, world! ~{}[]\"quoted\"\t\n";
+        for style in [
+            ASCIIEscapeStyle::Rust,
+            ASCIIEscapeStyle::Java,
+            ASCIIEscapeStyle::Percent,
+        ] {
+            assert_eq!(plain.as_bytes(), encode(plain, style));
+        }
+
+        let text = "100% \\o/ straße 日本 💣\u{80}\u{10FFFF}";
+        let escaped = encode(text, ASCIIEscapeStyle::Rust);
+        assert!(escaped.is_ascii());
+        let unescaped = ConvertedIterator::new(
+            escaped.iter().map(|&b| b as char),
+            RustStringUnescapeConverter::new(),
+        )
+        .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(text)), unescaped);
+
+        let escaped = encode(text, ASCIIEscapeStyle::Java);
+        assert!(escaped.is_ascii());
+        let unescaped = ConvertedIterator::new(
+            escaped.iter().map(|&b| b as char),
+            JsonStringUnescapeConverter::new(),
+        )
+        .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(text)), unescaped);
+
+        // decoding percent-encoded bytes by hand.
+        let escaped = encode(text, ASCIIEscapeStyle::Percent);
+        assert!(escaped.is_ascii());
+        let mut bytes = Vec::new();
+        let mut iter = escaped.split(|&b| b == b'%');
+        bytes.extend(iter.next().unwrap());
+        for chunk in iter {
+            let hex = core::str::from_utf8(&chunk[..2]).unwrap();
+            bytes.push(u8::from_str_radix(hex, 16).unwrap());
+            bytes.extend(&chunk[2..]);
+        }
+        assert_eq!(Ok(text), core::str::from_utf8(&bytes));
+    }
+}
@@ -0,0 +1,539 @@
+//! Base32 Encoder/Decoder, described in [RFC 4648], and [Crockford's Base32].
+//!
+//! [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648#section-6
+//! [Crockford's Base32]: https://www.crockford.com/base32.html
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+const STANDARD: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Alphabets of Base32.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Alphabet {
+    /// The standard alphabet, `A` to `Z` and `2` to `7`.
+    Standard,
+    /// The "Extended Hex" alphabet, `0` to `9` and `A` to `V`, which preserves the sort order.
+    Hex,
+    /// Crockford's alphabet, which excludes `I`, `L`, `O` and `U`.
+    ///
+    /// Decoding is case-insensitive, and `O` is decoded as `0`, and `I` and `L` as `1`.
+    Crockford,
+}
+
+impl Alphabet {
+    #[inline]
+    fn chars(self) -> &'static [u8; 32] {
+        match self {
+            Self::Standard => STANDARD,
+            Self::Hex => HEX,
+            Self::Crockford => CROCKFORD,
+        }
+    }
+
+    #[inline]
+    fn decode(self, c: u8) -> Option<u64> {
+        let c = match (self, c.to_ascii_uppercase()) {
+            (Self::Crockford, b'O') => b'0',
+            (Self::Crockford, b'I' | b'L') => b'1',
+            (Self::Crockford, upper) => upper,
+            _ => c,
+        };
+        self.chars()
+            .iter()
+            .position(|&x| x == c)
+            .map(|value| value as u64)
+    }
+}
+
+/// Configurations of Base32.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base32::{Alphabet, Config};
+///
+/// assert_eq!(Config::HEX_NO_PAD, Config::new(Alphabet::Hex, false));
+/// assert_eq!(Config::STANDARD, Config::STANDARD_NO_PAD.with_padding(true));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Config {
+    alphabet: Alphabet,
+    padding: bool,
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Config {
+    /// The standard alphabet with padding.
+    pub const STANDARD: Self = Self::new(Alphabet::Standard, true);
+    /// The standard alphabet without padding.
+    pub const STANDARD_NO_PAD: Self = Self::new(Alphabet::Standard, false);
+    /// The "Extended Hex" alphabet with padding.
+    pub const HEX: Self = Self::new(Alphabet::Hex, true);
+    /// The "Extended Hex" alphabet without padding.
+    pub const HEX_NO_PAD: Self = Self::new(Alphabet::Hex, false);
+    /// Crockford's alphabet without padding.
+    pub const CROCKFORD: Self = Self::new(Alphabet::Crockford, false);
+
+    /// Create a new configuration.
+    #[inline]
+    pub const fn new(alphabet: Alphabet, padding: bool) -> Self {
+        Self { alphabet, padding }
+    }
+
+    /// Setting the alphabet.
+    #[inline]
+    pub const fn with_alphabet(self, alphabet: Alphabet) -> Self {
+        Self { alphabet, ..self }
+    }
+
+    /// Setting whether the last quantum is padded with `=`.
+    #[inline]
+    pub const fn with_padding(self, padding: bool) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Returns the alphabet.
+    #[inline]
+    pub const fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Returns whether the last quantum is padded with `=`.
+    #[inline]
+    pub const fn padding(&self) -> bool {
+        self.padding
+    }
+}
+
+/// An encoder for Base32.
+///
+/// Each 5 bytes are encoded into 8 characters, and up to 4 remaining bytes are encoded (and
+/// padded if configured) at the end of the input.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base32::{Base32Encoder, Config};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(b"foobar".iter().cloned(), Base32Encoder::new());
+/// assert_eq!(Ok(b"MZXW6YTBOI======".to_vec()), encoded.collect());
+///
+/// let encoder = Base32Encoder::with_config(Config::CROCKFORD);
+/// let encoded = ConvertedIterator::new(b"foobar".iter().cloned(), encoder);
+/// assert_eq!(Ok(b"CSQPYRK1E8".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base32Encoder {
+    config: Config,
+    value: u64,
+    len: u8,
+}
+
+impl Base32Encoder {
+    /// Create a new instance, with [`Config::STANDARD`].
+    ///
+    /// [`Config::STANDARD`]: self::Config::STANDARD
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a configuration.
+    #[inline]
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+}
+
+impl Converter for Base32Encoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.value = self.value << 8 | item as u64;
+        self.len += 1;
+        if self.len < 5 {
+            return Ok(0);
+        }
+
+        let value = core::mem::take(&mut self.value);
+        self.len = 0;
+        let chars = self.config.alphabet.chars();
+        buf.extend(
+            (0..8)
+                .rev()
+                .map(|i| chars[(value >> (i * 5) & 0x1F) as usize]),
+        );
+        Ok(8)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = core::mem::take(&mut self.len) as usize;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        // left-aligns remaining bytes in 40 bits.
+        let value = core::mem::take(&mut self.value) << ((5 - len) * 8);
+        let chars = self.config.alphabet.chars();
+        let count = (len * 8).div_ceil(5);
+        let padding = if self.config.padding { 8 - count } else { 0 };
+        buf.extend(
+            (0..count)
+                .map(|n| chars[(value >> (35 - n * 5) & 0x1F) as usize])
+                .chain(core::iter::repeat_n(b'=', padding)),
+        );
+        Ok(count + padding)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(8)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(8))
+    }
+}
+
+/// An error while decoding Base32.
+///
+/// Offsets are counted in input bytes from the start of the stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base32DecodeError {
+    /// A byte out of the alphabet, and its offset.
+    InvalidByte(u8, usize),
+    /// A misplaced padding character, or a character after the padding, and its offset.
+    InvalidPadding(usize),
+    /// Non-zero bits discarded by the last quantum, and the offset of the last character.
+    NonCanonical(usize),
+    /// The input ended inside a quantum of 8 characters.
+    Truncated,
+}
+
+impl Base32DecodeError {
+    /// Returns the offset of the erroneous byte, unless the input is truncated.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidByte(_, offset) | Self::InvalidPadding(offset) => Some(offset),
+            Self::NonCanonical(offset) => Some(offset),
+            Self::Truncated => None,
+        }
+    }
+}
+
+impl fmt::Display for Base32DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte(b, offset) => {
+                write!(f, "invalid byte in Base32 at {}: {:#04x}", offset, b)
+            }
+            Self::InvalidPadding(offset) => write!(f, "misplaced padding in Base32 at {}.", offset),
+            Self::NonCanonical(offset) => {
+                write!(f, "non-zero trailing bits in Base32 at {}.", offset)
+            }
+            Self::Truncated => write!(f, "the input ended inside a Base32 quantum."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base32DecodeError {}
+
+/// A decoder for Base32.
+///
+/// The alphabet follows the [`Config`]. With padding, the last quantum must be padded, and
+/// without padding, `=` is not allowed. Like [`Base64Decoder`], trailing bits of the last quantum
+/// must be zero, nothing may follow the padding, and the current quantum is discarded after an
+/// error.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base32::{Base32DecodeError, Base32Decoder, Config};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(b"NBSWY3DP".iter().cloned(), Base32Decoder::new());
+/// assert_eq!(Ok(b"hello".to_vec()), decoded.collect());
+///
+/// let decoder = Base32Decoder::with_config(Config::CROCKFORD);
+/// let decoded = ConvertedIterator::new(b"dIjprv3f".iter().cloned(), decoder);
+/// assert_eq!(Ok(b"hello".to_vec()), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new(b"NBSW1".iter().cloned(), Base32Decoder::new());
+/// assert_eq!(Some(Err(Base32DecodeError::InvalidByte(b'1', 4))), decoded.next());
+/// ```
+///
+/// [`Config`]: self::Config
+/// [`Base64Decoder`]: super::base64::Base64Decoder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base32Decoder {
+    config: Config,
+    value: u64,
+    // the number of characters, and the number of padding characters in the current quantum.
+    len: u8,
+    padding: u8,
+    // the offset of the next byte, and of the last character of the alphabet.
+    offset: usize,
+    last: usize,
+    // whether a padded quantum has been completed.
+    ended: bool,
+}
+
+impl Base32Decoder {
+    /// Create a new instance, with [`Config::STANDARD`].
+    ///
+    /// [`Config::STANDARD`]: self::Config::STANDARD
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a configuration.
+    #[inline]
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    fn reset(&mut self) {
+        self.value = 0;
+        self.len = 0;
+        self.padding = 0;
+    }
+
+    fn emit<E>(&mut self, buf: &mut E) -> Result<usize, Base32DecodeError>
+    where
+        E: Extend<u8>,
+    {
+        // bits of the last character which do not fit into bytes.
+        let extra = 5 * self.len as u32 % 8;
+        if self.value & ((1 << extra) - 1) != 0 {
+            return self.error(Base32DecodeError::NonCanonical(self.last));
+        }
+
+        let len = 5 * self.len as usize / 8;
+        let value = self.value << (5 * (8 - self.len as u32));
+        self.reset();
+        buf.extend((0..len).map(|i| (value >> (32 - i * 8)) as u8));
+        Ok(len)
+    }
+
+    fn error(&mut self, err: Base32DecodeError) -> Result<usize, Base32DecodeError> {
+        self.reset();
+        self.ended = false;
+        Err(err)
+    }
+}
+
+impl Converter for Base32Decoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base32DecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        if self.ended {
+            return self.error(Base32DecodeError::InvalidPadding(offset));
+        }
+
+        if item == b'=' {
+            // padding can follow 2, 4, 5 or 7 characters.
+            if !self.config.padding || !matches!(self.len, 2 | 4 | 5 | 7) {
+                return self.error(Base32DecodeError::InvalidPadding(offset));
+            }
+            self.padding += 1;
+            return if self.len + self.padding == 8 {
+                self.ended = true;
+                self.emit(buf)
+            } else {
+                Ok(0)
+            };
+        }
+
+        let value = match self.config.alphabet.decode(item) {
+            Some(value) => value,
+            None => return self.error(Base32DecodeError::InvalidByte(item, offset)),
+        };
+        if self.padding > 0 {
+            return self.error(Base32DecodeError::InvalidPadding(offset));
+        }
+        self.value = self.value << 5 | value;
+        self.len += 1;
+        self.last = offset;
+        if self.len == 8 {
+            self.emit(buf)
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match (self.len, self.padding, self.config.padding) {
+            (0, _, _) => Ok(0),
+            (2 | 4 | 5 | 7, 0, false) => self.emit(buf),
+            _ => self.error(Base32DecodeError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(5))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rfc4648() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors: [(&[u8], &[u8], &[u8]); 7] = [
+            (b"", b"", b""),
+            (b"f", b"MY======", b"CO======"),
+            (b"fo", b"MZXQ====", b"CPNG===="),
+            (b"foo", b"MZXW6===", b"CPNMU==="),
+            (b"foob", b"MZXW6YQ=", b"CPNMUOG="),
+            (b"fooba", b"MZXW6YTB", b"CPNMUOJ1"),
+            (b"foobar", b"MZXW6YTBOI======", b"CPNMUOJ1E8======"),
+        ];
+        for (decoded, standard, hex) in vectors {
+            for (config, encoded) in [(Config::STANDARD, standard), (Config::HEX, hex)] {
+                let iter = decoded.iter().cloned();
+                let result = ConvertedIterator::new(iter, Base32Encoder::with_config(config))
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(encoded.to_vec()), result);
+
+                let iter = encoded.iter().cloned();
+                let result = ConvertedIterator::new(iter, Base32Decoder::with_config(config))
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(decoded.to_vec()), result);
+
+                // without padding.
+                let unpadded = encoded.iter().cloned().filter(|&b| b != b'=');
+                let config = config.with_padding(false);
+                let result = ConvertedIterator::new(unpadded, Base32Decoder::with_config(config))
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(decoded.to_vec()), result);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn crockford() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(
+                bytes.iter().cloned(),
+                Base32Decoder::with_config(Config::CROCKFORD),
+            )
+            .collect::<Result<Vec<_>, _>>()
+        };
+        assert_eq!(Ok(b"foobar".to_vec()), decode(b"CSQPYRK1E8"));
+        assert_eq!(Ok(b"foobar".to_vec()), decode(b"csqpyrk1e8"));
+        assert_eq!(Ok(b"foobar".to_vec()), decode(b"CSQPYRKiE8"));
+        assert_eq!(Ok(b"foobar".to_vec()), decode(b"CSQPYRKLE8"));
+        assert_eq!(Ok(vec![0x00, 0x01, 0xFF]), decode(b"oOoZY"));
+        assert_eq!(
+            Err(Base32DecodeError::InvalidByte(b'U', 3)),
+            decode(b"CSQUYRK1E8")
+        );
+        // case-sensitive in RFC 4648 alphabets.
+        let decoded = ConvertedIterator::new(b"mzxq====".iter().cloned(), Base32Decoder::new());
+        assert_eq!(
+            Err(Base32DecodeError::InvalidByte(b'm', 0)),
+            decoded.collect::<Result<Vec<_>, _>>()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8], config| {
+            ConvertedIterator::new(bytes.iter().cloned(), Base32Decoder::with_config(config))
+                .collect::<Vec<_>>()
+        };
+        let standard = |bytes: &[u8]| decode(bytes, Config::STANDARD);
+
+        assert_eq!(
+            vec![Err(Base32DecodeError::InvalidPadding(1))],
+            standard(b"M=======")[..1]
+        );
+        assert_eq!(
+            vec![Err(Base32DecodeError::InvalidPadding(6))],
+            standard(b"MZXW6Y==")[..1]
+        );
+        assert_eq!(
+            vec![Err(Base32DecodeError::InvalidPadding(4))],
+            standard(b"MZ==M===")[..1]
+        );
+        assert_eq!(
+            vec![Ok(b'f'), Err(Base32DecodeError::InvalidPadding(8))],
+            standard(b"MY======MY======")[..2]
+        );
+        assert_eq!(
+            vec![Err(Base32DecodeError::InvalidPadding(2))],
+            decode(b"MY======", Config::STANDARD_NO_PAD)[..1]
+        );
+        assert_eq!(
+            vec![Err(Base32DecodeError::NonCanonical(1))],
+            standard(b"MZ======")
+        );
+        assert_eq!(
+            vec![Err(Base32DecodeError::NonCanonical(6))],
+            decode(b"MZXW6YR", Config::STANDARD_NO_PAD)
+        );
+        assert_eq!(vec![Err(Base32DecodeError::Truncated)], standard(b"MZXQ"));
+        assert_eq!(vec![Err(Base32DecodeError::Truncated)], standard(b"MZXQ=="));
+        assert_eq!(
+            vec![Err(Base32DecodeError::Truncated)],
+            decode(b"MZX", Config::STANDARD_NO_PAD)
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Base58 Encoder/Decoder, with the Bitcoin alphabet.
+//!
+//! Base58 treats the whole message as a big number, so converters in this module buffer all
+//! inputs and emit outputs when the input ends. Since the conversion takes quadratic time, the
+//! length of inputs is limited. (inputs exceeding the limit are discarded with
+//! [`Base58Error::TooLong`])
+//!
+//! Leading zero bytes are encoded as leading `1`s, and vice versa.
+//!
+//! [`Base58Error::TooLong`]: self::Base58Error::TooLong
+
+use super::base_convert::convert_base;
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The default maximum length of inputs.
+pub const DEFAULT_MAX_LEN: usize = 1024;
+
+/// An error while encoding/decoding Base58.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base58Error {
+    /// A byte out of the alphabet, and its offset.
+    InvalidByte(u8, usize),
+    /// An input longer than the limit.
+    TooLong,
+}
+
+impl fmt::Display for Base58Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte(b, offset) => {
+                write!(f, "invalid byte in Base58 at {}: {:#04x}", offset, b)
+            }
+            Self::TooLong => write!(f, "the input is too long."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base58Error {}
+
+/// An encoder for Base58, emits encoded characters when the input ends.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base58::Base58Encoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(*b"\0\0Hello World!", Base58Encoder::new());
+///
+/// assert_eq!(Ok(b"112NEpo7TZRRrLZSi2U".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Base58Encoder {
+    buffer: Vec<u8>,
+    max_len: usize,
+    overflowed: bool,
+}
+
+impl Default for Base58Encoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_len: DEFAULT_MAX_LEN,
+            overflowed: false,
+        }
+    }
+}
+
+impl Base58Encoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limiting the number of input bytes. (default: [`DEFAULT_MAX_LEN`])
+    ///
+    /// [`DEFAULT_MAX_LEN`]: self::DEFAULT_MAX_LEN
+    #[inline]
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl Converter for Base58Encoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base58Error;
+
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.overflowed {
+            Ok(0)
+        } else if self.buffer.len() >= self.max_len {
+            self.buffer.clear();
+            self.overflowed = true;
+            Err(Base58Error::TooLong)
+        } else {
+            self.buffer.push(item);
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let input = core::mem::take(&mut self.buffer);
+        self.overflowed = false;
+        let zeros = input.iter().take_while(|&&b| b == 0).count();
+        let digits = convert_base(&input[zeros..], 256, 58);
+        buf.extend(
+            core::iter::repeat_n(b'1', zeros)
+                .chain(digits.iter().rev().map(|&d| ALPHABET[d as usize])),
+        );
+        Ok(zeros + digits.len())
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A decoder for Base58, emits decoded bytes when the input ends.
+///
+/// Bytes out of the alphabet are reported with their offsets, and skipped.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base58::{Base58Decoder, Base58Error};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(*b"112NEpo7TZRRrLZSi2U", Base58Decoder::new());
+/// assert_eq!(Ok(b"\0\0Hello World!".to_vec()), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new(*b"2NEp0", Base58Decoder::new());
+/// assert_eq!(Some(Err(Base58Error::InvalidByte(b'0', 4))), decoded.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Base58Decoder {
+    // values of digits.
+    buffer: Vec<u8>,
+    max_len: usize,
+    overflowed: bool,
+    // the offset of the next byte.
+    offset: usize,
+}
+
+impl Default for Base58Decoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_len: DEFAULT_MAX_LEN,
+            overflowed: false,
+            offset: 0,
+        }
+    }
+}
+
+impl Base58Decoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limiting the number of input characters. (default: [`DEFAULT_MAX_LEN`])
+    ///
+    /// [`DEFAULT_MAX_LEN`]: self::DEFAULT_MAX_LEN
+    #[inline]
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl Converter for Base58Decoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base58Error;
+
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        if self.overflowed {
+            return Ok(0);
+        }
+        let digit = match ALPHABET.iter().position(|&c| c == item) {
+            Some(digit) => digit as u8,
+            None => return Err(Base58Error::InvalidByte(item, offset)),
+        };
+        if self.buffer.len() >= self.max_len {
+            self.buffer.clear();
+            self.overflowed = true;
+            Err(Base58Error::TooLong)
+        } else {
+            self.buffer.push(digit);
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let input = core::mem::take(&mut self.buffer);
+        self.overflowed = false;
+        self.offset = 0;
+        let zeros = input.iter().take_while(|&&d| d == 0).count();
+        let bytes = convert_base(&input[zeros..], 58, 256);
+        buf.extend(core::iter::repeat_n(0, zeros).chain(bytes.iter().rev().cloned()));
+        Ok(zeros + bytes.len())
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+
+    #[test]
+    fn vectors() {
+        // outputs of a reference implementation with Python integers.
+        let vectors: [(&[u8], &[u8]); 7] = [
+            (b"", b""),
+            (b"\0", b"1"),
+            (b"\0\0\0\x01", b"1112"),
+            (b"Hello World!", b"2NEpo7TZRRrLZSi2U"),
+            (b"\0\0\x28\x7f\xb4\xcd", b"11233QC4"),
+            (
+                b"The quick brown fox jumps over the lazy dog",
+                b"7DdiPPYtxLjCD3wA1po2rvZHTDYjkZYiEtazrfiwJcwnKCizhGFhBGHeRdx",
+            ),
+            (
+                b"\x00\xeb\x15\x23\x1d\xfc\xeb\x60\x92\x58\x86\xb6\x7d\x06\x52\x99\x92\x59\x15\xae\xb1\x72\xc0\x66\x47",
+                b"1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+            ),
+        ];
+        for (decoded, encoded) in vectors {
+            let result = ConvertedIterator::new(decoded.iter().cloned(), Base58Encoder::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(encoded.to_vec()), result);
+
+            let result = ConvertedIterator::new(encoded.iter().cloned(), Base58Decoder::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(decoded.to_vec()), result);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use alloc::vec;
+
+        let encoder = Base58Encoder::new().with_max_len(4);
+        let encoded = ConvertedIterator::new(*b"abcd", encoder.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            b"3VNr6P".iter().map(|&b| Ok(b)).collect::<Vec<_>>(),
+            encoded
+        );
+        let encoded = ConvertedIterator::new(*b"abcde", encoder).collect::<Vec<_>>();
+        assert_eq!(vec![Err(Base58Error::TooLong)], encoded);
+
+        let decoder = Base58Decoder::new().with_max_len(2);
+        let decoded = ConvertedIterator::new(*b"5Q", decoder.clone()).collect::<Vec<_>>();
+        assert_eq!(vec![Ok(0xFF)], decoded);
+        let decoded = ConvertedIterator::new(*b"5Q1", decoder).collect::<Vec<_>>();
+        assert_eq!(vec![Err(Base58Error::TooLong)], decoded);
+
+        let decoded = ConvertedIterator::new(*b"1O2Il", Base58Decoder::new()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Err(Base58Error::InvalidByte(b'O', 1)),
+                Err(Base58Error::InvalidByte(b'I', 3)),
+                Err(Base58Error::InvalidByte(b'l', 4)),
+                Ok(0),
+                Ok(1)
+            ],
+            decoded
+        );
+    }
+}
@@ -0,0 +1,639 @@
+//! Base64 Encoder/Decoder, described in [RFC 4648].
+//!
+//! [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Alphabets of Base64.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Alphabet {
+    /// The standard alphabet, using `+` and `/`.
+    Standard,
+    /// The URL and filename safe alphabet, using `-` and `_`.
+    UrlSafe,
+}
+
+impl Alphabet {
+    #[inline]
+    fn chars(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => STANDARD,
+            Self::UrlSafe => URL_SAFE,
+        }
+    }
+
+    #[inline]
+    fn decode(self, c: u8) -> Option<u32> {
+        match (c, self) {
+            (b'A'..=b'Z', _) => Some((c - b'A') as u32),
+            (b'a'..=b'z', _) => Some((c - b'a') as u32 + 26),
+            (b'0'..=b'9', _) => Some((c - b'0') as u32 + 52),
+            (b'+', Self::Standard) | (b'-', Self::UrlSafe) => Some(62),
+            (b'/', Self::Standard) | (b'_', Self::UrlSafe) => Some(63),
+            _ => None,
+        }
+    }
+}
+
+/// Configurations of Base64.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base64::{Alphabet, Config};
+///
+/// assert_eq!(Config::URL_SAFE_NO_PAD, Config::new(Alphabet::UrlSafe, false));
+/// assert_eq!(Config::URL_SAFE, Config::URL_SAFE_NO_PAD.with_padding(true));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Config {
+    alphabet: Alphabet,
+    padding: bool,
+}
+
+impl Default for Config {
+    #[inline]
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Config {
+    /// The standard alphabet with padding.
+    pub const STANDARD: Self = Self::new(Alphabet::Standard, true);
+    /// The standard alphabet without padding.
+    pub const STANDARD_NO_PAD: Self = Self::new(Alphabet::Standard, false);
+    /// The URL-safe alphabet with padding.
+    pub const URL_SAFE: Self = Self::new(Alphabet::UrlSafe, true);
+    /// The URL-safe alphabet without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self::new(Alphabet::UrlSafe, false);
+
+    /// Create a new configuration.
+    #[inline]
+    pub const fn new(alphabet: Alphabet, padding: bool) -> Self {
+        Self { alphabet, padding }
+    }
+
+    /// Setting the alphabet.
+    #[inline]
+    pub const fn with_alphabet(self, alphabet: Alphabet) -> Self {
+        Self { alphabet, ..self }
+    }
+
+    /// Setting whether the last quantum is padded with `=`.
+    #[inline]
+    pub const fn with_padding(self, padding: bool) -> Self {
+        Self { padding, ..self }
+    }
+
+    /// Returns the alphabet.
+    #[inline]
+    pub const fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Returns whether the last quantum is padded with `=`.
+    #[inline]
+    pub const fn padding(&self) -> bool {
+        self.padding
+    }
+}
+
+/// An encoder for Base64.
+///
+/// Each 3 bytes are encoded into 4 characters, and up to 2 remaining bytes are encoded (and
+/// padded if configured) at the end of the input.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base64::{Base64Encoder, Config};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(b"hello?>".iter().cloned(), Base64Encoder::new());
+/// assert_eq!(Ok(b"aGVsbG8/Pg==".to_vec()), encoded.collect());
+///
+/// let encoder = Base64Encoder::with_config(Config::URL_SAFE_NO_PAD);
+/// let encoded = ConvertedIterator::new(b"hello?>".iter().cloned(), encoder);
+/// assert_eq!(Ok(b"aGVsbG8_Pg".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base64Encoder {
+    config: Config,
+    pending: [u8; 2],
+    len: u8,
+}
+
+impl Base64Encoder {
+    /// Create a new instance, with [`Config::STANDARD`].
+    ///
+    /// [`Config::STANDARD`]: self::Config::STANDARD
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a configuration.
+    #[inline]
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+}
+
+impl Converter for Base64Encoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.len < 2 {
+            self.pending[self.len as usize] = item;
+            self.len += 1;
+            return Ok(0);
+        }
+
+        self.len = 0;
+        let chars = self.config.alphabet.chars();
+        let value = (self.pending[0] as u32) << 16 | (self.pending[1] as u32) << 8 | item as u32;
+        buf.extend(
+            (0..4)
+                .rev()
+                .map(|i| chars[(value >> (i * 6) & 0x3F) as usize]),
+        );
+        Ok(4)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = core::mem::take(&mut self.len) as usize;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        // the second byte is not pending if `len` is 1.
+        let second = if len == 2 { self.pending[1] } else { 0 };
+        let value = (self.pending[0] as u32) << 16 | (second as u32) << 8;
+        let chars = self.config.alphabet.chars();
+        let padding = if self.config.padding { 3 - len } else { 0 };
+        buf.extend(
+            (0..=len)
+                .map(|n| chars[(value >> (18 - n * 6) & 0x3F) as usize])
+                .chain(core::iter::repeat_n(b'=', padding)),
+        );
+        Ok(len + 1 + padding)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(4))
+    }
+}
+
+/// An error while decoding Base64.
+///
+/// Offsets are counted in input bytes from the start of the stream, including skipped whitespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64DecodeError {
+    /// A byte out of the alphabet, and its offset.
+    InvalidByte(u8, usize),
+    /// A misplaced padding character, or a character after the padding, and its offset.
+    InvalidPadding(usize),
+    /// Non-zero bits discarded by the last quantum, and the offset of the last character.
+    NonCanonical(usize),
+    /// The input ended inside a quantum of 4 characters.
+    Truncated,
+}
+
+impl Base64DecodeError {
+    /// Returns the offset of the erroneous byte, unless the input is truncated.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidByte(_, offset) | Self::InvalidPadding(offset) => Some(offset),
+            Self::NonCanonical(offset) => Some(offset),
+            Self::Truncated => None,
+        }
+    }
+}
+
+impl fmt::Display for Base64DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte(b, offset) => {
+                write!(f, "invalid byte in Base64 at {}: {:#04x}", offset, b)
+            }
+            Self::InvalidPadding(offset) => write!(f, "misplaced padding in Base64 at {}.", offset),
+            Self::NonCanonical(offset) => {
+                write!(f, "non-zero trailing bits in Base64 at {}.", offset)
+            }
+            Self::Truncated => write!(f, "the input ended inside a Base64 quantum."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base64DecodeError {}
+
+/// A decoder for Base64.
+///
+/// The alphabet and the padding follow the [`Config`]: with padding, the last quantum must be
+/// padded, and without padding, `=` is not allowed. The decoding is strict, so trailing bits of
+/// the last quantum must be zero, and nothing may follow the padding. After an error, the current
+/// quantum is discarded.
+///
+/// ASCII whitespace is an error by default, and can be skipped for line-wrapped input like MIME
+/// and PEM.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base64::{Base64DecodeError, Base64Decoder, Config};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(b"aGVsbG8=".iter().cloned(), Base64Decoder::new());
+/// assert_eq!(Ok(b"hello".to_vec()), decoded.collect());
+///
+/// let decoder = Base64Decoder::with_config(Config::URL_SAFE_NO_PAD).skip_whitespace(true);
+/// let decoded = ConvertedIterator::new(b"aGVsbG8_\r\nPg".iter().cloned(), decoder);
+/// assert_eq!(Ok(b"hello?>".to_vec()), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new(b"aGV*".iter().cloned(), Base64Decoder::new());
+/// assert_eq!(Some(Err(Base64DecodeError::InvalidByte(b'*', 3))), decoded.next());
+/// ```
+///
+/// [`Config`]: self::Config
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base64Decoder {
+    config: Config,
+    skip_whitespace: bool,
+    value: u32,
+    // the number of characters, and the number of padding characters in the current quantum.
+    len: u8,
+    padding: u8,
+    // the offset of the next byte, and of the last character of the alphabet.
+    offset: usize,
+    last: usize,
+    // whether a padded quantum has been completed.
+    ended: bool,
+}
+
+impl Base64Decoder {
+    /// Create a new instance, with [`Config::STANDARD`].
+    ///
+    /// [`Config::STANDARD`]: self::Config::STANDARD
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a configuration.
+    #[inline]
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether ASCII whitespace (including `\r` and `\n`) is skipped.
+    #[inline]
+    pub fn skip_whitespace(self, skip: bool) -> Self {
+        Self {
+            skip_whitespace: skip,
+            ..self
+        }
+    }
+
+    fn reset(&mut self) {
+        self.value = 0;
+        self.len = 0;
+        self.padding = 0;
+    }
+
+    fn emit<E>(&mut self, buf: &mut E) -> Result<usize, Base64DecodeError>
+    where
+        E: Extend<u8>,
+    {
+        // bits of the last character which do not fit into bytes.
+        let extra = 6 * self.len as u32 % 8;
+        if self.value & ((1 << extra) - 1) != 0 {
+            return self.error(Base64DecodeError::NonCanonical(self.last));
+        }
+
+        let len = self.len as usize - 1;
+        let value = self.value << (6 * (4 - self.len as u32));
+        self.reset();
+        buf.extend((0..len).map(|i| (value >> (16 - i * 8)) as u8));
+        Ok(len)
+    }
+
+    fn error(&mut self, err: Base64DecodeError) -> Result<usize, Base64DecodeError> {
+        self.reset();
+        self.ended = false;
+        Err(err)
+    }
+}
+
+impl Converter for Base64Decoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base64DecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        if self.skip_whitespace && item.is_ascii_whitespace() {
+            return Ok(0);
+        }
+        if self.ended {
+            return self.error(Base64DecodeError::InvalidPadding(offset));
+        }
+
+        if item == b'=' {
+            if !self.config.padding || self.len < 2 {
+                return self.error(Base64DecodeError::InvalidPadding(offset));
+            }
+            self.padding += 1;
+            return if self.len + self.padding == 4 {
+                self.ended = true;
+                self.emit(buf)
+            } else {
+                Ok(0)
+            };
+        }
+
+        let value = match self.config.alphabet.decode(item) {
+            Some(value) => value,
+            None => return self.error(Base64DecodeError::InvalidByte(item, offset)),
+        };
+        if self.padding > 0 {
+            return self.error(Base64DecodeError::InvalidPadding(offset));
+        }
+        self.value = self.value << 6 | value;
+        self.len += 1;
+        self.last = offset;
+        if self.len == 4 {
+            self.emit(buf)
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match (self.len, self.padding, self.config.padding) {
+            (0, _, _) => Ok(0),
+            (2 | 3, 0, false) => self.emit(buf),
+            _ => self.error(Base64DecodeError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(3))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rfc4648() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors: [(&[u8], &[u8]); 7] = [
+            (b"", b""),
+            (b"f", b"Zg=="),
+            (b"fo", b"Zm8="),
+            (b"foo", b"Zm9v"),
+            (b"foob", b"Zm9vYg=="),
+            (b"fooba", b"Zm9vYmE="),
+            (b"foobar", b"Zm9vYmFy"),
+        ];
+        for (decoded, encoded) in vectors {
+            let iter = decoded.iter().cloned();
+            let result =
+                ConvertedIterator::new(iter, Base64Encoder::new()).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(encoded.to_vec()), result);
+
+            let iter = encoded.iter().cloned();
+            let result =
+                ConvertedIterator::new(iter, Base64Decoder::new()).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(decoded.to_vec()), result);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn configs() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::vec::Vec;
+
+        let mut next = random();
+        // outputs of the `base64` crate, with `STANDARD` and `URL_SAFE_NO_PAD` engines.
+        let vectors = [
+            (0, "", ""),
+            (1, "Og==", "Og"),
+            (2, "q6w=", "q6w"),
+            (3, "Jq8j", "Jq8j"),
+            (4, "GnFskQ==", "GnFskQ"),
+            (5, "XTEYPrw=", "XTEYPrw"),
+            (6, "0u9RIp1y", "0u9RIp1y"),
+            (
+                30,
+                "T9vZbzlurivIIi8M4+2MaHuiiZnWOaef8lX+kRW4",
+                "T9vZbzlurivIIi8M4-2MaHuiiZnWOaef8lX-kRW4",
+            ),
+            (
+                31,
+                "IKp6lIqgTcCd/klM3I7guQayMClKYBzfPLdiz0IFGQ==",
+                "IKp6lIqgTcCd_klM3I7guQayMClKYBzfPLdiz0IFGQ",
+            ),
+            (
+                32,
+                "DEuz3+F8RftQUWdweMkE+EMMtEhzy8YF2J9Y8G3X5Tg=",
+                "DEuz3-F8RftQUWdweMkE-EMMtEhzy8YF2J9Y8G3X5Tg",
+            ),
+        ];
+        for (len, standard, url_safe) in vectors {
+            let bytes = (0..len).map(|_| next() as u8).collect::<Vec<_>>();
+            let encode = |config| {
+                let encoder = Base64Encoder::with_config(config);
+                ConvertedIterator::new(bytes.iter().cloned(), encoder)
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            assert_eq!(Ok(standard.as_bytes().to_vec()), encode(Config::STANDARD));
+            assert_eq!(
+                Ok(url_safe.as_bytes().to_vec()),
+                encode(Config::URL_SAFE_NO_PAD)
+            );
+            assert_eq!(
+                Ok(standard.trim_end_matches('=').as_bytes().to_vec()),
+                encode(Config::STANDARD_NO_PAD)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::vec::Vec;
+
+        let mut next = random();
+        let configs = [
+            Config::STANDARD,
+            Config::STANDARD_NO_PAD,
+            Config::URL_SAFE,
+            Config::URL_SAFE_NO_PAD,
+        ];
+        for _ in 0..200 {
+            let len = next() as usize % 64;
+            let bytes = (0..len).map(|_| next() as u8).collect::<Vec<_>>();
+            for config in configs {
+                let encoder = Base64Encoder::with_config(config);
+                let encoded = ConvertedIterator::new(bytes.iter().cloned(), encoder)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+                let decoder = Base64Decoder::with_config(config);
+                let decoded = ConvertedIterator::new(encoded.iter().cloned(), decoder)
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(&bytes), decoded.as_ref());
+
+                // line breaks at random positions.
+                let wrapped = encoded.iter().flat_map(|&b| {
+                    let ws: &[u8] = if next().is_multiple_of(8) { b"\r\n" } else { b"" };
+                    ws.iter().cloned().chain([b])
+                });
+                let decoder = Base64Decoder::with_config(config).skip_whitespace(true);
+                let decoded = ConvertedIterator::new(wrapped.collect::<Vec<_>>(), decoder)
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(&bytes), decoded.as_ref());
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8], config| {
+            ConvertedIterator::new(bytes.iter().cloned(), Base64Decoder::with_config(config))
+                .collect::<Vec<_>>()
+        };
+        let standard = |bytes: &[u8]| decode(bytes, Config::STANDARD);
+
+        // invalid characters, including the other alphabet and whitespace.
+        assert_eq!(
+            vec![
+                Ok(b'f'),
+                Ok(b'o'),
+                Ok(b'o'),
+                Err(Base64DecodeError::InvalidByte(b'\n', 4))
+            ],
+            standard(b"Zm9v\nYmFy")[..4]
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::InvalidByte(b'-', 2))],
+            standard(b"Zm-v")[..1]
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::InvalidByte(b'/', 2))],
+            decode(b"Zm/v", Config::URL_SAFE)[..1]
+        );
+
+        // padding in the middle, or after the padding.
+        assert_eq!(
+            vec![Err(Base64DecodeError::InvalidPadding(1))],
+            standard(b"Z===")[..1]
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::InvalidPadding(3))],
+            standard(b"Zg=g")
+        );
+        assert_eq!(
+            vec![Ok(b'f'), Err(Base64DecodeError::InvalidPadding(4))],
+            standard(b"Zg==Zg==")[..2]
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::InvalidPadding(2))],
+            decode(b"Zg==", Config::STANDARD_NO_PAD)[..1]
+        );
+
+        // non-canonical trailing bits.
+        assert_eq!(
+            vec![Err(Base64DecodeError::NonCanonical(1))],
+            standard(b"Zh==")
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::NonCanonical(2))],
+            standard(b"Zm9=")
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::NonCanonical(2))],
+            decode(b"Zm9", Config::STANDARD_NO_PAD)
+        );
+
+        // truncated final quantum.
+        assert_eq!(vec![Err(Base64DecodeError::Truncated)], standard(b"Zm8"));
+        assert_eq!(
+            vec![
+                Ok(b'f'),
+                Ok(b'o'),
+                Ok(b'o'),
+                Err(Base64DecodeError::Truncated)
+            ],
+            standard(b"Zm9vY")
+        );
+        assert_eq!(vec![Err(Base64DecodeError::Truncated)], standard(b"Zg="));
+        assert_eq!(
+            vec![Ok(b'f'), Ok(b'o')],
+            decode(b"Zm8", Config::STANDARD_NO_PAD)
+        );
+        assert_eq!(
+            vec![Err(Base64DecodeError::Truncated)],
+            decode(b"Z", Config::STANDARD_NO_PAD)
+        );
+
+        // offsets count skipped whitespace.
+        let decoder = Base64Decoder::new().skip_whitespace(true);
+        let decoded = ConvertedIterator::new(b" Zm9v\r\n Y*".iter().cloned(), decoder);
+        assert_eq!(
+            Err(Base64DecodeError::InvalidByte(b'*', 9)),
+            decoded.collect::<Result<Vec<_>, _>>()
+        );
+    }
+}
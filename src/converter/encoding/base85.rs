@@ -0,0 +1,563 @@
+//! Base85 Encoder/Decoder, in variants of [Ascii85] (used in PostScript and PDF) and [Z85].
+//!
+//! Both variants encode each 4 bytes into 5 characters, with different alphabets.
+//!
+//! [Ascii85]: https://en.wikipedia.org/wiki/Ascii85
+//! [Z85]: https://rfc.zeromq.org/spec/32/
+
+use crate::Converter;
+use core::fmt;
+
+const Z85: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Variants of Base85.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Adobe's Ascii85, characters from `!` to `u`.
+    ///
+    /// A group of 4 zero bytes is abbreviated to `z`, and a partial group at the end of the input
+    /// is truncated. The encoded data can be framed by `<~` and `~>`, and whitespace is ignored
+    /// while decoding.
+    #[default]
+    Ascii85,
+    /// ZeroMQ's Z85, which uses characters safe in source code strings.
+    ///
+    /// The input length must be a multiple of 4 bytes (or 5 characters while decoding).
+    Z85,
+}
+
+impl Variant {
+    #[inline]
+    fn encode(self, digit: u32) -> u8 {
+        match self {
+            Self::Ascii85 => b'!' + digit as u8,
+            Self::Z85 => Z85[digit as usize],
+        }
+    }
+
+    #[inline]
+    fn decode(self, c: u8) -> Option<u64> {
+        match self {
+            Self::Ascii85 => matches!(c, b'!'..=b'u').then(|| (c - b'!') as u64),
+            Self::Z85 => Z85.iter().position(|&x| x == c).map(|digit| digit as u64),
+        }
+    }
+
+    fn encode_group(self, mut value: u32) -> [u8; 5] {
+        let mut chars = [0; 5];
+        for c in chars.iter_mut().rev() {
+            *c = self.encode(value % 85);
+            value /= 85;
+        }
+        chars
+    }
+}
+
+/// An error while encoding/decoding Base85.
+///
+/// Offsets are counted in input bytes from the start of the stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base85Error {
+    /// A byte out of the alphabet, or a misplaced `z` or frame delimiter, and its offset.
+    InvalidByte(u8, usize),
+    /// A group which exceeds 2^32 - 1, and the offset of its last character.
+    Overflow(usize),
+    /// The input length is not a multiple of the group size, or the last group of Ascii85 has
+    /// only one character.
+    Misaligned,
+    /// The input ended without the `~>` delimiter.
+    Unterminated,
+}
+
+impl fmt::Display for Base85Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte(b, offset) => {
+                write!(f, "invalid byte in Base85 at {}: {:#04x}", offset, b)
+            }
+            Self::Overflow(offset) => write!(f, "a Base85 group overflows at {}.", offset),
+            Self::Misaligned => write!(f, "the input ended inside a Base85 group."),
+            Self::Unterminated => write!(f, "the Ascii85 data is not terminated by `~>`."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base85Error {}
+
+/// An encoder for Base85.
+///
+/// Each 4 bytes are encoded into 5 characters. For Ascii85, up to 3 remaining bytes are encoded
+/// at the end of the input, and for Z85, remaining bytes are an error.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base85::{Base85Encoder, Base85Error, Variant};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(*b"\0\0\0\0ab", Base85Encoder::new().framed(true));
+/// assert_eq!(Ok(b"<~z@:B~>".to_vec()), encoded.collect());
+///
+/// let encoder = Base85Encoder::with_variant(Variant::Z85);
+/// let encoded = ConvertedIterator::new(*b"abc", encoder);
+/// assert_eq!(Err(Base85Error::Misaligned), encoded.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base85Encoder {
+    variant: Variant,
+    framed: bool,
+    value: u32,
+    len: u8,
+    // whether `<~` has been emitted.
+    opened: bool,
+}
+
+impl Base85Encoder {
+    /// Create a new instance, with [`Variant::Ascii85`].
+    ///
+    /// [`Variant::Ascii85`]: self::Variant::Ascii85
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a variant.
+    #[inline]
+    pub fn with_variant(variant: Variant) -> Self {
+        Self {
+            variant,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether the output is framed by `<~` and `~>`. (only for Ascii85)
+    #[inline]
+    pub fn framed(self, framed: bool) -> Self {
+        Self { framed, ..self }
+    }
+
+    fn open<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        if self.framed && !self.opened && self.variant == Variant::Ascii85 {
+            self.opened = true;
+            buf.extend(*b"<~");
+            2
+        } else {
+            0
+        }
+    }
+}
+
+impl Converter for Base85Encoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base85Error;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.value = self.value << 8 | item as u32;
+        self.len += 1;
+        if self.len < 4 {
+            return Ok(0);
+        }
+
+        let value = core::mem::take(&mut self.value);
+        self.len = 0;
+        let count = self.open(buf);
+        if value == 0 && self.variant == Variant::Ascii85 {
+            buf.extend([b'z']);
+            Ok(count + 1)
+        } else {
+            buf.extend(self.variant.encode_group(value));
+            Ok(count + 5)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = core::mem::take(&mut self.len) as usize;
+        let value = core::mem::take(&mut self.value);
+        if self.variant == Variant::Z85 {
+            return if len == 0 {
+                Ok(0)
+            } else {
+                Err(Base85Error::Misaligned)
+            };
+        }
+
+        let mut count = self.open(buf);
+        if len > 0 {
+            // pads with zero bytes, and truncates the group.
+            let chars = self.variant.encode_group(value << ((4 - len) * 8));
+            buf.extend(chars[..=len].iter().cloned());
+            count += len + 1;
+        }
+        if core::mem::take(&mut self.opened) {
+            buf.extend(*b"~>");
+            count += 2;
+        }
+        Ok(count)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        if self.variant == Variant::Z85 {
+            return Some(0);
+        }
+        // a partial group, and the delimiters if framed.
+        let group = match self.len {
+            0 => 0,
+            len => len as usize + 1,
+        };
+        Some(group + if self.framed { 4 } else { 0 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.framed && self.variant == Variant::Ascii85 {
+            (0, Some(8))
+        } else {
+            (0, Some(5))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Frame {
+    #[default]
+    Start,
+    // after `<`.
+    Opening,
+    Data,
+    // after `~`.
+    Closing,
+    Closed,
+}
+
+/// A decoder for Base85.
+///
+/// Each 5 characters are decoded into 4 bytes. For Ascii85, `z` is decoded into 4 zero bytes,
+/// whitespace is ignored, and a partial group of 2 to 4 characters at the end is decoded into 1
+/// to 3 bytes. If [`framed`], the input must start with `<~` and end with `~>`. The current group
+/// is discarded after an error.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base85::{Base85Decoder, Base85Error, Variant};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(*b"<~z@:B~>", Base85Decoder::new().framed(true));
+/// assert_eq!(Ok(b"\0\0\0\0ab".to_vec()), decoded.collect());
+///
+/// let decoder = Base85Decoder::with_variant(Variant::Z85);
+/// let decoded = ConvertedIterator::new(*b"Hello", decoder);
+/// assert_eq!(Ok(b"\x86\x4f\xd2\x6f".to_vec()), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new(*b"s8W-\"", Base85Decoder::new());
+/// assert_eq!(Some(Err(Base85Error::Overflow(4))), decoded.next());
+/// ```
+///
+/// [`framed`]: Self::framed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Base85Decoder {
+    variant: Variant,
+    framed: bool,
+    value: u64,
+    len: u8,
+    frame: Frame,
+    // the offset of the next byte, and of the last character of the alphabet.
+    offset: usize,
+    last: usize,
+}
+
+impl Base85Decoder {
+    /// Create a new instance, with [`Variant::Ascii85`].
+    ///
+    /// [`Variant::Ascii85`]: self::Variant::Ascii85
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a variant.
+    #[inline]
+    pub fn with_variant(variant: Variant) -> Self {
+        Self {
+            variant,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether the input is framed by `<~` and `~>`. (only for Ascii85)
+    #[inline]
+    pub fn framed(self, framed: bool) -> Self {
+        Self { framed, ..self }
+    }
+
+    fn reset(&mut self) {
+        self.value = 0;
+        self.len = 0;
+    }
+
+    // emits the current group, padded with `u` (the largest digit) if partial.
+    fn emit<E>(&mut self, buf: &mut E) -> Result<usize, Base85Error>
+    where
+        E: Extend<u8>,
+    {
+        let len = self.len as usize;
+        let value = (len..5).fold(self.value, |value, _| value * 85 + 84);
+        self.reset();
+        match len {
+            0 => Ok(0),
+            1 => Err(Base85Error::Misaligned),
+            _ if value > u32::MAX as u64 => Err(Base85Error::Overflow(self.last)),
+            _ => {
+                buf.extend((value as u32).to_be_bytes()[..len - 1].iter().cloned());
+                Ok(len - 1)
+            }
+        }
+    }
+
+    fn error(&mut self, err: Base85Error) -> Result<usize, Base85Error> {
+        self.reset();
+        Err(err)
+    }
+
+    fn decode<E>(&mut self, item: u8, offset: usize, buf: &mut E) -> Result<usize, Base85Error>
+    where
+        E: Extend<u8>,
+    {
+        let ascii85 = self.variant == Variant::Ascii85;
+        if ascii85 && self.framed && item == b'~' {
+            self.frame = Frame::Closing;
+            return self.emit(buf);
+        }
+        if ascii85 && item == b'z' {
+            if self.len != 0 {
+                return self.error(Base85Error::InvalidByte(item, offset));
+            }
+            buf.extend([0; 4]);
+            return Ok(4);
+        }
+
+        let digit = match self.variant.decode(item) {
+            Some(digit) => digit,
+            None => return self.error(Base85Error::InvalidByte(item, offset)),
+        };
+        self.value = self.value * 85 + digit;
+        self.len += 1;
+        self.last = offset;
+        if self.len == 5 {
+            self.emit(buf)
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+impl Converter for Base85Decoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base85Error;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        let ascii85 = self.variant == Variant::Ascii85;
+        if ascii85 && item.is_ascii_whitespace() {
+            return Ok(0);
+        }
+
+        let framed = ascii85 && self.framed;
+        let (expected, next) = match self.frame {
+            Frame::Start if framed => (Some(b'<'), Frame::Opening),
+            Frame::Opening => (Some(b'~'), Frame::Data),
+            Frame::Closing => (Some(b'>'), Frame::Closed),
+            Frame::Closed => (None, Frame::Closed),
+            Frame::Start | Frame::Data => {
+                self.frame = Frame::Data;
+                return self.decode(item, offset, buf);
+            }
+        };
+        if Some(item) == expected {
+            self.frame = next;
+            Ok(0)
+        } else {
+            // a broken `<~` is skipped, and a broken `~>` still closes the data.
+            self.frame = match self.frame {
+                Frame::Start | Frame::Opening => Frame::Data,
+                _ => Frame::Closed,
+            };
+            Err(Base85Error::InvalidByte(item, offset))
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let frame = core::mem::take(&mut self.frame);
+        if self.variant == Variant::Z85 {
+            return if self.len == 0 {
+                Ok(0)
+            } else {
+                self.error(Base85Error::Misaligned)
+            };
+        }
+        if self.framed && frame != Frame::Closed {
+            return self.error(Base85Error::Unterminated);
+        }
+        self.emit(buf)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(4))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn leviathan() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let text: &[u8] = b"Man is distinguished, not only by his reason, but by this singular \
+            passion from other animals, which is a lust of the mind, that by a perseverance of \
+            delight in the continued and indefatigable generation of knowledge, exceeds the short \
+            vehemence of any carnal pleasure.";
+        let encoded: &[u8] = b"<~9jqo^BlbD-BleB1DJ+*+F(f,q/0JhKF<GL>Cj@.4Gp$d7F!,L7@<6@)/0JDEF<G%<+EV:\
+            2F!,O<DJ+*.@<*K0@<6L(Df-\\0Ec5e;DffZ(EZee.Bl.9pF\"AGXBPCsi+DGm>@3BB/F*&OCAfu2/AKYi(DIb:@\
+            FD,*)+C]U=@3BN#EcYf8ATD3s@q?d$AftVqCh[NqF<G:8+EV:.+Cf>-FD5W8ARlolDIal(DId<j@<?3r@:F%a+D58\
+            'ATD4$Bl@l3De:,-DJs`8ARoFb/0JMK@qB4^F!,R<AKZ&-DfTqBG%G>uD.RTpAKYo'+CT/5+Cei#DII?(E,9)oF*2\
+            M7/c~>";
+
+        let encoder = Base85Encoder::new().framed(true);
+        let result = ConvertedIterator::new(text.iter().cloned(), encoder).collect();
+        assert_eq!(Ok(encoded.to_vec()), result);
+
+        // line breaks are ignored.
+        let decoder = Base85Decoder::new().framed(true);
+        let wrapped = encoded
+            .chunks(76)
+            .flat_map(|line| line.iter().chain(b"\r\n"));
+        let result = ConvertedIterator::new(wrapped.cloned(), decoder).collect();
+        assert_eq!(Ok(text.to_vec()), result);
+
+        // partial groups and `z`.
+        for len in 0..=text.len() {
+            let mut data = text[..len].to_vec();
+            data.extend(core::iter::repeat_n(0, len % 7));
+            let encoded = ConvertedIterator::new(data.iter().cloned(), Base85Encoder::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let decoded = ConvertedIterator::new(encoded, Base85Decoder::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(data), decoded);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn z85() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let data: &[u8] = &[0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+        let encoder = Base85Encoder::with_variant(Variant::Z85);
+        let result = ConvertedIterator::new(data.iter().cloned(), encoder).collect();
+        assert_eq!(Ok(b"HelloWorld".to_vec()), result);
+
+        let decoder = Base85Decoder::with_variant(Variant::Z85);
+        let result = ConvertedIterator::new(b"HelloWorld".iter().cloned(), decoder).collect();
+        assert_eq!(Ok(data.to_vec()), result);
+
+        // no abbreviation, no framing.
+        let encoder = Base85Encoder::with_variant(Variant::Z85).framed(true);
+        let result = ConvertedIterator::new([0; 4], encoder).collect();
+        assert_eq!(Ok(b"00000".to_vec()), result);
+
+        let decoder = Base85Decoder::with_variant(Variant::Z85);
+        let result = ConvertedIterator::new(b"Hello World".iter().cloned(), decoder)
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Err(Base85Error::InvalidByte(b' ', 5)), result);
+
+        let decoder = Base85Decoder::with_variant(Variant::Z85);
+        let result = ConvertedIterator::new(b"HelloWor".iter().cloned(), decoder)
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Err(Base85Error::Misaligned), result);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut decoder = Base85Decoder::new().framed(true);
+        let mut buf = Vec::new();
+        assert_eq!(
+            Err(Base85Error::InvalidByte(b'!', 0)),
+            decoder.convert(b'!', &mut buf)
+        );
+        for &c in b"!!" {
+            assert_eq!(Ok(0), decoder.convert(c, &mut buf));
+        }
+        assert_eq!(
+            Err(Base85Error::InvalidByte(b'z', 3)),
+            decoder.convert(b'z', &mut buf)
+        );
+        assert_eq!(Ok(4), decoder.convert(b'z', &mut buf));
+        assert_eq!(
+            Err(Base85Error::InvalidByte(b'v', 5)),
+            decoder.convert(b'v', &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(b'!', &mut buf));
+        assert_eq!(
+            Err(Base85Error::Misaligned),
+            decoder.convert(b'~', &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(b'>', &mut buf));
+        assert_eq!(
+            Err(Base85Error::InvalidByte(b'!', 9)),
+            decoder.convert(b'!', &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.finish(&mut buf));
+        assert_eq!(vec![0; 4], buf);
+
+        let mut decoder = Base85Decoder::new().framed(true);
+        for &c in b"<~!!" {
+            assert_eq!(Ok(0), decoder.convert(c, &mut buf));
+        }
+        assert_eq!(Err(Base85Error::Unterminated), decoder.finish(&mut buf));
+
+        let mut decoder = Base85Decoder::new();
+        assert_eq!(
+            Err(Base85Error::InvalidByte(b'~', 0)),
+            decoder.convert(b'~', &mut buf)
+        );
+        for &c in b"s8W-" {
+            assert_eq!(Ok(0), decoder.convert(c, &mut buf));
+        }
+        assert_eq!(Err(Base85Error::Overflow(4)), decoder.finish(&mut buf));
+    }
+}
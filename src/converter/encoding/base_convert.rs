@@ -0,0 +1,190 @@
+//! Converting digit sequences between numeric bases.
+//!
+//! Digits are values (not characters) in big-endian order, so `[1, 0]` in base 16 is sixteen.
+//! The whole sequence is treated as a big number, so converters in this module buffer all inputs
+//! and emit outputs when the input ends. The conversion takes quadratic time.
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error for digits out of the base.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BaseConvertError {
+    /// A digit not less than the base, and its offset.
+    InvalidDigit(u8, usize),
+}
+
+impl fmt::Display for BaseConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(d, offset) => write!(f, "invalid digit at {}: {}", offset, d),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BaseConvertError {}
+
+// converts big-endian digits in base `from` into little-endian digits in base `to`.
+pub(super) fn convert_base(input: &[u8], from: u32, to: u32) -> Vec<u8> {
+    let mut output = Vec::<u8>::new();
+    for &digit in input {
+        let mut carry = digit as u32;
+        for d in output.iter_mut() {
+            carry += *d as u32 * from;
+            *d = (carry % to) as u8;
+            carry /= to;
+        }
+        while carry > 0 {
+            output.push((carry % to) as u8);
+            carry /= to;
+        }
+    }
+    output
+}
+
+/// A converter for digit sequences from a base to another base, emits digits when the input
+/// ends.
+///
+/// Leading zeros are not preserved, and zero is emitted as a single `0`. (An empty input emits
+/// nothing.) Digits out of the base are reported with their offsets, and skipped.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::base_convert::{BaseConvertError, BaseConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// // 0xFF to decimal.
+/// let converted = ConvertedIterator::new([15, 15], BaseConverter::new(16, 10));
+/// assert_eq!(Ok(vec![2, 5, 5]), converted.collect());
+///
+/// let converted = ConvertedIterator::new([0, 0, 1, 0, 1], BaseConverter::new(2, 10));
+/// assert_eq!(Ok(vec![5]), converted.collect());
+///
+/// let mut converted = ConvertedIterator::new([1, 10, 0], BaseConverter::new(10, 2));
+/// assert_eq!(Some(Err(BaseConvertError::InvalidDigit(10, 1))), converted.next());
+/// assert_eq!(Some(Ok(1)), converted.next());
+/// assert_eq!(Some(Ok(0)), converted.next());
+/// assert_eq!(Some(Ok(1)), converted.next());
+/// assert_eq!(Some(Ok(0)), converted.next());
+/// assert_eq!(None, converted.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BaseConverter {
+    from: u8,
+    to: u8,
+    buffer: Vec<u8>,
+    // the offset of the next digit.
+    offset: usize,
+}
+
+impl BaseConverter {
+    /// Create a new instance converting digits in base `from` into base `to`.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is less than 2.
+    #[inline]
+    pub fn new(from: u8, to: u8) -> Self {
+        assert!(from >= 2 && to >= 2, "bases must be at least 2.");
+        Self {
+            from,
+            to,
+            buffer: Vec::new(),
+            offset: 0,
+        }
+    }
+}
+
+impl Converter for BaseConverter {
+    type Item = u8;
+    type Output = u8;
+    type Error = BaseConvertError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        if item >= self.from {
+            return Err(BaseConvertError::InvalidDigit(item, offset));
+        }
+        // leading zeros do not change the value.
+        if item != 0 || !self.buffer.is_empty() {
+            self.buffer.push(item);
+        }
+        Ok(0)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let input = core::mem::take(&mut self.buffer);
+        let empty = core::mem::take(&mut self.offset) == 0;
+        let digits = convert_base(&input, self.from as u32, self.to as u32);
+        if digits.is_empty() {
+            if empty {
+                return Ok(0);
+            }
+            buf.extend([0]);
+            return Ok(1);
+        }
+        buf.extend(digits.iter().rev().cloned());
+        Ok(digits.len())
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+
+    #[test]
+    fn vectors() {
+        let vectors: [(u8, &[u8], u8, &[u8]); 7] = [
+            (10, &[], 16, &[]),
+            (10, &[0, 0], 16, &[0]),
+            (
+                16,
+                &[0xD, 0xE, 0xA, 0xD, 0xB, 0xE, 0xE, 0xF],
+                10,
+                &[3, 7, 3, 5, 9, 2, 8, 5, 5, 9],
+            ),
+            (2, &[1, 1, 1, 1, 1, 1, 1, 1], 16, &[15, 15]),
+            (255, &[1, 0], 2, &[1; 8]),
+            (
+                10,
+                &[
+                    3, 4, 0, 2, 8, 2, 3, 6, 6, 9, 2, 0, 9, 3, 8, 4, 6, 3, 4, 6, 3, 3, 7, 4, 6, 0,
+                    7, 4, 3, 1, 7, 6, 8, 2, 1, 1, 4, 5, 5,
+                ],
+                16,
+                &[15; 32],
+            ),
+            (36, &[35, 0, 0], 10, &[4, 5, 3, 6, 0]),
+        ];
+        for (from, input, to, output) in vectors {
+            let result =
+                ConvertedIterator::new(input.iter().cloned(), BaseConverter::new(from, to))
+                    .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(output.to_vec()), result, "{:?}", input);
+
+            let result =
+                ConvertedIterator::new(output.iter().cloned(), BaseConverter::new(to, from))
+                    .collect::<Result<Vec<_>, _>>();
+            let stripped = match input.iter().position(|&d| d != 0) {
+                Some(start) => &input[start..],
+                None => &input[input.len().saturating_sub(1)..],
+            };
+            assert_eq!(Ok(stripped.to_vec()), result, "{:?}", output);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Packed BCD (binary-coded decimal) Encoder/Decoder.
+//!
+//! Each byte holds two decimal digits, one in each nibble. The high nibble comes first by default,
+//! and telephony formats like TBCD (3GPP TS 29.002) put the low nibble first, filling the last
+//! byte of an odd number of digits with the nibble `0xF`.
+
+use crate::converter::NibbleOrder;
+use crate::Converter;
+use core::fmt;
+
+// the filler nibble.
+const FILLER: u8 = 0xF;
+
+/// An error while encoding/decoding packed BCD.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BCDError {
+    /// A nibble which is not a decimal digit, with the offset of its byte.
+    InvalidNibble(u8, usize),
+    /// A character which is not a decimal digit.
+    InvalidDigit(char),
+    /// The input ended with an odd number of digits, without fillers.
+    OddDigits,
+}
+
+impl fmt::Display for BCDError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNibble(n, offset) => {
+                write!(f, "invalid BCD nibble 0x{:X} at {}.", n, offset)
+            }
+            Self::InvalidDigit(c) => write!(f, "invalid decimal digit: {:?}", c),
+            Self::OddDigits => write!(f, "an odd number of digits without fillers."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BCDError {}
+
+/// An encoder packing decimal digits into bytes.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::bcd::{BCDEncoder, BCDError};
+/// use conversion::converter::NibbleOrder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new("1234".chars(), BCDEncoder::new());
+/// assert_eq!(Ok(vec![0x12, 0x34]), encoded.collect());
+///
+/// let encoder = BCDEncoder::with_order(NibbleOrder::LowFirst).filler(true);
+/// let encoded = ConvertedIterator::new("123".chars(), encoder);
+/// assert_eq!(Ok(vec![0x21, 0xF3]), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new("123".chars(), BCDEncoder::new());
+/// assert_eq!(Err(BCDError::OddDigits), encoded.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BCDEncoder {
+    order: NibbleOrder,
+    filler: bool,
+    pending: Option<u8>,
+}
+
+impl BCDEncoder {
+    /// Create a new instance, puts the high nibble first.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a nibble order.
+    #[inline]
+    pub fn with_order(order: NibbleOrder) -> Self {
+        Self {
+            order,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether to fill the last byte of an odd number of digits with `0xF`.
+    #[inline]
+    pub fn filler(self, filler: bool) -> Self {
+        Self { filler, ..self }
+    }
+}
+
+impl Converter for BCDEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = BCDError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let digit = match item.to_digit(10) {
+            Some(digit) => digit as u8,
+            None => return Err(BCDError::InvalidDigit(item)),
+        };
+        match self.pending.take() {
+            Some(first) => {
+                buf.extend([self.order.join(first, digit)]);
+                Ok(1)
+            }
+            None => {
+                self.pending = Some(digit);
+                Ok(0)
+            }
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.pending.take() {
+            None => Ok(0),
+            Some(last) if self.filler => {
+                buf.extend([self.order.join(last, FILLER)]);
+                Ok(1)
+            }
+            Some(_) => Err(BCDError::OddDigits),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A decoder unpacking bytes into decimal digits.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::bcd::{BCDDecoder, BCDError};
+/// use conversion::converter::NibbleOrder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new([0x12, 0x34], BCDDecoder::new());
+/// assert_eq!(Ok(String::from("1234")), decoded.collect());
+///
+/// let decoder = BCDDecoder::with_order(NibbleOrder::LowFirst).filler(true);
+/// let decoded = ConvertedIterator::new([0x21, 0xF3], decoder);
+/// assert_eq!(Ok(String::from("123")), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new([0x12, 0xA3], BCDDecoder::new());
+/// assert_eq!(Some(Ok('1')), decoded.next());
+/// assert_eq!(Some(Ok('2')), decoded.next());
+/// assert_eq!(Some(Err(BCDError::InvalidNibble(0xA, 1))), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BCDDecoder {
+    order: NibbleOrder,
+    filler: bool,
+    offset: usize,
+}
+
+impl BCDDecoder {
+    /// Create a new instance, takes the high nibble first.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a nibble order.
+    #[inline]
+    pub fn with_order(order: NibbleOrder) -> Self {
+        Self {
+            order,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether to skip `0xF` nibbles as fillers, instead of returning errors.
+    #[inline]
+    pub fn filler(self, filler: bool) -> Self {
+        Self { filler, ..self }
+    }
+}
+
+impl Converter for BCDDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = BCDError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        let mut len = 0;
+        for nibble in self.order.split(item) {
+            match nibble {
+                0..=9 => {
+                    buf.extend([(b'0' + nibble) as char]);
+                    len += 1;
+                }
+                FILLER if self.filler => {}
+                _ => return Err(BCDError::InvalidNibble(nibble, offset)),
+            }
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.offset = 0;
+        Ok(())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn telephony() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let vectors: [(&str, &[u8]); 3] = [
+            ("", &[]),
+            ("0901234567", &[0x90, 0x10, 0x32, 0x54, 0x76]),
+            ("81901234567", &[0x18, 0x09, 0x21, 0x43, 0x65, 0xF7]),
+        ];
+        for (digits, bytes) in vectors {
+            let encoder = BCDEncoder::with_order(NibbleOrder::LowFirst).filler(true);
+            let encoded =
+                ConvertedIterator::new(digits.chars(), encoder).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(bytes.to_vec()), encoded);
+
+            let decoder = BCDDecoder::with_order(NibbleOrder::LowFirst).filler(true);
+            let decoded = ConvertedIterator::new(bytes.iter().cloned(), decoder)
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(digits)), decoded);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let mut decoder = BCDDecoder::new();
+        let mut digits = String::new();
+        assert_eq!(Ok(2), decoder.convert(0x09, &mut digits));
+        assert_eq!(
+            Err(BCDError::InvalidNibble(0xF, 1)),
+            decoder.convert(0x1F, &mut digits)
+        );
+        assert_eq!(
+            Err(BCDError::InvalidNibble(0xB, 2)),
+            decoder.convert(0xB0, &mut digits)
+        );
+        assert_eq!("091", digits);
+
+        let mut decoder = BCDDecoder::new().filler(true);
+        assert_eq!(
+            Err(BCDError::InvalidNibble(0xC, 0)),
+            decoder.convert(0xFC, &mut digits)
+        );
+
+        let mut encoder = BCDEncoder::new();
+        let mut bytes = Vec::new();
+        assert_eq!(
+            Err(BCDError::InvalidDigit('+')),
+            encoder.convert('+', &mut bytes)
+        );
+        assert_eq!(Ok(0), encoder.convert('4', &mut bytes));
+        assert_eq!(Err(BCDError::OddDigits), encoder.finish(&mut bytes));
+        assert_eq!(Ok(0), encoder.finish(&mut bytes));
+        assert!(bytes.is_empty());
+    }
+}
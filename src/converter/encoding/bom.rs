@@ -0,0 +1,296 @@
+//! Injecting/Stripping byte order marks.
+//!
+//! These converters work on arbitrary byte streams, so they can be combined with any encoders
+//! and decoders.
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+/// The byte order mark of UTF-8.
+pub const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+/// The byte order mark of UTF-16 (Big Endian).
+pub const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+/// The byte order mark of UTF-16 (Little Endian).
+pub const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+/// The byte order mark of UTF-32 (Big Endian).
+pub const UTF32BE_BOM: &[u8] = &[0x00, 0x00, 0xFE, 0xFF];
+/// The byte order mark of UTF-32 (Little Endian).
+pub const UTF32LE_BOM: &[u8] = &[0xFF, 0xFE, 0x00, 0x00];
+
+/// An error for streams without the required BOM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BOMAbsentError;
+
+impl fmt::Display for BOMAbsentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The byte order mark is absent.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BOMAbsentError {}
+
+/// Whether a BOM is required by [`BOMStripper`].
+///
+/// [`BOMStripper`]: self::BOMStripper
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BOMMode {
+    /// Streams without the BOM are errors.
+    Required,
+    /// Streams without the BOM are passed through.
+    Optional,
+}
+
+/// Prepending a BOM to a byte stream.
+///
+/// The BOM is emitted even if the input is empty.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::bom::{BOMInjector, UTF8_BOM};
+/// use conversion::converter::encoding::utf8::UTF8Encoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new("ß".chars(), UTF8Encoder::new()).map(Result::unwrap);
+/// let injected = ConvertedIterator::new(encoded, BOMInjector::new(UTF8_BOM));
+///
+/// assert_eq!(Ok(b"\xef\xbb\xbf\xc3\x9f".to_vec()), injected.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BOMInjector {
+    bom: &'static [u8],
+    // whether the BOM should be emitted before the next output.
+    pending: bool,
+}
+
+impl BOMInjector {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(bom: &'static [u8]) -> Self {
+        Self { bom, pending: true }
+    }
+
+    fn inject<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        if self.pending {
+            self.pending = false;
+            buf.extend(self.bom.iter().cloned());
+            self.bom.len()
+        } else {
+            0
+        }
+    }
+}
+
+impl Converter for BOMInjector {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = self.inject(buf);
+        buf.extend([item]);
+        Ok(len + 1)
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        Ok(self.inject(buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(if self.pending { self.bom.len() } else { 0 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.pending {
+            (1, Some(self.bom.len() + 1))
+        } else {
+            (1, Some(1))
+        }
+    }
+}
+
+/// Removing a leading BOM from a byte stream.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::bom::{BOMAbsentError, BOMMode, BOMStripper, UTF16LE_BOM};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let stripper = BOMStripper::new(UTF16LE_BOM, BOMMode::Optional);
+/// let stripped = ConvertedIterator::new(b"\xff\xfea\x00".iter().cloned(), stripper);
+/// assert_eq!(Ok(b"a\x00".to_vec()), stripped.collect());
+///
+/// let stripped = ConvertedIterator::new(b"\xffa\x00".iter().cloned(), stripper);
+/// assert_eq!(Ok(b"\xffa\x00".to_vec()), stripped.collect());
+///
+/// let stripper = BOMStripper::new(UTF16LE_BOM, BOMMode::Required);
+/// let mut stripped = ConvertedIterator::new(b"a\x00".iter().cloned(), stripper);
+/// assert_eq!(Some(Err(BOMAbsentError)), stripped.next());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BOMStripper {
+    bom: &'static [u8],
+    mode: BOMMode,
+    // the number of matched bytes, or `None` after the BOM.
+    matched: Option<usize>,
+}
+
+impl BOMStripper {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(bom: &'static [u8], mode: BOMMode) -> Self {
+        Self {
+            bom,
+            mode,
+            matched: Some(0),
+        }
+    }
+
+    // gives up the BOM, and flushes partially matched bytes in the optional mode.
+    fn absent<E>(&mut self, matched: usize, buf: &mut E) -> Result<usize, BOMAbsentError>
+    where
+        E: Extend<u8>,
+    {
+        self.matched = None;
+        match self.mode {
+            BOMMode::Required => Err(BOMAbsentError),
+            BOMMode::Optional => {
+                buf.extend(self.bom[..matched].iter().cloned());
+                Ok(matched)
+            }
+        }
+    }
+}
+
+impl Converter for BOMStripper {
+    type Item = u8;
+    type Output = u8;
+    type Error = BOMAbsentError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.matched {
+            None => {
+                buf.extend([item]);
+                Ok(1)
+            }
+            Some(matched) if self.bom[matched] == item => {
+                self.matched = if matched + 1 == self.bom.len() {
+                    None
+                } else {
+                    Some(matched + 1)
+                };
+                Ok(0)
+            }
+            Some(matched) => {
+                let len = self.absent(matched, buf);
+                buf.extend([item]);
+                Ok(len? + 1)
+            }
+        }
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.matched {
+            None => Ok(0),
+            Some(matched) => self.absent(matched, buf),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(self.matched.unwrap_or(0))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.matched {
+            None => (1, Some(1)),
+            Some(_) => (0, Some(self.bom.len())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn strip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let strip = |bytes: &[u8], mode| {
+            ConvertedIterator::new(bytes.iter().cloned(), BOMStripper::new(UTF32LE_BOM, mode))
+                .collect::<Vec<_>>()
+        };
+        for mode in [BOMMode::Required, BOMMode::Optional] {
+            assert_eq!(vec![Ok(b'a')], strip(b"\xff\xfe\x00\x00a", mode));
+            assert_eq!(
+                Vec::<Result<u8, _>>::new(),
+                strip(b"\xff\xfe\x00\x00", mode)
+            );
+            // only the first BOM is removed.
+            assert_eq!(
+                vec![Ok(0xFF), Ok(0xFE), Ok(0), Ok(0)],
+                strip(b"\xff\xfe\x00\x00\xff\xfe\x00\x00", mode)
+            );
+        }
+
+        assert_eq!(
+            vec![Err(BOMAbsentError), Ok(b'a')],
+            strip(b"\xff\xfea", BOMMode::Required)
+        );
+        assert_eq!(
+            vec![Err(BOMAbsentError)],
+            strip(b"\xff\xfe\x00", BOMMode::Required)
+        );
+        assert_eq!(vec![Err(BOMAbsentError)], strip(b"", BOMMode::Required));
+        assert_eq!(
+            vec![Ok(0xFF), Ok(0xFE), Ok(b'a')],
+            strip(b"\xff\xfea", BOMMode::Optional)
+        );
+        assert_eq!(
+            vec![Ok(0xFF), Ok(0xFE), Ok(0)],
+            strip(b"\xff\xfe\x00", BOMMode::Optional)
+        );
+        assert_eq!(Vec::<Result<u8, _>>::new(), strip(b"", BOMMode::Optional));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn inject() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let inject = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), BOMInjector::new(UTF16BE_BOM))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        assert_eq!(Ok(vec![0xFE, 0xFF, 0, b'a']), inject(b"\x00a"));
+        assert_eq!(Ok(vec![0xFE, 0xFF]), inject(b""));
+    }
+}
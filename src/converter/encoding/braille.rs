@@ -0,0 +1,185 @@
+//! Braille patterns Encoder/Decoder.
+//!
+//! The Unicode block "Braille Patterns" (U+2800-U+28FF) covers all cells of 8-dot braille. Dot
+//! `n` (from 1 to 8) of a cell is the bit `n - 1` of the offset from U+2800, and is the index
+//! `n - 1` of a `[bool; 8]` pattern here.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::encoding::braille::BrailleEncoder;
+//! use conversion::converter::{BitOrder, BitUnpacker, FixedArrayDecoder};
+//! use conversion::iter::ConvertedIterator;
+//! use conversion::Converter;
+//!
+//! // bytes into braille, one cell for each byte.
+//! let encoder = BitUnpacker::with_order(BitOrder::LsbFirst)
+//!     .pipe(FixedArrayDecoder::<bool, 8>::new())
+//!     .pipe(BrailleEncoder::new());
+//! let encoded = ConvertedIterator::new([0x00, 0x01, 0x3F, 0xFF], encoder);
+//! assert_eq!(Ok(String::from("⠀⠁⠿⣿")), encoded.collect());
+//! ```
+
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+// the first character of the block.
+const BLANK: u32 = 0x2800;
+
+/// An error while decoding braille patterns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BrailleError {
+    /// A character outside the Braille Patterns block.
+    NotBraille(char),
+}
+
+impl fmt::Display for BrailleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotBraille(c) => write!(f, "not a braille pattern: {:?}", c),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BrailleError {}
+
+/// An encoder for braille patterns, converts raised dots into a character.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::braille::BrailleEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// // dots 1-2-5 ("h") and 2-4 ("i").
+/// let h = [true, true, false, false, true, false, false, false];
+/// let i = [false, true, false, true, false, false, false, false];
+/// let encoded = ConvertedIterator::new([h, i], BrailleEncoder::new());
+/// assert_eq!(Ok(String::from("⠓⠊")), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BrailleEncoder;
+
+impl BrailleEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for BrailleEncoder {
+    type Item = [bool; 8];
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = item
+            .iter()
+            .rev()
+            .fold(0, |acc, &dot| (acc << 1) | dot as u32);
+        // U+2800-U+28FF are all valid characters.
+        buf.extend(char::from_u32(BLANK + offset));
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for BrailleEncoder {}
+
+impl ExactSizeConverter for BrailleEncoder {}
+
+/// A decoder for braille patterns, converts a character into raised dots.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::braille::{BrailleDecoder, BrailleError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut decoded = ConvertedIterator::new("⠓h".chars(), BrailleDecoder::new());
+/// assert_eq!(
+///     Some(Ok([true, true, false, false, true, false, false, false])),
+///     decoded.next(),
+/// );
+/// assert_eq!(Some(Err(BrailleError::NotBraille('h'))), decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BrailleDecoder;
+
+impl BrailleDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for BrailleDecoder {
+    type Item = char;
+    type Output = [bool; 8];
+    type Error = BrailleError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = match (item as u32).checked_sub(BLANK) {
+            Some(offset @ 0..=0xFF) => offset,
+            _ => return Err(BrailleError::NotBraille(item)),
+        };
+        buf.extend([core::array::from_fn(|dot| offset >> dot & 1 == 1)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for BrailleDecoder {}
+
+impl ExactSizeConverter for BrailleDecoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut encoder = BrailleEncoder::new();
+        let mut decoder = BrailleDecoder::new();
+        for offset in 0..=0xFFu32 {
+            let dots: [bool; 8] = core::array::from_fn(|dot| offset >> dot & 1 == 1);
+            let mut chars = Vec::new();
+            assert_eq!(Ok(1), encoder.convert(dots, &mut chars));
+            assert_eq!(char::from_u32(0x2800 + offset), chars.first().cloned());
+
+            let mut patterns = Vec::new();
+            assert_eq!(Ok(1), decoder.convert(chars[0], &mut patterns));
+            assert_eq!(vec![dots], patterns);
+        }
+
+        let mut patterns = Vec::new();
+        for c in ['\u{27FF}', '\u{2900}', ' ', 'a'] {
+            assert_eq!(
+                Err(BrailleError::NotBraille(c)),
+                decoder.convert(c, &mut patterns)
+            );
+        }
+        assert!(patterns.is_empty());
+    }
+}
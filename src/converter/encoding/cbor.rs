@@ -0,0 +1,388 @@
+//! CBOR (Concise Binary Object Representation, RFC 7049) Encoder/Decoder for basic types.
+//!
+//! Integers, byte strings, text strings, and headers of arrays and maps are supported. Tags,
+//! floating-point numbers, simple values and indefinite-length items are not.
+
+use crate::Converter;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error while encoding/decoding CBOR.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CBORError {
+    /// An initial byte which is reserved or not supported.
+    Unsupported(u8),
+    /// A negative integer out of the range of `i64`, or a non-negative [`NInt`].
+    ///
+    /// [`NInt`]: self::CBORValue::NInt
+    OutOfRange,
+    /// A text string which is not valid UTF-8.
+    InvalidUTF8,
+    /// The input ended inside a value.
+    Truncated,
+}
+
+impl fmt::Display for CBORError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(b) => write!(f, "unsupported initial byte: {:#x}", b),
+            Self::OutOfRange => write!(f, "the negative integer is out of range."),
+            Self::InvalidUTF8 => write!(f, "invalid UTF-8 string."),
+            Self::Truncated => write!(f, "the input ended inside a value."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CBORError {}
+
+/// A CBOR value.
+///
+/// Arrays and maps are represented by their headers, and their elements follow as separate
+/// values. (a map with `n` entries is followed by `2n` values, keys and values alternately)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CBORValue {
+    /// An unsigned integer. (major type 0)
+    UInt(u64),
+    /// A negative integer. (major type 1)
+    NInt(i64),
+    /// A byte string. (major type 2)
+    Bytes(Vec<u8>),
+    /// A text string. (major type 3)
+    Text(String),
+    /// A header of an array, with the number of elements. (major type 4)
+    Array(u64),
+    /// A header of a map, with the number of entries. (major type 5)
+    Map(u64),
+}
+
+// emitting an initial byte and an argument, and returns the number of bytes.
+fn head<E: Extend<u8>>(major: u8, argument: u64, buf: &mut E) -> usize {
+    let major = major << 5;
+    let bytes = argument.to_be_bytes();
+    let (info, len) = match argument {
+        0..=23 => (argument as u8, 0),
+        24..=0xff => (24, 1),
+        0x100..=0xffff => (25, 2),
+        0x1_0000..=0xffff_ffff => (26, 4),
+        _ => (27, 8),
+    };
+    buf.extend([major | info]);
+    buf.extend(bytes[8 - len..].iter().cloned());
+    len + 1
+}
+
+/// An encoder for CBOR, emits bytes of each value.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::cbor::{CBORPrimitiveEncoder, CBORValue};
+/// use conversion::iter::ConvertedIterator;
+///
+/// // [1, "abc", {-1: h'ff'}]
+/// let iter = [
+///     CBORValue::Array(3),
+///     CBORValue::UInt(1),
+///     CBORValue::Text(String::from("abc")),
+///     CBORValue::Map(1),
+///     CBORValue::NInt(-1),
+///     CBORValue::Bytes(vec![0xff]),
+/// ];
+/// let encoded = ConvertedIterator::new(iter, CBORPrimitiveEncoder::new());
+/// assert_eq!(
+///     Ok(vec![0x83, 0x01, 0x63, b'a', b'b', b'c', 0xa1, 0x20, 0x41, 0xff]),
+///     encoded.collect(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CBORPrimitiveEncoder;
+
+impl CBORPrimitiveEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for CBORPrimitiveEncoder {
+    type Item = CBORValue;
+    type Output = u8;
+    type Error = CBORError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        Ok(match item {
+            CBORValue::UInt(n) => head(0, n, buf),
+            CBORValue::NInt(n) if n < 0 => head(1, !n as u64, buf),
+            CBORValue::NInt(_) => return Err(CBORError::OutOfRange),
+            CBORValue::Bytes(bytes) => {
+                let len = head(2, bytes.len() as u64, buf) + bytes.len();
+                buf.extend(bytes);
+                len
+            }
+            CBORValue::Text(text) => {
+                let len = head(3, text.len() as u64, buf) + text.len();
+                buf.extend(text.into_bytes());
+                len
+            }
+            CBORValue::Array(n) => head(4, n, buf),
+            CBORValue::Map(n) => head(5, n, buf),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, None)
+    }
+}
+
+fn be(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &b| acc << 8 | b as u64)
+}
+
+// the number of bytes following the initial byte, computed from the bytes read so far.
+fn required(initial: u8, buf: &[u8]) -> Result<usize, CBORError> {
+    let n = match initial & 0x1f {
+        0..=23 => 0,
+        info @ 24..=27 => 1 << (info - 24),
+        _ => return Err(CBORError::Unsupported(initial)),
+    };
+    Ok(match initial >> 5 {
+        0 | 1 | 4 | 5 => n,
+        2 | 3 if n == 0 => (initial & 0x1f) as usize,
+        2 | 3 if buf.len() < n => n,
+        2 | 3 => n + be(&buf[..n]) as usize,
+        _ => return Err(CBORError::Unsupported(initial)),
+    })
+}
+
+fn value(initial: u8, mut buf: Vec<u8>) -> Result<CBORValue, CBORError> {
+    let n = match initial & 0x1f {
+        0..=23 => 0,
+        info => 1 << (info - 24),
+    };
+    let argument = if n == 0 {
+        (initial & 0x1f) as u64
+    } else {
+        be(&buf[..n])
+    };
+    Ok(match initial >> 5 {
+        0 => CBORValue::UInt(argument),
+        1 if argument <= i64::MAX as u64 => CBORValue::NInt(!(argument as i64)),
+        1 => return Err(CBORError::OutOfRange),
+        2 => CBORValue::Bytes(buf.split_off(n.min(buf.len()))),
+        3 => {
+            let bytes = buf.split_off(n.min(buf.len()));
+            CBORValue::Text(String::from_utf8(bytes).map_err(|_| CBORError::InvalidUTF8)?)
+        }
+        4 => CBORValue::Array(argument),
+        _ => CBORValue::Map(argument),
+    })
+}
+
+/// A decoder for CBOR, emits a value for each data item.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::cbor::{CBORPrimitiveDecoder, CBORError, CBORValue};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0x82, 0x38, 0x63, 0x62, 0xc3, 0xbc, 0xf5, 0x19, 0x03];
+/// let mut decoded = ConvertedIterator::new(iter, CBORPrimitiveDecoder::new());
+///
+/// assert_eq!(Some(Ok(CBORValue::Array(2))), decoded.next());
+/// assert_eq!(Some(Ok(CBORValue::NInt(-100))), decoded.next());
+/// assert_eq!(Some(Ok(CBORValue::Text(String::from("ü")))), decoded.next());
+/// // `true` is not supported.
+/// assert_eq!(Some(Err(CBORError::Unsupported(0xf5))), decoded.next());
+/// assert_eq!(Some(Err(CBORError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CBORPrimitiveDecoder {
+    // the initial byte of the current value.
+    initial: Option<u8>,
+    buffer: Vec<u8>,
+}
+
+impl CBORPrimitiveDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn try_emit<E>(&mut self, initial: u8, buf: &mut E) -> Result<usize, CBORError>
+    where
+        E: Extend<CBORValue>,
+    {
+        match required(initial, &self.buffer) {
+            Ok(len) if self.buffer.len() < len => Ok(0),
+            Ok(_) => {
+                self.initial = None;
+                let value = value(initial, core::mem::take(&mut self.buffer))?;
+                buf.extend([value]);
+                Ok(1)
+            }
+            Err(e) => {
+                self.initial = None;
+                self.buffer.clear();
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Converter for CBORPrimitiveDecoder {
+    type Item = u8;
+    type Output = CBORValue;
+    type Error = CBORError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.initial {
+            Some(initial) => {
+                self.buffer.push(item);
+                self.try_emit(initial, buf)
+            }
+            None => {
+                self.initial = Some(item);
+                self.try_emit(item, buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.initial.take() {
+            Some(_) => {
+                self.buffer.clear();
+                Err(CBORError::Truncated)
+            }
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::vec;
+
+    #[test]
+    fn rfc7049() {
+        // from Appendix A of RFC 7049.
+        let cases: Vec<(Vec<u8>, CBORValue)> = vec![
+            (vec![0x00], CBORValue::UInt(0)),
+            (vec![0x17], CBORValue::UInt(23)),
+            (vec![0x18, 0x18], CBORValue::UInt(24)),
+            (vec![0x18, 0x64], CBORValue::UInt(100)),
+            (vec![0x19, 0x03, 0xe8], CBORValue::UInt(1000)),
+            (vec![0x1a, 0x00, 0x0f, 0x42, 0x40], CBORValue::UInt(1000000)),
+            (
+                vec![0x1b, 0x00, 0x00, 0x00, 0xe8, 0xd4, 0xa5, 0x10, 0x00],
+                CBORValue::UInt(1000000000000),
+            ),
+            (
+                vec![0x1b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                CBORValue::UInt(u64::MAX),
+            ),
+            (vec![0x20], CBORValue::NInt(-1)),
+            (vec![0x29], CBORValue::NInt(-10)),
+            (vec![0x38, 0x63], CBORValue::NInt(-100)),
+            (vec![0x39, 0x03, 0xe7], CBORValue::NInt(-1000)),
+            (
+                vec![0x3b, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                CBORValue::NInt(i64::MIN),
+            ),
+            (vec![0x40], CBORValue::Bytes(vec![])),
+            (
+                vec![0x44, 0x01, 0x02, 0x03, 0x04],
+                CBORValue::Bytes(vec![1, 2, 3, 4]),
+            ),
+            (vec![0x60], CBORValue::Text(String::new())),
+            (vec![0x61, 0x61], CBORValue::Text(String::from("a"))),
+            (
+                vec![0x64, 0x49, 0x45, 0x54, 0x46],
+                CBORValue::Text(String::from("IETF")),
+            ),
+            (
+                vec![0x62, 0xc3, 0xbc],
+                CBORValue::Text(String::from("\u{00fc}")),
+            ),
+            (vec![0x80], CBORValue::Array(0)),
+            (vec![0x83], CBORValue::Array(3)),
+            (vec![0x98, 0x19], CBORValue::Array(25)),
+            (vec![0xa0], CBORValue::Map(0)),
+            (vec![0xb9, 0x01, 0x00], CBORValue::Map(256)),
+        ];
+        let bytes = cases
+            .iter()
+            .flat_map(|(b, _)| b.clone())
+            .collect::<Vec<_>>();
+        let values = cases.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+
+        let encoded = ConvertedIterator::new(values.iter().cloned(), CBORPrimitiveEncoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes.clone()), encoded);
+        let decoded = ConvertedIterator::new(bytes, CBORPrimitiveDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(values), decoded);
+
+        // a long text string.
+        let text = "cbor".repeat(100);
+        let encoded =
+            ConvertedIterator::new([CBORValue::Text(text.clone())], CBORPrimitiveEncoder::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        assert_eq!(&[0x79, 0x01, 0x90], &encoded[..3]);
+        let decoded = ConvertedIterator::new(encoded, CBORPrimitiveDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![CBORValue::Text(text)]), decoded);
+    }
+
+    #[test]
+    fn errors() {
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), CBORPrimitiveDecoder::new())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Err(CBORError::Unsupported(0x1c)), Ok(CBORValue::UInt(0))],
+            decode(&[0x1c, 0x00])
+        );
+        // an indefinite-length array, and a tag.
+        assert_eq!(vec![Err(CBORError::Unsupported(0x9f))], decode(&[0x9f]));
+        assert_eq!(vec![Err(CBORError::Unsupported(0xc0))], decode(&[0xc0]));
+        assert_eq!(
+            vec![Err(CBORError::OutOfRange)],
+            decode(&[0x3b, 0x80, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            vec![Err(CBORError::InvalidUTF8), Ok(CBORValue::UInt(1))],
+            decode(&[0x61, 0xff, 0x01])
+        );
+        assert_eq!(vec![Err(CBORError::Truncated)], decode(&[0x19, 0x03]));
+        assert_eq!(vec![Err(CBORError::Truncated)], decode(&[0x42, 0x00]));
+
+        let mut encoder = CBORPrimitiveEncoder::new();
+        let mut buf = Vec::new();
+        assert_eq!(
+            Err(CBORError::OutOfRange),
+            encoder.convert(CBORValue::NInt(0), &mut buf)
+        );
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,357 @@
+//! Delimiter-separated frame Encoder/Decoder.
+//!
+//! Frames are terminated by a delimiter of one or more bytes, like newline-delimited JSON
+//! (`\n`), CRLF-terminated lines (`\r\n`) or NUL-separated records (`\0`).
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error while encoding/decoding delimited frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DelimitedError {
+    /// A frame longer than the limit, with the limit.
+    TooLong(usize),
+    /// A frame containing the delimiter, rejected by [`DelimitedEncoder::strict`].
+    ///
+    /// [`DelimitedEncoder::strict`]: self::DelimitedEncoder::strict
+    EmbeddedDelimiter,
+    /// The input ended without a delimiter, rejected by [`DelimitedDecoder::strict`].
+    ///
+    /// [`DelimitedDecoder::strict`]: self::DelimitedDecoder::strict
+    Unterminated,
+}
+
+impl fmt::Display for DelimitedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(max) => write!(f, "the frame is longer than {} bytes.", max),
+            Self::EmbeddedDelimiter => write!(f, "a delimiter inside the frame."),
+            Self::Unterminated => write!(f, "the frame is not terminated by a delimiter."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DelimitedError {}
+
+// returns the delimiter, panicking if it is empty.
+#[inline]
+fn delimiter(delimiter: &[u8]) -> Vec<u8> {
+    assert!(!delimiter.is_empty(), "the delimiter must not be empty.");
+    delimiter.to_vec()
+}
+
+/// An encoder for delimited frames, emits bytes of each frame followed by the delimiter.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::delimited::{DelimitedEncoder, DelimitedError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [b"foo".to_vec(), Vec::new(), b"bar".to_vec()];
+/// let encoded = ConvertedIterator::new(iter, DelimitedEncoder::new(b"\r\n"));
+/// assert_eq!(Ok(b"foo\r\n\r\nbar\r\n".to_vec()), encoded.collect());
+///
+/// let mut encoded = ConvertedIterator::new([b"a\nb".to_vec()], DelimitedEncoder::new(b"\n").strict(true));
+/// assert_eq!(Some(Err(DelimitedError::EmbeddedDelimiter)), encoded.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DelimitedEncoder {
+    delimiter: Vec<u8>,
+    strict: bool,
+}
+
+impl DelimitedEncoder {
+    /// Create a new instance with a delimiter.
+    ///
+    /// # Panics
+    /// Panics if `delimiter` is empty.
+    #[inline]
+    pub fn new(delimiter: &[u8]) -> Self {
+        Self {
+            delimiter: self::delimiter(delimiter),
+            strict: false,
+        }
+    }
+
+    /// Setting whether to reject frames containing the delimiter, which would be split when
+    /// decoded.
+    #[inline]
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+}
+
+impl Converter for DelimitedEncoder {
+    type Item = Vec<u8>;
+    type Output = u8;
+    type Error = DelimitedError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.strict
+            && item
+                .windows(self.delimiter.len())
+                .any(|window| window == self.delimiter)
+        {
+            return Err(DelimitedError::EmbeddedDelimiter);
+        }
+        let len = item.len() + self.delimiter.len();
+        buf.extend(item);
+        buf.extend(self.delimiter.iter().cloned());
+        Ok(len)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.delimiter.len(), None)
+    }
+}
+
+/// A decoder for delimited frames, emits bytes of each frame.
+///
+/// Bytes after the last delimiter are emitted as a frame at the end of inputs by default, and
+/// [`strict`] rejects them instead. A frame longer than [`max_frame_size`] is reported as an
+/// error, and skipped until the next delimiter.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::delimited::{DelimitedDecoder, DelimitedError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"foo\r\n\r\nbar".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, DelimitedDecoder::new(b"\r\n"));
+/// assert_eq!(
+///     Ok(vec![b"foo".to_vec(), Vec::new(), b"bar".to_vec()]),
+///     decoded.collect(),
+/// );
+///
+/// let decoder = DelimitedDecoder::new(b"\n").keep_delimiter(true).strict(true);
+/// let mut decoded = ConvertedIterator::new(b"foo\nbar".iter().cloned(), decoder);
+/// assert_eq!(Some(Ok(b"foo\n".to_vec())), decoded.next());
+/// assert_eq!(Some(Err(DelimitedError::Unterminated)), decoded.next());
+///
+/// let decoder = DelimitedDecoder::new(b"\0").max_frame_size(4);
+/// let mut decoded = ConvertedIterator::new(b"toolong\0ok\0".iter().cloned(), decoder);
+/// assert_eq!(Some(Err(DelimitedError::TooLong(4))), decoded.next());
+/// assert_eq!(Some(Ok(b"ok".to_vec())), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+///
+/// [`strict`]: Self::strict
+/// [`max_frame_size`]: Self::max_frame_size
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DelimitedDecoder {
+    delimiter: Vec<u8>,
+    max_frame_size: usize,
+    keep_delimiter: bool,
+    strict: bool,
+    data: Vec<u8>,
+    // skipping a too long frame, keeping just enough bytes to find the delimiter in `data`.
+    skipping: bool,
+}
+
+impl DelimitedDecoder {
+    /// Create a new instance with a delimiter, strips delimiters without a maximum frame size.
+    ///
+    /// # Panics
+    /// Panics if `delimiter` is empty.
+    #[inline]
+    pub fn new(delimiter: &[u8]) -> Self {
+        Self {
+            delimiter: self::delimiter(delimiter),
+            max_frame_size: usize::MAX,
+            keep_delimiter: false,
+            strict: false,
+            data: Vec::new(),
+            skipping: false,
+        }
+    }
+
+    /// Setting the maximum size of frames (excluding the delimiter), not to allocate too much
+    /// memory for malicious inputs.
+    #[inline]
+    pub fn max_frame_size(self, max_frame_size: usize) -> Self {
+        Self {
+            max_frame_size,
+            ..self
+        }
+    }
+
+    /// Setting whether to include the delimiter at the end of each frame.
+    #[inline]
+    pub fn keep_delimiter(self, keep_delimiter: bool) -> Self {
+        Self {
+            keep_delimiter,
+            ..self
+        }
+    }
+
+    /// Setting whether to reject bytes after the last delimiter, instead of emitting them as a
+    /// frame.
+    #[inline]
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+}
+
+impl DelimitedDecoder {
+    // the length of the longest suffix of `data` which is a proper prefix of the delimiter.
+    fn partial(&self) -> usize {
+        (1..self.delimiter.len())
+            .rev()
+            .find(|&len| self.data.ends_with(&self.delimiter[..len]))
+            .unwrap_or(0)
+    }
+}
+
+impl Converter for DelimitedDecoder {
+    type Item = u8;
+    type Output = Vec<u8>;
+    type Error = DelimitedError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let delimiter_len = self.delimiter.len();
+        self.data.push(item);
+        if self.data.ends_with(&self.delimiter) {
+            let mut data = core::mem::take(&mut self.data);
+            if core::mem::take(&mut self.skipping) {
+                return Ok(0);
+            }
+            if !self.keep_delimiter {
+                data.truncate(data.len() - delimiter_len);
+            }
+            buf.extend([data]);
+            return Ok(1);
+        }
+
+        if self.skipping {
+            // the oldest byte can no longer be a part of the delimiter.
+            if self.data.len() >= delimiter_len {
+                self.data.remove(0);
+            }
+            Ok(0)
+        } else if self.data.len() - self.partial() > self.max_frame_size {
+            // the frame exceeds the limit, excluding bytes which may start the delimiter.
+            self.skipping = true;
+            let start = (self.data.len() + 1).saturating_sub(delimiter_len);
+            self.data.drain(..start);
+            Err(DelimitedError::TooLong(self.max_frame_size))
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let data = core::mem::take(&mut self.data);
+        if core::mem::take(&mut self.skipping) {
+            return if self.strict {
+                Err(DelimitedError::Unterminated)
+            } else {
+                Ok(0)
+            };
+        }
+        match data.len() {
+            0 => Ok(0),
+            _ if self.strict => Err(DelimitedError::Unterminated),
+            len if len > self.max_frame_size => Err(DelimitedError::TooLong(self.max_frame_size)),
+            _ => {
+                buf.extend([data]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn crlf_across_chunks() {
+        use crate::iter::ConvertedIterator;
+
+        let input = b"first\r\nsecond\r\n\r\nlast\r";
+        for chunk in 1..input.len() {
+            let mut decoder = DelimitedDecoder::new(b"\r\n");
+            let mut frames = Vec::new();
+            // feeding bytes chunk by chunk, so that CRLFs are split across chunks.
+            for bytes in input.chunks(chunk) {
+                for &byte in bytes {
+                    decoder.convert(byte, &mut frames).unwrap();
+                }
+            }
+            assert_eq!(Ok(1), decoder.finish(&mut frames));
+            assert_eq!(
+                vec![
+                    b"first".to_vec(),
+                    b"second".to_vec(),
+                    Vec::new(),
+                    b"last\r".to_vec()
+                ],
+                frames
+            );
+        }
+
+        let frames = [b"a\rb".to_vec(), b"\n".to_vec(), b"\r".to_vec()];
+        let encoded = ConvertedIterator::new(frames.clone(), DelimitedEncoder::new(b"\r\n"))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(b"a\rb\r\n\n\r\n\r\r\n".to_vec(), encoded);
+        let decoded = ConvertedIterator::new(encoded, DelimitedDecoder::new(b"\r\n").strict(true))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(frames.to_vec()), decoded);
+    }
+
+    #[test]
+    fn max_frame_size() {
+        let mut decoder = DelimitedDecoder::new(b"\r\n").max_frame_size(3);
+        let mut frames = Vec::new();
+        let mut results = Vec::new();
+        for &byte in b"abc\r\nabc\rx\r\r\nabcd\r\nok\r" {
+            results.push(decoder.convert(byte, &mut frames));
+        }
+        assert_eq!(Ok(1), decoder.finish(&mut frames));
+        assert_eq!(vec![b"abc".to_vec(), b"ok\r".to_vec()], frames);
+        let errors = results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| result.is_err())
+            .map(|(i, result)| (i, *result))
+            .collect::<Vec<_>>();
+        // `abc\r` may be followed by `\n`, but `abcd` is already too long.
+        assert_eq!(
+            vec![
+                (9, Err(DelimitedError::TooLong(3))),
+                (16, Err(DelimitedError::TooLong(3))),
+            ],
+            errors
+        );
+
+        // bytes which may start the delimiter are a part of the trailing frame at the end.
+        for &byte in b"abc\r" {
+            assert_eq!(Ok(0), decoder.convert(byte, &mut frames));
+        }
+        assert_eq!(Err(DelimitedError::TooLong(3)), decoder.finish(&mut frames));
+    }
+}
@@ -0,0 +1,284 @@
+//! Detecting the encoding of a byte stream heuristically.
+
+use super::registry::{canonical_name, decoder_for, EncodingError};
+use crate::converter::BoxConverter;
+use crate::Converter;
+use alloc::collections::VecDeque;
+use core::fmt;
+
+/// The default number of bytes to sniff.
+pub const DEFAULT_WINDOW: usize = 4096;
+
+/// A decoder which detects the encoding from the first bytes.
+///
+/// The decoder buffers up to `window` bytes (or all bytes if the input is shorter), and detects
+/// the encoding in this order:
+///
+/// 1. A byte order mark of UTF-8, UTF-16 or UTF-32. (the BOM is removed)
+/// 2. UTF-16 without BOM, if NUL bytes appear in every other position.
+/// 3. UTF-8, if the buffered bytes are valid UTF-8.
+/// 4. The fallback encoding. (Windows-1252 by default)
+///
+/// Then the buffered bytes are decoded with the detected encoding, and the rest are streamed.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::detect::SniffingDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(b"\x93quoted\x94".iter().cloned(), SniffingDecoder::new());
+/// assert_eq!(Ok(String::from("“quoted”")), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new(b"stra\xc3\x9fe".iter().cloned(), SniffingDecoder::new());
+/// assert_eq!(Ok(String::from("straße")), decoded.collect());
+/// ```
+pub struct SniffingDecoder {
+    window: usize,
+    fallback: &'static str,
+    buffer: VecDeque<u8>,
+    detected: Option<(&'static str, BoxConverter<u8, char, EncodingError>)>,
+}
+
+impl fmt::Debug for SniffingDecoder {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SniffingDecoder")
+            .field("window", &self.window)
+            .field("fallback", &self.fallback)
+            .field("buffer", &self.buffer)
+            .field("detected", &self.detected())
+            .finish()
+    }
+}
+
+impl Default for SniffingDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_WINDOW,
+            fallback: "windows-1252",
+            buffer: VecDeque::new(),
+            detected: None,
+        }
+    }
+}
+
+impl SniffingDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the number of bytes to sniff. (default: [`DEFAULT_WINDOW`])
+    ///
+    /// [`DEFAULT_WINDOW`]: self::DEFAULT_WINDOW
+    #[inline]
+    pub fn with_window(self, window: usize) -> Self {
+        Self { window, ..self }
+    }
+
+    /// Setting the fallback encoding by a label.
+    ///
+    /// Returns `None` if the label is unknown. (see [`registry`])
+    ///
+    /// [`registry`]: super::registry
+    #[inline]
+    pub fn with_fallback(self, label: &str) -> Option<Self> {
+        let fallback = canonical_name(label)?;
+        Some(Self { fallback, ..self })
+    }
+
+    /// The canonical name of the detected encoding, or `None` if not detected yet.
+    #[inline]
+    pub fn detected(&self) -> Option<&'static str> {
+        self.detected.as_ref().map(|&(name, _)| name)
+    }
+
+    fn detect(&mut self, end: bool) {
+        let bytes = self.buffer.make_contiguous();
+        let (name, bom) = match bytes {
+            [0xEF, 0xBB, 0xBF, ..] => ("utf-8", 3),
+            [0xFF, 0xFE, 0x00, 0x00, ..] => ("utf-32le", 4),
+            [0x00, 0x00, 0xFE, 0xFF, ..] => ("utf-32be", 4),
+            [0xFF, 0xFE, ..] => ("utf-16le", 2),
+            [0xFE, 0xFF, ..] => ("utf-16be", 2),
+            _ => (Self::sniff(bytes, end).unwrap_or(self.fallback), 0),
+        };
+        self.buffer.drain(..bom);
+        // names are taken from the registry, so they always exist.
+        self.detected = Some((name, decoder_for(name).unwrap()));
+    }
+
+    fn sniff(bytes: &[u8], end: bool) -> Option<&'static str> {
+        let pairs = bytes.len() / 2;
+        if pairs > 0 {
+            let nul = |offset: usize| bytes.chunks_exact(2).filter(|p| p[offset] == 0).count();
+            let (even, odd) = (nul(0), nul(1));
+            // mostly ASCII text in UTF-16.
+            if odd * 10 >= pairs * 4 && even * 10 < pairs {
+                return Some("utf-16le");
+            }
+            if even * 10 >= pairs * 4 && odd * 10 < pairs {
+                return Some("utf-16be");
+            }
+        }
+
+        match core::str::from_utf8(bytes) {
+            Ok(_) => Some("utf-8"),
+            // an incomplete sequence cut by the window.
+            Err(e) if !end && e.error_len().is_none() => Some("utf-8"),
+            Err(_) => None,
+        }
+    }
+}
+
+impl Converter for SniffingDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = EncodingError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.buffer.push_back(item);
+        if self.detected.is_none() {
+            if self.buffer.len() < self.window {
+                return Ok(0);
+            }
+            self.detect(false);
+        }
+
+        let decoder = &mut self.detected.as_mut().unwrap().1;
+        let mut len = 0;
+        while let Some(byte) = self.buffer.pop_front() {
+            len += decoder.convert(byte, buf)?;
+        }
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.detected.is_none() {
+            self.detect(true);
+        }
+
+        // decoding all remaining bytes, and returns the first error.
+        let decoder = &mut self.detected.as_mut().unwrap().1;
+        let mut len = 0;
+        let mut res = Ok(());
+        for byte in self.buffer.drain(..) {
+            match decoder.convert(byte, buf) {
+                Ok(n) => len += n,
+                Err(e) => res = res.and(Err(e)),
+            }
+        }
+        res.and(decoder.finish(buf).map(|n| len + n))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn decode(
+        bytes: &[u8],
+        mut decoder: SniffingDecoder,
+    ) -> (Result<String, EncodingError>, &'static str) {
+        let mut decoded = Vec::new();
+        let res = bytes
+            .iter()
+            .try_for_each(|&b| decoder.convert(b, &mut decoded).map(|_| ()))
+            .and_then(|_| decoder.finish(&mut decoded))
+            .map(|_| decoded.into_iter().collect());
+        (res, decoder.detected().unwrap())
+    }
+
+    #[test]
+    fn utf16() {
+        use alloc::vec;
+
+        let text = "Hello, world!\r\nこんにちは";
+        let le = text
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let be = text
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            (Ok(String::from(text)), "utf-16le"),
+            decode(&le, SniffingDecoder::new())
+        );
+        assert_eq!(
+            (Ok(String::from(text)), "utf-16be"),
+            decode(&be, SniffingDecoder::new())
+        );
+        assert_eq!(
+            (Ok(String::from(text)), "utf-16le"),
+            decode(&le, SniffingDecoder::new().with_window(9))
+        );
+
+        let mut bom = vec![0xFE, 0xFF];
+        bom.extend(&be);
+        assert_eq!(
+            (Ok(String::from(text)), "utf-16be"),
+            decode(&bom, SniffingDecoder::new())
+        );
+    }
+
+    #[test]
+    fn utf8() {
+        use alloc::vec;
+
+        let text = "straße, 日本語, 💣";
+        assert_eq!(
+            (Ok(String::from(text)), "utf-8"),
+            decode(text.as_bytes(), SniffingDecoder::new())
+        );
+        // the window ends inside '日'.
+        assert_eq!(
+            (Ok(String::from(text)), "utf-8"),
+            decode(text.as_bytes(), SniffingDecoder::new().with_window(10))
+        );
+
+        let mut bom = vec![0xEF, 0xBB, 0xBF];
+        bom.extend(text.as_bytes());
+        assert_eq!(
+            (Ok(String::from(text)), "utf-8"),
+            decode(&bom, SniffingDecoder::new())
+        );
+        assert_eq!(
+            (Ok(String::new()), "utf-8"),
+            decode(b"", SniffingDecoder::new())
+        );
+    }
+
+    #[test]
+    fn fallback() {
+        let bytes = b"\x93Smart quotes\x94 cost \x80 5";
+        assert_eq!(
+            (Ok(String::from("“Smart quotes” cost € 5")), "windows-1252"),
+            decode(bytes, SniffingDecoder::new())
+        );
+
+        let decoder = SniffingDecoder::new().with_fallback("us-ascii").unwrap();
+        let (decoded, detected) = decode(bytes, decoder);
+        assert!(decoded.is_err());
+        assert_eq!("us-ascii", detected);
+
+        assert!(SniffingDecoder::new().with_fallback("utf-7").is_none());
+    }
+}
@@ -0,0 +1,451 @@
+//! Formatting/Parsing [`Duration`]s.
+//!
+//! Two formats are supported:
+//! - [`Human`](DurationFormat::Human): space-separated components with units, like
+//!   `1h 30m 15s 500ms`. The units are `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`) and `ns`.
+//! - [`ISO8601`](DurationFormat::ISO8601): ISO 8601 durations like `PT1H30M15.5S`. Years and
+//!   months are not supported, because their lengths vary.
+
+use crate::Converter;
+use alloc::string::String;
+use core::fmt::{self, Write};
+use std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// units of the human-readable format, in nanoseconds.
+const HUMAN_UNITS: [(&str, u128); 7] = [
+    ("d", 86_400 * NANOS_PER_SEC),
+    ("h", 3_600 * NANOS_PER_SEC),
+    ("m", 60 * NANOS_PER_SEC),
+    ("s", NANOS_PER_SEC),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Formats of durations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DurationFormat {
+    /// Human-readable components, like `1h 30m 15s 500ms`.
+    #[default]
+    Human,
+    /// ISO 8601 durations, like `PT1H30M15.5S`.
+    ISO8601,
+}
+
+/// An error while parsing durations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationError {
+    /// A string without any components.
+    Empty,
+    /// A malformed string, with the byte offset of the error.
+    Invalid(usize),
+    /// A designator of years or months in ISO 8601 durations.
+    Unsupported(char),
+    /// A duration exceeding [`Duration::MAX`].
+    Overflow,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the duration has no components."),
+            Self::Invalid(offset) => write!(f, "malformed duration at {}.", offset),
+            Self::Unsupported(c) => write!(f, "unsupported designator: {:?}", c),
+            Self::Overflow => write!(f, "the duration is too long."),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+// converts a total of nanoseconds into a duration.
+fn from_nanos(nanos: u128) -> Result<Duration, DurationError> {
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| DurationError::Overflow)?;
+    Ok(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+// reads leading decimal digits, returns the value and the rest.
+fn digits(s: &str, offset: usize) -> Result<(u128, &str), DurationError> {
+    let len = s.bytes().take_while(u8::is_ascii_digit).count();
+    if len == 0 {
+        return Err(DurationError::Invalid(offset));
+    }
+    let value = s[..len]
+        .parse::<u64>()
+        .map_err(|_| DurationError::Overflow)?;
+    Ok((value as u128, &s[len..]))
+}
+
+fn format_human(duration: Duration, s: &mut String) {
+    let mut nanos = duration.as_nanos();
+    if nanos == 0 {
+        s.push_str("0s");
+        return;
+    }
+    for (unit, len) in HUMAN_UNITS {
+        if nanos >= len {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            let _ = write!(s, "{}{}", nanos / len, unit);
+            nanos %= len;
+        }
+    }
+}
+
+fn parse_human(s: &str) -> Result<Duration, DurationError> {
+    let mut total = 0u128;
+    let mut empty = true;
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let (value, tail) = digits(rest, s.len() - rest.len())?;
+        let offset = s.len() - tail.len();
+        let unit_len = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let len = match &tail[..unit_len] {
+            "µs" => 1_000,
+            unit => HUMAN_UNITS
+                .iter()
+                .find(|(u, _)| *u == unit)
+                .map(|(_, len)| *len)
+                .ok_or(DurationError::Invalid(offset))?,
+        };
+        total = value
+            .checked_mul(len)
+            .and_then(|nanos| total.checked_add(nanos))
+            .ok_or(DurationError::Overflow)?;
+        empty = false;
+        rest = tail[unit_len..].trim_start();
+    }
+    if empty {
+        Err(DurationError::Empty)
+    } else {
+        from_nanos(total)
+    }
+}
+
+fn format_iso8601(duration: Duration, s: &mut String) {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    let (days, hours, minutes, secs) =
+        (secs / 86_400, secs / 3_600 % 24, secs / 60 % 60, secs % 60);
+    s.push('P');
+    if days > 0 {
+        let _ = write!(s, "{}D", days);
+    }
+    if hours == 0 && minutes == 0 && secs == 0 && nanos == 0 {
+        if days == 0 {
+            s.push_str("T0S");
+        }
+        return;
+    }
+    s.push('T');
+    if hours > 0 {
+        let _ = write!(s, "{}H", hours);
+    }
+    if minutes > 0 {
+        let _ = write!(s, "{}M", minutes);
+    }
+    if secs > 0 || nanos > 0 {
+        let _ = write!(s, "{}", secs);
+        if nanos > 0 {
+            let fraction = alloc::format!("{:09}", nanos);
+            let _ = write!(s, ".{}", fraction.trim_end_matches('0'));
+        }
+        s.push('S');
+    }
+}
+
+fn parse_iso8601(s: &str) -> Result<Duration, DurationError> {
+    let mut rest = s.strip_prefix('P').ok_or(DurationError::Invalid(0))?;
+    let mut total = 0u128;
+    let mut empty = true;
+    let mut time = false;
+    // designators must appear in this order.
+    let mut next = 0;
+    while !rest.is_empty() {
+        let offset = s.len() - rest.len();
+        if !time {
+            if let Some(tail) = rest.strip_prefix('T') {
+                if tail.is_empty() {
+                    return Err(DurationError::Invalid(offset + 1));
+                }
+                time = true;
+                rest = tail;
+                continue;
+            }
+        }
+        let (mut value, tail) = digits(rest, offset)?;
+        // a fraction of seconds, with either `.` or `,`.
+        let (fraction, tail) = match tail.strip_prefix(['.', ',']) {
+            Some(tail) => {
+                let offset = s.len() - tail.len();
+                let len = tail.bytes().take_while(u8::is_ascii_digit).count();
+                if len == 0 {
+                    return Err(DurationError::Invalid(offset));
+                }
+                let nanos = tail[..len]
+                    .bytes()
+                    .chain(core::iter::repeat(b'0'))
+                    .take(9)
+                    .fold(0, |acc, b| acc * 10 + (b - b'0') as u128);
+                (Some(nanos), &tail[len..])
+            }
+            None => (None, tail),
+        };
+        let offset = s.len() - tail.len();
+        let designator = tail.chars().next().ok_or(DurationError::Invalid(offset))?;
+        let (rank, len) = match (time, designator) {
+            (false, 'Y' | 'M') => return Err(DurationError::Unsupported(designator)),
+            (false, 'W') => (0, 7 * 86_400 * NANOS_PER_SEC),
+            (false, 'D') => (1, 86_400 * NANOS_PER_SEC),
+            (true, 'H') => (2, 3_600 * NANOS_PER_SEC),
+            (true, 'M') => (3, 60 * NANOS_PER_SEC),
+            (true, 'S') => (4, NANOS_PER_SEC),
+            _ => return Err(DurationError::Invalid(offset)),
+        };
+        if rank < next || (fraction.is_some() && rank != 4) {
+            return Err(DurationError::Invalid(offset));
+        }
+        next = rank + 1;
+        value = value
+            .checked_mul(len)
+            .and_then(|nanos| nanos.checked_add(fraction.unwrap_or(0)))
+            .ok_or(DurationError::Overflow)?;
+        total = total.checked_add(value).ok_or(DurationError::Overflow)?;
+        empty = false;
+        rest = &tail[designator.len_utf8()..];
+    }
+    if empty {
+        Err(DurationError::Empty)
+    } else {
+        from_nanos(total)
+    }
+}
+
+/// A converter formatting [`Duration`]s.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::duration::{DurationFormat, DurationFormatter};
+/// use conversion::iter::ConvertedIterator;
+/// use std::time::Duration;
+///
+/// let iter = [Duration::from_millis(5_415_500), Duration::ZERO];
+/// let formatted = ConvertedIterator::new(iter, DurationFormatter::new());
+/// assert_eq!(
+///     Ok(vec![String::from("1h 30m 15s 500ms"), String::from("0s")]),
+///     formatted.collect(),
+/// );
+///
+/// let formatter = DurationFormatter::with_format(DurationFormat::ISO8601);
+/// let formatted = ConvertedIterator::new(iter, formatter);
+/// assert_eq!(
+///     Ok(vec![String::from("PT1H30M15.5S"), String::from("PT0S")]),
+///     formatted.collect(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DurationFormatter {
+    format: DurationFormat,
+}
+
+impl DurationFormatter {
+    /// Create a new instance, formats human-readable durations.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a format.
+    #[inline]
+    pub fn with_format(format: DurationFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl Converter for DurationFormatter {
+    type Item = Duration;
+    type Output = String;
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut s = String::new();
+        match self.format {
+            DurationFormat::Human => format_human(item, &mut s),
+            DurationFormat::ISO8601 => format_iso8601(item, &mut s),
+        }
+        buf.extend([s]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+/// A converter parsing [`Duration`]s.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::duration::{DurationError, DurationFormat, DurationParser};
+/// use conversion::iter::ConvertedIterator;
+/// use std::time::Duration;
+///
+/// let iter = ["1h 30m 15s 500ms", "90s", "1h30m"].map(String::from);
+/// let parsed = ConvertedIterator::new(iter, DurationParser::new());
+/// assert_eq!(
+///     Ok(vec![
+///         Duration::from_millis(5_415_500),
+///         Duration::from_secs(90),
+///         Duration::from_secs(5_400),
+///     ]),
+///     parsed.collect(),
+/// );
+///
+/// let iter = ["P1W", "PT0,25S", "P1M"].map(String::from);
+/// let mut parsed = ConvertedIterator::new(iter, DurationParser::with_format(DurationFormat::ISO8601));
+/// assert_eq!(Some(Ok(Duration::from_secs(604_800))), parsed.next());
+/// assert_eq!(Some(Ok(Duration::from_millis(250))), parsed.next());
+/// assert_eq!(Some(Err(DurationError::Unsupported('M'))), parsed.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DurationParser {
+    format: DurationFormat,
+}
+
+impl DurationParser {
+    /// Create a new instance, parses human-readable durations.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a format.
+    #[inline]
+    pub fn with_format(format: DurationFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl Converter for DurationParser {
+    type Item = String;
+    type Output = Duration;
+    type Error = DurationError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let duration = match self.format {
+            DurationFormat::Human => parse_human(&item)?,
+            DurationFormat::ISO8601 => parse_iso8601(&item)?,
+        };
+        buf.extend([duration]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors = [
+            (Duration::ZERO, "0s", "PT0S"),
+            (Duration::from_nanos(1), "1ns", "PT0.000000001S"),
+            (Duration::from_micros(1_500), "1ms 500us", "PT0.0015S"),
+            (Duration::from_secs(60), "1m", "PT1M"),
+            (Duration::from_secs(86_400), "1d", "P1D"),
+            (
+                Duration::new(93_784, 5_000_000),
+                "1d 2h 3m 4s 5ms",
+                "P1DT2H3M4.005S",
+            ),
+            (
+                Duration::MAX,
+                "213503982334601d 7h 15s 999ms 999us 999ns",
+                "P213503982334601DT7H15.999999999S",
+            ),
+        ];
+        for (format, index) in [(DurationFormat::Human, 0), (DurationFormat::ISO8601, 1)] {
+            let durations = vectors.map(|v| v.0);
+            let strings = vectors.map(|v| String::from([v.1, v.2][index]));
+            let formatted =
+                ConvertedIterator::new(durations, DurationFormatter::with_format(format))
+                    .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(strings.to_vec()), formatted);
+            let parsed = ConvertedIterator::new(strings, DurationParser::with_format(format))
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(durations.to_vec()), parsed);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use alloc::vec::Vec;
+
+        let mut parser = DurationParser::new();
+        let mut durations = Vec::new();
+        let vectors = [
+            ("", DurationError::Empty),
+            ("  ", DurationError::Empty),
+            ("h", DurationError::Invalid(0)),
+            ("1h 30", DurationError::Invalid(5)),
+            ("1h 30x", DurationError::Invalid(5)),
+            ("1.5s", DurationError::Invalid(1)),
+            ("213503982334602d", DurationError::Overflow),
+            ("99999999999999999999s", DurationError::Overflow),
+        ];
+        for (s, e) in vectors {
+            assert_eq!(
+                Err(e),
+                parser.convert(String::from(s), &mut durations),
+                "{:?}",
+                s
+            );
+        }
+
+        let mut parser = DurationParser::with_format(DurationFormat::ISO8601);
+        let vectors = [
+            ("", DurationError::Invalid(0)),
+            ("P", DurationError::Empty),
+            ("PT", DurationError::Invalid(2)),
+            ("1D", DurationError::Invalid(0)),
+            ("P1Y", DurationError::Unsupported('Y')),
+            ("PT1D", DurationError::Invalid(3)),
+            ("P1H", DurationError::Invalid(2)),
+            ("PT1S1M", DurationError::Invalid(5)),
+            ("PT1.5M", DurationError::Invalid(5)),
+            ("PT1.S", DurationError::Invalid(4)),
+            ("PT1", DurationError::Invalid(3)),
+        ];
+        for (s, e) in vectors {
+            assert_eq!(
+                Err(e),
+                parser.convert(String::from(s), &mut durations),
+                "{:?}",
+                s
+            );
+        }
+        assert!(durations.is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+//! A converter from characters to extended grapheme clusters, described in [UAX #29].
+//!
+//! Grapheme clusters are user-perceived characters, like `e` with a combining acute accent, a
+//! Hangul syllable of conjoining jamo, or an emoji sequence joined with ZWJ. Boundaries are
+//! determined by the [`unicode-segmentation`] crate.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+//! [`unicode-segmentation`]: https://docs.rs/unicode-segmentation
+
+use alloc::string::String;
+use core::convert::Infallible;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::Converter;
+
+/// A converter from characters to grapheme clusters.
+///
+/// Characters are buffered until the next boundary, and the last cluster is emitted at the end of
+/// the input.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::grapheme::GraphemeClusterConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "e\u{301}각\r\n🇯🇵👨\u{200D}👩\u{200D}👧!".chars();
+/// let clusters = ConvertedIterator::new(iter, GraphemeClusterConverter::new())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     vec!["e\u{301}", "각", "\r\n", "🇯🇵", "👨\u{200D}👩\u{200D}👧", "!"],
+///     clusters,
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphemeClusterConverter {
+    cluster: String,
+}
+
+impl GraphemeClusterConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for GraphemeClusterConverter {
+    type Item = char;
+    type Output = String;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        // boundaries only depend on the characters of the current cluster, so the cluster is
+        // complete once the appended character starts another one.
+        self.cluster.push(item);
+        let first = self.cluster.graphemes(true).next().map_or(0, str::len);
+        if first < self.cluster.len() {
+            let rest = self.cluster.split_off(first);
+            buf.extend([core::mem::replace(&mut self.cluster, rest)]);
+            Ok(1)
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let cluster = core::mem::take(self).cluster;
+        if cluster.is_empty() {
+            Ok(0)
+        } else {
+            buf.extend([cluster]);
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clusters() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors: [(&str, &[&str]); 11] = [
+            ("", &[]),
+            ("abc", &["a", "b", "c"]),
+            ("\r\n\n\r", &["\r\n", "\n", "\r"]),
+            (
+                "\u{1100}\u{1161}\u{11A8}\u{1100}",
+                &["\u{1100}\u{1161}\u{11A8}", "\u{1100}"],
+            ),
+            ("각\u{11A8}가\u{1161}", &["각\u{11A8}", "가\u{1161}"]),
+            ("\u{301}a\u{301}\u{301}", &["\u{301}", "a\u{301}\u{301}"]),
+            ("\u{600}1\u{600}", &["\u{600}1", "\u{600}"]),
+            ("क\u{93F}\u{200D}", &["क\u{93F}\u{200D}"]),
+            ("🇯🇵🇺🇸🇫", &["🇯🇵", "🇺🇸", "🇫"]),
+            ("a\u{200D}😀", &["a\u{200D}", "😀"]),
+            (
+                "😀\u{1F3FB}\u{200D}😀\u{200D}\u{200D}😀",
+                &["😀\u{1F3FB}\u{200D}😀\u{200D}\u{200D}", "😀"],
+            ),
+        ];
+        for (text, expected) in vectors {
+            let clusters = ConvertedIterator::new(text.chars(), GraphemeClusterConverter::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(expected, clusters, "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! gRPC message framing Encoder/Decoder.
+//!
+//! Each message of gRPC (over HTTP/2) is prefixed with a 5-byte header, which is a compressed
+//! flag byte (`0` or `1`) and the length of the message as a 4-byte big-endian integer.
+//! Compression of messages itself is not handled here.
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+// the length of frame headers.
+const HEADER_LEN: usize = 5;
+
+/// An error while encoding/decoding gRPC frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GRPCFrameError {
+    /// A message longer than the limit, with its length.
+    TooLong(usize),
+    /// A compressed flag which is neither `0` nor `1`.
+    InvalidFlag(u8),
+    /// The input ended inside a frame.
+    Truncated,
+}
+
+impl fmt::Display for GRPCFrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(len) => write!(f, "the message is too long: {} bytes", len),
+            Self::InvalidFlag(flag) => write!(f, "invalid compressed flag: {}", flag),
+            Self::Truncated => write!(f, "the input ended inside a frame."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GRPCFrameError {}
+
+/// An encoder for gRPC frames, emits a header and bytes of each message.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::grpc::GRPCFrameEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [b"hi".to_vec(), Vec::new()];
+/// let encoded = ConvertedIterator::new(iter, GRPCFrameEncoder::new());
+/// assert_eq!(Ok(b"\0\0\0\0\x02hi\0\0\0\0\0".to_vec()), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new([vec![0xAB]], GRPCFrameEncoder::new().compressed(true));
+/// assert_eq!(Ok(vec![1, 0, 0, 0, 1, 0xAB]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GRPCFrameEncoder {
+    compressed: bool,
+}
+
+impl GRPCFrameEncoder {
+    /// Create a new instance, marks messages as uncompressed.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting whether to mark messages as compressed. (Messages must be already compressed.)
+    #[inline]
+    pub fn compressed(self, compressed: bool) -> Self {
+        Self { compressed }
+    }
+}
+
+impl Converter for GRPCFrameEncoder {
+    type Item = Vec<u8>;
+    type Output = u8;
+    type Error = GRPCFrameError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = u32::try_from(item.len()).map_err(|_| GRPCFrameError::TooLong(item.len()))?;
+        buf.extend([self.compressed as u8]);
+        buf.extend(len.to_be_bytes());
+        buf.extend(item);
+        Ok(HEADER_LEN + len as usize)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (HEADER_LEN, None)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum State {
+    Header {
+        header: [u8; HEADER_LEN],
+        len: usize,
+    },
+    Payload {
+        compressed: bool,
+        remaining: usize,
+        data: Vec<u8>,
+    },
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Header {
+            header: [0; HEADER_LEN],
+            len: 0,
+        }
+    }
+}
+
+/// A decoder for gRPC frames, emits the compressed flag and bytes of each message.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::grpc::{GRPCFrameDecoder, GRPCFrameError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\0\0\0\0\x02hi\x01\0\0\0\0\0\0\0".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, GRPCFrameDecoder::new());
+/// assert_eq!(Some(Ok((false, b"hi".to_vec()))), decoded.next());
+/// assert_eq!(Some(Ok((true, Vec::new()))), decoded.next());
+/// assert_eq!(Some(Err(GRPCFrameError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+///
+/// let iter = b"\0\0\x01\0\0".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, GRPCFrameDecoder::new().max_length(1024));
+/// assert_eq!(Some(Err(GRPCFrameError::TooLong(65536))), decoded.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GRPCFrameDecoder {
+    max_length: usize,
+    state: State,
+}
+
+impl Default for GRPCFrameDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max_length: u32::MAX as usize,
+            state: State::default(),
+        }
+    }
+}
+
+impl GRPCFrameDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the maximum length of messages, not to allocate too much memory for malicious
+    /// inputs. (No limit by default.)
+    #[inline]
+    pub fn max_length(self, max_length: usize) -> Self {
+        Self { max_length, ..self }
+    }
+}
+
+impl Converter for GRPCFrameDecoder {
+    type Item = u8;
+    type Output = (bool, Vec<u8>);
+    type Error = GRPCFrameError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match &mut self.state {
+            State::Header { header, len } => {
+                header[*len] = item;
+                *len += 1;
+                if *len < HEADER_LEN {
+                    return Ok(0);
+                }
+
+                let header = *header;
+                self.state = State::default();
+                let compressed = match header[0] {
+                    0 => false,
+                    1 => true,
+                    flag => return Err(GRPCFrameError::InvalidFlag(flag)),
+                };
+                let remaining =
+                    u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+                if remaining > self.max_length {
+                    return Err(GRPCFrameError::TooLong(remaining));
+                } else if remaining == 0 {
+                    buf.extend([(compressed, Vec::new())]);
+                    return Ok(1);
+                }
+                self.state = State::Payload {
+                    compressed,
+                    remaining,
+                    data: Vec::with_capacity(remaining),
+                };
+                Ok(0)
+            }
+            State::Payload {
+                compressed,
+                remaining,
+                data,
+            } => {
+                data.push(item);
+                *remaining -= 1;
+                if *remaining > 0 {
+                    return Ok(0);
+                }
+
+                let frame = (*compressed, core::mem::take(data));
+                self.state = State::default();
+                buf.extend([frame]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::Header { len: 0, .. } => Ok(()),
+            _ => Err(GRPCFrameError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+
+        let messages = vec![
+            (false, b"\x08\x96\x01".to_vec()),
+            (true, Vec::new()),
+            (false, (0..=255).cycle().take(70000).collect()),
+        ];
+        let mut encoded = Vec::new();
+        for (compressed, data) in messages.iter().cloned() {
+            let mut encoder = GRPCFrameEncoder::new().compressed(compressed);
+            encoder.convert(data, &mut encoded).unwrap();
+        }
+        assert_eq!(
+            &[0, 0, 0, 0, 3, 0x08, 0x96, 0x01, 1, 0, 0, 0, 0],
+            &encoded[..13]
+        );
+        assert_eq!(&[0, 0, 1, 0x11, 0x70], &encoded[13..18]);
+
+        let decoded =
+            ConvertedIterator::new(encoded, GRPCFrameDecoder::new()).collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(messages), decoded);
+    }
+
+    #[test]
+    fn errors() {
+        let mut decoder = GRPCFrameDecoder::new();
+        let mut buf = Vec::new();
+        for b in [2, 0, 0, 0] {
+            assert_eq!(Ok(0), decoder.convert(b, &mut buf));
+        }
+        assert_eq!(
+            Err(GRPCFrameError::InvalidFlag(2)),
+            decoder.convert(1, &mut buf)
+        );
+        // the frame is skipped, and the next header follows.
+        for b in [0, 0, 0, 0, 1] {
+            assert_eq!(Ok(0), decoder.convert(b, &mut buf));
+        }
+        assert_eq!(Err(GRPCFrameError::Truncated), decoder.finalize());
+        assert_eq!(Ok(()), decoder.finalize());
+        assert!(buf.is_empty());
+    }
+}
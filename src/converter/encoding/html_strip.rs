@@ -0,0 +1,198 @@
+//! Stripping HTML/XML tags, keeping only the text content.
+//!
+//! Tags (including end tags, declarations like `<!DOCTYPE html>` and processing instructions)
+//! and comments are removed, and `>` in quoted attribute values does not end the tag. Like
+//! browsers, `<` not followed by a letter, `/`, `!` or `?` is kept as text.
+//!
+//! Character references like `&amp;` are kept as is, and contents of `<script>` and `<style>` are
+//! emitted as text.
+
+use crate::Converter;
+use core::fmt;
+
+/// An error while stripping tags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HTMLStripError {
+    /// The input ended inside a tag.
+    UnterminatedTag,
+    /// The input ended inside a comment.
+    UnterminatedComment,
+}
+
+impl fmt::Display for HTMLStripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedTag => write!(f, "the input ended inside a tag."),
+            Self::UnterminatedComment => write!(f, "the input ended inside a comment."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HTMLStripError {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    InText,
+    // after `<`.
+    Open,
+    // after `<!`, with the number of following `-`, which is 0 or 1.
+    Bang(u8),
+    // with the quote of the attribute value.
+    InTag(Option<char>),
+    // with the number of `-` before the current character.
+    InComment(u8),
+}
+
+/// A converter stripping tags, emits characters of the text content.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::html_strip::{HTMLStripError, HTMLTagStripper};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let html = r#"<p class="a>b">1 < 2 <!-- <b>comment</b> --><br/>&amp; 3</p>"#;
+/// let text = ConvertedIterator::new(html.chars(), HTMLTagStripper::new());
+/// assert_eq!(Ok(String::from("1 < 2 &amp; 3")), text.collect());
+///
+/// let text = ConvertedIterator::new("text<a href=".chars(), HTMLTagStripper::new());
+/// assert_eq!(
+///     Err(HTMLStripError::UnterminatedTag),
+///     text.collect::<Result<String, _>>(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HTMLTagStripper {
+    state: State,
+}
+
+impl HTMLTagStripper {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for HTMLTagStripper {
+    type Item = char;
+    type Output = char;
+    type Error = HTMLStripError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (state, len) = match (self.state, item) {
+            (State::InText, '<') => (State::Open, 0),
+            (State::InText, c) => {
+                buf.extend([c]);
+                (State::InText, 1)
+            }
+            (State::Open, '!') => (State::Bang(0), 0),
+            (State::Open, c) if c.is_ascii_alphabetic() || c == '/' || c == '?' => {
+                (State::InTag(None), 0)
+            }
+            // not a tag.
+            (State::Open, '<') => {
+                buf.extend(['<']);
+                (State::Open, 1)
+            }
+            (State::Open, c) => {
+                buf.extend(['<', c]);
+                (State::InText, 2)
+            }
+            (State::Bang(0), '-') => (State::Bang(1), 0),
+            (State::Bang(1), '-') => (State::InComment(0), 0),
+            (State::Bang(_), '>') => (State::InText, 0),
+            (State::Bang(_), _) => (State::InTag(None), 0),
+            (State::InTag(None), '>') => (State::InText, 0),
+            (State::InTag(None), c @ ('"' | '\'')) => (State::InTag(Some(c)), 0),
+            (State::InTag(Some(q)), c) if c == q => (State::InTag(None), 0),
+            (State::InTag(quote), _) => (State::InTag(quote), 0),
+            (State::InComment(dashes), '-') => (State::InComment(dashes.saturating_add(1)), 0),
+            (State::InComment(dashes), '>') if dashes >= 2 => (State::InText, 0),
+            (State::InComment(_), _) => (State::InComment(0), 0),
+        };
+        self.state = state;
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match core::mem::take(&mut self.state) {
+            State::InText => Ok(0),
+            State::Open => {
+                buf.extend(['<']);
+                Ok(1)
+            }
+            State::Bang(_) | State::InTag(_) => Err(HTMLStripError::UnterminatedTag),
+            State::InComment(_) => Err(HTMLStripError::UnterminatedComment),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(usize::from(matches!(self.state, State::Open)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn strip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let vectors = [
+            ("", ""),
+            ("plain text", "plain text"),
+            ("<!DOCTYPE html><html><body>a<br>b</body></html>", "ab"),
+            ("<?xml version=\"1.0\"?><r>x</r>", "x"),
+            ("a<b title='>'>b</b>c", "abc"),
+            ("<!---->a<!-- - -- --->b<!--->-->c", "abc"),
+            ("<![CDATA[x]]>y", "y"),
+            ("a <= b <<3", "a <= b <<3"),
+            ("3 < 4", "3 < 4"),
+            ("1 <", "1 <"),
+        ];
+        for (html, expected) in vectors {
+            let text = ConvertedIterator::new(html.chars(), HTMLTagStripper::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(expected)), text, "{:?}", html);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unterminated() {
+        use alloc::string::String;
+
+        for (html, error) in [
+            ("<a", HTMLStripError::UnterminatedTag),
+            ("<a title='>", HTMLStripError::UnterminatedTag),
+            ("<!", HTMLStripError::UnterminatedTag),
+            ("<!-- a --", HTMLStripError::UnterminatedComment),
+        ] {
+            let mut stripper = HTMLTagStripper::new();
+            let mut buf = String::new();
+            for c in html.chars() {
+                assert_eq!(Ok(0), stripper.convert(c, &mut buf));
+            }
+            assert_eq!(Err(error), stripper.finish(&mut buf));
+            // the state is reset.
+            assert_eq!(Ok(1), stripper.convert('a', &mut buf));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Quoted-string Encoder/Decoder for HTTP header values, described in [RFC 9110].
+//!
+//! A quoted-string is wrapped in `"`, and `"` and `\` inside it are escaped with `\`. Control
+//! characters other than the horizontal tab are not allowed, which also prevents injecting line
+//! breaks into headers.
+//!
+//! [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#section-5.6.4
+
+use crate::Converter;
+use core::fmt;
+
+/// An error while encoding/decoding quoted-strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuotedStringError {
+    /// A control character other than the horizontal tab.
+    InvalidChar(char),
+    /// The input does not start with `"`.
+    MissingQuote,
+    /// A character after the closing `"`.
+    TrailingChar(char),
+    /// The input ended without the closing `"`, or inside an escape sequence.
+    Unterminated,
+}
+
+impl fmt::Display for QuotedStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(c) => write!(f, "invalid character in quoted-string: {:?}", c),
+            Self::MissingQuote => write!(f, "the quoted-string does not start with `\"`."),
+            Self::TrailingChar(c) => write!(f, "a character after the quoted-string: {:?}", c),
+            Self::Unterminated => write!(f, "unterminated quoted-string."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QuotedStringError {}
+
+#[inline]
+fn is_invalid(c: char) -> bool {
+    c != '\t' && c.is_ascii_control()
+}
+
+/// An encoder for quoted-strings.
+///
+/// The whole input is wrapped in `"`, and the closing `"` is emitted at the end of the input.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::http_quoted::{QuotedStringEncoder, QuotedStringError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = r#"say "hi" \o/"#.chars();
+/// let encoded = ConvertedIterator::new(iter, QuotedStringEncoder::new());
+/// assert_eq!(Ok(String::from(r#""say \"hi\" \\o/""#)), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new("a\r\nb".chars(), QuotedStringEncoder::new());
+/// assert_eq!(
+///     Err(QuotedStringError::InvalidChar('\r')),
+///     encoded.collect::<Result<String, _>>(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuotedStringEncoder {
+    // whether the opening `"` has been emitted.
+    started: bool,
+}
+
+impl QuotedStringEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn open<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<char>,
+    {
+        if core::mem::replace(&mut self.started, true) {
+            0
+        } else {
+            buf.extend(['"']);
+            1
+        }
+    }
+}
+
+impl Converter for QuotedStringEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = QuotedStringError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if is_invalid(item) {
+            return Err(QuotedStringError::InvalidChar(item));
+        }
+        let len = self.open(buf);
+        match item {
+            '"' | '\\' => {
+                buf.extend(['\\', item]);
+                Ok(len + 2)
+            }
+            c => {
+                buf.extend([c]);
+                Ok(len + 1)
+            }
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = self.open(buf);
+        self.started = false;
+        buf.extend(['"']);
+        Ok(len + 1)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(if self.started { 1 } else { 2 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(3))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Start,
+    Quoted,
+    // after `\`.
+    Escape,
+    Closed,
+}
+
+/// A decoder for quoted-strings.
+///
+/// The converter ends at the closing `"` (see [`is_ended`]), so characters after it are left in
+/// the input, or passed to the next converter of [`chain`]. Characters passed after the end are
+/// reported as [`TrailingChar`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::http_quoted::{QuotedStringDecoder, QuotedStringError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut iter = r#""say \"hi\" \\o/"; charset=utf-8"#.chars();
+/// let decoded = ConvertedIterator::new(&mut iter, QuotedStringDecoder::new());
+/// assert_eq!(Ok(String::from(r#"say "hi" \o/"#)), decoded.collect());
+/// assert_eq!("; charset=utf-8", iter.as_str());
+///
+/// let decoded = ConvertedIterator::new(r#""abc\"#.chars(), QuotedStringDecoder::new());
+/// assert_eq!(
+///     Err(QuotedStringError::Unterminated),
+///     decoded.collect::<Result<String, _>>(),
+/// );
+/// ```
+///
+/// [`is_ended`]: crate::Converter::is_ended
+/// [`chain`]: crate::Converter::chain
+/// [`TrailingChar`]: self::QuotedStringError::TrailingChar
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QuotedStringDecoder {
+    state: State,
+}
+
+impl QuotedStringDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for QuotedStringDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = QuotedStringError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match (self.state, item) {
+            (State::Closed, c) => Err(QuotedStringError::TrailingChar(c)),
+            (_, c) if is_invalid(c) => Err(QuotedStringError::InvalidChar(c)),
+            (State::Start, '"') => {
+                self.state = State::Quoted;
+                Ok(0)
+            }
+            (State::Start, _) => {
+                // the rest is still decoded as a quoted-string.
+                self.state = State::Quoted;
+                Err(QuotedStringError::MissingQuote)
+            }
+            (State::Quoted, '"') => {
+                self.state = State::Closed;
+                Ok(0)
+            }
+            (State::Quoted, '\\') => {
+                self.state = State::Escape;
+                Ok(0)
+            }
+            (State::Quoted | State::Escape, c) => {
+                self.state = State::Quoted;
+                buf.extend([c]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.state == State::Closed
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::Closed => Ok(()),
+            State::Start => Err(QuotedStringError::MissingQuote),
+            State::Quoted | State::Escape => Err(QuotedStringError::Unterminated),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        for text in ["", "\"", "\\", "a\tb", r#"\"\\""#, "straße 😀"] {
+            let encoded = ConvertedIterator::new(text.chars(), QuotedStringEncoder::new())
+                .collect::<Result<String, _>>()
+                .unwrap();
+            assert!(encoded.starts_with('"') && encoded.ends_with('"'));
+            let decoded = ConvertedIterator::new(encoded.chars(), QuotedStringDecoder::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(text)), decoded);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use alloc::string::String;
+
+        let mut decoder = QuotedStringDecoder::new();
+        let mut buf = String::new();
+        assert_eq!(
+            Err(QuotedStringError::MissingQuote),
+            decoder.convert('a', &mut buf)
+        );
+        assert_eq!(
+            Err(QuotedStringError::InvalidChar('\n')),
+            decoder.convert('\n', &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert('"', &mut buf));
+        assert!(decoder.is_ended());
+        assert_eq!(
+            Err(QuotedStringError::TrailingChar('b')),
+            decoder.convert('b', &mut buf)
+        );
+        assert_eq!(Ok(()), decoder.finalize());
+        assert_eq!("", buf);
+
+        assert_eq!(Err(QuotedStringError::MissingQuote), decoder.finalize());
+    }
+}
@@ -0,0 +1,351 @@
+//! Huffman Encoder/Decoder with user-supplied code tables.
+
+use crate::Converter;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error while Huffman coding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HuffmanError {
+    /// A symbol not in the code table.
+    UnknownSymbol,
+    /// A bit sequence not in the code table.
+    UnknownCode,
+    /// The code table is not a prefix code.
+    AmbiguousCode,
+    /// The input ended before the expected number of symbols are decoded.
+    Truncated,
+}
+
+impl fmt::Display for HuffmanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol => write!(f, "found a symbol not in the code table."),
+            Self::UnknownCode => write!(f, "found a code not in the code table."),
+            Self::AmbiguousCode => write!(f, "the code table is not a prefix code."),
+            Self::Truncated => write!(f, "the input ended unexpectedly."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HuffmanError {}
+
+/// A Huffman encoder, emits codes of symbols as bytes. (most significant bit first)
+///
+/// The last byte is padded with `0`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::huffman::HuffmanEncoder;
+/// use conversion::iter::ConvertedIterator;
+/// use std::collections::BTreeMap;
+///
+/// let table = BTreeMap::from([
+///     ('a', vec![false]),
+///     ('b', vec![true, false]),
+///     ('c', vec![true, true]),
+/// ]);
+/// let encoded = ConvertedIterator::new("abacab".chars(), HuffmanEncoder::new(table));
+///
+/// // 01001101 0
+/// assert_eq!(Ok(vec![0x4D, 0x00]), encoded.collect());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuffmanEncoder<T> {
+    table: BTreeMap<T, Vec<bool>>,
+    byte: u8,
+    bits: u8,
+}
+
+impl<T> HuffmanEncoder<T> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(table: BTreeMap<T, Vec<bool>>) -> Self {
+        Self {
+            table,
+            byte: 0,
+            bits: 0,
+        }
+    }
+}
+
+impl<T: Ord> Converter for HuffmanEncoder<T> {
+    type Item = T;
+    type Output = u8;
+    type Error = HuffmanError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let code = self.table.get(&item).ok_or(HuffmanError::UnknownSymbol)?;
+        let mut len = 0;
+        for &bit in code {
+            self.byte = self.byte << 1 | bit as u8;
+            self.bits += 1;
+            if self.bits == 8 {
+                buf.extend([self.byte]);
+                self.byte = 0;
+                self.bits = 0;
+                len += 1;
+            }
+        }
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.bits == 0 {
+            Ok(0)
+        } else {
+            buf.extend([self.byte << (8 - self.bits)]);
+            self.byte = 0;
+            self.bits = 0;
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Node<T> {
+    Branch([Option<usize>; 2]),
+    Leaf(T),
+}
+
+/// A Huffman tree for [`HuffmanDecoder`].
+///
+/// [`HuffmanDecoder`]: self::HuffmanDecoder
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuffmanTree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> HuffmanTree<T> {
+    /// Building a tree from pairs of symbols and codes.
+    ///
+    /// Returns [`HuffmanError::AmbiguousCode`] if the codes are not a prefix code.
+    ///
+    /// [`HuffmanError::AmbiguousCode`]: self::HuffmanError::AmbiguousCode
+    pub fn new<I>(codes: I) -> Result<Self, HuffmanError>
+    where
+        I: IntoIterator<Item = (T, Vec<bool>)>,
+    {
+        let mut nodes = vec![Node::Branch([None, None])];
+        for (symbol, code) in codes {
+            let mut index = 0;
+            for bit in code {
+                let next = match &nodes[index] {
+                    Node::Branch(children) => children[bit as usize],
+                    Node::Leaf(_) => return Err(HuffmanError::AmbiguousCode),
+                };
+                index = match next {
+                    Some(next) => next,
+                    None => {
+                        let next = nodes.len();
+                        nodes.push(Node::Branch([None, None]));
+                        if let Node::Branch(children) = &mut nodes[index] {
+                            children[bit as usize] = Some(next);
+                        }
+                        next
+                    }
+                };
+            }
+
+            match nodes[index] {
+                Node::Branch([None, None]) if index != 0 => nodes[index] = Node::Leaf(symbol),
+                _ => return Err(HuffmanError::AmbiguousCode),
+            }
+        }
+        Ok(Self { nodes })
+    }
+}
+
+/// A Huffman decoder, emits symbols from bytes. (most significant bit first)
+///
+/// Because the last byte may be padded, trailing bits which don't complete a code are ignored,
+/// and you may want to specify the number of symbols by [`with_len`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::huffman::{HuffmanDecoder, HuffmanTree};
+/// use conversion::iter::ConvertedIterator;
+/// use std::collections::BTreeMap;
+///
+/// let table = BTreeMap::from([
+///     ('a', vec![false]),
+///     ('b', vec![true, false]),
+///     ('c', vec![true, true]),
+/// ]);
+/// let tree = HuffmanTree::new(table).unwrap();
+/// let decoded = ConvertedIterator::new([0x4D, 0x00], HuffmanDecoder::new(tree).with_len(6));
+///
+/// assert_eq!(Ok(String::from("abacab")), decoded.collect());
+/// ```
+///
+/// [`with_len`]: Self::with_len
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuffmanDecoder<T> {
+    tree: HuffmanTree<T>,
+    // current node of the tree.
+    node: usize,
+    // remaining number of symbols.
+    remain: Option<usize>,
+}
+
+impl<T> HuffmanDecoder<T> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(tree: HuffmanTree<T>) -> Self {
+        Self {
+            tree,
+            node: 0,
+            remain: None,
+        }
+    }
+
+    /// Stopping after decoding `len` symbols.
+    #[inline]
+    pub fn with_len(self, len: usize) -> Self {
+        Self {
+            remain: Some(len),
+            ..self
+        }
+    }
+}
+
+impl<T: Clone> Converter for HuffmanDecoder<T> {
+    type Item = u8;
+    type Output = T;
+    type Error = HuffmanError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        for i in (0..8).rev() {
+            if self.remain == Some(0) {
+                break;
+            }
+
+            let next = match &self.tree.nodes[self.node] {
+                Node::Branch(children) => children[(item >> i & 1) as usize],
+                Node::Leaf(_) => unreachable!(),
+            };
+            self.node = match next {
+                Some(next) => next,
+                None => {
+                    self.node = 0;
+                    return Err(HuffmanError::UnknownCode);
+                }
+            };
+
+            if let Node::Leaf(symbol) = &self.tree.nodes[self.node] {
+                buf.extend([symbol.clone()]);
+                len += 1;
+                self.node = 0;
+                if let Some(remain) = &mut self.remain {
+                    *remain -= 1;
+                }
+            }
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.remain == Some(0)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.remain {
+            Some(remain) if remain > 0 => Err(HuffmanError::Truncated),
+            _ => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(8))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::string::String;
+
+    fn table() -> BTreeMap<char, Vec<bool>> {
+        BTreeMap::from([
+            ('a', vec![false, false]),
+            ('b', vec![false, true]),
+            ('c', vec![true, false, false]),
+            ('d', vec![true, false, true]),
+            ('e', vec![true, true, false]),
+        ])
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "deadbeef cab";
+        let encoded = ConvertedIterator::new(text.chars(), HuffmanEncoder::new(table()))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Err(HuffmanError::UnknownSymbol), encoded);
+
+        let text = "deadbeadcabbed";
+        let encoded = ConvertedIterator::new(text.chars(), HuffmanEncoder::new(table()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let tree = HuffmanTree::new(table()).unwrap();
+        let decoded = ConvertedIterator::new(
+            encoded.clone(),
+            HuffmanDecoder::new(tree.clone()).with_len(text.len()),
+        );
+        assert_eq!(Ok(String::from(text)), decoded.collect());
+
+        let decoded =
+            ConvertedIterator::new(encoded, HuffmanDecoder::new(tree).with_len(text.len() + 5));
+        assert_eq!(
+            Err(HuffmanError::Truncated),
+            decoded.collect::<Result<String, _>>()
+        );
+    }
+
+    #[test]
+    fn invalid_table() {
+        assert_eq!(
+            Err(HuffmanError::AmbiguousCode),
+            HuffmanTree::new([('a', vec![true]), ('b', vec![true, false])])
+        );
+        assert_eq!(
+            Err(HuffmanError::AmbiguousCode),
+            HuffmanTree::new([('a', vec![true, false]), ('b', vec![true])])
+        );
+        assert_eq!(
+            Err(HuffmanError::AmbiguousCode),
+            HuffmanTree::new([('a', vec![true]), ('b', vec![true])])
+        );
+        assert_eq!(
+            Err(HuffmanError::AmbiguousCode),
+            HuffmanTree::new([('a', vec![])])
+        );
+
+        // `111` is not in the table.
+        let tree = HuffmanTree::new(table()).unwrap();
+        let mut decoded = ConvertedIterator::new([0b1110_0000], HuffmanDecoder::new(tree));
+        assert_eq!(Some(Err(HuffmanError::UnknownCode)), decoded.next());
+        assert_eq!(None, decoded.next());
+    }
+}
@@ -0,0 +1,441 @@
+//! Encoding fixed-width integers into bytes, and decoding them.
+//!
+//! The byte order is a type parameter, one of [`BigEndian`], [`LittleEndian`] and
+//! [`NativeEndian`]. Decoders can be used one after another on the same iterator to parse binary
+//! formats, since each of them consumes just the bytes of an integer per output.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::encoding::int::{U16Decoder, U32Decoder};
+//! use conversion::converter::encoding::word::{BigEndian, LittleEndian};
+//! use conversion::iter::ConvertedIterator;
+//!
+//! let mut iter = b"\x00\x02\x01\x00\x00\x00\x02\x00\x00\x00".iter().cloned();
+//! let len = ConvertedIterator::new(&mut iter, U16Decoder::<BigEndian>::new()).next();
+//! assert_eq!(Some(Ok(2)), len);
+//!
+//! let values = ConvertedIterator::new(&mut iter, U32Decoder::<LittleEndian>::new());
+//! assert_eq!(Ok(vec![1, 2]), values.collect());
+//! ```
+//!
+//! [`BigEndian`]: super::word::BigEndian
+//! [`LittleEndian`]: super::word::LittleEndian
+//! [`NativeEndian`]: super::word::NativeEndian
+
+use super::word::ByteOrder;
+use super::Endianness;
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// An error for inputs which ended inside an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TruncatedIntError {
+    missing: usize,
+}
+
+impl TruncatedIntError {
+    /// Returns the number of bytes missing to complete the integer.
+    #[inline]
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+}
+
+impl fmt::Display for TruncatedIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the input ended {} bytes short of an integer.",
+            self.missing
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TruncatedIntError {}
+
+macro_rules! int_converter {
+    (
+        $(#[$decoder_attr:meta])* $decoder:ident,
+        $(#[$encoder_attr:meta])* $encoder:ident,
+        $int:ty
+    ) => {
+        $(#[$decoder_attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $decoder<B> {
+            bytes: [u8; core::mem::size_of::<$int>()],
+            count: usize,
+            _order: PhantomData<B>,
+        }
+
+        impl<B> $decoder<B> {
+            /// Create a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    bytes: [0; core::mem::size_of::<$int>()],
+                    count: 0,
+                    _order: PhantomData,
+                }
+            }
+        }
+
+        impl<B: ByteOrder> Converter for $decoder<B> {
+            type Item = u8;
+            type Output = $int;
+            type Error = TruncatedIntError;
+
+            #[inline]
+            fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                self.bytes[self.count] = item;
+                self.count += 1;
+                if self.count < self.bytes.len() {
+                    return Ok(0);
+                }
+
+                self.count = 0;
+                buf.extend([match B::ENDIANNESS {
+                    Endianness::Big => <$int>::from_be_bytes(self.bytes),
+                    Endianness::Little => <$int>::from_le_bytes(self.bytes),
+                }]);
+                Ok(1)
+            }
+
+            #[inline]
+            fn finalize(&mut self) -> Result<(), Self::Error> {
+                match core::mem::take(&mut self.count) {
+                    0 => Ok(()),
+                    count => Err(TruncatedIntError {
+                        missing: self.bytes.len() - count,
+                    }),
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(1))
+            }
+        }
+
+        $(#[$encoder_attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $encoder<B> {
+            _order: PhantomData<B>,
+        }
+
+        impl<B> $encoder<B> {
+            /// Create a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    _order: PhantomData,
+                }
+            }
+        }
+
+        impl<B: ByteOrder> Converter for $encoder<B> {
+            type Item = $int;
+            type Output = u8;
+            type Error = Infallible;
+
+            #[inline]
+            fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                buf.extend(match B::ENDIANNESS {
+                    Endianness::Big => item.to_be_bytes(),
+                    Endianness::Little => item.to_le_bytes(),
+                });
+                Ok(core::mem::size_of::<$int>())
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = core::mem::size_of::<$int>();
+                (len, Some(len))
+            }
+        }
+
+        impl<B> crate::sealed::Sealed for $encoder<B> {}
+
+        impl<B: ByteOrder> ExactSizeConverter for $encoder<B> {}
+    };
+}
+
+int_converter!(
+    /// Decoding each 2 bytes as a `u16`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::U16Decoder;
+    /// use conversion::converter::encoding::word::LittleEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\x34\x12\xFF\xFF".iter().cloned();
+    /// let ints = ConvertedIterator::new(iter, U16Decoder::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![0x1234, 0xFFFF]), ints.collect());
+    /// ```
+    U16Decoder,
+    /// Encoding each `u16` into 2 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::U16Encoder;
+    /// use conversion::converter::encoding::word::BigEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [0x1234, 0xFFFF].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, U16Encoder::<BigEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\x12\x34\xFF\xFF".to_vec()), bytes.collect());
+    /// ```
+    U16Encoder,
+    u16
+);
+
+int_converter!(
+    /// Decoding each 4 bytes as a `u32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::U32Decoder;
+    /// use conversion::converter::encoding::word::BigEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\x12\x34\x56\x78\x9A".iter().cloned();
+    /// let mut ints = ConvertedIterator::new(iter, U32Decoder::<BigEndian>::new());
+    ///
+    /// assert_eq!(Some(Ok(0x12345678)), ints.next());
+    /// assert_eq!(Some(3), ints.next().and_then(|r| r.err()).map(|e| e.missing()));
+    /// assert_eq!(None, ints.next());
+    /// ```
+    U32Decoder,
+    /// Encoding each `u32` into 4 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::{U32Decoder, U32Encoder};
+    /// use conversion::converter::encoding::word::BigEndian;
+    /// use conversion::iter::ConvertedIterator;
+    /// use conversion::Converter;
+    ///
+    /// let iter = [1u32, 0xDEADBEEF].into_iter();
+    /// let bytes = ConvertedIterator::new(iter.clone(), U32Encoder::<BigEndian>::new());
+    /// assert_eq!(Ok(b"\x00\x00\x00\x01\xDE\xAD\xBE\xEF".to_vec()), bytes.collect());
+    ///
+    /// let conv = U32Encoder::<BigEndian>::new().pipe(U32Decoder::<BigEndian>::new());
+    /// let round_trip = ConvertedIterator::new(iter, conv);
+    /// assert_eq!(Ok(vec![1, 0xDEADBEEF]), round_trip.collect());
+    /// ```
+    U32Encoder,
+    u32
+);
+
+int_converter!(
+    /// Decoding each 8 bytes as a `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::U64Decoder;
+    /// use conversion::converter::encoding::word::NativeEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = u64::MAX.to_ne_bytes().into_iter();
+    /// let ints = ConvertedIterator::new(iter, U64Decoder::<NativeEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![u64::MAX]), ints.collect());
+    /// ```
+    U64Decoder,
+    /// Encoding each `u64` into 8 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::U64Encoder;
+    /// use conversion::converter::encoding::word::LittleEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [1].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, U64Encoder::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\x01\x00\x00\x00\x00\x00\x00\x00".to_vec()), bytes.collect());
+    /// ```
+    U64Encoder,
+    u64
+);
+
+int_converter!(
+    /// Decoding each 2 bytes as an `i16`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I16Decoder;
+    /// use conversion::converter::encoding::word::BigEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\xFF\xFE\x80\x00".iter().cloned();
+    /// let ints = ConvertedIterator::new(iter, I16Decoder::<BigEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![-2, i16::MIN]), ints.collect());
+    /// ```
+    I16Decoder,
+    /// Encoding each `i16` into 2 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I16Encoder;
+    /// use conversion::converter::encoding::word::LittleEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [-2, i16::MIN].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, I16Encoder::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\xFE\xFF\x00\x80".to_vec()), bytes.collect());
+    /// ```
+    I16Encoder,
+    i16
+);
+
+int_converter!(
+    /// Decoding each 4 bytes as an `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I32Decoder;
+    /// use conversion::converter::encoding::word::LittleEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\xFF\xFF\xFF\xFF".iter().cloned();
+    /// let ints = ConvertedIterator::new(iter, I32Decoder::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![-1]), ints.collect());
+    /// ```
+    I32Decoder,
+    /// Encoding each `i32` into 4 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I32Encoder;
+    /// use conversion::converter::encoding::word::BigEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [-1].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, I32Encoder::<BigEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\xFF\xFF\xFF\xFF".to_vec()), bytes.collect());
+    /// ```
+    I32Encoder,
+    i32
+);
+
+int_converter!(
+    /// Decoding each 8 bytes as an `i64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I64Decoder;
+    /// use conversion::converter::encoding::word::LittleEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = i64::MIN.to_le_bytes().into_iter();
+    /// let ints = ConvertedIterator::new(iter, I64Decoder::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![i64::MIN]), ints.collect());
+    /// ```
+    I64Decoder,
+    /// Encoding each `i64` into 8 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::int::I64Encoder;
+    /// use conversion::converter::encoding::word::NativeEndian;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [i64::MIN].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, I64Encoder::<NativeEndian>::new());
+    ///
+    /// assert_eq!(Ok(i64::MIN.to_ne_bytes().to_vec()), bytes.collect());
+    /// ```
+    I64Encoder,
+    i64
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use super::super::word::{BigEndian, LittleEndian};
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        macro_rules! check {
+            ($decoder:ident, $encoder:ident, $int:ty) => {
+                let values = [0, 1, <$int>::MAX, <$int>::MIN, <$int>::MAX / 3];
+                let big = values.iter().flat_map(|n| n.to_be_bytes());
+                let encoded = ConvertedIterator::new(values, $encoder::<BigEndian>::new())
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(big.clone().collect()), encoded);
+                let decoded = ConvertedIterator::new(big, $decoder::<BigEndian>::new())
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(values.to_vec()), decoded);
+                let little = values.iter().flat_map(|n| n.to_le_bytes());
+                let encoded = ConvertedIterator::new(values, $encoder::<LittleEndian>::new())
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(little.clone().collect()), encoded);
+                let decoded = ConvertedIterator::new(little, $decoder::<LittleEndian>::new())
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(values.to_vec()), decoded);
+
+                let len = core::mem::size_of::<$int>();
+                for count in 1..len {
+                    let mut decoder = $decoder::<BigEndian>::new();
+                    for byte in 0..count as u8 {
+                        assert_eq!(Ok(0), decoder.convert(byte, &mut Vec::new()));
+                    }
+                    let missing = decoder.finalize().map_err(|e| e.missing());
+                    assert_eq!(Err(len - count), missing);
+                    assert_eq!(Ok(()), decoder.finalize());
+                }
+            };
+        }
+
+        check!(U16Decoder, U16Encoder, u16);
+        check!(U32Decoder, U32Encoder, u32);
+        check!(U64Decoder, U64Encoder, u64);
+        check!(I16Decoder, I16Encoder, i16);
+        check!(I32Decoder, I32Encoder, i32);
+        check!(I64Decoder, I64Encoder, i64);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn interleave() {
+        use super::super::word::{BigEndian, LittleEndian};
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let bytes = [
+            &0xCAFEu16.to_be_bytes()[..],
+            &(-5i32).to_le_bytes(),
+            &7u64.to_be_bytes(),
+        ]
+        .concat();
+        let mut iter = bytes.into_iter();
+        let mut magic = ConvertedIterator::new(&mut iter, U16Decoder::<BigEndian>::new());
+        assert_eq!(Some(Ok(0xCAFE)), magic.next());
+        let mut offset = ConvertedIterator::new(&mut iter, I32Decoder::<LittleEndian>::new());
+        assert_eq!(Some(Ok(-5)), offset.next());
+        let rest = ConvertedIterator::new(&mut iter, U64Decoder::<BigEndian>::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(alloc::vec![7]), rest);
+    }
+}
@@ -0,0 +1,570 @@
+//! Intel HEX Encoder/Decoder, a text format of firmware images.
+//!
+//! Each record is a line like `:10010000214601360121470136007EFE09D2190140`, which is a colon
+//! followed by hexadecimal bytes: the byte count, the 16-bit address, the record type, the data
+//! and the checksum. The last record must be the end-of-file record `:00000001FF`.
+//!
+//! Addresses above 64 KiB are given by extended segment address (type `02`) or extended linear
+//! address (type `04`) records, which are applied to addresses of following data records.
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The default number of data bytes in a record.
+pub const DEFAULT_RECORD_LEN: u8 = 16;
+
+/// An error while decoding Intel HEX, with the (1-based) line number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntelHexError {
+    /// A character which is neither a hexadecimal digit in a record, nor a whitespace outside
+    /// records.
+    InvalidChar(char, usize),
+    /// A record with an odd number of digits, a wrong byte count, or a wrong length for its
+    /// type.
+    Malformed(usize),
+    /// A record with a wrong checksum, with the line number, the checksum in the record, and the
+    /// computed one.
+    Checksum(usize, u8, u8),
+    /// A record of an unknown type.
+    UnknownType(u8, usize),
+    /// A record after the end-of-file record.
+    AfterEndOfFile(usize),
+    /// The input ended without the end-of-file record.
+    MissingEndOfFile,
+}
+
+impl fmt::Display for IntelHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(c, line) => write!(f, "invalid character at line {}: {:?}", line, c),
+            Self::Malformed(line) => write!(f, "malformed record at line {}.", line),
+            Self::Checksum(line, expected, actual) => write!(
+                f,
+                "checksum mismatch at line {}: expected 0x{:02X}, actual 0x{:02X}.",
+                line, expected, actual
+            ),
+            Self::UnknownType(ty, line) => {
+                write!(f, "unknown record type at line {}: 0x{:02X}", line, ty)
+            }
+            Self::AfterEndOfFile(line) => {
+                write!(f, "a record after the end-of-file record at line {}.", line)
+            }
+            Self::MissingEndOfFile => write!(f, "the input ended without the end-of-file record."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntelHexError {}
+
+/// Types of Intel HEX records, with values of address records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HexRecordKind {
+    /// Data. (type `00`)
+    Data,
+    /// The end of file. (type `01`)
+    EndOfFile,
+    /// The segment base address, multiplied by 16 for following addresses. (type `02`)
+    ExtendedSegmentAddress(u16),
+    /// The start address as `CS:IP`. (type `03`)
+    StartSegmentAddress(u16, u16),
+    /// The upper 16 bits of following addresses. (type `04`)
+    ExtendedLinearAddress(u16),
+    /// The 32-bit start address. (type `05`)
+    StartLinearAddress(u32),
+}
+
+/// A decoded Intel HEX record.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HexRecord {
+    /// The address of the data, with the extended address applied for data records.
+    pub address: u32,
+    /// The type of the record.
+    pub kind: HexRecordKind,
+    /// The data bytes of the record.
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum State {
+    // outside records.
+    Idle,
+    // digits of a record, and the line of it.
+    Record(Vec<u8>, usize),
+    // skipping the rest of an invalid line.
+    Skipping,
+}
+
+/// A decoder for Intel HEX, emits records.
+///
+/// Blank lines and whitespaces around records are allowed. After an error, the rest of the line
+/// is skipped.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::intel_hex::{HexRecord, HexRecordKind, IntelHexDecoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let hex = ":020000040800F2\n:0400100001020304E2\n:00000001FF\n";
+/// let mut decoded = ConvertedIterator::new(hex.chars(), IntelHexDecoder::new());
+/// assert_eq!(
+///     Some(Ok(HexRecord {
+///         address: 0,
+///         kind: HexRecordKind::ExtendedLinearAddress(0x0800),
+///         data: vec![0x08, 0x00],
+///     })),
+///     decoded.next(),
+/// );
+/// assert_eq!(
+///     Some(Ok(HexRecord {
+///         address: 0x0800_0010,
+///         kind: HexRecordKind::Data,
+///         data: vec![1, 2, 3, 4],
+///     })),
+///     decoded.next(),
+/// );
+/// assert_eq!(Some(HexRecordKind::EndOfFile), decoded.next().map(|r| r.unwrap().kind));
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntelHexDecoder {
+    state: State,
+    // the current line number.
+    line: usize,
+    base: u32,
+    eof: bool,
+}
+
+impl Default for IntelHexDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            state: State::Idle,
+            line: 1,
+            base: 0,
+            eof: false,
+        }
+    }
+}
+
+impl IntelHexDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // decodes digits of a record.
+    fn record(&mut self, digits: &[u8], line: usize) -> Result<HexRecord, IntelHexError> {
+        if !digits.len().is_multiple_of(2) {
+            return Err(IntelHexError::Malformed(line));
+        }
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect::<Vec<_>>();
+        if bytes.len() < 5 || bytes.len() != bytes[0] as usize + 5 {
+            return Err(IntelHexError::Malformed(line));
+        }
+        let (&checksum, rest) = bytes.split_last().unwrap_or((&0, &[]));
+        let actual = rest
+            .iter()
+            .fold(0u8, |acc, &b| acc.wrapping_add(b))
+            .wrapping_neg();
+        if checksum != actual {
+            return Err(IntelHexError::Checksum(line, checksum, actual));
+        }
+
+        let offset = u16::from_be_bytes([rest[1], rest[2]]);
+        let data = rest[4..].to_vec();
+        let word = |i: usize| u16::from_be_bytes([data[i], data[i + 1]]);
+        let (kind, address) = match (rest[3], data.len()) {
+            (0x00, _) => (HexRecordKind::Data, self.base.wrapping_add(offset as u32)),
+            (0x01, 0) => (HexRecordKind::EndOfFile, offset as u32),
+            (0x02, 2) => {
+                self.base = (word(0) as u32) << 4;
+                (
+                    HexRecordKind::ExtendedSegmentAddress(word(0)),
+                    offset as u32,
+                )
+            }
+            (0x03, 4) => (
+                HexRecordKind::StartSegmentAddress(word(0), word(2)),
+                offset as u32,
+            ),
+            (0x04, 2) => {
+                self.base = (word(0) as u32) << 16;
+                (HexRecordKind::ExtendedLinearAddress(word(0)), offset as u32)
+            }
+            (0x05, 4) => (
+                HexRecordKind::StartLinearAddress((word(0) as u32) << 16 | word(2) as u32),
+                offset as u32,
+            ),
+            (0x01..=0x05, _) => return Err(IntelHexError::Malformed(line)),
+            (ty, _) => return Err(IntelHexError::UnknownType(ty, line)),
+        };
+        self.eof = kind == HexRecordKind::EndOfFile;
+        Ok(HexRecord {
+            address,
+            kind,
+            data,
+        })
+    }
+
+    // finishes the current record at the end of a line.
+    fn end_line<E>(&mut self, buf: &mut E) -> Result<usize, IntelHexError>
+    where
+        E: Extend<HexRecord>,
+    {
+        match core::mem::replace(&mut self.state, State::Idle) {
+            State::Record(digits, line) => {
+                buf.extend([self.record(&digits, line)?]);
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+impl Converter for IntelHexDecoder {
+    type Item = char;
+    type Output = HexRecord;
+    type Error = IntelHexError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item == '\n' {
+            let result = self.end_line(buf);
+            self.line += 1;
+            return result;
+        }
+        match &mut self.state {
+            State::Skipping => Ok(0),
+            State::Idle if item.is_whitespace() => Ok(0),
+            State::Idle if item == ':' && self.eof => {
+                self.state = State::Skipping;
+                Err(IntelHexError::AfterEndOfFile(self.line))
+            }
+            State::Idle if item == ':' => {
+                self.state = State::Record(Vec::new(), self.line);
+                Ok(0)
+            }
+            State::Record(digits, _) => match item.to_digit(16) {
+                Some(digit) => {
+                    digits.push(digit as u8);
+                    Ok(0)
+                }
+                // trailing whitespaces like `\r`.
+                None if item.is_whitespace() => self.end_line(buf),
+                None => {
+                    self.state = State::Skipping;
+                    Err(IntelHexError::InvalidChar(item, self.line))
+                }
+            },
+            State::Idle => {
+                self.state = State::Skipping;
+                Err(IntelHexError::InvalidChar(item, self.line))
+            }
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let result = self.end_line(buf);
+        let eof = self.eof;
+        *self = Self::default();
+        match result {
+            Ok(_) if !eof => Err(IntelHexError::MissingEndOfFile),
+            result => result,
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// An encoder for Intel HEX, converts pairs of an address and data into records.
+///
+/// Data are split into records of at most [`record_len`] bytes, and at 64 KiB boundaries.
+/// Extended linear address records are emitted when the upper 16 bits of addresses change, and
+/// the end-of-file record is emitted when the input ends. Each record is terminated by `\n`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::intel_hex::IntelHexEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let data = vec![(0x0800_0010, vec![1, 2, 3, 4])];
+/// let encoded = ConvertedIterator::new(data, IntelHexEncoder::new());
+/// assert_eq!(
+///     Ok(String::from(":020000040800F2\n:0400100001020304E2\n:00000001FF\n")),
+///     encoded.collect(),
+/// );
+///
+/// let data = vec![(0xFFFE, vec![0xAA, 0xBB, 0xCC])];
+/// let encoded = ConvertedIterator::new(data, IntelHexEncoder::new().record_len(2));
+/// assert_eq!(
+///     Ok(String::from(":02FFFE00AABB9C\n:020000040001F9\n:01000000CC33\n:00000001FF\n")),
+///     encoded.collect(),
+/// );
+/// ```
+///
+/// [`record_len`]: Self::record_len
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntelHexEncoder {
+    record_len: u8,
+    // the upper 16 bits of the current address.
+    upper: u16,
+}
+
+impl Default for IntelHexEncoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            record_len: DEFAULT_RECORD_LEN,
+            upper: 0,
+        }
+    }
+}
+
+impl IntelHexEncoder {
+    /// Create a new instance, emits records of [`DEFAULT_RECORD_LEN`] bytes.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the maximum number of data bytes in a record.
+    ///
+    /// # Panics
+    /// Panics if `record_len` is zero.
+    #[inline]
+    pub fn record_len(self, record_len: u8) -> Self {
+        assert!(record_len > 0, "the record length must not be zero.");
+        Self { record_len, ..self }
+    }
+
+    // emits a record, returns the number of characters.
+    fn record<E>(buf: &mut E, address: u16, ty: u8, data: &[u8]) -> usize
+    where
+        E: Extend<char>,
+    {
+        let [high, low] = address.to_be_bytes();
+        let header = [data.len() as u8, high, low, ty];
+        let checksum = header
+            .iter()
+            .chain(data)
+            .fold(0u8, |acc, &b| acc.wrapping_add(b))
+            .wrapping_neg();
+        let bytes = header.iter().chain(data).chain([&checksum]);
+        buf.extend(
+            core::iter::once(':')
+                .chain(bytes.flat_map(|&b| {
+                    [
+                        HEX_DIGITS[(b >> 4) as usize],
+                        HEX_DIGITS[(b & 0xF) as usize],
+                    ]
+                    .map(char::from)
+                }))
+                .chain(['\n']),
+        );
+        (header.len() + data.len() + 1) * 2 + 2
+    }
+}
+
+impl Converter for IntelHexEncoder {
+    type Item = (u32, Vec<u8>);
+    type Output = char;
+    type Error = core::convert::Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (mut address, data) = item;
+        let mut data = &data[..];
+        let mut len = 0;
+        while !data.is_empty() {
+            let upper = (address >> 16) as u16;
+            if upper != self.upper {
+                self.upper = upper;
+                len += Self::record(buf, 0, 0x04, &upper.to_be_bytes());
+            }
+            let lower = address as u16;
+            // not to cross a 64 KiB boundary.
+            let max = (0x1_0000 - lower as usize).min(self.record_len as usize);
+            let (chunk, rest) = data.split_at(max.min(data.len()));
+            len += Self::record(buf, lower, 0x00, chunk);
+            address = address.wrapping_add(chunk.len() as u32);
+            data = rest;
+        }
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.upper = 0;
+        Ok(Self::record(buf, 0, 0x01, &[]))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(12)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::string::String;
+    use alloc::vec;
+
+    fn decode(hex: &str) -> Vec<Result<HexRecord, IntelHexError>> {
+        ConvertedIterator::new(hex.chars(), IntelHexDecoder::new()).collect()
+    }
+
+    #[test]
+    fn vectors() {
+        let hex = "\
+:10010000214601360121470136007EFE09D2190140\r
+:100110002146017E17C20001FF5F16002148011928\r
+:10012000194E79234623965778239EDA3F01B2CAA7\r
+:100130003F0156702B5E712B722B732146013421C7\r
+:00000001FF\r
+";
+        let records = decode(hex)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(5, records.len());
+        assert_eq!(
+            HexRecord {
+                address: 0x0100,
+                kind: HexRecordKind::Data,
+                data: vec![
+                    0x21, 0x46, 0x01, 0x36, 0x01, 0x21, 0x47, 0x01, 0x36, 0x00, 0x7E, 0xFE, 0x09,
+                    0xD2, 0x19, 0x01
+                ],
+            },
+            records[0]
+        );
+        assert_eq!(
+            vec![0x0100, 0x0110, 0x0120, 0x0130],
+            records[..4].iter().map(|r| r.address).collect::<Vec<_>>()
+        );
+        assert_eq!(HexRecordKind::EndOfFile, records[4].kind);
+
+        // re-encoding the data.
+        let data = records[..4]
+            .iter()
+            .map(|r| (r.address, r.data.clone()))
+            .collect::<Vec<_>>();
+        let encoded =
+            ConvertedIterator::new(data, IntelHexEncoder::new()).collect::<Result<String, _>>();
+        assert_eq!(Ok(hex.replace('\r', "")), encoded);
+    }
+
+    #[test]
+    fn extended_addresses() {
+        let hex = "\
+:020000021200EA
+:0400100001020304E2
+
+:0400000300003800C1
+:020000040800F2
+:0400100001020304E2
+:0400000508000131BD
+:00000001FF";
+        let records = decode(hex)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let summary = records
+            .iter()
+            .map(|r| (r.address, r.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (0, HexRecordKind::ExtendedSegmentAddress(0x1200)),
+                (0x12010, HexRecordKind::Data),
+                (0, HexRecordKind::StartSegmentAddress(0, 0x3800)),
+                (0, HexRecordKind::ExtendedLinearAddress(0x0800)),
+                (0x0800_0010, HexRecordKind::Data),
+                (0, HexRecordKind::StartLinearAddress(0x0800_0131)),
+                (0, HexRecordKind::EndOfFile),
+            ],
+            summary
+        );
+
+        // data across 64 KiB boundaries.
+        let data = (0..0x30).collect::<Vec<u8>>();
+        let encoded = ConvertedIterator::new([(0x0001_FFF0, data.clone())], IntelHexEncoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(6, encoded.lines().count());
+        let decoded = decode(&encoded)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let mut image = Vec::new();
+        for record in decoded.iter().filter(|r| r.kind == HexRecordKind::Data) {
+            assert_eq!(0x0001_FFF0 + image.len() as u32, record.address);
+            image.extend_from_slice(&record.data);
+        }
+        assert_eq!(data, image);
+    }
+
+    #[test]
+    fn errors() {
+        let hex = "\
+:10010000214601360121470136007EFE09D2190140
+:100110002146017E17C20001FF5F16002148011929
+:0201000021
+:0100000221DC
+:10012000194E79234623965778239EDA3F01B2CAZ7
+:0000000AF6
+# comment
+:00000001FF
+:00000001FF
+";
+        let errors = decode(hex)
+            .into_iter()
+            .filter_map(Result::err)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                IntelHexError::Checksum(2, 0x29, 0x28),
+                IntelHexError::Malformed(3),
+                IntelHexError::Malformed(4),
+                IntelHexError::InvalidChar('Z', 5),
+                IntelHexError::UnknownType(0x0A, 6),
+                IntelHexError::InvalidChar('#', 7),
+                IntelHexError::AfterEndOfFile(9),
+            ],
+            errors
+        );
+
+        assert_eq!(
+            Some(&Err(IntelHexError::MissingEndOfFile)),
+            decode(":0400100001020304E2\n").last()
+        );
+        assert_eq!(vec![Err(IntelHexError::MissingEndOfFile)], decode(""));
+    }
+}
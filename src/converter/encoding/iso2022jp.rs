@@ -0,0 +1,296 @@
+//! ISO-2022-JP Decoder, described in [RFC 1468].
+//!
+//! ISO-2022-JP switches character sets by escape sequences: `ESC ( B` for ASCII, `ESC ( J` for
+//! JIS X 0201 Roman, `ESC ( I` for JIS X 0201 Katakana, and `ESC $ @` or `ESC $ B` for JIS X 0208.
+//! Each text starts with ASCII.
+//!
+//! [RFC 1468]: https://www.rfc-editor.org/rfc/rfc1468
+
+mod jis0208;
+
+use crate::Converter;
+use core::fmt;
+
+const ESC: u8 = 0x1B;
+
+/// Character sets of ISO-2022-JP.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Charset {
+    /// ASCII, selected by `ESC ( B`.
+    ASCII,
+    /// JIS X 0201 Roman, selected by `ESC ( J`. It is ASCII except for `¥` and `‾`.
+    Roman,
+    /// JIS X 0201 Katakana (half-width katakana), selected by `ESC ( I`.
+    Katakana,
+    /// JIS X 0208, selected by `ESC $ @` or `ESC $ B`.
+    JISX0208,
+}
+
+/// An error while decoding ISO-2022-JP.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ISO2022JPDecodeError {
+    /// An unknown or malformed escape sequence.
+    InvalidEscape,
+    /// A byte not allowed in the current character set.
+    InvalidByte(u8),
+    /// A double-byte code unassigned in JIS X 0208.
+    Unassigned(u16),
+    /// The input ended inside an escape sequence or a double-byte character.
+    Truncated,
+}
+
+impl fmt::Display for ISO2022JPDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEscape => write!(f, "invalid escape sequence."),
+            Self::InvalidByte(b) => write!(f, "invalid byte in ISO-2022-JP: {:#04x}", b),
+            Self::Unassigned(code) => write!(f, "unassigned JIS X 0208 code: {:#06x}", code),
+            Self::Truncated => write!(f, "the input ended inside a sequence."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ISO2022JPDecodeError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `ESC`, `ESC (` and `ESC $`.
+    Escape,
+    EscapeParen,
+    EscapeDollar,
+    // after the first byte of JIS X 0208.
+    Lead(u8),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// A decoder for ISO-2022-JP.
+///
+/// `\r` and `\n` are passed through in any character set, though RFC 1468 requires switching back
+/// to ASCII before line endings. Invalid escape sequences and double-byte characters are
+/// discarded.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::iso2022jp::ISO2022JPDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\x1b$B$3$s$K$A$O!\"@$3&\x1b(B!".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, ISO2022JPDecoder::new());
+/// assert_eq!(Ok(String::from("こんにちは、世界!")), decoded.collect());
+///
+/// let iter = b"\x1b(J\\100 \x1b(I12".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, ISO2022JPDecoder::new());
+/// assert_eq!(Ok(String::from("¥100 ｱｲ")), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ISO2022JPDecoder {
+    current_charset: Charset,
+    state: State,
+}
+
+impl Default for ISO2022JPDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            current_charset: Charset::ASCII,
+            state: State::Normal,
+        }
+    }
+}
+
+impl ISO2022JPDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current character set.
+    #[inline]
+    pub fn current_charset(&self) -> Charset {
+        self.current_charset
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, ISO2022JPDecodeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn error(&mut self, err: ISO2022JPDecodeError) -> Result<usize, ISO2022JPDecodeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+
+    fn switch(&mut self, charset: Option<Charset>) -> Result<usize, ISO2022JPDecodeError> {
+        match charset {
+            Some(charset) => {
+                self.current_charset = charset;
+                self.state = State::Normal;
+                Ok(0)
+            }
+            None => self.error(ISO2022JPDecodeError::InvalidEscape),
+        }
+    }
+}
+
+impl Converter for ISO2022JPDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = ISO2022JPDecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match (self.state, self.current_charset, item) {
+            (State::Normal, _, ESC) => {
+                self.state = State::Escape;
+                Ok(0)
+            }
+            (State::Normal, _, b'\r' | b'\n') => self.emit(item as char, buf),
+            (State::Normal, Charset::Roman, b'\\') => self.emit('\u{A5}', buf),
+            (State::Normal, Charset::Roman, b'~') => self.emit('\u{203E}', buf),
+            (State::Normal, Charset::ASCII | Charset::Roman, 0x00..=0x7F) => {
+                self.emit(item as char, buf)
+            }
+            (State::Normal, Charset::Katakana, 0x21..=0x5F) => {
+                let c = char::from_u32(0xFF61 + (item - 0x21) as u32).unwrap();
+                self.emit(c, buf)
+            }
+            (State::Normal, Charset::JISX0208, 0x21..=0x7E) => {
+                self.state = State::Lead(item);
+                Ok(0)
+            }
+            (State::Normal, _, _) => self.error(ISO2022JPDecodeError::InvalidByte(item)),
+            (State::Escape, _, b'(') => {
+                self.state = State::EscapeParen;
+                Ok(0)
+            }
+            (State::Escape, _, b'$') => {
+                self.state = State::EscapeDollar;
+                Ok(0)
+            }
+            (State::Escape, _, _) => self.error(ISO2022JPDecodeError::InvalidEscape),
+            (State::EscapeParen, _, _) => self.switch(match item {
+                b'B' => Some(Charset::ASCII),
+                b'J' => Some(Charset::Roman),
+                b'I' => Some(Charset::Katakana),
+                _ => None,
+            }),
+            (State::EscapeDollar, _, _) => self.switch(match item {
+                b'@' | b'B' => Some(Charset::JISX0208),
+                _ => None,
+            }),
+            (State::Lead(lead), _, 0x21..=0x7E) => {
+                let index = (lead - 0x21) as usize * 94 + (item - 0x21) as usize;
+                match jis0208::JIS0208[index] {
+                    0 => self.error(ISO2022JPDecodeError::Unassigned(
+                        (lead as u16) << 8 | item as u16,
+                    )),
+                    code => self.emit(char::from_u32(code as u32).unwrap(), buf),
+                }
+            }
+            (State::Lead(_), _, _) => self.error(ISO2022JPDecodeError::InvalidByte(item)),
+        }
+    }
+
+    fn finish<E>(&mut self, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal => Ok(0),
+            _ => self.error(ISO2022JPDecodeError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), ISO2022JPDecoder::new())
+                .collect::<Result<String, _>>()
+        };
+        // an output of Python's `iso2022_jp` codec.
+        assert_eq!(
+            Ok(String::from("こんにちは、世界!\n日本語")),
+            decode(b"\x1b$B$3$s$K$A$O!\"@$3&\x1b(B!\n\x1b$BF|K\\8l\x1b(B")
+        );
+        // `ESC $ @` is the older designation of JIS X 0208.
+        assert_eq!(Ok(String::from("漢字")), decode(b"\x1b$@4A;z"));
+        assert_eq!(Ok(String::from("~\\‾¥")), decode(b"~\\\x1b(J~\\\x1b(B"));
+        assert_eq!(Ok(String::from("｡ﾟ")), decode(b"\x1b(I!_"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), ISO2022JPDecoder::new())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::InvalidEscape), Ok('a')],
+            decode(b"\x1b(Xa")
+        );
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::InvalidEscape), Ok('B')],
+            decode(b"\x1b$(B")
+        );
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::InvalidByte(0xE3))],
+            decode(b"\xe3")
+        );
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::InvalidByte(b'a'))],
+            decode(b"\x1b(Ia")
+        );
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::Unassigned(0x2F21)), Ok('漢')],
+            decode(b"\x1b$B/!4A")
+        );
+        assert_eq!(
+            vec![Err(ISO2022JPDecodeError::InvalidByte(b'\n')), Ok('漢')],
+            decode(b"\x1b$B4\n4A")
+        );
+        assert_eq!(vec![Err(ISO2022JPDecodeError::Truncated)], decode(b"\x1b$"));
+        assert_eq!(
+            vec![Ok('漢'), Err(ISO2022JPDecodeError::Truncated)],
+            decode(b"\x1b$B4A;")
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Decoders/Encoders for the ISO-8859 family.
+//!
+//! Bytes from `0x00` to `0x9F` are the same as ISO-8859-1 (ASCII and C1 control characters) in all
+//! parts, and bytes from `0xA0` to `0xFF` are mapped with tables of each part. Some parts have
+//! undefined bytes, which are decoding errors.
+//!
+//! ISO-8859-1 and ISO-8859-15 are available in [`windows1252`] and [`latin9`].
+//!
+//! [`windows1252`]: super::windows1252
+//! [`latin9`]: super::latin9
+
+use crate::{Converter, ExactSizeConverter};
+use core::fmt;
+
+/// An error while decoding undefined bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ISO8859DecodeError;
+
+impl fmt::Display for ISO8859DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "An undefined byte.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ISO8859DecodeError {}
+
+/// An error while encoding characters out of the part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ISO8859EncodingError;
+
+impl fmt::Display for ISO8859EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A character out of bound.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ISO8859EncodingError {}
+
+/// Parts of ISO-8859.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ISO8859Part {
+    /// ISO-8859-2 (Latin-2, Central European)
+    Latin2,
+    /// ISO-8859-3 (Latin-3, South European)
+    Latin3,
+    /// ISO-8859-4 (Latin-4, North European)
+    Latin4,
+    /// ISO-8859-5 (Latin/Cyrillic)
+    Cyrillic,
+    /// ISO-8859-7 (Latin/Greek)
+    Greek,
+    /// ISO-8859-8 (Latin/Hebrew)
+    Hebrew,
+}
+
+impl ISO8859Part {
+    // characters of bytes from `0xA0` to `0xFF`, `0` for undefined bytes.
+    fn table(self) -> &'static [u16; 96] {
+        match self {
+            Self::Latin2 => &ISO8859_2,
+            Self::Latin3 => &ISO8859_3,
+            Self::Latin4 => &ISO8859_4,
+            Self::Cyrillic => &ISO8859_5,
+            Self::Greek => &ISO8859_7,
+            Self::Hebrew => &ISO8859_8,
+        }
+    }
+}
+
+const ISO8859_2: [u16; 96] = [
+    0x00A0, 0x0104, 0x02D8, 0x0141, 0x00A4, 0x013D, 0x015A, 0x00A7, 0x00A8, 0x0160, 0x015E, 0x0164,
+    0x0179, 0x00AD, 0x017D, 0x017B, 0x00B0, 0x0105, 0x02DB, 0x0142, 0x00B4, 0x013E, 0x015B, 0x02C7,
+    0x00B8, 0x0161, 0x015F, 0x0165, 0x017A, 0x02DD, 0x017E, 0x017C, 0x0154, 0x00C1, 0x00C2, 0x0102,
+    0x00C4, 0x0139, 0x0106, 0x00C7, 0x010C, 0x00C9, 0x0118, 0x00CB, 0x011A, 0x00CD, 0x00CE, 0x010E,
+    0x0110, 0x0143, 0x0147, 0x00D3, 0x00D4, 0x0150, 0x00D6, 0x00D7, 0x0158, 0x016E, 0x00DA, 0x0170,
+    0x00DC, 0x00DD, 0x0162, 0x00DF, 0x0155, 0x00E1, 0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7,
+    0x010D, 0x00E9, 0x0119, 0x00EB, 0x011B, 0x00ED, 0x00EE, 0x010F, 0x0111, 0x0144, 0x0148, 0x00F3,
+    0x00F4, 0x0151, 0x00F6, 0x00F7, 0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+];
+
+const ISO8859_3: [u16; 96] = [
+    0x00A0, 0x0126, 0x02D8, 0x00A3, 0x00A4, 0x0000, 0x0124, 0x00A7, 0x00A8, 0x0130, 0x015E, 0x011E,
+    0x0134, 0x00AD, 0x0000, 0x017B, 0x00B0, 0x0127, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x0125, 0x00B7,
+    0x00B8, 0x0131, 0x015F, 0x011F, 0x0135, 0x00BD, 0x0000, 0x017C, 0x00C0, 0x00C1, 0x00C2, 0x0000,
+    0x00C4, 0x010A, 0x0108, 0x00C7, 0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF,
+    0x0000, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x0120, 0x00D6, 0x00D7, 0x011C, 0x00D9, 0x00DA, 0x00DB,
+    0x00DC, 0x016C, 0x015C, 0x00DF, 0x00E0, 0x00E1, 0x00E2, 0x0000, 0x00E4, 0x010B, 0x0109, 0x00E7,
+    0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF, 0x0000, 0x00F1, 0x00F2, 0x00F3,
+    0x00F4, 0x0121, 0x00F6, 0x00F7, 0x011D, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x016D, 0x015D, 0x02D9,
+];
+
+const ISO8859_4: [u16; 96] = [
+    0x00A0, 0x0104, 0x0138, 0x0156, 0x00A4, 0x0128, 0x013B, 0x00A7, 0x00A8, 0x0160, 0x0112, 0x0122,
+    0x0166, 0x00AD, 0x017D, 0x00AF, 0x00B0, 0x0105, 0x02DB, 0x0157, 0x00B4, 0x0129, 0x013C, 0x02C7,
+    0x00B8, 0x0161, 0x0113, 0x0123, 0x0167, 0x014A, 0x017E, 0x014B, 0x0100, 0x00C1, 0x00C2, 0x00C3,
+    0x00C4, 0x00C5, 0x00C6, 0x012E, 0x010C, 0x00C9, 0x0118, 0x00CB, 0x0116, 0x00CD, 0x00CE, 0x012A,
+    0x0110, 0x0145, 0x014C, 0x0136, 0x00D4, 0x00D5, 0x00D6, 0x00D7, 0x00D8, 0x0172, 0x00DA, 0x00DB,
+    0x00DC, 0x0168, 0x016A, 0x00DF, 0x0101, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x012F,
+    0x010D, 0x00E9, 0x0119, 0x00EB, 0x0117, 0x00ED, 0x00EE, 0x012B, 0x0111, 0x0146, 0x014D, 0x0137,
+    0x00F4, 0x00F5, 0x00F6, 0x00F7, 0x00F8, 0x0173, 0x00FA, 0x00FB, 0x00FC, 0x0169, 0x016B, 0x02D9,
+];
+
+const ISO8859_5: [u16; 96] = [
+    0x00A0, 0x0401, 0x0402, 0x0403, 0x0404, 0x0405, 0x0406, 0x0407, 0x0408, 0x0409, 0x040A, 0x040B,
+    0x040C, 0x00AD, 0x040E, 0x040F, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417,
+    0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421, 0x0422, 0x0423,
+    0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E, 0x042F,
+    0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B,
+    0x043C, 0x043D, 0x043E, 0x043F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447,
+    0x0448, 0x0449, 0x044A, 0x044B, 0x044C, 0x044D, 0x044E, 0x044F, 0x2116, 0x0451, 0x0452, 0x0453,
+    0x0454, 0x0455, 0x0456, 0x0457, 0x0458, 0x0459, 0x045A, 0x045B, 0x045C, 0x00A7, 0x045E, 0x045F,
+];
+
+const ISO8859_7: [u16; 96] = [
+    0x00A0, 0x2018, 0x2019, 0x00A3, 0x20AC, 0x20AF, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x037A, 0x00AB,
+    0x00AC, 0x00AD, 0x0000, 0x2015, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x0385, 0x0386, 0x00B7,
+    0x0388, 0x0389, 0x038A, 0x00BB, 0x038C, 0x00BD, 0x038E, 0x038F, 0x0390, 0x0391, 0x0392, 0x0393,
+    0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F,
+    0x03A0, 0x03A1, 0x0000, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x03AA, 0x03AB,
+    0x03AC, 0x03AD, 0x03AE, 0x03AF, 0x03B0, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7,
+    0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C2, 0x03C3,
+    0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0x0000,
+];
+
+const ISO8859_8: [u16; 96] = [
+    0x00A0, 0x0000, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00D7, 0x00AB,
+    0x00AC, 0x00AD, 0x00AE, 0x00AF, 0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00F7, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x2017, 0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7,
+    0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF, 0x05E0, 0x05E1, 0x05E2, 0x05E3,
+    0x05E4, 0x05E5, 0x05E6, 0x05E7, 0x05E8, 0x05E9, 0x05EA, 0x0000, 0x0000, 0x200E, 0x200F, 0x0000,
+];
+
+/// A decoder for the ISO-8859 family.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::iso8859::{ISO8859DecodeError, ISO8859Decoder, ISO8859Part};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\xe1\xeb\xf6\xe1".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, ISO8859Decoder::new(ISO8859Part::Greek));
+/// assert_eq!(Ok(String::from("αλφα")), decoded.collect());
+///
+/// let iter = b"\xa5".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, ISO8859Decoder::new(ISO8859Part::Latin3));
+/// assert_eq!(Some(Err(ISO8859DecodeError)), decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ISO8859Decoder {
+    part: ISO8859Part,
+}
+
+impl ISO8859Decoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(part: ISO8859Part) -> Self {
+        Self { part }
+    }
+}
+
+impl Converter for ISO8859Decoder {
+    type Item = u8;
+    type Output = char;
+    type Error = ISO8859DecodeError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let c = match item {
+            0x00..=0x9F => item as char,
+            _ => match self.part.table()[(item - 0xA0) as usize] {
+                0 => return Err(ISO8859DecodeError),
+                c => char::from_u32(c as u32).unwrap(),
+            },
+        };
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for ISO8859Decoder {}
+
+impl ExactSizeConverter for ISO8859Decoder {}
+
+/// An encoder for the ISO-8859 family.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::iso8859::{ISO8859Encoder, ISO8859EncodingError, ISO8859Part};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut encoded = ConvertedIterator::new("Жa€".chars(), ISO8859Encoder::new(ISO8859Part::Cyrillic));
+///
+/// assert_eq!(Some(Ok(0xB6)), encoded.next());
+/// assert_eq!(Some(Ok(b'a')), encoded.next());
+/// assert_eq!(Some(Err(ISO8859EncodingError)), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ISO8859Encoder {
+    part: ISO8859Part,
+}
+
+impl ISO8859Encoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(part: ISO8859Part) -> Self {
+        Self { part }
+    }
+}
+
+impl Converter for ISO8859Encoder {
+    type Item = char;
+    type Output = u8;
+    type Error = ISO8859EncodingError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let byte = match item as u32 {
+            c @ 0x00..=0x9F => c as u8,
+            c => match self.part.table().iter().position(|&t| t as u32 == c) {
+                Some(i) => 0xA0 + i as u8,
+                None => return Err(ISO8859EncodingError),
+            },
+        };
+        buf.extend([byte]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for ISO8859Encoder {}
+
+impl ExactSizeConverter for ISO8859Encoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let parts = [
+            (ISO8859Part::Latin2, 0),
+            (ISO8859Part::Latin3, 7),
+            (ISO8859Part::Latin4, 0),
+            (ISO8859Part::Cyrillic, 0),
+            (ISO8859Part::Greek, 3),
+            (ISO8859Part::Hebrew, 36),
+        ];
+        for (part, undefined) in parts {
+            let decoded =
+                ConvertedIterator::new(0..=255, ISO8859Decoder::new(part)).collect::<Vec<_>>();
+            assert_eq!(undefined, decoded.iter().filter(|c| c.is_err()).count());
+
+            let chars = decoded.into_iter().flatten();
+            let encoded = ConvertedIterator::new(chars, ISO8859Encoder::new(part))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let defined = (0..=255)
+                .filter(|&b| b < 0xA0 || part.table()[(b - 0xA0) as usize] != 0)
+                .collect::<Vec<u8>>();
+            assert_eq!(defined, encoded);
+        }
+    }
+}
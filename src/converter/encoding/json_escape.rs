@@ -0,0 +1,436 @@
+//! Escaping/Unescaping JSON strings.
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while unescaping JSON strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonEscapeError {
+    /// An unknown escape sequence, like `\a`.
+    UnknownEscape(char),
+    /// An invalid character inside `\uXXXX`.
+    InvalidDigit(char),
+    /// A surrogate code unit which is not a part of a surrogate pair.
+    UnpairedSurrogate(u16),
+    /// The input ended inside an escape sequence.
+    Unterminated,
+}
+
+impl fmt::Display for JsonEscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEscape(c) => write!(f, "unknown escape sequence: \\{}", c),
+            Self::InvalidDigit(c) => write!(f, "invalid character in escape sequence: {:?}", c),
+            Self::UnpairedSurrogate(u) => write!(f, "unpaired surrogate: \\u{:04x}", u),
+            Self::Unterminated => write!(f, "unterminated escape sequence."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsonEscapeError {}
+
+/// An escaper for JSON strings.
+///
+/// `"`, `\` and control characters (`U+0000` to `U+001F`) are escaped, using short escapes (`\n`,
+/// `\t`, `\r`, `\b` and `\f`) if possible and `\u00XX` otherwise, like `serde_json`. With
+/// [`escape_non_ascii`], non-ASCII characters are also escaped as `\uXXXX`, with surrogate pairs
+/// for characters outside the BMP, so the output is pure ASCII.
+///
+/// The output is the contents of a string, without surrounding quotes.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::json_escape::JsonStringEscapeConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "\"straße\"\n\\\u{1}".chars();
+/// let escaped = ConvertedIterator::new(iter, JsonStringEscapeConverter::new());
+/// assert_eq!(Ok(String::from(r#"\"straße\"\n\\\u0001"#)), escaped.collect());
+///
+/// let escaper = JsonStringEscapeConverter::new().escape_non_ascii(true);
+/// let escaped = ConvertedIterator::new("ß💣".chars(), escaper);
+/// assert_eq!(Ok(String::from(r#"\u00df\ud83d\udca3"#)), escaped.collect());
+/// ```
+///
+/// [`escape_non_ascii`]: Self::escape_non_ascii
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonStringEscapeConverter {
+    escape_non_ascii: bool,
+}
+
+impl JsonStringEscapeConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting whether non-ASCII characters are escaped.
+    #[inline]
+    pub fn escape_non_ascii(self, escape_non_ascii: bool) -> Self {
+        Self { escape_non_ascii }
+    }
+
+    // `\uXXXX` with lowercase digits.
+    fn unicode<E>(unit: u16, buf: &mut E)
+    where
+        E: Extend<char>,
+    {
+        let hex = |i: u16| char::from_digit((unit >> (i * 4) & 0xF) as u32, 16).unwrap();
+        buf.extend(['\\', 'u']);
+        buf.extend((0..4).rev().map(hex));
+    }
+}
+
+impl Converter for JsonStringEscapeConverter {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let short = match item {
+            '"' | '\\' => item,
+            '\n' => 'n',
+            '\t' => 't',
+            '\r' => 'r',
+            '\x08' => 'b',
+            '\x0c' => 'f',
+            '\0'..='\x1f' => {
+                Self::unicode(item as u16, buf);
+                return Ok(6);
+            }
+            _ if self.escape_non_ascii && !item.is_ascii() => {
+                let mut units = [0; 2];
+                let units = item.encode_utf16(&mut units);
+                units.iter().for_each(|&unit| Self::unicode(unit, buf));
+                return Ok(units.len() * 6);
+            }
+            _ => {
+                buf.extend([item]);
+                return Ok(1);
+            }
+        };
+        buf.extend(['\\', short]);
+        Ok(2)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.escape_non_ascii {
+            // a surrogate pair
+            (1, Some(12))
+        } else {
+            (1, Some(6))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `\`, with the preceding high surrogate.
+    Escape(Option<u16>),
+    // after `\u`, with the number of digits, the value and the preceding high surrogate.
+    Unicode(u8, u16, Option<u16>),
+    // after a high surrogate.
+    Surrogate(u16),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// An unescaper for JSON strings.
+///
+/// Supports `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t`, and `\uXXXX` (including surrogate
+/// pairs like `\ud83d\ude00`).
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::json_escape::{JsonEscapeError, JsonStringUnescapeConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = r#"a\/\u00df\ud83d\ude00\n\a"#.chars();
+/// let mut unescaped = ConvertedIterator::new(iter, JsonStringUnescapeConverter::new());
+///
+/// assert_eq!(Some(Ok('a')), unescaped.next());
+/// assert_eq!(Some(Ok('/')), unescaped.next());
+/// assert_eq!(Some(Ok('ß')), unescaped.next());
+/// assert_eq!(Some(Ok('😀')), unescaped.next());
+/// assert_eq!(Some(Ok('\n')), unescaped.next());
+/// assert_eq!(Some(Err(JsonEscapeError::UnknownEscape('a'))), unescaped.next());
+/// assert_eq!(None, unescaped.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonStringUnescapeConverter {
+    state: State,
+}
+
+impl JsonStringUnescapeConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, JsonEscapeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn error(&mut self, err: JsonEscapeError) -> Result<usize, JsonEscapeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+
+    fn unit<E>(
+        &mut self,
+        unit: u16,
+        high: Option<u16>,
+        buf: &mut E,
+    ) -> Result<usize, JsonEscapeError>
+    where
+        E: Extend<char>,
+    {
+        match (high, unit) {
+            (None, 0xD800..=0xDBFF) => {
+                self.state = State::Surrogate(unit);
+                Ok(0)
+            }
+            (None, 0xDC00..=0xDFFF) => self.error(JsonEscapeError::UnpairedSurrogate(unit)),
+            (None, _) => self.emit(char::from_u32(unit as u32).unwrap(), buf),
+            (Some(high), 0xDC00..=0xDFFF) => {
+                let code = 0x10000 + ((high as u32 - 0xD800) << 10 | (unit as u32 - 0xDC00));
+                self.emit(char::from_u32(code).unwrap(), buf)
+            }
+            (Some(high), _) => {
+                // the second unit is still valid by itself.
+                self.unit(unit, None, buf)?;
+                Err(JsonEscapeError::UnpairedSurrogate(high))
+            }
+        }
+    }
+}
+
+impl Converter for JsonStringUnescapeConverter {
+    type Item = char;
+    type Output = char;
+    type Error = JsonEscapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal if item == '\\' => {
+                self.state = State::Escape(None);
+                Ok(0)
+            }
+            State::Normal => self.emit(item, buf),
+            State::Escape(Some(high)) if item != 'u' => {
+                self.state = State::Escape(None);
+                self.convert(item, buf)?;
+                Err(JsonEscapeError::UnpairedSurrogate(high))
+            }
+            State::Escape(high) => match item {
+                '"' | '\\' | '/' => self.emit(item, buf),
+                'b' => self.emit('\x08', buf),
+                'f' => self.emit('\x0c', buf),
+                'n' => self.emit('\n', buf),
+                'r' => self.emit('\r', buf),
+                't' => self.emit('\t', buf),
+                'u' => {
+                    self.state = State::Unicode(0, 0, high);
+                    Ok(0)
+                }
+                c => self.error(JsonEscapeError::UnknownEscape(c)),
+            },
+            State::Unicode(digits, value, high) => match item.to_digit(16) {
+                Some(d) if digits < 3 => {
+                    self.state = State::Unicode(digits + 1, value << 4 | d as u16, high);
+                    Ok(0)
+                }
+                Some(d) => self.unit(value << 4 | d as u16, high, buf),
+                None => self.error(JsonEscapeError::InvalidDigit(item)),
+            },
+            State::Surrogate(high) if item == '\\' => {
+                self.state = State::Escape(Some(high));
+                Ok(0)
+            }
+            State::Surrogate(high) => {
+                self.state = State::Normal;
+                self.convert(item, buf)?;
+                Err(JsonEscapeError::UnpairedSurrogate(high))
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.state {
+            State::Normal => Ok(()),
+            State::Surrogate(high) => {
+                self.state = State::Normal;
+                Err(JsonEscapeError::UnpairedSurrogate(high))
+            }
+            _ => {
+                self.state = State::Normal;
+                Err(JsonEscapeError::Unterminated)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let text = "\0\x01\x08\x0c\x1f \"quoted\" \\ / \r\n\t\u{7f} straße 💣";
+        let escaped = ConvertedIterator::new(text.chars(), JsonStringEscapeConverter::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(
+            "\\u0000\\u0001\\b\\f\\u001f \\\"quoted\\\" \\\\ / \\r\\n\\t\u{7f} straße 💣",
+            escaped
+        );
+        for escape_non_ascii in [false, true] {
+            let escaper = JsonStringEscapeConverter::new().escape_non_ascii(escape_non_ascii);
+            let escaped = ConvertedIterator::new(text.chars(), escaper)
+                .collect::<Result<String, _>>()
+                .unwrap();
+            assert_eq!(escape_non_ascii, escaped.is_ascii());
+            let unescaped =
+                ConvertedIterator::new(escaped.chars(), JsonStringUnescapeConverter::new())
+                    .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(text)), unescaped);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn serde_json_compat() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let corpus = [
+            "",
+            "plain text",
+            "\"quoted\" \\ /",
+            "\n\t\r\x08\x0c",
+            "\0\x01\x0b\x0e\x1b\x1f",
+            "\u{7f} \u{80} \u{9f}",
+            "straße 💣 \u{2028} \u{ffff} \u{10ffff}",
+        ];
+        let all = (0..0x250).filter_map(char::from_u32).collect::<String>();
+        for text in corpus.iter().cloned().chain([all.as_str()]) {
+            // `serde_json` quotes the string.
+            let json = serde_json::to_string(text).unwrap();
+            let escaped = ConvertedIterator::new(text.chars(), JsonStringEscapeConverter::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(
+                Ok(&json[1..json.len() - 1]),
+                escaped.as_deref(),
+                "{:?}",
+                text
+            );
+
+            // ASCII-only outputs are parsed back by `serde_json`.
+            let escaper = JsonStringEscapeConverter::new().escape_non_ascii(true);
+            let escaped = ConvertedIterator::new(text.chars(), escaper)
+                .collect::<Result<String, _>>()
+                .unwrap();
+            let parsed = serde_json::from_str::<String>(&alloc::format!("\"{}\"", escaped));
+            assert_eq!(text, parsed.unwrap(), "{:?}", escaped);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescape() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let unescape = |s: &str| {
+            ConvertedIterator::new(s.chars(), JsonStringUnescapeConverter::new())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Ok('\x08'), Ok('\x0c'), Ok('\u{10FFFF}'), Ok('A')],
+            unescape(r"\b\f\udbff\udfffA")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::InvalidDigit('g')), Ok('0'), Ok('x')],
+            unescape(r"\u00g0x")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::UnpairedSurrogate(0xDC00)), Ok('a')],
+            unescape(r"\udc00a")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::UnpairedSurrogate(0xD800)), Ok('a')],
+            unescape(r"\ud800a")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::UnpairedSurrogate(0xD800)), Ok('\n')],
+            unescape(r"\ud800\n")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::UnpairedSurrogate(0xD800)), Ok('A')],
+            unescape(r"\ud800\u0041")
+        );
+        assert_eq!(
+            vec![Err(JsonEscapeError::UnpairedSurrogate(0xD800))],
+            unescape(r"\ud800")
+        );
+        assert_eq!(vec![Err(JsonEscapeError::Unterminated)], unescape(r"\u00"));
+        assert_eq!(
+            Err(JsonEscapeError::UnknownEscape('x')),
+            ConvertedIterator::new(r"\x41".chars(), JsonStringUnescapeConverter::new())
+                .collect::<Result<String, _>>()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split_calls() {
+        use alloc::string::String;
+
+        let mut unescaper = JsonStringUnescapeConverter::new();
+        let mut unescaped = String::new();
+        for c in r"\ud83d\ude0".chars() {
+            assert_eq!(Ok(0), unescaper.convert(c, &mut unescaped));
+        }
+        assert_eq!(Ok(1), unescaper.convert('0', &mut unescaped));
+        assert_eq!(Ok(0), unescaper.convert('\\', &mut unescaped));
+        assert_eq!(Ok(1), unescaper.convert('"', &mut unescaped));
+        assert_eq!(Ok(0), unescaper.convert('\\', &mut unescaped));
+        assert_eq!(Err(JsonEscapeError::Unterminated), unescaper.finalize());
+        assert_eq!(Ok(()), unescaper.finalize());
+        assert_eq!("😀\"", unescaped);
+    }
+}
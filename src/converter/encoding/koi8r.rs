@@ -0,0 +1,318 @@
+//! KOI8-R and KOI8-U Decoder/Encoder.
+//!
+//! KOI8-R ([RFC 1489]) is for Russian, and KOI8-U ([RFC 2319]) replaces 8 box-drawing characters
+//! of KOI8-R with Ukrainian letters. Both are ASCII for bytes under `0x80`.
+//!
+//! [RFC 1489]: https://www.rfc-editor.org/rfc/rfc1489
+//! [RFC 2319]: https://www.rfc-editor.org/rfc/rfc2319
+
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while encoding KOI8-R or KOI8-U characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KOI8EncodingError;
+
+impl fmt::Display for KOI8EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A character out of bound.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KOI8EncodingError {}
+
+// characters of KOI8-R bytes from `0x80` to `0xFF`.
+const TABLE: [char; 128] = [
+    '\u{2500}', '\u{2502}', '\u{250C}', '\u{2510}', '\u{2514}', '\u{2518}', '\u{251C}', '\u{2524}',
+    '\u{252C}', '\u{2534}', '\u{253C}', '\u{2580}', '\u{2584}', '\u{2588}', '\u{258C}', '\u{2590}',
+    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2320}', '\u{25A0}', '\u{2219}', '\u{221A}', '\u{2248}',
+    '\u{2264}', '\u{2265}', '\u{A0}', '\u{2321}', '\u{B0}', '\u{B2}', '\u{B7}', '\u{F7}',
+    '\u{2550}', '\u{2551}', '\u{2552}', '\u{451}', '\u{2553}', '\u{2554}', '\u{2555}', '\u{2556}',
+    '\u{2557}', '\u{2558}', '\u{2559}', '\u{255A}', '\u{255B}', '\u{255C}', '\u{255D}', '\u{255E}',
+    '\u{255F}', '\u{2560}', '\u{2561}', '\u{401}', '\u{2562}', '\u{2563}', '\u{2564}', '\u{2565}',
+    '\u{2566}', '\u{2567}', '\u{2568}', '\u{2569}', '\u{256A}', '\u{256B}', '\u{256C}', '\u{A9}',
+    '\u{44E}', '\u{430}', '\u{431}', '\u{446}', '\u{434}', '\u{435}', '\u{444}', '\u{433}',
+    '\u{445}', '\u{438}', '\u{439}', '\u{43A}', '\u{43B}', '\u{43C}', '\u{43D}', '\u{43E}',
+    '\u{43F}', '\u{44F}', '\u{440}', '\u{441}', '\u{442}', '\u{443}', '\u{436}', '\u{432}',
+    '\u{44C}', '\u{44B}', '\u{437}', '\u{448}', '\u{44D}', '\u{449}', '\u{447}', '\u{44A}',
+    '\u{42E}', '\u{410}', '\u{411}', '\u{426}', '\u{414}', '\u{415}', '\u{424}', '\u{413}',
+    '\u{425}', '\u{418}', '\u{419}', '\u{41A}', '\u{41B}', '\u{41C}', '\u{41D}', '\u{41E}',
+    '\u{41F}', '\u{42F}', '\u{420}', '\u{421}', '\u{422}', '\u{423}', '\u{416}', '\u{412}',
+    '\u{42C}', '\u{42B}', '\u{417}', '\u{428}', '\u{42D}', '\u{429}', '\u{427}', '\u{42A}',
+];
+
+// bytes which differ in KOI8-U, and their characters.
+const UKRAINIAN: [(u8, char); 8] = [
+    (0xA4, '\u{454}'),
+    (0xA6, '\u{456}'),
+    (0xA7, '\u{457}'),
+    (0xAD, '\u{491}'),
+    (0xB4, '\u{404}'),
+    (0xB6, '\u{406}'),
+    (0xB7, '\u{407}'),
+    (0xBD, '\u{490}'),
+];
+
+fn decode(item: u8, ukrainian: bool) -> char {
+    if item < 0x80 {
+        return item as char;
+    }
+    match UKRAINIAN.iter().find(|&&(b, _)| ukrainian && b == item) {
+        Some(&(_, c)) => c,
+        None => TABLE[(item - 0x80) as usize],
+    }
+}
+
+fn encode(item: char, ukrainian: bool) -> Result<u8, KOI8EncodingError> {
+    if item.is_ascii() {
+        return Ok(item as u8);
+    }
+    if ukrainian {
+        if let Some(&(b, _)) = UKRAINIAN.iter().find(|&&(_, c)| c == item) {
+            return Ok(b);
+        }
+    }
+    match TABLE.iter().position(|&c| c == item) {
+        Some(i) if !(ukrainian && UKRAINIAN.iter().any(|&(b, _)| b as usize == 0x80 + i)) => {
+            Ok(0x80 + i as u8)
+        }
+        _ => Err(KOI8EncodingError),
+    }
+}
+
+/// A decoder for KOI8-R.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::koi8r::KOI8RDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\xf0\xd2\xc9\xd7\xc5\xd4, \xcd\xc9\xd2!".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, KOI8RDecoder::new());
+///
+/// assert_eq!(Ok(String::from("Привет, мир!")), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KOI8RDecoder;
+
+impl KOI8RDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for KOI8RDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([decode(item, false)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for KOI8RDecoder {}
+
+impl ExactSizeConverter for KOI8RDecoder {}
+
+/// An encoder for KOI8-R.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::koi8r::{KOI8EncodingError, KOI8REncoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut encoded = ConvertedIterator::new("Ёж ї".chars(), KOI8REncoder::new());
+///
+/// assert_eq!(Some(Ok(0xB3)), encoded.next());
+/// assert_eq!(Some(Ok(0xD6)), encoded.next());
+/// assert_eq!(Some(Ok(b' ')), encoded.next());
+/// assert_eq!(Some(Err(KOI8EncodingError)), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KOI8REncoder;
+
+impl KOI8REncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for KOI8REncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = KOI8EncodingError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([encode(item, false)?]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for KOI8REncoder {}
+
+impl ExactSizeConverter for KOI8REncoder {}
+
+/// A decoder for KOI8-U.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::koi8r::KOI8UDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\xe7\xc1\xcc\xa6\xde\xc9\xce\xc1, \xa7\xd6\xc1\xcb".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, KOI8UDecoder::new());
+///
+/// assert_eq!(Ok(String::from("Галічина, їжак")), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KOI8UDecoder;
+
+impl KOI8UDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for KOI8UDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([decode(item, true)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for KOI8UDecoder {}
+
+impl ExactSizeConverter for KOI8UDecoder {}
+
+/// An encoder for KOI8-U.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::koi8r::{KOI8EncodingError, KOI8UEncoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut encoded = ConvertedIterator::new("ї╗€".chars(), KOI8UEncoder::new());
+///
+/// assert_eq!(Some(Ok(0xA7)), encoded.next());
+/// assert_eq!(Some(Ok(0xA8)), encoded.next());
+/// assert_eq!(Some(Err(KOI8EncodingError)), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KOI8UEncoder;
+
+impl KOI8UEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for KOI8UEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = KOI8EncodingError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([encode(item, true)?]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for KOI8UEncoder {}
+
+impl ExactSizeConverter for KOI8UEncoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        let decoded = ConvertedIterator::new(bytes.iter().cloned(), KOI8RDecoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(256, decoded.chars().count());
+        let encoded = ConvertedIterator::new(decoded.chars(), KOI8REncoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes.clone()), encoded);
+
+        let decoded = ConvertedIterator::new(bytes.iter().cloned(), KOI8UDecoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(256, decoded.chars().count());
+        let encoded = ConvertedIterator::new(decoded.chars(), KOI8UEncoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes), encoded);
+    }
+
+    #[test]
+    fn replaced() {
+        // box-drawing characters replaced in KOI8-U are not encodable.
+        for &(b, c) in UKRAINIAN.iter() {
+            let old = TABLE[(b - 0x80) as usize];
+            assert_eq!(Ok(b), encode(old, false));
+            assert_eq!(Err(KOI8EncodingError), encode(old, true));
+            assert_eq!(Err(KOI8EncodingError), encode(c, false));
+            assert_eq!(Ok(b), encode(c, true));
+        }
+    }
+}
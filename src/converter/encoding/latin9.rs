@@ -0,0 +1,161 @@
+//! ISO-8859-15 (Latin-9) Decoder/Encoder.
+//!
+//! ISO-8859-15 is the same as ISO-8859-1 (Latin-1) except for 8 characters, including the Euro
+//! sign.
+
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while encoding ISO-8859-15 characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Latin9EncodingError;
+
+impl fmt::Display for Latin9EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A character out of bound.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Latin9EncodingError {}
+
+// bytes which differ from ISO-8859-1, and their characters.
+const DIFFERENCES: [(u8, char); 8] = [
+    (0xA4, '\u{20AC}'),
+    (0xA6, '\u{160}'),
+    (0xA8, '\u{161}'),
+    (0xB4, '\u{17D}'),
+    (0xB8, '\u{17E}'),
+    (0xBC, '\u{152}'),
+    (0xBD, '\u{153}'),
+    (0xBE, '\u{178}'),
+];
+
+/// A decoder for ISO-8859-15.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::latin9::Latin9Decoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\xa4 \xbcuvre, stra\xdfe".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, Latin9Decoder::new());
+///
+/// assert_eq!(Ok(String::from("€ Œuvre, straße")), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Latin9Decoder;
+
+impl Latin9Decoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for Latin9Decoder {
+    type Item = u8;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let c = match DIFFERENCES.iter().find(|&&(b, _)| b == item) {
+            Some(&(_, c)) => c,
+            None => item as char,
+        };
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for Latin9Decoder {}
+
+impl ExactSizeConverter for Latin9Decoder {}
+
+/// An encoder for ISO-8859-15.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::latin9::{Latin9Encoder, Latin9EncodingError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut encoded = ConvertedIterator::new("€ß¤".chars(), Latin9Encoder::new());
+///
+/// assert_eq!(Some(Ok(0xA4)), encoded.next());
+/// assert_eq!(Some(Ok(0xDF)), encoded.next());
+/// assert_eq!(Some(Err(Latin9EncodingError)), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Latin9Encoder;
+
+impl Latin9Encoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for Latin9Encoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Latin9EncodingError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let byte = match DIFFERENCES.iter().find(|&&(_, c)| c == item) {
+            Some(&(b, _)) => b,
+            None => match item as u32 {
+                c @ 0x00..=0xFF if !DIFFERENCES.iter().any(|&(b, _)| b as u32 == c) => c as u8,
+                _ => return Err(Latin9EncodingError),
+            },
+        };
+        buf.extend([byte]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for Latin9Encoder {}
+
+impl ExactSizeConverter for Latin9Encoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        let decoded = ConvertedIterator::new(bytes.iter().cloned(), Latin9Decoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(256, decoded.chars().count());
+        let encoded = ConvertedIterator::new(decoded.chars(), Latin9Encoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes), encoded);
+    }
+}
@@ -0,0 +1,488 @@
+//! Length-prefixed frame Encoder/Decoder.
+//!
+//! Each frame is an unsigned integer of its length (1, 2, 4 or 8 bytes, big-endian by default),
+//! followed by that many bytes of the payload. This is the most common framing of messages over
+//! TCP.
+
+use super::Endianness;
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// An error while encoding/decoding length-prefixed frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthPrefixError {
+    /// A frame longer than the maximum size or the width of lengths, with its length. The decoder
+    /// skips the payload.
+    TooLarge(u64),
+    /// The input ended inside a length, with the number of missing bytes.
+    TruncatedHeader(usize),
+    /// The input ended inside a payload, with the number of missing bytes.
+    TruncatedPayload(u64),
+}
+
+impl fmt::Display for LengthPrefixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge(len) => write!(f, "the frame is too large: {} bytes", len),
+            Self::TruncatedHeader(missing) => {
+                write!(
+                    f,
+                    "the input ended inside a length, missing {} bytes.",
+                    missing
+                )
+            }
+            Self::TruncatedPayload(missing) => {
+                write!(
+                    f,
+                    "the input ended inside a payload, missing {} bytes.",
+                    missing
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthPrefixError {}
+
+// parsing a length.
+#[inline]
+fn length(bytes: &[u8], endianness: Endianness) -> u64 {
+    let fold = |len: u64, &b: &u8| len << 8 | b as u64;
+    match endianness {
+        Endianness::Big => bytes.iter().fold(0, fold),
+        Endianness::Little => bytes.iter().rev().fold(0, fold),
+    }
+}
+
+/// An encoder for length-prefixed frames, emits a length and bytes of each payload.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::length_prefix::{LengthPrefixEncoder, LengthPrefixError};
+/// use conversion::converter::encoding::Endianness;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [b"hi".to_vec(), Vec::new()];
+/// let encoded = ConvertedIterator::new(iter, LengthPrefixEncoder::new());
+/// assert_eq!(Ok(b"\0\0\0\x02hi\0\0\0\0".to_vec()), encoded.collect());
+///
+/// let encoder = LengthPrefixEncoder::new()
+///     .width(2)
+///     .endianness(Endianness::Little);
+/// let encoded = ConvertedIterator::new(["abc"], encoder);
+/// assert_eq!(Ok(b"\x03\0abc".to_vec()), encoded.collect());
+///
+/// let encoder = LengthPrefixEncoder::new().width(1);
+/// let mut encoded = ConvertedIterator::new(["a".repeat(256)], encoder);
+/// assert_eq!(Some(Err(LengthPrefixError::TooLarge(256))), encoded.next());
+/// ```
+pub struct LengthPrefixEncoder<B> {
+    width: usize,
+    endianness: Endianness,
+    _phantom: PhantomData<B>,
+}
+
+impl<B> Clone for LengthPrefixEncoder<B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B> Copy for LengthPrefixEncoder<B> {}
+
+impl<B> fmt::Debug for LengthPrefixEncoder<B> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LengthPrefixEncoder")
+            .field("width", &self.width)
+            .field("endianness", &self.endianness)
+            .finish()
+    }
+}
+
+impl<B> Default for LengthPrefixEncoder<B> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 4,
+            endianness: Endianness::Big,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<B> PartialEq for LengthPrefixEncoder<B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.endianness == other.endianness
+    }
+}
+
+impl<B> Eq for LengthPrefixEncoder<B> {}
+
+impl<B> LengthPrefixEncoder<B> {
+    /// Create a new instance, emits 4-byte big-endian lengths.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the number of bytes of lengths.
+    ///
+    /// # Panics
+    /// Panics if `width` is not 1, 2, 4 or 8.
+    #[inline]
+    pub fn width(self, width: usize) -> Self {
+        assert!(
+            matches!(width, 1 | 2 | 4 | 8),
+            "the width must be 1, 2, 4 or 8"
+        );
+        Self { width, ..self }
+    }
+
+    /// Setting the byte order of lengths.
+    #[inline]
+    pub fn endianness(self, endianness: Endianness) -> Self {
+        Self { endianness, ..self }
+    }
+}
+
+impl<B: AsRef<[u8]>> Converter for LengthPrefixEncoder<B> {
+    type Item = B;
+    type Output = u8;
+    type Error = LengthPrefixError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let payload = item.as_ref();
+        let len = payload.len() as u64;
+        if self.width < 8 && len >> (self.width * 8) != 0 {
+            return Err(LengthPrefixError::TooLarge(len));
+        }
+        match self.endianness {
+            Endianness::Big => buf.extend(len.to_be_bytes()[8 - self.width..].iter().cloned()),
+            Endianness::Little => buf.extend(len.to_le_bytes()[..self.width].iter().cloned()),
+        }
+        buf.extend(payload.iter().cloned());
+        Ok(self.width + payload.len())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.width, None)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum State {
+    Header { bytes: [u8; 8], len: usize },
+    Payload { remaining: usize, data: Vec<u8> },
+    // skipping the payload of a too large frame.
+    Skipping(u64),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Header {
+            bytes: [0; 8],
+            len: 0,
+        }
+    }
+}
+
+/// A decoder for length-prefixed frames, emits payloads.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::length_prefix::{LengthPrefixDecoder, LengthPrefixError};
+/// use conversion::converter::encoding::Endianness;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\0\0\0\x02hi\0\0\0\0\0\0".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, LengthPrefixDecoder::new());
+/// assert_eq!(Some(Ok(b"hi".to_vec())), decoded.next());
+/// assert_eq!(Some(Ok(Vec::new())), decoded.next());
+/// assert_eq!(Some(Err(LengthPrefixError::TruncatedHeader(2))), decoded.next());
+/// assert_eq!(None, decoded.next());
+///
+/// let decoder = LengthPrefixDecoder::new()
+///     .width(2)
+///     .endianness(Endianness::Little)
+///     .max_frame_size(4);
+/// let iter = b"\x05\0hello\x02\0ok".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, decoder);
+/// assert_eq!(Some(Err(LengthPrefixError::TooLarge(5))), decoded.next());
+/// assert_eq!(Some(Ok(b"ok".to_vec())), decoded.next());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LengthPrefixDecoder {
+    width: usize,
+    endianness: Endianness,
+    max_frame_size: usize,
+    state: State,
+}
+
+impl Default for LengthPrefixDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 4,
+            endianness: Endianness::Big,
+            max_frame_size: usize::MAX,
+            state: State::default(),
+        }
+    }
+}
+
+impl LengthPrefixDecoder {
+    /// Create a new instance, reads 4-byte big-endian lengths without a maximum frame size.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the number of bytes of lengths.
+    ///
+    /// # Panics
+    /// Panics if `width` is not 1, 2, 4 or 8.
+    #[inline]
+    pub fn width(self, width: usize) -> Self {
+        assert!(
+            matches!(width, 1 | 2 | 4 | 8),
+            "the width must be 1, 2, 4 or 8"
+        );
+        Self { width, ..self }
+    }
+
+    /// Setting the byte order of lengths.
+    #[inline]
+    pub fn endianness(self, endianness: Endianness) -> Self {
+        Self { endianness, ..self }
+    }
+
+    /// Setting the maximum size of payloads, not to allocate too much memory for malicious
+    /// inputs.
+    #[inline]
+    pub fn max_frame_size(self, max_frame_size: usize) -> Self {
+        Self {
+            max_frame_size,
+            ..self
+        }
+    }
+}
+
+impl Converter for LengthPrefixDecoder {
+    type Item = u8;
+    type Output = Vec<u8>;
+    type Error = LengthPrefixError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match &mut self.state {
+            State::Header { bytes, len } => {
+                bytes[*len] = item;
+                *len += 1;
+                if *len < self.width {
+                    return Ok(0);
+                }
+
+                let length = length(&bytes[..self.width], self.endianness);
+                match usize::try_from(length) {
+                    Ok(0) => {
+                        self.state = State::default();
+                        buf.extend([Vec::new()]);
+                        Ok(1)
+                    }
+                    Ok(remaining) if remaining <= self.max_frame_size => {
+                        self.state = State::Payload {
+                            remaining,
+                            data: Vec::with_capacity(remaining),
+                        };
+                        Ok(0)
+                    }
+                    _ => {
+                        self.state = State::Skipping(length);
+                        Err(LengthPrefixError::TooLarge(length))
+                    }
+                }
+            }
+            State::Payload { remaining, data } => {
+                data.push(item);
+                *remaining -= 1;
+                if *remaining > 0 {
+                    return Ok(0);
+                }
+
+                let data = core::mem::take(data);
+                self.state = State::default();
+                buf.extend([data]);
+                Ok(1)
+            }
+            State::Skipping(remaining) => {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    self.state = State::default();
+                }
+                Ok(0)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::Header { len: 0, .. } => Ok(()),
+            State::Header { len, .. } => Err(LengthPrefixError::TruncatedHeader(self.width - len)),
+            State::Payload { remaining, .. } => {
+                Err(LengthPrefixError::TruncatedPayload(remaining as u64))
+            }
+            State::Skipping(remaining) => Err(LengthPrefixError::TruncatedPayload(remaining)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    // frames of `lengths`, with 2-byte little-endian lengths.
+    fn frames(lengths: &[usize]) -> (Vec<u8>, Vec<Vec<u8>>) {
+        let mut bytes = Vec::new();
+        let mut payloads = Vec::new();
+        for (i, &len) in lengths.iter().enumerate() {
+            let payload = (0..len).map(|j| (i * 31 + j) as u8).collect::<Vec<_>>();
+            bytes.extend((len as u16).to_le_bytes());
+            bytes.extend(payload.iter().cloned());
+            payloads.push(payload);
+        }
+        (bytes, payloads)
+    }
+
+    fn decoder() -> LengthPrefixDecoder {
+        LengthPrefixDecoder::new()
+            .width(2)
+            .endianness(Endianness::Little)
+    }
+
+    #[test]
+    fn decode() {
+        use crate::iter::ConvertedIterator;
+
+        let (bytes, payloads) = frames(&[0, 1, 0, 300, 0]);
+        let decoded = ConvertedIterator::new(bytes, decoder()).collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(payloads), decoded);
+
+        for width in [1, 2, 4, 8] {
+            let mut bytes = vec![0; width];
+            bytes[width - 1] = 1;
+            bytes.push(0xAB);
+            let decoded = ConvertedIterator::new(bytes, LengthPrefixDecoder::new().width(width))
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(vec![vec![0xAB]]), decoded);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let mut decoder = LengthPrefixDecoder::new().width(8).max_frame_size(1 << 20);
+        let mut buf = Vec::new();
+        // the length is rejected before reading the payload.
+        for b in [0xFF; 7] {
+            assert_eq!(Ok(0), decoder.convert(b, &mut buf));
+        }
+        assert_eq!(
+            Err(LengthPrefixError::TooLarge(u64::MAX)),
+            decoder.convert(0xFF, &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(0, &mut buf));
+        assert_eq!(
+            Err(LengthPrefixError::TruncatedPayload(u64::MAX - 1)),
+            decoder.finalize()
+        );
+
+        for b in [0, 0, 0, 0, 0, 0, 0, 3, 1] {
+            assert_eq!(Ok(0), decoder.convert(b, &mut buf));
+        }
+        assert_eq!(
+            Err(LengthPrefixError::TruncatedPayload(2)),
+            decoder.finalize()
+        );
+        assert_eq!(Ok(0), decoder.convert(0, &mut buf));
+        assert_eq!(
+            Err(LengthPrefixError::TruncatedHeader(7)),
+            decoder.finalize()
+        );
+        assert_eq!(Ok(()), decoder.finalize());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use crate::Converter;
+
+        let mut next = random();
+        let mut lengths = (0..50).map(|_| next() as usize % 256).collect::<Vec<_>>();
+        lengths.push(255);
+        let payloads = lengths
+            .iter()
+            .map(|&len| (0..len).map(|_| next() as u8).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for width in [1, 2, 4, 8] {
+            for endianness in [Endianness::Big, Endianness::Little] {
+                let encoder = LengthPrefixEncoder::new()
+                    .width(width)
+                    .endianness(endianness);
+                let decoder = LengthPrefixDecoder::new()
+                    .width(width)
+                    .endianness(endianness);
+                let decoded = ConvertedIterator::new(payloads.iter(), encoder.pipe(decoder))
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(payloads.clone()), decoded);
+            }
+        }
+
+        let mut encoder = LengthPrefixEncoder::new().width(1);
+        let mut buf = Vec::new();
+        assert_eq!(Ok(256), encoder.convert(vec![0xAA; 255], &mut buf));
+        assert_eq!(255, buf[0]);
+        assert_eq!(
+            Err(LengthPrefixError::TooLarge(256)),
+            encoder.convert(vec![0xAA; 256], &mut buf)
+        );
+        assert_eq!(256, buf.len());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn chunks() {
+        use crate::stream::ConvertedStream;
+        use futures::stream::{self, StreamExt, TryStreamExt};
+
+        // a stream of chunks, which split frames and lengths.
+        let (bytes, payloads) = frames(&[5, 0, 1000, 17]);
+        let chunks = bytes.chunks(3).map(<[u8]>::to_vec).collect::<Vec<_>>();
+        let stream = stream::iter(chunks).flat_map(stream::iter);
+        let decoded = futures::executor::block_on(
+            ConvertedStream::new(stream, decoder()).try_collect::<Vec<_>>(),
+        );
+        assert_eq!(Ok(payloads), decoded);
+    }
+}
@@ -0,0 +1,402 @@
+//! MIME Base64 Encoder/Decoder, described in [RFC 2045].
+//!
+//! Unlike plain Base64, encoded lines are limited to 76 characters. Lines of 64 characters, used by
+//! PEM, are also supported.
+//!
+//! [RFC 2045]: https://www.rfc-editor.org/rfc/rfc2045#section-6.8
+
+use super::base64::{Base64DecodeError, Base64Decoder, Base64Encoder};
+use crate::Converter;
+use core::convert::Infallible;
+
+/// The maximum line length of MIME Base64.
+pub const MIME_LINE_WIDTH: usize = 76;
+/// The line length of PEM, described in [RFC 7468].
+///
+/// [RFC 7468]: https://www.rfc-editor.org/rfc/rfc7468#section-2
+pub const PEM_LINE_WIDTH: usize = 64;
+
+/// Line endings inserted by [`MimeBase64Encoder`].
+///
+/// [`MimeBase64Encoder`]: self::MimeBase64Encoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\r\n`, required by MIME.
+    CrLf,
+    /// `\n`.
+    Lf,
+}
+
+impl LineEnding {
+    #[inline]
+    fn as_bytes(self) -> &'static [u8] {
+        match self {
+            Self::CrLf => b"\r\n",
+            Self::Lf => b"\n",
+        }
+    }
+}
+
+// an `Extend` implementation inserting line endings.
+struct Wrap<'a, E> {
+    buf: &'a mut E,
+    column: &'a mut usize,
+    width: usize,
+    ending: LineEnding,
+    // the number of inserted bytes.
+    len: usize,
+}
+
+impl<E: Extend<u8>> Extend<u8> for Wrap<'_, E> {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        for byte in iter {
+            if *self.column == self.width {
+                let ending = self.ending.as_bytes();
+                self.buf.extend(ending.iter().cloned());
+                self.len += ending.len();
+                *self.column = 0;
+            }
+            self.buf.extend([byte]);
+            *self.column += 1;
+        }
+    }
+}
+
+/// An encoder for MIME Base64.
+///
+/// A line ending is inserted after every `width` characters (76 by default), but not after the
+/// last line unless [`terminate_last_line`] is set, so the output can be concatenated with other
+/// lines of MIME bodies or PEM files. Empty input produces empty output in both cases.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::mime_base64::{LineEnding, MimeBase64Encoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"hello, world".iter().cloned();
+/// let encoded = ConvertedIterator::new(iter, MimeBase64Encoder::new());
+/// assert_eq!(Ok(b"aGVsbG8sIHdvcmxk".to_vec()), encoded.collect());
+///
+/// let iter = b"hello, world".iter().cloned();
+/// let encoder = MimeBase64Encoder::new().with_width(6).with_line_ending(LineEnding::Lf);
+/// let encoded = ConvertedIterator::new(iter, encoder);
+/// assert_eq!(Ok(b"aGVsbG\n8sIHdv\ncmxk".to_vec()), encoded.collect());
+///
+/// let iter = b"hello, world".iter().cloned();
+/// let encoded = ConvertedIterator::new(iter, MimeBase64Encoder::new().terminate_last_line(true));
+/// assert_eq!(Ok(b"aGVsbG8sIHdvcmxk\r\n".to_vec()), encoded.collect());
+/// ```
+///
+/// [`terminate_last_line`]: Self::terminate_last_line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MimeBase64Encoder {
+    inner: Base64Encoder,
+    width: usize,
+    ending: LineEnding,
+    terminated: bool,
+    // the number of characters in the current line.
+    column: usize,
+}
+
+impl Default for MimeBase64Encoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: Base64Encoder::new(),
+            width: MIME_LINE_WIDTH,
+            ending: LineEnding::CrLf,
+            terminated: false,
+            column: 0,
+        }
+    }
+}
+
+impl MimeBase64Encoder {
+    /// Create a new instance, with 76 columns and `\r\n`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance for PEM, with 64 columns and `\n`, terminating the last line.
+    #[inline]
+    pub fn pem() -> Self {
+        Self::new()
+            .with_width(PEM_LINE_WIDTH)
+            .with_line_ending(LineEnding::Lf)
+            .terminate_last_line(true)
+    }
+
+    /// Setting the number of characters in a line.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero.
+    #[inline]
+    pub fn with_width(self, width: usize) -> Self {
+        assert!(width > 0, "the line width must be positive");
+        Self { width, ..self }
+    }
+
+    /// Setting the line ending.
+    #[inline]
+    pub fn with_line_ending(self, ending: LineEnding) -> Self {
+        Self { ending, ..self }
+    }
+
+    /// Setting whether the last line is terminated by a line ending.
+    #[inline]
+    pub fn terminate_last_line(self, terminated: bool) -> Self {
+        Self { terminated, ..self }
+    }
+}
+
+impl Converter for MimeBase64Encoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut wrap = Wrap {
+            buf,
+            column: &mut self.column,
+            width: self.width,
+            ending: self.ending,
+            len: 0,
+        };
+        let len = self.inner.convert(item, &mut wrap)?;
+        Ok(len + wrap.len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut wrap = Wrap {
+            buf,
+            column: &mut self.column,
+            width: self.width,
+            ending: self.ending,
+            len: 0,
+        };
+        let len = self.inner.finish(&mut wrap)? + wrap.len;
+        if core::mem::take(&mut self.column) == 0 || !self.terminated {
+            return Ok(len);
+        }
+        let ending = self.ending.as_bytes();
+        buf.extend(ending.iter().cloned());
+        Ok(len + ending.len())
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        let breaks = 4 / self.width + 1 + usize::from(self.terminated);
+        self.inner
+            .finish_hint()
+            .map(|n| n + breaks * self.ending.as_bytes().len())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // line endings inserted among 4 characters of a quantum.
+        let (lower, upper) = self.inner.size_hint();
+        let breaks = 4 / self.width + 1;
+        (
+            lower,
+            upper.map(|n| n + breaks * self.ending.as_bytes().len()),
+        )
+    }
+}
+
+/// A decoder for MIME Base64.
+///
+/// Line endings (`\r` and `\n`) are skipped, and other bytes are passed to [`Base64Decoder`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::mime_base64::MimeBase64Decoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"aGVsbG\r\n8sIHdv\ncmxk".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, MimeBase64Decoder::new());
+/// assert_eq!(Ok(b"hello, world".to_vec()), decoded.collect());
+/// ```
+///
+/// [`Base64Decoder`]: super::base64::Base64Decoder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MimeBase64Decoder {
+    inner: Base64Decoder,
+}
+
+impl MimeBase64Decoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for MimeBase64Decoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Base64DecodeError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match item {
+            b'\r' | b'\n' => Ok(0),
+            _ => self.inner.convert(item, buf),
+        }
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.inner.finish(buf)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn wrap() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        let encoded = ConvertedIterator::new(bytes.iter().cloned(), MimeBase64Encoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let lines: Vec<&[u8]> = encoded.split(|&b| b == b'\n').collect();
+        // 256 bytes are encoded into 344 characters.
+        assert_eq!(5, lines.len());
+        assert!(lines[..4]
+            .iter()
+            .all(|line| line.len() == MIME_LINE_WIDTH + 1 && line.ends_with(b"\r")));
+        assert_eq!(344 - MIME_LINE_WIDTH * 4, lines[4].len());
+
+        let decoded = ConvertedIterator::new(encoded, MimeBase64Decoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes), decoded);
+
+        // no line ending after the last full line.
+        let encoded = ConvertedIterator::new([0; 57], MimeBase64Encoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(MIME_LINE_WIDTH, encoded.len());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn terminated() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        // outputs of Python's `base64.encodebytes`, whose padded tails end at the line width.
+        let encoder = MimeBase64Encoder::new().terminate_last_line(true);
+        let encoded = ConvertedIterator::new(0..56, encoder)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            &b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc=\r\n"[..],
+            encoded
+        );
+        let encoded = ConvertedIterator::new(0..47, MimeBase64Encoder::pem())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            &b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4=\n"[..],
+            encoded
+        );
+
+        let encoded = ConvertedIterator::new(core::iter::empty(), MimeBase64Encoder::pem())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(Vec::new()), encoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn base64_crate() {
+        use crate::converter::encoding::base64::Base64Decoder;
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+        use base64::engine::general_purpose::STANDARD;
+        use base64::Engine;
+
+        let expected = |bytes: &[u8], width: usize, ending: &[u8], terminated: bool| {
+            let plain = STANDARD.encode(bytes);
+            let mut lines = plain
+                .as_bytes()
+                .chunks(width)
+                .collect::<Vec<_>>()
+                .join(ending);
+            if terminated && !plain.is_empty() {
+                lines.extend_from_slice(ending);
+            }
+            lines
+        };
+
+        let crlf = &b"\r\n"[..];
+        let lf = &b"\n"[..];
+        let encoders = [
+            (MimeBase64Encoder::new(), MIME_LINE_WIDTH, crlf, false),
+            (
+                MimeBase64Encoder::new().terminate_last_line(true),
+                MIME_LINE_WIDTH,
+                crlf,
+                true,
+            ),
+            (MimeBase64Encoder::pem(), PEM_LINE_WIDTH, lf, true),
+            (
+                MimeBase64Encoder::new()
+                    .with_width(PEM_LINE_WIDTH)
+                    .with_line_ending(LineEnding::Lf),
+                PEM_LINE_WIDTH,
+                lf,
+                false,
+            ),
+            (MimeBase64Encoder::new().with_width(3), 3, crlf, false),
+        ];
+        for (encoder, width, ending, terminated) in encoders {
+            // includes lengths whose padding ends exactly at the line width, like 56 and 47.
+            for len in 0..=200 {
+                let bytes = (0..len).map(|i| (i * 7) as u8).collect::<Vec<u8>>();
+                let encoded = ConvertedIterator::new(bytes.iter().cloned(), encoder)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+                assert_eq!(
+                    expected(&bytes, width, ending, terminated),
+                    encoded,
+                    "{} {}",
+                    width,
+                    len
+                );
+
+                let decoder = Base64Decoder::new().skip_whitespace(true);
+                let decoded =
+                    ConvertedIterator::new(encoded, decoder).collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(bytes), decoded);
+            }
+        }
+    }
+}
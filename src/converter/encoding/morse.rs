@@ -0,0 +1,356 @@
+//! Morse code Encoder/Decoder, described in [ITU-R M.1677-1].
+//!
+//! Codes are written with `.` and `-`, letters are separated by a space, and words by three
+//! spaces. Letters are case-insensitive, and decoded as uppercase.
+//!
+//! [ITU-R M.1677-1]: https://www.itu.int/rec/R-REC-M.1677-1-200910-I/
+
+use crate::Converter;
+use core::fmt;
+
+// the longest code in `CODES`.
+const MAX_LEN: usize = 7;
+
+// the number of spaces between words.
+const WORD_GAP: usize = 3;
+
+static CODES: [(char, &str); 54] = [
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+/// An error while encoding/decoding Morse code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MorseError {
+    /// A character without a code while encoding, or other than `.`, `-` and space while
+    /// decoding.
+    InvalidChar(char),
+    /// A code of no characters, with the offset of its first symbol.
+    UnknownCode(usize),
+}
+
+impl fmt::Display for MorseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(c) => write!(f, "invalid character for Morse code: {:?}", c),
+            Self::UnknownCode(offset) => write!(f, "unknown Morse code at {}.", offset),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MorseError {}
+
+// a gap to be emitted before the next letter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Gap {
+    #[default]
+    None,
+    Letter,
+    Word,
+}
+
+/// An encoder for Morse code.
+///
+/// Gaps are emitted before the next letter, so the output has no leading or trailing spaces, and
+/// consecutive spaces in the input are emitted as a single word gap.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::morse::{MorseEncoder, MorseError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new("SOS at 5".chars(), MorseEncoder::new());
+/// assert_eq!(
+///     Ok(String::from("... --- ...   .- -   .....")),
+///     encoded.collect(),
+/// );
+///
+/// let encoded = ConvertedIterator::new("ß".chars(), MorseEncoder::new());
+/// assert_eq!(
+///     Err(MorseError::InvalidChar('ß')),
+///     encoded.collect::<Result<String, _>>(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MorseEncoder {
+    gap: Gap,
+}
+
+impl MorseEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for MorseEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = MorseError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item == ' ' {
+            if self.gap == Gap::Letter {
+                self.gap = Gap::Word;
+            }
+            return Ok(0);
+        }
+        let upper = item.to_ascii_uppercase();
+        let code = match CODES.iter().find(|&&(c, _)| c == upper) {
+            Some(&(_, code)) => code,
+            None => return Err(MorseError::InvalidChar(item)),
+        };
+        let gap = match core::mem::replace(&mut self.gap, Gap::Letter) {
+            Gap::None => 0,
+            Gap::Letter => 1,
+            Gap::Word => WORD_GAP,
+        };
+        buf.extend(core::iter::repeat_n(' ', gap).chain(code.chars()));
+        Ok(gap + code.len())
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.gap = Gap::None;
+        Ok(0)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(WORD_GAP + MAX_LEN))
+    }
+}
+
+/// A decoder for Morse code.
+///
+/// A code is decoded at the following space or the end of the input, and three or more spaces
+/// after a code are decoded as a space.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::morse::{MorseDecoder, MorseError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "... --- ...   .- -   .....".chars();
+/// let decoded = ConvertedIterator::new(iter, MorseDecoder::new());
+/// assert_eq!(Ok(String::from("SOS AT 5")), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new("........ .-".chars(), MorseDecoder::new());
+/// assert_eq!(Some(Err(MorseError::UnknownCode(0))), decoded.next());
+/// assert_eq!(Some(Ok('A')), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MorseDecoder {
+    code: [u8; MAX_LEN],
+    // the length of the code, which may exceed `MAX_LEN`.
+    len: usize,
+    // the offset of the code.
+    start: usize,
+    offset: usize,
+    // the number of spaces after the last code, `None` before the first code.
+    spaces: Option<usize>,
+}
+
+impl MorseDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn decode(&mut self) -> Result<char, MorseError> {
+        let code = self.code.get(..core::mem::take(&mut self.len));
+        CODES
+            .iter()
+            .find(|&&(_, c)| Some(c.as_bytes()) == code)
+            .map(|&(c, _)| c)
+            .ok_or(MorseError::UnknownCode(self.start))
+    }
+}
+
+impl Converter for MorseDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = MorseError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        match item {
+            '.' | '-' => {
+                if self.len == 0 {
+                    self.start = offset;
+                }
+                if let Some(symbol) = self.code.get_mut(self.len) {
+                    *symbol = item as u8;
+                }
+                self.len += 1;
+                Ok(0)
+            }
+            ' ' if self.len > 0 => {
+                self.spaces = Some(1);
+                let c = self.decode()?;
+                buf.extend([c]);
+                Ok(1)
+            }
+            ' ' => match &mut self.spaces {
+                Some(spaces) => {
+                    *spaces += 1;
+                    if *spaces == WORD_GAP {
+                        buf.extend([' ']);
+                        Ok(1)
+                    } else {
+                        Ok(0)
+                    }
+                }
+                None => Ok(0),
+            },
+            c => Err(MorseError::InvalidChar(c)),
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.offset = 0;
+        self.spaces = None;
+        if self.len == 0 {
+            return Ok(0);
+        }
+        let c = self.decode()?;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let text: String = CODES.iter().map(|&(c, _)| c).collect();
+        for text in ["", "E", "HELLO WORLD", &text] {
+            let encoded = ConvertedIterator::new(text.chars(), MorseEncoder::new())
+                .collect::<Result<String, _>>()
+                .unwrap();
+            let decoded = ConvertedIterator::new(encoded.chars(), MorseDecoder::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(text)), decoded);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use alloc::string::String;
+
+        let mut decoder = MorseDecoder::new();
+        let mut buf = String::new();
+        assert_eq!(
+            Err(MorseError::InvalidChar('x')),
+            decoder.convert('x', &mut buf)
+        );
+        for c in "..--.. ".chars() {
+            assert!(decoder.convert(c, &mut buf).is_ok());
+        }
+        for c in ".-.-.-.-".chars() {
+            assert_eq!(Ok(0), decoder.convert(c, &mut buf));
+        }
+        assert_eq!(Err(MorseError::UnknownCode(8)), decoder.finish(&mut buf));
+        assert_eq!("?", buf);
+
+        let mut encoder = MorseEncoder::new();
+        assert_eq!(Ok(0), encoder.convert(' ', &mut buf));
+        assert_eq!(
+            Err(MorseError::InvalidChar('#')),
+            encoder.convert('#', &mut buf)
+        );
+        assert_eq!(Ok(1), encoder.convert('t', &mut buf));
+        assert_eq!("?-", buf);
+    }
+}
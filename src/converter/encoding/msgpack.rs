@@ -0,0 +1,340 @@
+//! MessagePack frame decoder.
+
+use crate::Converter;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error while decoding MessagePack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MsgPackError {
+    /// A format byte which is never used. (`0xc1`)
+    InvalidFormat(u8),
+    /// A string which is not valid UTF-8.
+    InvalidUTF8,
+    /// The input ended inside a value.
+    Truncated,
+}
+
+impl fmt::Display for MsgPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat(b) => write!(f, "invalid format byte: {:#x}", b),
+            Self::InvalidUTF8 => write!(f, "invalid UTF-8 string."),
+            Self::Truncated => write!(f, "the input ended inside a value."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MsgPackError {}
+
+/// A MessagePack value.
+///
+/// Arrays and maps are represented by their headers, and their elements follow as separate
+/// values. (a map with `n` entries is followed by `2n` values, keys and values alternately)
+#[derive(Clone, Debug, PartialEq)]
+pub enum MsgPackValue {
+    /// `nil`
+    Nil,
+    /// `true` or `false`
+    Bool(bool),
+    /// A signed integer. (`negative fixint` and `int 8` to `int 64`)
+    Int(i64),
+    /// An unsigned integer. (`positive fixint` and `uint 8` to `uint 64`)
+    UInt(u64),
+    /// `float 32`
+    F32(f32),
+    /// `float 64`
+    F64(f64),
+    /// A string.
+    Str(String),
+    /// A byte array.
+    Bin(Vec<u8>),
+    /// A header of an array, with the number of elements.
+    Array(u32),
+    /// A header of a map, with the number of entries.
+    Map(u32),
+    /// An extension type, with the type and the data.
+    Ext(i8, Vec<u8>),
+}
+
+fn be(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &b| acc << 8 | b as u64)
+}
+
+// the number of bytes following the format byte, computed from the bytes read so far.
+fn required(tag: u8, buf: &[u8]) -> Result<usize, MsgPackError> {
+    // the length of a length field, and the length of fields between it and the payload.
+    let prefixed = |n: usize, extra: usize| {
+        if buf.len() < n {
+            n
+        } else {
+            n + extra + be(&buf[..n]) as usize
+        }
+    };
+    Ok(match tag {
+        0x00..=0x9f | 0xc0 | 0xc2 | 0xc3 | 0xe0..=0xff => 0,
+        0xa0..=0xbf => (tag & 0x1f) as usize,
+        0xc1 => return Err(MsgPackError::InvalidFormat(tag)),
+        0xc4 | 0xd9 => prefixed(1, 0),
+        0xc5 | 0xda => prefixed(2, 0),
+        0xc6 | 0xdb => prefixed(4, 0),
+        0xc7 => prefixed(1, 1),
+        0xc8 => prefixed(2, 1),
+        0xc9 => prefixed(4, 1),
+        0xcc | 0xd0 => 1,
+        0xcd | 0xd1 | 0xdc | 0xde => 2,
+        0xca | 0xce | 0xd2 | 0xdd | 0xdf => 4,
+        0xcb | 0xcf | 0xd3 => 8,
+        0xd4..=0xd8 => 1 + (1 << (tag - 0xd4)),
+    })
+}
+
+fn value(tag: u8, buf: Vec<u8>) -> Result<MsgPackValue, MsgPackError> {
+    let string = |bytes: Vec<u8>| {
+        String::from_utf8(bytes)
+            .map(MsgPackValue::Str)
+            .map_err(|_| MsgPackError::InvalidUTF8)
+    };
+    let ext = |mut bytes: Vec<u8>, n: usize| {
+        let data = bytes.split_off(n + 1);
+        MsgPackValue::Ext(bytes[n] as i8, data)
+    };
+    Ok(match tag {
+        0x00..=0x7f => MsgPackValue::UInt(tag as u64),
+        0x80..=0x8f => MsgPackValue::Map((tag & 0x0f) as u32),
+        0x90..=0x9f => MsgPackValue::Array((tag & 0x0f) as u32),
+        0xa0..=0xbf => return string(buf),
+        0xc0 => MsgPackValue::Nil,
+        0xc2 => MsgPackValue::Bool(false),
+        0xc3 => MsgPackValue::Bool(true),
+        0xc4..=0xc6 => MsgPackValue::Bin(buf[1 << (tag - 0xc4)..].to_vec()),
+        0xc7..=0xc9 => ext(buf, 1 << (tag - 0xc7)),
+        0xca => MsgPackValue::F32(f32::from_bits(be(&buf) as u32)),
+        0xcb => MsgPackValue::F64(f64::from_bits(be(&buf))),
+        0xcc..=0xcf => MsgPackValue::UInt(be(&buf)),
+        0xd0 => MsgPackValue::Int(buf[0] as i8 as i64),
+        0xd1 => MsgPackValue::Int(be(&buf) as u16 as i16 as i64),
+        0xd2 => MsgPackValue::Int(be(&buf) as u32 as i32 as i64),
+        0xd3 => MsgPackValue::Int(be(&buf) as i64),
+        0xd4..=0xd8 => ext(buf, 0),
+        0xd9..=0xdb => return string(buf[1 << (tag - 0xd9)..].to_vec()),
+        0xdc | 0xdd => MsgPackValue::Array(be(&buf) as u32),
+        0xde | 0xdf => MsgPackValue::Map(be(&buf) as u32),
+        0xe0..=0xff => MsgPackValue::Int(tag as i8 as i64),
+        0xc1 => unreachable!(),
+    })
+}
+
+/// A decoder for MessagePack, emits a value for each format.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::msgpack::{MsgPackFrameDecoder, MsgPackValue};
+/// use conversion::iter::ConvertedIterator;
+///
+/// // [1, "abc", {true: nil}]
+/// let iter = [0x93, 0x01, 0xa3, b'a', b'b', b'c', 0x81, 0xc3, 0xc0].into_iter();
+/// let decoded = ConvertedIterator::new(iter, MsgPackFrameDecoder::new());
+///
+/// assert_eq!(
+///     Ok(vec![
+///         MsgPackValue::Array(3),
+///         MsgPackValue::UInt(1),
+///         MsgPackValue::Str(String::from("abc")),
+///         MsgPackValue::Map(1),
+///         MsgPackValue::Bool(true),
+///         MsgPackValue::Nil,
+///     ]),
+///     decoded.collect()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MsgPackFrameDecoder {
+    // the format byte of the current value.
+    tag: Option<u8>,
+    buffer: Vec<u8>,
+}
+
+impl MsgPackFrameDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn try_emit<E>(&mut self, tag: u8, buf: &mut E) -> Result<usize, MsgPackError>
+    where
+        E: Extend<MsgPackValue>,
+    {
+        match required(tag, &self.buffer) {
+            Ok(len) if self.buffer.len() < len => Ok(0),
+            Ok(_) => {
+                self.tag = None;
+                let value = value(tag, core::mem::take(&mut self.buffer))?;
+                buf.extend([value]);
+                Ok(1)
+            }
+            Err(e) => {
+                self.tag = None;
+                self.buffer.clear();
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Converter for MsgPackFrameDecoder {
+    type Item = u8;
+    type Output = MsgPackValue;
+    type Error = MsgPackError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.tag {
+            Some(tag) => {
+                self.buffer.push(item);
+                self.try_emit(tag, buf)
+            }
+            None => {
+                self.tag = Some(item);
+                self.try_emit(item, buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.tag {
+            Some(_) => {
+                self.tag = None;
+                self.buffer.clear();
+                Err(MsgPackError::Truncated)
+            }
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::vec;
+
+    fn decode(bytes: &[u8]) -> Vec<Result<MsgPackValue, MsgPackError>> {
+        ConvertedIterator::new(bytes.iter().cloned(), MsgPackFrameDecoder::new()).collect()
+    }
+
+    #[test]
+    fn formats() {
+        let cases: Vec<(Vec<u8>, MsgPackValue)> = vec![
+            (vec![0x7f], MsgPackValue::UInt(0x7f)),
+            (vec![0xe0], MsgPackValue::Int(-32)),
+            (vec![0xcc, 0xff], MsgPackValue::UInt(0xff)),
+            (vec![0xcd, 0x01, 0x00], MsgPackValue::UInt(0x100)),
+            (
+                vec![0xce, 0xde, 0xad, 0xbe, 0xef],
+                MsgPackValue::UInt(0xdeadbeef),
+            ),
+            (
+                vec![0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                MsgPackValue::UInt(u64::MAX),
+            ),
+            (vec![0xd0, 0x80], MsgPackValue::Int(-128)),
+            (vec![0xd1, 0xff, 0xfe], MsgPackValue::Int(-2)),
+            (
+                vec![0xd2, 0x80, 0x00, 0x00, 0x00],
+                MsgPackValue::Int(i32::MIN as i64),
+            ),
+            (
+                vec![0xd3, 0x80, 0, 0, 0, 0, 0, 0, 0],
+                MsgPackValue::Int(i64::MIN),
+            ),
+            (vec![0xca, 0x3f, 0xc0, 0x00, 0x00], MsgPackValue::F32(1.5)),
+            (
+                vec![0xcb, 0xc0, 0x04, 0, 0, 0, 0, 0, 0],
+                MsgPackValue::F64(-2.5),
+            ),
+            (vec![0xa0], MsgPackValue::Str(String::new())),
+            (
+                vec![0xd9, 0x02, 0xc3, 0x9f],
+                MsgPackValue::Str(String::from("ß")),
+            ),
+            (
+                vec![0xda, 0x00, 0x01, b'a'],
+                MsgPackValue::Str(String::from("a")),
+            ),
+            (
+                vec![0xdb, 0, 0, 0, 0x01, b'b'],
+                MsgPackValue::Str(String::from("b")),
+            ),
+            (vec![0xc4, 0x00], MsgPackValue::Bin(vec![])),
+            (vec![0xc5, 0x00, 0x02, 1, 2], MsgPackValue::Bin(vec![1, 2])),
+            (vec![0xc6, 0, 0, 0, 0x01, 3], MsgPackValue::Bin(vec![3])),
+            (vec![0xd4, 0x01, 0xaa], MsgPackValue::Ext(1, vec![0xaa])),
+            (
+                vec![
+                    0xd8, 0xff, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+                ],
+                MsgPackValue::Ext(-1, (0..16).collect()),
+            ),
+            (vec![0xc7, 0x00, 0x05], MsgPackValue::Ext(5, vec![])),
+            (
+                vec![0xc8, 0x00, 0x01, 0x05, 9],
+                MsgPackValue::Ext(5, vec![9]),
+            ),
+            (
+                vec![0xc9, 0, 0, 0, 0x01, 0x05, 9],
+                MsgPackValue::Ext(5, vec![9]),
+            ),
+            (vec![0x9f], MsgPackValue::Array(15)),
+            (vec![0xdc, 0x01, 0x00], MsgPackValue::Array(256)),
+            (
+                vec![0xdd, 0x00, 0x01, 0x00, 0x00],
+                MsgPackValue::Array(0x10000),
+            ),
+            (vec![0x80], MsgPackValue::Map(0)),
+            (vec![0xde, 0x01, 0x00], MsgPackValue::Map(256)),
+            (
+                vec![0xdf, 0x00, 0x01, 0x00, 0x00],
+                MsgPackValue::Map(0x10000),
+            ),
+            (vec![0xc2], MsgPackValue::Bool(false)),
+        ];
+        let bytes = cases
+            .iter()
+            .flat_map(|(b, _)| b.clone())
+            .collect::<Vec<_>>();
+        let values = cases.into_iter().map(|(_, v)| Ok(v)).collect::<Vec<_>>();
+        assert_eq!(values, decode(&bytes));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            vec![
+                Err(MsgPackError::InvalidFormat(0xc1)),
+                Ok(MsgPackValue::Nil)
+            ],
+            decode(&[0xc1, 0xc0])
+        );
+        assert_eq!(
+            vec![Err(MsgPackError::InvalidUTF8), Ok(MsgPackValue::Nil)],
+            decode(&[0xa1, 0xff, 0xc0])
+        );
+        assert_eq!(
+            vec![Err(MsgPackError::Truncated)],
+            decode(&[0xda, 0x00, 0x02, b'a'])
+        );
+        assert_eq!(vec![Err(MsgPackError::Truncated)], decode(&[0xcd, 0x00]));
+    }
+}
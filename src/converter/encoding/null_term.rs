@@ -0,0 +1,281 @@
+//! Null-terminated (C-style) string Encoder/Decoder.
+
+use crate::Converter;
+use core::fmt;
+
+/// An error while encoding/decoding null-terminated strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullTerminatedError {
+    /// The input ended without a null byte.
+    Unterminated,
+    /// A null byte inside the string, rejected by [`NullTerminatedEncoder::strict`] and
+    /// [`NullTerminatedDecoder::strict`].
+    ///
+    /// [`NullTerminatedEncoder::strict`]: self::NullTerminatedEncoder::strict
+    /// [`NullTerminatedDecoder::strict`]: self::NullTerminatedDecoder::strict
+    EmbeddedNull,
+}
+
+impl fmt::Display for NullTerminatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unterminated => write!(f, "the string is not terminated by a null byte."),
+            Self::EmbeddedNull => write!(f, "a null byte inside the string."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NullTerminatedError {}
+
+/// An encoder for null-terminated strings, emits a null byte when the input ends.
+///
+/// Null bytes in the input are passed through by default, and truncate the string for C
+/// functions. [`strict`] rejects them instead.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::null_term::{NullTerminatedEncoder, NullTerminatedError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(*b"foo", NullTerminatedEncoder::new());
+/// assert_eq!(Ok(b"foo\0".to_vec()), encoded.collect());
+///
+/// let mut encoded = ConvertedIterator::new(*b"a\0b", NullTerminatedEncoder::strict());
+/// assert_eq!(Some(Ok(b'a')), encoded.next());
+/// assert_eq!(Some(Err(NullTerminatedError::EmbeddedNull)), encoded.next());
+/// assert_eq!(Some(Ok(b'b')), encoded.next());
+/// assert_eq!(Some(Ok(b'\0')), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+///
+/// [`strict`]: Self::strict
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NullTerminatedEncoder {
+    strict: bool,
+}
+
+impl NullTerminatedEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance, which rejects null bytes in the input.
+    #[inline]
+    pub fn strict() -> Self {
+        Self { strict: true }
+    }
+}
+
+impl Converter for NullTerminatedEncoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = NullTerminatedError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.strict && item == 0 {
+            return Err(NullTerminatedError::EmbeddedNull);
+        }
+        buf.extend([item]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([0]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A decoder for null-terminated strings, emits bytes before the first null byte.
+///
+/// The converter ends at the null byte (see [`is_ended`]), so bytes after it are left in the
+/// input, or passed to the next converter of [`chain`]. [`strict`] requires the null byte to be
+/// the last one instead, and reports bytes after it as an embedded null.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::null_term::{NullTerminatedDecoder, NullTerminatedError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut iter = b"foo\0bar".iter().cloned();
+/// let decoded = ConvertedIterator::new(&mut iter, NullTerminatedDecoder::new());
+/// assert_eq!(Ok(b"foo".to_vec()), decoded.collect());
+/// assert_eq!(b"bar".to_vec(), iter.collect::<Vec<_>>());
+///
+/// let decoded = ConvertedIterator::new(*b"foo", NullTerminatedDecoder::new());
+/// assert_eq!(Err(NullTerminatedError::Unterminated), decoded.collect::<Result<Vec<_>, _>>());
+///
+/// let decoded = ConvertedIterator::new(*b"foo\0bar\0", NullTerminatedDecoder::strict());
+/// assert_eq!(Err(NullTerminatedError::EmbeddedNull), decoded.collect::<Result<Vec<_>, _>>());
+/// ```
+///
+/// [`is_ended`]: crate::Converter::is_ended
+/// [`chain`]: crate::Converter::chain
+/// [`strict`]: Self::strict
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NullTerminatedDecoder {
+    strict: bool,
+    terminated: bool,
+    embedded: bool,
+}
+
+impl NullTerminatedDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance, which rejects bytes after the null byte.
+    #[inline]
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Converter for NullTerminatedDecoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = NullTerminatedError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.terminated {
+            // only reachable in strict mode: the previous null byte was an embedded one.
+            if self.embedded {
+                Ok(0)
+            } else {
+                self.embedded = true;
+                Err(NullTerminatedError::EmbeddedNull)
+            }
+        } else if item == 0 {
+            self.terminated = true;
+            Ok(0)
+        } else {
+            buf.extend([item]);
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.terminated && !self.strict
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        if self.terminated {
+            Ok(())
+        } else {
+            Err(NullTerminatedError::Unterminated)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let encoded = ConvertedIterator::new(b"".iter().cloned(), NullTerminatedEncoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(b"\0".to_vec()), encoded);
+
+        let strings: [&[u8]; 3] = [b"foo", b"", b"bar baz"];
+        let encoded = strings
+            .iter()
+            .flat_map(|s| ConvertedIterator::new(s.iter().cloned(), NullTerminatedEncoder::new()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(b"foo\0\0bar baz\0".to_vec(), encoded);
+
+        let mut iter = encoded.into_iter();
+        for s in strings {
+            let decoded = ConvertedIterator::new(&mut iter, NullTerminatedDecoder::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(s.to_vec()), decoded);
+        }
+        let decoded = ConvertedIterator::new(&mut iter, NullTerminatedDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Err(NullTerminatedError::Unterminated), decoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn size_hint_with_finish() {
+        use crate::iter::ConvertedIterator;
+
+        // the terminator stored by `finish` is counted.
+        let mut iter = ConvertedIterator::new("".bytes(), NullTerminatedEncoder::new());
+        assert_eq!((0, Some(1)), iter.size_hint());
+        assert_eq!(Some(Ok(0)), iter.next());
+        assert_eq!((0, Some(0)), iter.size_hint());
+        assert_eq!(None, iter.next());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn strict() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut encoder = NullTerminatedEncoder::strict();
+        let mut buf = Vec::new();
+        assert_eq!(Ok(1), encoder.convert(b'a', &mut buf));
+        assert_eq!(
+            Err(NullTerminatedError::EmbeddedNull),
+            encoder.convert(0, &mut buf)
+        );
+        assert_eq!(Ok(1), encoder.finish(&mut buf));
+        assert_eq!(vec![b'a', 0], buf);
+
+        let mut decoder = NullTerminatedDecoder::strict();
+        let mut buf = Vec::new();
+        assert_eq!(Ok(1), decoder.convert(b'a', &mut buf));
+        assert_eq!(Ok(0), decoder.convert(0, &mut buf));
+        assert!(!decoder.is_ended());
+        assert_eq!(
+            Err(NullTerminatedError::EmbeddedNull),
+            decoder.convert(b'b', &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(0, &mut buf));
+        assert_eq!(Ok(()), decoder.finalize());
+        assert_eq!(vec![b'a'], buf);
+    }
+}
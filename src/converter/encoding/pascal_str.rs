@@ -0,0 +1,240 @@
+//! Pascal-style (length-prefixed) string Encoder/Decoder.
+
+use crate::Converter;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// An error while encoding/decoding Pascal-style strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PascalStringError {
+    /// A string longer than 255 bytes, with its length.
+    TooLong(usize),
+    /// A string which is not valid UTF-8.
+    InvalidUTF8,
+    /// The input ended inside a string.
+    Truncated,
+}
+
+impl fmt::Display for PascalStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong(len) => write!(f, "the string is too long: {} bytes", len),
+            Self::InvalidUTF8 => write!(f, "invalid UTF-8 string."),
+            Self::Truncated => write!(f, "the input ended inside a string."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PascalStringError {}
+
+/// An encoder for Pascal-style strings, emits a length byte and UTF-8 bytes of each string.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::pascal_str::{PascalStringEncoder, PascalStringError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = ["foo", "", "ß"].into_iter();
+/// let encoded = ConvertedIterator::new(iter, PascalStringEncoder::new());
+/// assert_eq!(Ok(b"\x03foo\x00\x02\xc3\x9f".to_vec()), encoded.collect());
+///
+/// let long = "a".repeat(256);
+/// let mut encoded = ConvertedIterator::new([long].into_iter(), PascalStringEncoder::new());
+/// assert_eq!(Some(Err(PascalStringError::TooLong(256))), encoded.next());
+/// ```
+pub struct PascalStringEncoder<S> {
+    _phantomi: PhantomData<S>,
+}
+
+impl<S> Clone for PascalStringEncoder<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for PascalStringEncoder<S> {}
+
+impl<S> fmt::Debug for PascalStringEncoder<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PascalStringEncoder").finish()
+    }
+}
+
+impl<S> Default for PascalStringEncoder<S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            _phantomi: PhantomData,
+        }
+    }
+}
+
+impl<S> PartialEq for PascalStringEncoder<S> {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<S> Eq for PascalStringEncoder<S> {}
+
+impl<S> PascalStringEncoder<S> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<S: AsRef<str>> Converter for PascalStringEncoder<S> {
+    type Item = S;
+    type Output = u8;
+    type Error = PascalStringError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let bytes = item.as_ref().as_bytes();
+        if bytes.len() > u8::MAX as usize {
+            return Err(PascalStringError::TooLong(bytes.len()));
+        }
+        buf.extend([bytes.len() as u8]);
+        buf.extend(bytes.iter().cloned());
+        Ok(bytes.len() + 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(256))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum State {
+    WaitingForLength,
+    ReadingBytes { remaining: u8, buf: Vec<u8> },
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::WaitingForLength
+    }
+}
+
+/// A decoder for Pascal-style strings.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::pascal_str::{PascalStringDecoder, PascalStringError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\x03foo\x00\x02\xc3\x9f\x02a".iter().cloned();
+/// let mut decoded = ConvertedIterator::new(iter, PascalStringDecoder::new());
+///
+/// assert_eq!(Some(Ok(String::from("foo"))), decoded.next());
+/// assert_eq!(Some(Ok(String::new())), decoded.next());
+/// assert_eq!(Some(Ok(String::from("ß"))), decoded.next());
+/// assert_eq!(Some(Err(PascalStringError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PascalStringDecoder {
+    state: State,
+}
+
+impl PascalStringDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for PascalStringDecoder {
+    type Item = u8;
+    type Output = String;
+    type Error = PascalStringError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match &mut self.state {
+            State::WaitingForLength if item == 0 => {
+                buf.extend([String::new()]);
+                Ok(1)
+            }
+            State::WaitingForLength => {
+                self.state = State::ReadingBytes {
+                    remaining: item,
+                    buf: Vec::with_capacity(item as usize),
+                };
+                Ok(0)
+            }
+            State::ReadingBytes {
+                remaining,
+                buf: bytes,
+            } => {
+                bytes.push(item);
+                *remaining -= 1;
+                if *remaining > 0 {
+                    return Ok(0);
+                }
+
+                let bytes = core::mem::take(bytes);
+                self.state = State::WaitingForLength;
+                let s = String::from_utf8(bytes).map_err(|_| PascalStringError::InvalidUTF8)?;
+                buf.extend([s]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::WaitingForLength => Ok(()),
+            State::ReadingBytes { .. } => Err(PascalStringError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+
+    #[test]
+    fn round_trip() {
+        let strings = ["", "a", "straße", "💣💣💣", &"x".repeat(255)]
+            .iter()
+            .map(|s| String::from(*s))
+            .collect::<Vec<_>>();
+        let encoded = ConvertedIterator::new(strings.iter(), PascalStringEncoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let decoded = ConvertedIterator::new(encoded, PascalStringDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(strings), decoded);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut decoded = ConvertedIterator::new(*b"\x02\xc3\x28\x01a", PascalStringDecoder::new());
+        assert_eq!(Some(Err(PascalStringError::InvalidUTF8)), decoded.next());
+        assert_eq!(Some(Ok(String::from("a"))), decoded.next());
+        assert_eq!(None, decoded.next());
+    }
+}
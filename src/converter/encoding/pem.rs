@@ -0,0 +1,466 @@
+//! PEM Encoder/Decoder, described in [RFC 7468].
+//!
+//! A PEM block is Base64 wrapped at 64 columns, between `-----BEGIN <label>-----` and
+//! `-----END <label>-----` lines.
+//!
+//! [RFC 7468]: https://www.rfc-editor.org/rfc/rfc7468
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+
+use super::base64::{Base64DecodeError, Base64Decoder};
+use super::mime_base64::MimeBase64Encoder;
+use crate::Converter;
+
+// the maximum length of lines starting with `-`, which are buffered as markers.
+const MAX_MARKER_LEN: usize = 256;
+
+/// An error while decoding PEM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PemError {
+    /// An invalid Base64 body. (offsets are counted in the bodies)
+    Base64(Base64DecodeError),
+    /// An END line whose label differs from the BEGIN line, and the label of the END line.
+    LabelMismatch(String),
+    /// A malformed marker line in a block, or text outside blocks in the strict mode, and the
+    /// offset of the line.
+    InvalidLine(usize),
+    /// The input ended inside a block.
+    Unterminated,
+}
+
+impl fmt::Display for PemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64(e) => write!(f, "{}", e),
+            Self::LabelMismatch(label) => write!(f, "mismatched END label: {:?}", label),
+            Self::InvalidLine(offset) => write!(f, "invalid line in PEM at {}.", offset),
+            Self::Unterminated => write!(f, "the PEM block is not terminated by an END line."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PemError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Base64(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// parses `-----<kind> <label>-----`, with trailing whitespace.
+fn parse_marker<'a>(line: &'a [u8], kind: &str) -> Option<&'a str> {
+    let line = line.trim_ascii_end();
+    let label = line
+        .strip_prefix(b"-----")?
+        .strip_prefix(kind.as_bytes())?
+        .strip_prefix(b" ")?
+        .strip_suffix(b"-----")?;
+    if label.iter().all(|&b| (b' '..=b'~').contains(&b)) {
+        core::str::from_utf8(label).ok()
+    } else {
+        None
+    }
+}
+
+/// An encoder for PEM, which encodes the whole input into a block.
+///
+/// The BEGIN line is emitted before the first line of the body, and the END line at the end of
+/// the input. The encoder can be reused for the next block after [`finish`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::pem::PemEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(*b"hello, world", PemEncoder::new("MESSAGE"));
+/// assert_eq!(
+///     Ok(String::from("-----BEGIN MESSAGE-----\naGVsbG8sIHdvcmxk\n-----END MESSAGE-----\n")),
+///     encoded.map(|b| b.map(char::from)).collect(),
+/// );
+/// ```
+///
+/// [`finish`]: crate::Converter::finish
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PemEncoder {
+    label: String,
+    inner: MimeBase64Encoder,
+    // whether the BEGIN line has been emitted.
+    started: bool,
+}
+
+impl PemEncoder {
+    /// Create a new instance with a label, like `CERTIFICATE`.
+    #[inline]
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: String::from(label),
+            inner: MimeBase64Encoder::pem(),
+            started: false,
+        }
+    }
+
+    /// Returns the label.
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn marker<E>(&self, kind: &str, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        let parts = [
+            b"-----",
+            kind.as_bytes(),
+            b" ",
+            self.label.as_bytes(),
+            b"-----\n",
+        ];
+        buf.extend(parts.iter().flat_map(|part| part.iter().cloned()));
+        parts.iter().map(|part| part.len()).sum()
+    }
+}
+
+impl Converter for PemEncoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = if self.started {
+            0
+        } else {
+            self.started = true;
+            self.marker("BEGIN", buf)
+        };
+        Ok(len + self.inner.convert(item, buf)?)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = if core::mem::take(&mut self.started) {
+            0
+        } else {
+            self.marker("BEGIN", buf)
+        };
+        len += self.inner.finish(buf)?;
+        Ok(len + self.marker("END", buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Line {
+    #[default]
+    Start,
+    // a line starting with `-`, buffered.
+    Marker,
+    // a line of the body.
+    Body,
+    // a line outside blocks.
+    Text,
+    // the rest of an invalid line.
+    Skip,
+}
+
+/// A decoder for PEM, which decodes bodies of blocks.
+///
+/// Text outside blocks, like explanations before the BEGIN line, is ignored unless [`strict`].
+/// The label of the current (or the last) block is available by [`label`]. After an END line, the
+/// decoder looks for the next BEGIN line, so bodies of multiple blocks are emitted one after
+/// another.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::pem::{PemDecoder, PemError};
+/// use conversion::Converter;
+///
+/// let text = b"Message:\n-----BEGIN MESSAGE-----\naGVsbG8sIHdvcmxk\n-----END MESSAGE-----\n";
+/// let mut decoder = PemDecoder::new();
+/// let mut buf = Vec::new();
+/// for &b in text.iter() {
+///     decoder.convert(b, &mut buf).unwrap();
+/// }
+/// decoder.finish(&mut buf).unwrap();
+/// assert_eq!(b"hello, world".to_vec(), buf);
+/// assert_eq!(Some("MESSAGE"), decoder.label());
+///
+/// let mut decoder = PemDecoder::new().strict(true);
+/// assert_eq!(Err(PemError::InvalidLine(0)), decoder.convert(b'M', &mut buf));
+/// ```
+///
+/// [`strict`]: Self::strict
+/// [`label`]: Self::label
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PemDecoder {
+    strict: bool,
+    inner: Base64Decoder,
+    label: Option<String>,
+    in_block: bool,
+    line: Line,
+    marker: Vec<u8>,
+    // the offset of the next byte, and of the start of the current line.
+    offset: usize,
+    line_start: usize,
+}
+
+impl PemDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting whether text outside blocks is rejected.
+    #[inline]
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    /// Returns the label of the current block, or the last block.
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn invalid_line(&mut self) -> Result<usize, PemError> {
+        self.line = Line::Skip;
+        Err(PemError::InvalidLine(self.line_start))
+    }
+
+    fn end_line<E>(&mut self, buf: &mut E) -> Result<usize, PemError>
+    where
+        E: Extend<u8>,
+    {
+        if core::mem::take(&mut self.line) != Line::Marker {
+            return Ok(0);
+        }
+        let marker = core::mem::take(&mut self.marker);
+        if !self.in_block {
+            return match parse_marker(&marker, "BEGIN") {
+                Some(label) => {
+                    self.label = Some(String::from(label));
+                    self.in_block = true;
+                    Ok(0)
+                }
+                None if self.strict => Err(PemError::InvalidLine(self.line_start)),
+                None => Ok(0),
+            };
+        }
+
+        match parse_marker(&marker, "END") {
+            Some(label) if Some(label) == self.label.as_deref() => {
+                self.in_block = false;
+                // the Base64 decoder rejects data after padding, so it is renewed for each block.
+                core::mem::take(&mut self.inner)
+                    .finish(buf)
+                    .map_err(PemError::Base64)
+            }
+            Some(label) => {
+                self.in_block = false;
+                self.inner = Base64Decoder::new();
+                Err(PemError::LabelMismatch(String::from(label)))
+            }
+            None => Err(PemError::InvalidLine(self.line_start)),
+        }
+    }
+}
+
+impl Converter for PemDecoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = PemError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let offset = self.offset;
+        self.offset += 1;
+        if item == b'\n' {
+            return self.end_line(buf);
+        }
+
+        if self.line == Line::Start {
+            self.line_start = offset;
+            self.line = match item {
+                b'-' => Line::Marker,
+                _ if self.in_block => Line::Body,
+                _ => Line::Text,
+            };
+        }
+        match self.line {
+            Line::Marker if self.marker.len() >= MAX_MARKER_LEN => {
+                self.marker.clear();
+                self.invalid_line()
+            }
+            Line::Marker => {
+                self.marker.push(item);
+                Ok(0)
+            }
+            Line::Body => match item {
+                b'\r' | b'\t' | b' ' => Ok(0),
+                _ => self.inner.convert(item, buf).map_err(PemError::Base64),
+            },
+            Line::Text if self.strict && !item.is_ascii_whitespace() => self.invalid_line(),
+            _ => Ok(0),
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        // the last line may not be terminated.
+        let len = self.end_line(buf)?;
+        if core::mem::take(&mut self.in_block) {
+            self.inner = Base64Decoder::new();
+            Err(PemError::Unterminated)
+        } else {
+            Ok(len)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(3))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random_bytes;
+
+        // about the size of a certificate.
+        let payload = random_bytes(1200);
+        let encoded =
+            ConvertedIterator::new(payload.iter().cloned(), PemEncoder::new("CERTIFICATE"))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        let text = core::str::from_utf8(&encoded).unwrap();
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!("-----BEGIN CERTIFICATE-----", lines[0]);
+        assert_eq!("-----END CERTIFICATE-----", lines[lines.len() - 1]);
+        // 1200 bytes are encoded into 1600 characters.
+        assert_eq!(2 + 25, lines.len());
+        assert!(lines[1..26].iter().all(|line| line.len() == 64));
+        assert!(text.ends_with('\n'));
+
+        let mut decoder = PemDecoder::new().strict(true);
+        let mut decoded = Vec::new();
+        for &b in encoded.iter() {
+            decoder.convert(b, &mut decoded).unwrap();
+        }
+        decoder.finish(&mut decoded).unwrap();
+        assert_eq!(payload, decoded);
+        assert_eq!(Some("CERTIFICATE"), decoder.label());
+
+        // CRLF, and without the last line ending.
+        let crlf = text.trim_end().replace('\n', "\r\n");
+        let decoded = ConvertedIterator::new(crlf.bytes(), PemDecoder::new().strict(true))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(payload), decoded);
+    }
+
+    #[test]
+    fn multiple_blocks() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random_bytes;
+        use alloc::vec;
+
+        let key = random_bytes(100);
+        let cert = random_bytes(300);
+        let mut file = b"Subject: example\n\n".to_vec();
+        let mut encoder = PemEncoder::new("PRIVATE KEY");
+        for &b in key.iter() {
+            encoder.convert(b, &mut file).unwrap();
+        }
+        encoder.finish(&mut file).unwrap();
+        file.extend(b"\n");
+        file.extend(
+            ConvertedIterator::new(cert.iter().cloned(), PemEncoder::new("CERTIFICATE"))
+                .map(Result::unwrap),
+        );
+
+        // bodies of blocks are split by labels.
+        let mut decoder = PemDecoder::new();
+        let mut blocks: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut buf = Vec::new();
+        for &b in file.iter() {
+            decoder.convert(b, &mut buf).unwrap();
+            if let Some(label) = decoder.label() {
+                if blocks.last().map(|(last, _)| last.as_str()) != Some(label) {
+                    blocks.push((String::from(label), Vec::new()));
+                }
+                blocks.last_mut().unwrap().1.append(&mut buf);
+            }
+        }
+        assert_eq!(Ok(0), decoder.finish(&mut buf));
+        assert_eq!(
+            vec![
+                (String::from("PRIVATE KEY"), key),
+                (String::from("CERTIFICATE"), cert),
+            ],
+            blocks
+        );
+
+        // text between blocks.
+        let result = ConvertedIterator::new(file.iter().cloned(), PemDecoder::new().strict(true))
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Err(PemError::InvalidLine(0)), result);
+    }
+
+    #[test]
+    fn errors() {
+        let inputs: [(&[u8], PemError); 4] = [
+            (
+                b"-----BEGIN A-----\nAAAA\n-----END B-----\n",
+                PemError::LabelMismatch(String::from("B")),
+            ),
+            (b"-----BEGIN A-----\nAAAA\n", PemError::Unterminated),
+            (b"-----BEGIN A-----\n-----\n", PemError::InvalidLine(18)),
+            (
+                b"-----BEGIN A-----\nAA-A\n",
+                PemError::Base64(Base64DecodeError::InvalidByte(b'-', 2)),
+            ),
+        ];
+        for (input, err) in inputs {
+            let mut decoder = PemDecoder::new();
+            let mut buf = Vec::new();
+            let result = input
+                .iter()
+                .try_for_each(|&b| decoder.convert(b, &mut buf).map(|_| ()))
+                .and_then(|_| decoder.finish(&mut buf).map(|_| ()));
+            assert_eq!(Err(err), result);
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Phonetic codes of English words, Soundex and Metaphone.
+//!
+//! Characters of a word are consumed until the end of the input, and the code is emitted at
+//! [`finish`]. Characters other than ASCII letters are ignored.
+//!
+//! [`finish`]: crate::Converter::finish
+
+use crate::Converter;
+use core::fmt;
+
+/// An error for words without ASCII letters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhoneticError {
+    /// The word has no ASCII letters.
+    Empty,
+}
+
+impl fmt::Display for PhoneticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the word has no letters."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PhoneticError {}
+
+// the Soundex digit of an uppercase letter, `0` for vowels and `None` for `H` and `W`.
+fn soundex_digit(c: u8) -> Option<u8> {
+    Some(match c {
+        b'B' | b'F' | b'P' | b'V' => 1,
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => 2,
+        b'D' | b'T' => 3,
+        b'L' => 4,
+        b'M' | b'N' => 5,
+        b'R' => 6,
+        b'H' | b'W' => return None,
+        _ => 0,
+    })
+}
+
+/// A converter computing the American Soundex code of a word, like `R163` for "Robert".
+///
+/// The code is computed on the fly, without buffering the word.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::phonetics::{PhoneticError, SoundexConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let code = ConvertedIterator::new("Ashcraft".chars(), SoundexConverter::new());
+/// assert_eq!(Ok(String::from("A261")), code.collect());
+///
+/// let code = ConvertedIterator::new("Lee".chars(), SoundexConverter::new());
+/// assert_eq!(Ok(String::from("L000")), code.collect());
+///
+/// let mut code = ConvertedIterator::new("123".chars(), SoundexConverter::new());
+/// assert_eq!(Some(Err(PhoneticError::Empty)), code.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SoundexConverter {
+    first: Option<u8>,
+    // the digit of the last letter, except `H` and `W`.
+    last: u8,
+    digits: [u8; 3],
+    len: usize,
+}
+
+impl SoundexConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for SoundexConverter {
+    type Item = char;
+    type Output = char;
+    type Error = PhoneticError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if !item.is_ascii_alphabetic() {
+            return Ok(0);
+        }
+        let c = item.to_ascii_uppercase() as u8;
+        let digit = match soundex_digit(c) {
+            Some(digit) => digit,
+            // `H` and `W` do not separate letters with the same digit.
+            None if self.first.is_some() => return Ok(0),
+            None => 0,
+        };
+        if self.first.is_none() {
+            self.first = Some(c);
+        } else if digit != 0 && digit != self.last && self.len < 3 {
+            self.digits[self.len] = digit;
+            self.len += 1;
+        }
+        self.last = digit;
+        Ok(0)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let Self { first, digits, .. } = core::mem::take(self);
+        let first = first.ok_or(PhoneticError::Empty)?;
+        buf.extend([first as char]);
+        buf.extend(digits.iter().map(|&d| (b'0' + d) as char));
+        Ok(4)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+/// A converter computing the Metaphone code of a word, like `SKMTT` for "Schmidt".
+///
+/// This is the original Metaphone by Lawrence Philips, with the rules of Apache Commons Codec,
+/// and the code is not truncated.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::phonetics::{MetaphoneConverter, PhoneticError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let code = ConvertedIterator::new("Knight".chars(), MetaphoneConverter::new());
+/// assert_eq!(Ok(String::from("NT")), code.collect());
+///
+/// let code = ConvertedIterator::new("Thompson".chars(), MetaphoneConverter::new());
+/// assert_eq!(Ok(String::from("0MPSN")), code.collect());
+///
+/// let mut code = ConvertedIterator::new("".chars(), MetaphoneConverter::new());
+/// assert_eq!(Some(Err(PhoneticError::Empty)), code.next());
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MetaphoneConverter {
+    word: alloc::vec::Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl MetaphoneConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// the Metaphone code of an uppercase word.
+#[cfg(feature = "alloc")]
+fn metaphone<E: Extend<char>>(w: &[u8], buf: &mut E) -> usize {
+    let at = |i: usize| w.get(i).copied().unwrap_or(0);
+    let is_vowel = |i: usize| matches!(at(i), b'A' | b'E' | b'I' | b'O' | b'U');
+    let is_front = |i: usize| matches!(at(i), b'E' | b'I' | b'Y');
+    let matches = |i: usize, s: &[u8]| w[i..].starts_with(s);
+    let last = w.len() - 1;
+
+    let mut len = 0;
+    let mut push = |code: &str| {
+        buf.extend(code.chars());
+        len += code.len();
+    };
+
+    // initial exceptions.
+    let mut i = match (at(0), at(1)) {
+        (b'A', b'E') => {
+            push("E");
+            2
+        }
+        (b'G' | b'K' | b'P', b'N') => {
+            push("N");
+            2
+        }
+        (b'W', b'R') => {
+            push("R");
+            2
+        }
+        (b'W', b'H') => {
+            push("W");
+            2
+        }
+        (b'X', _) => {
+            push("S");
+            1
+        }
+        (b'A' | b'E' | b'I' | b'O' | b'U', _) => {
+            push(core::str::from_utf8(&w[..1]).unwrap());
+            1
+        }
+        _ => 0,
+    };
+
+    while i < w.len() {
+        let c = w[i];
+        // adjacent same letters are coded once, except `C`.
+        if c != b'C' && i > 0 && at(i - 1) == c {
+            i += 1;
+            continue;
+        }
+        match c {
+            b'B' if !(i == last && i > 0 && at(i - 1) == b'M') => push("B"),
+            b'C' => {
+                if i > 0 && at(i - 1) == b'S' && is_front(i + 1) {
+                    // silent in `SCI`, `SCE` and `SCY`.
+                } else if matches(i, b"CIA") {
+                    push("X");
+                } else if is_front(i + 1) {
+                    push("S");
+                } else if i > 0 && at(i - 1) == b'S' && at(i + 1) == b'H' {
+                    push("K");
+                } else if at(i + 1) == b'H' {
+                    push(if i == 0 && w.len() >= 3 && is_vowel(2) {
+                        "K"
+                    } else {
+                        "X"
+                    });
+                } else {
+                    push("K");
+                }
+            }
+            b'D' if at(i + 1) == b'G' && is_front(i + 2) => {
+                push("J");
+                i += 2;
+            }
+            b'D' => push("T"),
+            b'G' => {
+                let silent = (i + 1 == last && at(i + 1) == b'H')
+                    || (i + 2 < w.len() && at(i + 1) == b'H' && !is_vowel(i + 2))
+                    || (i > 0 && matches(i, b"GN"));
+                let hard = i > 0 && at(i - 1) == b'G';
+                if !silent {
+                    push(if is_front(i + 1) && !hard { "J" } else { "K" });
+                }
+            }
+            b'H' => {
+                let after_varson = i > 0 && matches!(at(i - 1), b'C' | b'S' | b'P' | b'T' | b'G');
+                if i != last && !after_varson && is_vowel(i + 1) {
+                    push("H");
+                }
+            }
+            b'K' if !(i > 0 && at(i - 1) == b'C') => push("K"),
+            b'P' if at(i + 1) == b'H' => push("F"),
+            b'P' => push("P"),
+            b'Q' => push("K"),
+            b'S' if matches(i, b"SH") || matches(i, b"SIO") || matches(i, b"SIA") => push("X"),
+            b'S' => push("S"),
+            b'T' if matches(i, b"TIA") || matches(i, b"TIO") => push("X"),
+            b'T' if matches(i, b"TCH") => {}
+            b'T' if at(i + 1) == b'H' => push("0"),
+            b'T' => push("T"),
+            b'V' => push("F"),
+            b'W' | b'Y' if is_vowel(i + 1) => push(if c == b'W' { "W" } else { "Y" }),
+            b'X' => push("KS"),
+            b'Z' => push("S"),
+            b'F' => push("F"),
+            b'J' => push("J"),
+            b'L' => push("L"),
+            b'M' => push("M"),
+            b'N' => push("N"),
+            b'R' => push("R"),
+            // vowels except the first letter, and silent letters.
+            _ => {}
+        }
+        i += 1;
+    }
+    len
+}
+
+#[cfg(feature = "alloc")]
+impl Converter for MetaphoneConverter {
+    type Item = char;
+    type Output = char;
+    type Error = PhoneticError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item.is_ascii_alphabetic() {
+            self.word.push(item.to_ascii_uppercase() as u8);
+        }
+        Ok(0)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let word = core::mem::take(&mut self.word);
+        if word.is_empty() {
+            return Err(PhoneticError::Empty);
+        }
+        Ok(metaphone(&word, buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn soundex() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let vectors = [
+            ("Robert", "R163"),
+            ("Rupert", "R163"),
+            ("Rubin", "R150"),
+            ("Ashcroft", "A261"),
+            ("Tymczak", "T522"),
+            ("Pfister", "P236"),
+            ("Honeyman", "H555"),
+            ("O'Hara", "O600"),
+            ("Wu", "W000"),
+            ("  van Dyke ", "V532"),
+        ];
+        for (word, code) in vectors {
+            let computed = ConvertedIterator::new(word.chars(), SoundexConverter::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(code)), computed, "{:?}", word);
+        }
+
+        // the code is stored at the end.
+        let computed = ConvertedIterator::new("Robert".chars(), SoundexConverter::new());
+        assert_eq!((0, Some(4)), computed.size_hint());
+        assert_eq!(4, computed.count());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn metaphone() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let vectors = [
+            ("Knight", "NT"),
+            ("Wright", "RT"),
+            ("White", "WT"),
+            ("Xavier", "SFR"),
+            ("Aeon", "EN"),
+            ("Schmidt", "SKMTT"),
+            ("Thumb", "0M"),
+            ("Science", "SNS"),
+            ("Judge", "JJ"),
+            ("Character", "KRKTR"),
+            ("Church", "KRX"),
+            ("Nation", "NXN"),
+            ("Philip", "FLP"),
+            ("Gnome", "NM"),
+            ("Laugh", "L"),
+            ("Yellow", "YL"),
+            ("Ox", "OKS"),
+        ];
+        for (word, code) in vectors {
+            let computed = ConvertedIterator::new(word.chars(), MetaphoneConverter::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(code)), computed, "{:?}", word);
+        }
+
+        // the length of the code is unknown until the end.
+        let computed = ConvertedIterator::new("Knight".chars(), MetaphoneConverter::new());
+        assert_eq!((0, None), computed.size_hint());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn empty() {
+        use alloc::string::String;
+
+        let mut buf = String::new();
+        let mut soundex = SoundexConverter::new();
+        assert_eq!(Ok(0), soundex.convert('-', &mut buf));
+        assert_eq!(Err(PhoneticError::Empty), soundex.finish(&mut buf));
+        // the state is reset after each word.
+        assert_eq!(Ok(0), soundex.convert('a', &mut buf));
+        assert_eq!(Ok(4), soundex.finish(&mut buf));
+        assert_eq!(Err(PhoneticError::Empty), soundex.finish(&mut buf));
+
+        let mut metaphone = MetaphoneConverter::new();
+        assert_eq!(Ok(0), metaphone.convert('B', &mut buf));
+        assert_eq!(Ok(1), metaphone.finish(&mut buf));
+        assert_eq!(Err(PhoneticError::Empty), metaphone.finish(&mut buf));
+        assert_eq!("A000B", buf);
+    }
+}
@@ -0,0 +1,462 @@
+//! Protocol Buffers varint (unsigned LEB128) Encoder/Decoder.
+//!
+//! Signed values are mapped into unsigned values by ZigZag encoding (`0, -1, 1, -2, ...` to
+//! `0, 1, 2, 3, ...`) before encoding, like `sint64` fields. [`SignedVarintEncoder`] and
+//! [`SignedVarintDecoder`] do both at once.
+
+use crate::converter::PipedConverter;
+use crate::error::ChainedError;
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while decoding varints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProtoVarintError {
+    /// A varint longer than 10 bytes, or a value exceeding 64 bits.
+    Overflow,
+    /// The input ended inside a varint.
+    Truncated,
+}
+
+impl fmt::Display for ProtoVarintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "a varint exceeds 64 bits."),
+            Self::Truncated => write!(f, "the input ended inside a varint."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProtoVarintError {}
+
+/// An encoder for varints.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::{ProtoVarintEncoder, ZigZagEncoder};
+/// use conversion::iter::ConvertedIterator;
+/// use conversion::Converter;
+///
+/// let iter = [1, 150, u64::MAX].into_iter();
+/// let encoded = ConvertedIterator::new(iter, ProtoVarintEncoder::new());
+///
+/// assert_eq!(
+///     Ok(vec![0x01, 0x96, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]),
+///     encoded.collect()
+/// );
+///
+/// // signed values with ZigZag encoding.
+/// let iter = [-1i64, 1].into_iter();
+/// let conv = ZigZagEncoder::new().pipe(ProtoVarintEncoder::new());
+/// let encoded = ConvertedIterator::new(iter, conv);
+///
+/// assert_eq!(Ok(vec![0x01, 0x02]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtoVarintEncoder;
+
+impl ProtoVarintEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for ProtoVarintEncoder {
+    type Item = u64;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut bytes = [0; 10];
+        let mut value = item;
+        let mut len = 0;
+        loop {
+            bytes[len] = (value & 0x7F) as u8;
+            value >>= 7;
+            len += 1;
+            if value == 0 {
+                break;
+            }
+            bytes[len - 1] |= 0x80;
+        }
+        buf.extend(bytes[..len].iter().cloned());
+        Ok(len)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(10))
+    }
+}
+
+/// A decoder for varints.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::{ProtoVarintDecoder, ProtoVarintError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0x01, 0x96, 0x01, 0xAC].into_iter();
+/// let mut decoded = ConvertedIterator::new(iter, ProtoVarintDecoder::new());
+///
+/// assert_eq!(Some(Ok(1)), decoded.next());
+/// assert_eq!(Some(Ok(150)), decoded.next());
+/// assert_eq!(Some(Err(ProtoVarintError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtoVarintDecoder {
+    accumulator: u64,
+    shift: u8,
+}
+
+impl ProtoVarintDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for ProtoVarintDecoder {
+    type Item = u8;
+    type Output = u64;
+    type Error = ProtoVarintError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        // the 10th byte can only hold the most significant bit.
+        if self.shift == 63 && item & 0x7F > 1 {
+            *self = Self::default();
+            return Err(ProtoVarintError::Overflow);
+        }
+
+        self.accumulator |= ((item & 0x7F) as u64) << self.shift;
+        if item & 0x80 == 0 {
+            buf.extend([self.accumulator]);
+            *self = Self::default();
+            Ok(1)
+        } else if self.shift == 63 {
+            *self = Self::default();
+            Err(ProtoVarintError::Overflow)
+        } else {
+            self.shift += 7;
+            Ok(0)
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        if self.shift == 0 {
+            Ok(())
+        } else {
+            *self = Self::default();
+            Err(ProtoVarintError::Truncated)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A converter mapping signed values into unsigned values by ZigZag encoding.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::ZigZagEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0, -1, 1, -2, i64::MAX, i64::MIN].into_iter();
+/// let encoded = ConvertedIterator::new(iter, ZigZagEncoder::new());
+///
+/// assert_eq!(Ok(vec![0, 1, 2, 3, u64::MAX - 1, u64::MAX]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZigZagEncoder;
+
+impl ZigZagEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for ZigZagEncoder {
+    type Item = i64;
+    type Output = u64;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([((item << 1) ^ (item >> 63)) as u64]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for ZigZagEncoder {}
+
+impl ExactSizeConverter for ZigZagEncoder {}
+
+/// A converter mapping unsigned values into signed values by ZigZag decoding.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::ZigZagDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0, 1, 2, 3, u64::MAX - 1, u64::MAX].into_iter();
+/// let decoded = ConvertedIterator::new(iter, ZigZagDecoder::new());
+///
+/// assert_eq!(Ok(vec![0, -1, 1, -2, i64::MAX, i64::MIN]), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZigZagDecoder;
+
+impl ZigZagDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for ZigZagDecoder {
+    type Item = u64;
+    type Output = i64;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([(item >> 1) as i64 ^ -((item & 1) as i64)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for ZigZagDecoder {}
+
+impl ExactSizeConverter for ZigZagDecoder {}
+
+/// An encoder for signed varints, ZigZag encoding piped into [`ProtoVarintEncoder`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::SignedVarintEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [-1, 1, -64, 64].into_iter();
+/// let encoded = ConvertedIterator::new(iter, SignedVarintEncoder::new());
+///
+/// assert_eq!(Ok(vec![0x01, 0x02, 0x7F, 0x80, 0x01]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SignedVarintEncoder {
+    inner: PipedConverter<ZigZagEncoder, ProtoVarintEncoder>,
+}
+
+impl SignedVarintEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for SignedVarintEncoder {
+    type Item = i64;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.inner.convert(item, buf).map_err(|e| match e {
+            ChainedError::First(e) | ChainedError::Second(e) => e,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(10))
+    }
+}
+
+/// A decoder for signed varints, [`ProtoVarintDecoder`] piped into ZigZag decoding.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::proto_varint::{ProtoVarintError, SignedVarintDecoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0x01, 0x02, 0x7F, 0x80, 0x01, 0x80].into_iter();
+/// let mut decoded = ConvertedIterator::new(iter, SignedVarintDecoder::new());
+///
+/// assert_eq!(Some(Ok(-1)), decoded.next());
+/// assert_eq!(Some(Ok(1)), decoded.next());
+/// assert_eq!(Some(Ok(-64)), decoded.next());
+/// assert_eq!(Some(Ok(64)), decoded.next());
+/// assert_eq!(Some(Err(ProtoVarintError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SignedVarintDecoder {
+    inner: PipedConverter<ProtoVarintDecoder, ZigZagDecoder>,
+}
+
+impl SignedVarintDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for SignedVarintDecoder {
+    type Item = u8;
+    type Output = i64;
+    type Error = ProtoVarintError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.inner.convert(item, buf).map_err(|e| match e {
+            ChainedError::First(e) => e,
+            ChainedError::Second(e) => match e {},
+        })
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.inner.finalize().map_err(|e| match e {
+            ChainedError::First(e) => e,
+            ChainedError::Second(e) => match e {},
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let values = (0..64)
+            .flat_map(|i| [1u64 << i, (1u64 << i) - 1, (1u64 << i) + 1])
+            .chain([u64::MAX, 300, 0x7F, 0x80, 0x3FFF, 0x4000])
+            .collect::<Vec<_>>();
+        let encoded = ConvertedIterator::new(values.iter().cloned(), ProtoVarintEncoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let decoded = ConvertedIterator::new(encoded, ProtoVarintDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(values), decoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn overflow() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), ProtoVarintDecoder::new())
+                .collect::<Vec<_>>()
+        };
+        // 11 bytes.
+        assert_eq!(
+            vec![Err(ProtoVarintError::Overflow), Ok(0)],
+            decode(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x81, 0x00])
+        );
+        // 65 bits.
+        assert_eq!(
+            vec![Err(ProtoVarintError::Overflow)],
+            decode(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02])
+        );
+        // non-canonical, but valid.
+        assert_eq!(vec![Ok(1)], decode(&[0x81, 0x80, 0x00]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zigzag() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        // vectors from the Protocol Buffers encoding guide.
+        let vectors = [
+            (0, 0),
+            (-1, 1),
+            (1, 2),
+            (-2, 3),
+            (i32::MAX as i64, 0xFFFF_FFFE),
+            (i32::MIN as i64, 0xFFFF_FFFF),
+            (i64::MAX, u64::MAX - 1),
+            (i64::MIN, u64::MAX),
+        ];
+        for (signed, unsigned) in vectors {
+            let mut encoded = Vec::new();
+            let mut decoded = Vec::new();
+            assert_eq!(Ok(1), ZigZagEncoder::new().convert(signed, &mut encoded));
+            assert_eq!(Ok(1), ZigZagDecoder::new().convert(unsigned, &mut decoded));
+            assert_eq!((vec![unsigned], vec![signed]), (encoded, decoded));
+        }
+
+        let mut next = random();
+        let values = (0..1000)
+            .map(|_| {
+                let n = ((next() as u64) << 32 | next() as u64) as i64;
+                n >> (next() % 64)
+            })
+            .chain([0, -1, 1, i64::MAX, i64::MIN])
+            .collect::<Vec<_>>();
+        let encoded = ConvertedIterator::new(values.iter().cloned(), SignedVarintEncoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let decoded = ConvertedIterator::new(encoded, SignedVarintDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(values), decoded);
+    }
+}
@@ -0,0 +1,446 @@
+//! Punycode (RFC 3492) Encoder/Decoder.
+//!
+//! Because Punycode needs the whole label, converters in this module buffer all inputs and emit
+//! outputs when the input ends. The length of a label is limited to avoid unbounded memory use.
+//! (inputs exceeding the limit are discarded with [`PunycodeError::TooLong`])
+//!
+//! [`PunycodeError::TooLong`]: self::PunycodeError::TooLong
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// The default maximum length of labels.
+pub const DEFAULT_MAX_LEN: usize = 256;
+
+/// An error while encoding/decoding Punycode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// An invalid byte in the encoded string.
+    InvalidDigit(u8),
+    /// A decoded value which is not a valid character.
+    InvalidCodepoint(u32),
+    /// The encoded string ended inside a variable-length integer.
+    Truncated,
+    /// An arithmetic overflow.
+    Overflow,
+    /// A label longer than the limit.
+    TooLong,
+}
+
+impl fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(b) => write!(f, "invalid digit: {:#x}", b),
+            Self::InvalidCodepoint(v) => write!(f, "invalid character code: {:#x}", v),
+            Self::Truncated => write!(f, "the input ended unexpectedly."),
+            Self::Overflow => write!(f, "arithmetic overflow."),
+            Self::TooLong => write!(f, "the label is too long."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PunycodeError {}
+
+fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    delta += delta / points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        TMIN
+    } else if k >= bias + TMAX {
+        TMAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> u8 {
+    if d < 26 {
+        b'a' + d as u8
+    } else {
+        b'0' + (d - 26) as u8
+    }
+}
+
+fn decode_digit(b: u8) -> Option<u32> {
+    match b {
+        b'a'..=b'z' => Some((b - b'a') as u32),
+        b'A'..=b'Z' => Some((b - b'A') as u32),
+        b'0'..=b'9' => Some((b - b'0') as u32 + 26),
+        _ => None,
+    }
+}
+
+fn encode(input: &[char], output: &mut Vec<u8>) -> Result<(), PunycodeError> {
+    output.extend(input.iter().filter(|c| c.is_ascii()).map(|&c| c as u8));
+    let basic = output.len() as u32;
+    if basic > 0 {
+        output.push(b'-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic;
+    while (handled as usize) < input.len() {
+        let m = input
+            .iter()
+            .map(|&c| c as u32)
+            .filter(|&c| c >= n)
+            .min()
+            .unwrap();
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|d| d.checked_add(delta))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for c in input.iter().map(|&c| c as u32) {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            } else if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(())
+}
+
+fn decode(input: &[u8], output: &mut Vec<char>) -> Result<(), PunycodeError> {
+    let (basic, mut rest) = match input.iter().rposition(|&b| b == b'-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => (&input[..0], input),
+    };
+    for &b in basic {
+        if !b.is_ascii() {
+            return Err(PunycodeError::InvalidDigit(b));
+        }
+        output.push(b as char);
+    }
+
+    let mut n = INITIAL_N;
+    let mut i = 0u32;
+    let mut bias = INITIAL_BIAS;
+    while !rest.is_empty() {
+        let old = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let (&b, next) = rest.split_first().ok_or(PunycodeError::Truncated)?;
+            rest = next;
+            let digit = decode_digit(b).ok_or(PunycodeError::InvalidDigit(b))?;
+            i = digit
+                .checked_mul(w)
+                .and_then(|d| d.checked_add(i))
+                .ok_or(PunycodeError::Overflow)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old, len, old == 0);
+        n = n.checked_add(i / len).ok_or(PunycodeError::Overflow)?;
+        i %= len;
+        let c = char::from_u32(n).ok_or(PunycodeError::InvalidCodepoint(n))?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(())
+}
+
+/// An encoder for Punycode, emits an encoded label when the input ends.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::punycode::PunycodeEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new("bücher".chars(), PunycodeEncoder::new());
+///
+/// assert_eq!(Ok(b"bcher-kva".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PunycodeEncoder {
+    buffer: Vec<char>,
+    max_len: usize,
+    overflowed: bool,
+}
+
+impl Default for PunycodeEncoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_len: DEFAULT_MAX_LEN,
+            overflowed: false,
+        }
+    }
+}
+
+impl PunycodeEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limiting the number of characters in a label. (default: [`DEFAULT_MAX_LEN`])
+    ///
+    /// [`DEFAULT_MAX_LEN`]: self::DEFAULT_MAX_LEN
+    #[inline]
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl Converter for PunycodeEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = PunycodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.overflowed {
+            Ok(0)
+        } else if self.buffer.len() >= self.max_len {
+            self.buffer.clear();
+            self.overflowed = true;
+            Err(PunycodeError::TooLong)
+        } else {
+            self.buffer.push(item);
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let input = core::mem::take(&mut self.buffer);
+        self.overflowed = false;
+        let mut output = Vec::new();
+        encode(&input, &mut output)?;
+        let len = output.len();
+        buf.extend(output);
+        Ok(len)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A decoder for Punycode, emits a decoded label when the input ends.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::punycode::{PunycodeDecoder, PunycodeError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new(*b"bcher-kva", PunycodeDecoder::new());
+/// assert_eq!(Ok(String::from("bücher")), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new(*b"bcher-kv!", PunycodeDecoder::new());
+/// assert_eq!(Err(PunycodeError::InvalidDigit(b'!')), decoded.collect::<Result<String, _>>());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PunycodeDecoder {
+    buffer: Vec<u8>,
+    max_len: usize,
+    overflowed: bool,
+}
+
+impl Default for PunycodeDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_len: DEFAULT_MAX_LEN,
+            overflowed: false,
+        }
+    }
+}
+
+impl PunycodeDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limiting the number of bytes in a label. (default: [`DEFAULT_MAX_LEN`])
+    ///
+    /// [`DEFAULT_MAX_LEN`]: self::DEFAULT_MAX_LEN
+    #[inline]
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self { max_len, ..self }
+    }
+}
+
+impl Converter for PunycodeDecoder {
+    type Item = u8;
+    type Output = char;
+    type Error = PunycodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.overflowed {
+            Ok(0)
+        } else if self.buffer.len() >= self.max_len {
+            self.buffer.clear();
+            self.overflowed = true;
+            Err(PunycodeError::TooLong)
+        } else {
+            self.buffer.push(item);
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let input = core::mem::take(&mut self.buffer);
+        self.overflowed = false;
+        let mut output = Vec::new();
+        decode(&input, &mut output)?;
+        let len = output.len();
+        buf.extend(output);
+        Ok(len)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::string::String;
+
+    // samples from RFC 3492, section 7.1.
+    const SAMPLES: [(&str, &str); 8] = [
+        // (A) Arabic (Egyptian)
+        (
+            "\u{644}\u{64A}\u{647}\u{645}\u{627}\u{628}\u{62A}\u{643}\u{644}\u{645}\u{648}\u{634}\u{639}\u{631}\u{628}\u{64A}\u{61F}",
+            "egbpdaj6bu4bxfgehfvwxn",
+        ),
+        // (B) Chinese (simplified)
+        (
+            "\u{4ED6}\u{4EEC}\u{4E3A}\u{4EC0}\u{4E48}\u{4E0D}\u{8BF4}\u{4E2D}\u{6587}",
+            "ihqwcrb4cv8a8dqg056pqjye",
+        ),
+        // (C) Chinese (traditional)
+        (
+            "\u{4ED6}\u{5011}\u{7232}\u{4EC0}\u{9EBD}\u{4E0D}\u{8AAA}\u{4E2D}\u{6587}",
+            "ihqwctvzc91f659drss3x8bo0yb",
+        ),
+        // (E) Hebrew
+        (
+            "\u{5DC}\u{5DE}\u{5D4}\u{5D4}\u{5DD}\u{5E4}\u{5E9}\u{5D5}\u{5D8}\u{5DC}\u{5D0}\u{5DE}\u{5D3}\u{5D1}\u{5E8}\u{5D9}\u{5DD}\u{5E2}\u{5D1}\u{5E8}\u{5D9}\u{5EA}",
+            "4dbcagdahymbxekheh6e0a7fei0b",
+        ),
+        // (J) Spanish
+        (
+            "Porqu\u{E9}nopuedensimplementehablarenEspa\u{F1}ol",
+            "PorqunopuedensimplementehablarenEspaol-fmd56a",
+        ),
+        // (L) 3<nen>B<gumi><kinpachi><sensei>
+        (
+            "3\u{5E74}B\u{7D44}\u{91D1}\u{516B}\u{5148}\u{751F}",
+            "3B-ww4c5e180e575a65lsy2b",
+        ),
+        // (Q) <amuro><namie>-with-SUPER-MONKEYS
+        (
+            "\u{5B89}\u{5BA4}\u{5948}\u{7F8E}\u{6075}-with-SUPER-MONKEYS",
+            "-with-SUPER-MONKEYS-pc58ag80a8qai00g7n9n",
+        ),
+        // (S) -> $1.00 <-
+        ("-> $1.00 <-", "-> $1.00 <--"),
+    ];
+
+    #[test]
+    fn samples() {
+        for (decoded, encoded) in SAMPLES {
+            let output = ConvertedIterator::new(decoded.chars(), PunycodeEncoder::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(encoded.as_bytes(), output);
+
+            let output = ConvertedIterator::new(encoded.bytes(), PunycodeDecoder::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(decoded)), output);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let decode = |s: &[u8]| {
+            ConvertedIterator::new(s.iter().cloned(), PunycodeDecoder::new().with_max_len(16))
+                .collect::<Result<String, _>>()
+        };
+        assert_eq!(Err(PunycodeError::Truncated), decode(b"bcher-kv"));
+        assert_eq!(Err(PunycodeError::InvalidDigit(0xC3)), decode(b"b\xC3-kva"));
+        assert_eq!(Err(PunycodeError::Overflow), decode(b"99999999999"));
+        assert_eq!(
+            Err(PunycodeError::InvalidCodepoint(0xD800)),
+            decode(b"ib9b")
+        );
+        assert_eq!(Err(PunycodeError::TooLong), decode(&[b'a'; 17]));
+        assert_eq!(
+            Ok(String::from("aaaaaaaaaaaaaaa")),
+            decode(b"aaaaaaaaaaaaaaa-")
+        );
+
+        let mut encoded = ConvertedIterator::new(
+            "bücherbücher".chars(),
+            PunycodeEncoder::new().with_max_len(6),
+        );
+        assert_eq!(Some(Err(PunycodeError::TooLong)), encoded.next());
+        assert_eq!(None, encoded.next());
+    }
+}
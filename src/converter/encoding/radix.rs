@@ -0,0 +1,430 @@
+//! Digits in arbitrary radixes from 2 to 36, like base-36 identifiers or octal permissions.
+//!
+//! [`DigitDecoder`] and [`DigitEncoder`] convert between digit characters (`0`-`9` and `a`-`z`)
+//! and their values, and [`NumberAccumulator`] folds digit values into an integer.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::encoding::radix::{DigitDecoder, NumberAccumulator};
+//! use conversion::iter::ConvertedIterator;
+//! use conversion::Converter;
+//!
+//! let parser = DigitDecoder::new(36).pipe(NumberAccumulator::<u32>::new(36));
+//! let parsed = ConvertedIterator::new("Zz9".chars(), parser);
+//! assert_eq!(Ok(vec![46_629]), parsed.collect());
+//! ```
+
+use crate::Converter;
+use core::fmt;
+
+/// An error while converting digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RadixError {
+    /// A character which is not a digit in the radix.
+    InvalidDigit(char),
+    /// A value which is not less than the radix.
+    InvalidValue(u8),
+    /// A number which does not fit in the integer type.
+    Overflow,
+}
+
+impl fmt::Display for RadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(c) => write!(f, "invalid digit: {:?}", c),
+            Self::InvalidValue(v) => write!(f, "invalid digit value: {}", v),
+            Self::Overflow => write!(f, "the number is too large."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RadixError {}
+
+// returns the radix, panicking if it is not supported.
+#[inline]
+fn radix(radix: u32) -> u32 {
+    assert!((2..=36).contains(&radix), "the radix must be from 2 to 36.");
+    radix
+}
+
+/// A decoder for digit characters, emits their values.
+///
+/// Letters are case-insensitive.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::radix::{DigitDecoder, RadixError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new("755".chars(), DigitDecoder::new(8));
+/// assert_eq!(Ok(vec![7, 5, 5]), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new("aZ".chars(), DigitDecoder::new(36));
+/// assert_eq!(Ok(vec![10, 35]), decoded.collect());
+///
+/// let mut decoded = ConvertedIterator::new("18".chars(), DigitDecoder::new(8));
+/// assert_eq!(Some(Ok(1)), decoded.next());
+/// assert_eq!(Some(Err(RadixError::InvalidDigit('8'))), decoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitDecoder {
+    radix: u32,
+}
+
+impl DigitDecoder {
+    /// Create a new instance with a radix.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not from 2 to 36.
+    #[inline]
+    pub fn new(radix: u32) -> Self {
+        Self {
+            radix: self::radix(radix),
+        }
+    }
+}
+
+impl Converter for DigitDecoder {
+    type Item = char;
+    type Output = u8;
+    type Error = RadixError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let value = item
+            .to_digit(self.radix)
+            .ok_or(RadixError::InvalidDigit(item))?;
+        buf.extend([value as u8]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// An encoder for digit values, emits their characters.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::radix::{DigitEncoder, RadixError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new([10, 35, 0], DigitEncoder::new(36));
+/// assert_eq!(Ok(String::from("az0")), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new([15, 15], DigitEncoder::new(16).uppercase(true));
+/// assert_eq!(Ok(String::from("FF")), encoded.collect());
+///
+/// let mut encoded = ConvertedIterator::new([2], DigitEncoder::new(2));
+/// assert_eq!(Some(Err(RadixError::InvalidValue(2))), encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitEncoder {
+    radix: u32,
+    uppercase: bool,
+}
+
+impl DigitEncoder {
+    /// Create a new instance with a radix, emits lowercase letters.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not from 2 to 36.
+    #[inline]
+    pub fn new(radix: u32) -> Self {
+        Self {
+            radix: self::radix(radix),
+            uppercase: false,
+        }
+    }
+
+    /// Setting whether to emit uppercase letters.
+    #[inline]
+    pub fn uppercase(self, uppercase: bool) -> Self {
+        Self { uppercase, ..self }
+    }
+}
+
+impl Converter for DigitEncoder {
+    type Item = u8;
+    type Output = char;
+    type Error = RadixError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let c = char::from_digit(item as u32, self.radix).ok_or(RadixError::InvalidValue(item))?;
+        buf.extend([if self.uppercase {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// Integers which [`NumberAccumulator`] can fold digits into.
+pub trait RadixInt: Copy {
+    /// Zero.
+    const ZERO: Self;
+
+    /// Returning `self * radix + digit`, or `None` on overflow.
+    fn push_digit(self, radix: u32, digit: u8) -> Option<Self>;
+}
+
+macro_rules! radix_int {
+    ($($int:ty),*) => {
+        $(
+            impl RadixInt for $int {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn push_digit(self, radix: u32, digit: u8) -> Option<Self> {
+                    self.checked_mul(radix as Self)?.checked_add(digit as Self)
+                }
+            }
+        )*
+    };
+}
+
+radix_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A converter folding digit values (most significant first) into an integer, emits it when the
+/// input ends.
+///
+/// An empty input emits nothing. After an overflow, the rest of the digits are skipped, and
+/// nothing is emitted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::radix::{NumberAccumulator, RadixError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let parsed = ConvertedIterator::new([7, 5, 5], NumberAccumulator::<u16>::new(8));
+/// assert_eq!(Ok(vec![0o755]), parsed.collect());
+///
+/// let parsed = ConvertedIterator::new([2, 5, 6], NumberAccumulator::<u8>::new(10));
+/// assert_eq!(Err(RadixError::Overflow), parsed.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberAccumulator<T> {
+    radix: u32,
+    // `None` after an overflow.
+    value: Option<T>,
+    empty: bool,
+}
+
+impl<T: RadixInt> NumberAccumulator<T> {
+    /// Create a new instance with a radix.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not from 2 to 36.
+    #[inline]
+    pub fn new(radix: u32) -> Self {
+        Self {
+            radix: self::radix(radix),
+            value: Some(T::ZERO),
+            empty: true,
+        }
+    }
+}
+
+impl<T: RadixInt> Converter for NumberAccumulator<T> {
+    type Item = u8;
+    type Output = T;
+    type Error = RadixError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item as u32 >= self.radix {
+            return Err(RadixError::InvalidValue(item));
+        }
+        self.empty = false;
+        if let Some(value) = self.value {
+            self.value = value.push_digit(self.radix, item);
+            if self.value.is_none() {
+                return Err(RadixError::Overflow);
+            }
+        }
+        Ok(0)
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let value = self.value.replace(T::ZERO);
+        match (core::mem::replace(&mut self.empty, true), value) {
+            (false, Some(value)) => {
+                buf.extend([value]);
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boundaries() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let digits = "0123456789abcdefghijklmnopqrstuvwxyz";
+        for radix in [2, 10, 16, 36] {
+            let valid = &digits[..radix as usize];
+            let decoded = ConvertedIterator::new(valid.chars(), DigitDecoder::new(radix))
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok((0..radix as u8).collect()), decoded);
+            let upper =
+                ConvertedIterator::new(valid.to_uppercase().chars(), DigitDecoder::new(radix))
+                    .collect::<Result<Vec<_>, _>>();
+            assert_eq!(decoded, upper);
+            let encoded = ConvertedIterator::new(0..radix as u8, DigitEncoder::new(radix))
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(valid)), encoded);
+
+            let mut buf = Vec::new();
+            if let Some(c) = digits.chars().nth(radix as usize) {
+                assert_eq!(
+                    Err(RadixError::InvalidDigit(c)),
+                    DigitDecoder::new(radix).convert(c, &mut buf)
+                );
+            }
+            let mut chars = String::new();
+            assert_eq!(
+                Err(RadixError::InvalidValue(radix as u8)),
+                DigitEncoder::new(radix).convert(radix as u8, &mut chars)
+            );
+            assert_eq!(
+                Err(RadixError::InvalidValue(radix as u8)),
+                NumberAccumulator::<u8>::new(radix).convert(radix as u8, &mut buf)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsupported_radix() {
+        DigitEncoder::new(37);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn overflow() {
+        use crate::error::ChainedError;
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let parse = |s: &str, radix| {
+            ConvertedIterator::new(
+                s.chars(),
+                DigitDecoder::new(radix).pipe(NumberAccumulator::<i8>::new(radix)),
+            )
+            .collect::<Result<Vec<_>, _>>()
+        };
+        assert_eq!(Ok(Vec::new()), parse("", 10));
+        assert_eq!(Ok(vec![0]), parse("000", 10));
+        assert_eq!(Ok(vec![127]), parse("127", 10));
+        assert_eq!(
+            Err(ChainedError::Second(RadixError::Overflow)),
+            parse("128", 10)
+        );
+        assert_eq!(Ok(vec![127]), parse("1111111", 2));
+        assert_eq!(
+            Err(ChainedError::Second(RadixError::Overflow)),
+            parse("10000000", 2)
+        );
+        assert_eq!(Ok(vec![127]), parse("3j", 36));
+        assert_eq!(
+            Err(ChainedError::Second(RadixError::Overflow)),
+            parse("3k", 36)
+        );
+
+        // the accumulator is reusable after an overflow.
+        let mut accumulator = NumberAccumulator::<u8>::new(16);
+        let mut buf = Vec::new();
+        assert_eq!(Ok(0), accumulator.convert(0xF, &mut buf));
+        assert_eq!(Ok(0), accumulator.convert(0xF, &mut buf));
+        assert_eq!(Err(RadixError::Overflow), accumulator.convert(0, &mut buf));
+        assert_eq!(Ok(0), accumulator.convert(0, &mut buf));
+        assert_eq!(Ok(0), accumulator.finish(&mut buf));
+        assert_eq!(Ok(0), accumulator.convert(0xA, &mut buf));
+        assert_eq!(Ok(1), accumulator.finish(&mut buf));
+        assert_eq!(vec![0xA], buf);
+
+        // the number is stored at the end.
+        let parsed = ConvertedIterator::new([2, 5, 6], NumberAccumulator::<u8>::new(10));
+        assert_eq!((0, Some(1)), parsed.size_hint());
+        assert_eq!(1, parsed.count());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::string::String;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut next = random();
+        for radix in [2, 3, 8, 10, 16, 36] {
+            for _ in 0..100 {
+                let value = (next() as u64) << 32 | next() as u64;
+                let mut digits = Vec::new();
+                let mut rest = value;
+                loop {
+                    digits.push((rest % radix as u64) as u8);
+                    rest /= radix as u64;
+                    if rest == 0 {
+                        break;
+                    }
+                }
+                digits.reverse();
+
+                let encoded =
+                    ConvertedIterator::new(digits, DigitEncoder::new(radix).uppercase(true))
+                        .collect::<Result<String, _>>()
+                        .unwrap();
+                assert_eq!(Ok(value), u64::from_str_radix(&encoded, radix));
+                let decoder = DigitDecoder::new(radix).pipe(NumberAccumulator::<u64>::new(radix));
+                let decoded =
+                    ConvertedIterator::new(encoded.chars(), decoder).collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(vec![value]), decoded);
+            }
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! Looking up encoders and decoders by labels at runtime.
+//!
+//! Labels are matched like the [WHATWG Encoding Standard]: leading and trailing ASCII whitespaces
+//! are removed, and comparisons are ASCII case-insensitive. Unlike the standard, ASCII labels
+//! (`"us-ascii"`, `"ascii"`, ...) are resolved to the strict 7-bit ASCII codec. Latin-1 labels
+//! (`"latin1"`, `"iso-8859-1"`, ...) are resolved to Windows-1252 as the standard does.
+//!
+//! [WHATWG Encoding Standard]: https://encoding.spec.whatwg.org/#names-and-labels
+
+use super::ascii::{ASCIIDecoder, ASCIIEncoder, ASCIIEncodingError};
+use super::iso8859::{
+    ISO8859DecodeError, ISO8859Decoder, ISO8859Encoder, ISO8859EncodingError, ISO8859Part,
+};
+use super::koi8r::{KOI8EncodingError, KOI8RDecoder, KOI8REncoder, KOI8UDecoder, KOI8UEncoder};
+use super::latin9::{Latin9Decoder, Latin9Encoder, Latin9EncodingError};
+use super::utf16::{
+    UTF16BEDecoder, UTF16BEEncoder, UTF16EncodingError, UTF16LEDecoder, UTF16LEEncoder,
+};
+use super::utf32::{
+    UTF32BEDecoder, UTF32BEEncoder, UTF32EncodingError, UTF32LEDecoder, UTF32LEEncoder,
+};
+use super::utf8::{UTF8DecodeError, UTF8Decoder, UTF8Encoder};
+use super::windows1252::{Windows1252Decoder, Windows1252Encoder, Windows1252EncodingError};
+use crate::converter::BoxConverter;
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error from converters in the registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingError {
+    ASCII(ASCIIEncodingError),
+    UTF8(UTF8DecodeError),
+    UTF16(UTF16EncodingError),
+    UTF32(UTF32EncodingError),
+    Windows1252(Windows1252EncodingError),
+    Latin9(Latin9EncodingError),
+    ISO8859Decode(ISO8859DecodeError),
+    ISO8859Encode(ISO8859EncodingError),
+    KOI8(KOI8EncodingError),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ASCII(e) => write!(f, "{}", e),
+            Self::UTF8(e) => write!(f, "{}", e),
+            Self::UTF16(e) => write!(f, "{}", e),
+            Self::UTF32(e) => write!(f, "{}", e),
+            Self::Windows1252(e) => write!(f, "{}", e),
+            Self::Latin9(e) => write!(f, "{}", e),
+            Self::ISO8859Decode(e) => write!(f, "{}", e),
+            Self::ISO8859Encode(e) => write!(f, "{}", e),
+            Self::KOI8(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ASCII(e) => Some(e),
+            Self::UTF8(e) => Some(e),
+            Self::UTF16(e) => Some(e),
+            Self::UTF32(e) => Some(e),
+            Self::Windows1252(e) => Some(e),
+            Self::Latin9(e) => Some(e),
+            Self::ISO8859Decode(e) => Some(e),
+            Self::ISO8859Encode(e) => Some(e),
+            Self::KOI8(e) => Some(e),
+        }
+    }
+}
+
+impl From<Infallible> for EncodingError {
+    #[inline]
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<ASCIIEncodingError> for EncodingError {
+    #[inline]
+    fn from(e: ASCIIEncodingError) -> Self {
+        Self::ASCII(e)
+    }
+}
+
+impl From<UTF8DecodeError> for EncodingError {
+    #[inline]
+    fn from(e: UTF8DecodeError) -> Self {
+        Self::UTF8(e)
+    }
+}
+
+impl From<UTF16EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: UTF16EncodingError) -> Self {
+        Self::UTF16(e)
+    }
+}
+
+impl From<UTF32EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: UTF32EncodingError) -> Self {
+        Self::UTF32(e)
+    }
+}
+
+impl From<Windows1252EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: Windows1252EncodingError) -> Self {
+        Self::Windows1252(e)
+    }
+}
+
+impl From<Latin9EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: Latin9EncodingError) -> Self {
+        Self::Latin9(e)
+    }
+}
+
+impl From<ISO8859DecodeError> for EncodingError {
+    #[inline]
+    fn from(e: ISO8859DecodeError) -> Self {
+        Self::ISO8859Decode(e)
+    }
+}
+
+impl From<ISO8859EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: ISO8859EncodingError) -> Self {
+        Self::ISO8859Encode(e)
+    }
+}
+
+impl From<KOI8EncodingError> for EncodingError {
+    #[inline]
+    fn from(e: KOI8EncodingError) -> Self {
+        Self::KOI8(e)
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    ASCII,
+    UTF8,
+    UTF16BE,
+    UTF16LE,
+    UTF32BE,
+    UTF32LE,
+    Windows1252,
+    ISO8859(ISO8859Part),
+    Latin9,
+    KOI8R,
+    KOI8U,
+}
+
+impl Encoding {
+    fn name(self) -> &'static str {
+        match self {
+            Self::ASCII => "us-ascii",
+            Self::UTF8 => "utf-8",
+            Self::UTF16BE => "utf-16be",
+            Self::UTF16LE => "utf-16le",
+            Self::UTF32BE => "utf-32be",
+            Self::UTF32LE => "utf-32le",
+            Self::Windows1252 => "windows-1252",
+            Self::ISO8859(ISO8859Part::Latin2) => "iso-8859-2",
+            Self::ISO8859(ISO8859Part::Latin3) => "iso-8859-3",
+            Self::ISO8859(ISO8859Part::Latin4) => "iso-8859-4",
+            Self::ISO8859(ISO8859Part::Cyrillic) => "iso-8859-5",
+            Self::ISO8859(ISO8859Part::Greek) => "iso-8859-7",
+            Self::ISO8859(ISO8859Part::Hebrew) => "iso-8859-8",
+            Self::Latin9 => "iso-8859-15",
+            Self::KOI8R => "koi8-r",
+            Self::KOI8U => "koi8-u",
+        }
+    }
+}
+
+const LABELS: &[(&str, Encoding)] = &[
+    ("ansi_x3.4-1968", Encoding::ASCII),
+    ("ascii", Encoding::ASCII),
+    ("cp367", Encoding::ASCII),
+    ("csascii", Encoding::ASCII),
+    ("ibm367", Encoding::ASCII),
+    ("iso-ir-6", Encoding::ASCII),
+    ("iso646-us", Encoding::ASCII),
+    ("us", Encoding::ASCII),
+    ("us-ascii", Encoding::ASCII),
+    ("unicode-1-1-utf-8", Encoding::UTF8),
+    ("unicode11utf8", Encoding::UTF8),
+    ("unicode20utf8", Encoding::UTF8),
+    ("utf-8", Encoding::UTF8),
+    ("utf8", Encoding::UTF8),
+    ("x-unicode20utf8", Encoding::UTF8),
+    ("unicodefffe", Encoding::UTF16BE),
+    ("utf-16be", Encoding::UTF16BE),
+    ("csunicode", Encoding::UTF16LE),
+    ("iso-10646-ucs-2", Encoding::UTF16LE),
+    ("ucs-2", Encoding::UTF16LE),
+    ("unicode", Encoding::UTF16LE),
+    ("unicodefeff", Encoding::UTF16LE),
+    ("utf-16", Encoding::UTF16LE),
+    ("utf-16le", Encoding::UTF16LE),
+    ("utf-32be", Encoding::UTF32BE),
+    ("utf-32", Encoding::UTF32LE),
+    ("utf-32le", Encoding::UTF32LE),
+    ("cp1252", Encoding::Windows1252),
+    ("cp819", Encoding::Windows1252),
+    ("csisolatin1", Encoding::Windows1252),
+    ("ibm819", Encoding::Windows1252),
+    ("iso-8859-1", Encoding::Windows1252),
+    ("iso-ir-100", Encoding::Windows1252),
+    ("iso8859-1", Encoding::Windows1252),
+    ("iso88591", Encoding::Windows1252),
+    ("iso_8859-1", Encoding::Windows1252),
+    ("iso_8859-1:1987", Encoding::Windows1252),
+    ("l1", Encoding::Windows1252),
+    ("latin1", Encoding::Windows1252),
+    ("windows-1252", Encoding::Windows1252),
+    ("x-cp1252", Encoding::Windows1252),
+    ("csisolatin2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso-8859-2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso-ir-101", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso8859-2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso88592", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso_8859-2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("iso_8859-2:1987", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("l2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("latin2", Encoding::ISO8859(ISO8859Part::Latin2)),
+    ("csisolatin3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso-8859-3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso-ir-109", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso8859-3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso88593", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso_8859-3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("iso_8859-3:1988", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("l3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("latin3", Encoding::ISO8859(ISO8859Part::Latin3)),
+    ("csisolatin4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso-8859-4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso-ir-110", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso8859-4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso88594", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso_8859-4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("iso_8859-4:1988", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("l4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    ("latin4", Encoding::ISO8859(ISO8859Part::Latin4)),
+    (
+        "csisolatincyrillic",
+        Encoding::ISO8859(ISO8859Part::Cyrillic),
+    ),
+    ("cyrillic", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso-8859-5", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso-ir-144", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso8859-5", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso88595", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso_8859-5", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("iso_8859-5:1988", Encoding::ISO8859(ISO8859Part::Cyrillic)),
+    ("csiso88597", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("ecma-118", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("elot_928", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("greek", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("greek8", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso-8859-7", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso-ir-126", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso8859-7", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso88597", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso_8859-7", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("iso_8859-7:1987", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("sun_eu_greek", Encoding::ISO8859(ISO8859Part::Greek)),
+    ("csiso88598e", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("csisolatinhebrew", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("hebrew", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso-8859-8", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso-8859-8-e", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso-ir-138", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso8859-8", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso88598", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso_8859-8", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("iso_8859-8:1988", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("visual", Encoding::ISO8859(ISO8859Part::Hebrew)),
+    ("csisolatin9", Encoding::Latin9),
+    ("iso-8859-15", Encoding::Latin9),
+    ("iso8859-15", Encoding::Latin9),
+    ("iso885915", Encoding::Latin9),
+    ("iso_8859-15", Encoding::Latin9),
+    ("l9", Encoding::Latin9),
+    ("cskoi8r", Encoding::KOI8R),
+    ("koi", Encoding::KOI8R),
+    ("koi8", Encoding::KOI8R),
+    ("koi8-r", Encoding::KOI8R),
+    ("koi8_r", Encoding::KOI8R),
+    ("koi8-ru", Encoding::KOI8U),
+    ("koi8-u", Encoding::KOI8U),
+];
+
+fn lookup(label: &str) -> Option<Encoding> {
+    let label = label.trim_matches(|c: char| c.is_ascii_whitespace());
+    LABELS
+        .iter()
+        .find(|(l, _)| l.eq_ignore_ascii_case(label))
+        .map(|&(_, encoding)| encoding)
+}
+
+/// Resolving a label into the canonical name of the encoding.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::registry::canonical_name;
+///
+/// assert_eq!(Some("utf-8"), canonical_name(" UTF8\n"));
+/// assert_eq!(Some("utf-16le"), canonical_name("unicode"));
+/// assert_eq!(None, canonical_name("utf-7"));
+/// ```
+pub fn canonical_name(label: &str) -> Option<&'static str> {
+    lookup(label).map(Encoding::name)
+}
+
+/// Getting a decoder by a label.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::registry::decoder_for;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoder = decoder_for("UTF-16BE").unwrap();
+/// let decoded = ConvertedIterator::new(b"\x00a\x00\xdf".into_iter().cloned(), decoder);
+///
+/// assert_eq!(Ok(String::from("aß")), decoded.collect());
+/// ```
+pub fn decoder_for(label: &str) -> Option<BoxConverter<u8, char, EncodingError>> {
+    Some(match lookup(label)? {
+        Encoding::ASCII => BoxConverter::new(ASCIIDecoder::new()),
+        Encoding::UTF8 => BoxConverter::new(UTF8Decoder::new()),
+        Encoding::UTF16BE => BoxConverter::new(UTF16BEDecoder::new()),
+        Encoding::UTF16LE => BoxConverter::new(UTF16LEDecoder::new()),
+        Encoding::UTF32BE => BoxConverter::new(UTF32BEDecoder::new()),
+        Encoding::UTF32LE => BoxConverter::new(UTF32LEDecoder::new()),
+        Encoding::Windows1252 => BoxConverter::new(Windows1252Decoder::new()),
+        Encoding::ISO8859(part) => BoxConverter::new(ISO8859Decoder::new(part)),
+        Encoding::Latin9 => BoxConverter::new(Latin9Decoder::new()),
+        Encoding::KOI8R => BoxConverter::new(KOI8RDecoder::new()),
+        Encoding::KOI8U => BoxConverter::new(KOI8UDecoder::new()),
+    })
+}
+
+/// Getting an encoder by a label.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::registry::{encoder_for, EncodingError};
+/// use conversion::converter::encoding::ascii::ASCIIEncodingError;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoder = encoder_for("us-ascii").unwrap();
+/// let mut encoded = ConvertedIterator::new("aß".chars(), encoder);
+///
+/// assert_eq!(Some(Ok(b'a')), encoded.next());
+/// assert_eq!(Some(Err(EncodingError::ASCII(ASCIIEncodingError))), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+pub fn encoder_for(label: &str) -> Option<BoxConverter<char, u8, EncodingError>> {
+    Some(match lookup(label)? {
+        Encoding::ASCII => BoxConverter::new(ASCIIEncoder::new()),
+        Encoding::UTF8 => BoxConverter::new(UTF8Encoder::new()),
+        Encoding::UTF16BE => BoxConverter::new(UTF16BEEncoder::new()),
+        Encoding::UTF16LE => BoxConverter::new(UTF16LEEncoder::new()),
+        Encoding::UTF32BE => BoxConverter::new(UTF32BEEncoder::new()),
+        Encoding::UTF32LE => BoxConverter::new(UTF32LEEncoder::new()),
+        Encoding::Windows1252 => BoxConverter::new(Windows1252Encoder::new()),
+        Encoding::ISO8859(part) => BoxConverter::new(ISO8859Encoder::new(part)),
+        Encoding::Latin9 => BoxConverter::new(Latin9Encoder::new()),
+        Encoding::KOI8R => BoxConverter::new(KOI8REncoder::new()),
+        Encoding::KOI8U => BoxConverter::new(KOI8UEncoder::new()),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn aliases() {
+        let cases = [
+            ("utf-8", "utf-8"),
+            ("UTF8", "utf-8"),
+            ("\t unicode-1-1-utf-8 \r\n", "utf-8"),
+            ("Unicode", "utf-16le"),
+            ("UTF-16", "utf-16le"),
+            ("ucs-2", "utf-16le"),
+            ("UnicodeFFFE", "utf-16be"),
+            ("utf-32", "utf-32le"),
+            ("US-ASCII", "us-ascii"),
+            ("iso646-us", "us-ascii"),
+            ("Latin1", "windows-1252"),
+            ("ISO-8859-1", "windows-1252"),
+            ("latin2", "iso-8859-2"),
+            ("ISO_8859-5:1988", "iso-8859-5"),
+            ("greek", "iso-8859-7"),
+            ("visual", "iso-8859-8"),
+            ("L9", "iso-8859-15"),
+            ("KOI8", "koi8-r"),
+            ("koi8-ru", "koi8-u"),
+        ];
+        for (label, name) in cases {
+            assert_eq!(Some(name), canonical_name(label), "{}", label);
+            assert!(decoder_for(label).is_some());
+            assert!(encoder_for(label).is_some());
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        for label in ["", "utf-7", "utf 8", "utf-8x", "\u{130}", "latin1-"] {
+            assert_eq!(None, canonical_name(label));
+            assert!(decoder_for(label).is_none());
+            assert!(encoder_for(label).is_none());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "straße 💣";
+        let unicode = |e: &Encoding| {
+            !matches!(
+                e,
+                Encoding::ASCII
+                    | Encoding::Windows1252
+                    | Encoding::ISO8859(_)
+                    | Encoding::Latin9
+                    | Encoding::KOI8R
+                    | Encoding::KOI8U
+            )
+        };
+        for (name, _) in LABELS.iter().filter(|(_, e)| unicode(e)) {
+            let encoded = ConvertedIterator::new(text.chars(), encoder_for(name).unwrap())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let decoded = ConvertedIterator::new(encoded, decoder_for(name).unwrap())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(text)), decoded);
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Run-length Encoder/Decoder.
+//!
+//! Two formats are supported:
+//!
+//! - Pairs of a count and a value, as old image formats and telemetry use. A count is `1` to the
+//!   maximum run length, which is `255` by default.
+//! - PackBits, as TIFF and ICNS use. A signed header byte `n` is followed by `n + 1` literal bytes
+//!   if `0 <= n`, or a byte repeated `1 - n` times if `-127 <= n <= -1`. `-128` is a no-op.
+
+use crate::Converter;
+use core::fmt;
+
+/// An error while decoding run-length encoded bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RLEError {
+    /// A count of zero, in the strict pair format.
+    ZeroCount,
+    /// The input ended after a count or inside a literal run.
+    UnexpectedEnd,
+}
+
+impl fmt::Display for RLEError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroCount => write!(f, "a run of zero bytes."),
+            Self::UnexpectedEnd => write!(f, "the input ended inside a run."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RLEError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Pairs { max_run: u8 },
+    PackBits,
+}
+
+impl Default for Format {
+    #[inline]
+    fn default() -> Self {
+        Self::Pairs { max_run: 255 }
+    }
+}
+
+// the maximum length of PackBits runs.
+const PACKBITS_MAX: usize = 128;
+
+/// A run-length encoder.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::rle::RLEEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let encoded = ConvertedIterator::new(*b"aaabccc", RLEEncoder::new());
+/// assert_eq!(Ok(vec![3, b'a', 1, b'b', 3, b'c']), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new(*b"aaabccc", RLEEncoder::new().max_run(2));
+/// assert_eq!(Ok(vec![2, b'a', 1, b'a', 1, b'b', 2, b'c', 1, b'c']), encoded.collect());
+///
+/// let encoded = ConvertedIterator::new(*b"xyzzzzz", RLEEncoder::packbits());
+/// assert_eq!(Ok(vec![0x01, b'x', b'y', 0xFC, b'z']), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RLEEncoder {
+    format: Format,
+    // pending literal bytes of PackBits.
+    literal: [u8; PACKBITS_MAX],
+    len: usize,
+    // the current run, `0` for none.
+    run: usize,
+    value: u8,
+}
+
+impl Default for RLEEncoder {
+    #[inline]
+    fn default() -> Self {
+        Self::with_format(Format::default())
+    }
+}
+
+impl RLEEncoder {
+    #[inline]
+    fn with_format(format: Format) -> Self {
+        Self {
+            format,
+            literal: [0; PACKBITS_MAX],
+            len: 0,
+            run: 0,
+            value: 0,
+        }
+    }
+
+    /// Create a new instance, emits pairs of a count and a value.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance, emits PackBits.
+    #[inline]
+    pub fn packbits() -> Self {
+        Self::with_format(Format::PackBits)
+    }
+
+    /// Setting the maximum run length of the pair format. (Ignored for PackBits.)
+    ///
+    /// # Panics
+    /// Panics if `max_run` is zero.
+    #[inline]
+    pub fn max_run(self, max_run: u8) -> Self {
+        assert!(max_run > 0, "the maximum run length must not be zero");
+        match self.format {
+            Format::Pairs { .. } => Self::with_format(Format::Pairs { max_run }),
+            Format::PackBits => self,
+        }
+    }
+
+    // emitting the current run.
+    fn flush_run<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        let count = match (core::mem::take(&mut self.run), self.format) {
+            (0, _) => return 0,
+            (run, Format::Pairs { .. }) => run as u8,
+            (run, Format::PackBits) => (257 - run) as u8,
+        };
+        buf.extend([count, self.value]);
+        2
+    }
+
+    // emitting the first `len` bytes of the pending literal.
+    fn flush_literal<E>(&mut self, buf: &mut E, len: usize) -> usize
+    where
+        E: Extend<u8>,
+    {
+        if len == 0 {
+            return 0;
+        }
+        buf.extend([(len - 1) as u8]);
+        buf.extend(self.literal[..len].iter().cloned());
+        self.literal.copy_within(len..self.len, 0);
+        self.len -= len;
+        len + 1
+    }
+}
+
+impl Converter for RLEEncoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = core::convert::Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let max_run = match self.format {
+            Format::Pairs { max_run } => max_run as usize,
+            Format::PackBits => PACKBITS_MAX,
+        };
+        if self.run > 0 && item == self.value && self.run < max_run {
+            self.run += 1;
+            return Ok(0);
+        }
+        let len = self.flush_run(buf);
+        if let Format::Pairs { .. } = self.format {
+            self.run = 1;
+            self.value = item;
+            return Ok(len);
+        }
+
+        // PackBits: collects literal bytes, until the last 3 bytes are equal.
+        let mut len = len;
+        if self.len == PACKBITS_MAX {
+            len += self.flush_literal(buf, PACKBITS_MAX);
+        }
+        self.literal[self.len] = item;
+        self.len += 1;
+        if self.len >= 3
+            && self.literal[self.len - 3..self.len]
+                .iter()
+                .all(|&b| b == item)
+        {
+            len += self.flush_literal(buf, self.len - 3);
+            self.len = 0;
+            self.run = 3;
+            self.value = item;
+        }
+        Ok(len)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = self.flush_run(buf) + self.flush_literal(buf, self.len);
+        Ok(len)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        // a run, and a literal with its header.
+        Some(2 + self.len + 1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.format {
+            Format::Pairs { .. } => (0, Some(2)),
+            Format::PackBits => (0, Some(PACKBITS_MAX + 1)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    // expecting a count or a header.
+    #[default]
+    Header,
+    // expecting a value to repeat.
+    Repeat(usize),
+    // expecting literal bytes.
+    Literal(usize),
+}
+
+/// A run-length decoder.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::rle::{RLEDecoder, RLEError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let decoded = ConvertedIterator::new([3, b'a', 1, b'b'], RLEDecoder::new());
+/// assert_eq!(Ok(b"aaab".to_vec()), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new([0xFE, b'a', 0x00, b'b'], RLEDecoder::packbits());
+/// assert_eq!(Ok(b"aaab".to_vec()), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new([0, b'a', 1, b'b'], RLEDecoder::new());
+/// assert_eq!(Err(RLEError::ZeroCount), decoded.collect::<Result<Vec<_>, _>>());
+///
+/// let decoded = ConvertedIterator::new([0, b'a', 1, b'b'], RLEDecoder::new().strict(false));
+/// assert_eq!(Ok(b"b".to_vec()), decoded.collect());
+///
+/// let decoded = ConvertedIterator::new([1, b'a', 2], RLEDecoder::new());
+/// assert_eq!(Err(RLEError::UnexpectedEnd), decoded.collect::<Result<Vec<_>, _>>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RLEDecoder {
+    packbits: bool,
+    strict: bool,
+    state: State,
+}
+
+impl Default for RLEDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            packbits: false,
+            strict: true,
+            state: State::Header,
+        }
+    }
+}
+
+impl RLEDecoder {
+    /// Create a new instance, reads pairs of a count and a value.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance, reads PackBits.
+    #[inline]
+    pub fn packbits() -> Self {
+        Self {
+            packbits: true,
+            ..Self::default()
+        }
+    }
+
+    /// Setting whether to return errors for zero counts of the pair format, instead of ignoring
+    /// the pairs. (Defaults to `true`.)
+    #[inline]
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+}
+
+impl Converter for RLEDecoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = RLEError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Header if self.packbits => {
+                self.state = match item as i8 {
+                    -128 => State::Header,
+                    n @ -127..=-1 => State::Repeat((1 - n as isize) as usize),
+                    n => State::Literal(n as usize + 1),
+                };
+                Ok(0)
+            }
+            State::Header => {
+                // the value of a zero count is skipped, to keep the stream aligned.
+                self.state = State::Repeat(item as usize);
+                if item == 0 && self.strict {
+                    return Err(RLEError::ZeroCount);
+                }
+                Ok(0)
+            }
+            State::Repeat(count) => {
+                buf.extend(core::iter::repeat_n(item, count));
+                self.state = State::Header;
+                Ok(count)
+            }
+            State::Literal(count) => {
+                buf.extend([item]);
+                self.state = match count {
+                    1 => State::Header,
+                    n => State::Literal(n - 1),
+                };
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::Header => Ok(()),
+            _ => Err(RLEError::UnexpectedEnd),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.packbits {
+            true => (0, Some(PACKBITS_MAX)),
+            false => (0, Some(255)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn encode(input: &[u8], encoder: RLEEncoder) -> Vec<u8> {
+        ConvertedIterator::new(input.iter().cloned(), encoder)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn decode(input: &[u8], decoder: RLEDecoder) -> Result<Vec<u8>, RLEError> {
+        ConvertedIterator::new(input.iter().cloned(), decoder).collect()
+    }
+
+    #[test]
+    fn packbits() {
+        // from Apple Technical Note TN1023.
+        let unpacked = [
+            0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0xAA, 0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0x22,
+            0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        ];
+        let packed = [
+            0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A, 0xFD, 0xAA, 0x03, 0x80, 0x00, 0x2A, 0x22, 0xF7,
+            0xAA,
+        ];
+        assert_eq!(packed.to_vec(), encode(&unpacked, RLEEncoder::packbits()));
+        assert_eq!(
+            Ok(unpacked.to_vec()),
+            decode(&packed, RLEDecoder::packbits())
+        );
+
+        // no-op headers, and the longest runs.
+        assert_eq!(
+            Ok(b"ab".to_vec()),
+            decode(&[0x80, 0x01, b'a', b'b', 0x80], RLEDecoder::packbits())
+        );
+        let long = [7; 300];
+        let packed = encode(&long, RLEEncoder::packbits());
+        assert_eq!(vec![0x81, 7, 0x81, 7, 0xD5, 7], packed);
+        let literal = (0..=255).collect::<Vec<u8>>();
+        let packed = encode(&literal, RLEEncoder::packbits());
+        assert_eq!(258, packed.len());
+        assert_eq!((0x7F, 0x7F), (packed[0], packed[129]));
+        assert_eq!(Ok(literal), decode(&packed, RLEDecoder::packbits()));
+
+        assert_eq!(
+            Err(RLEError::UnexpectedEnd),
+            decode(&[0x02, 1, 2], RLEDecoder::packbits())
+        );
+        assert_eq!(
+            Err(RLEError::UnexpectedEnd),
+            decode(&[0xFF], RLEDecoder::packbits())
+        );
+    }
+
+    #[test]
+    fn pairs() {
+        assert_eq!(vec![255, 0, 45, 0], encode(&[0; 300], RLEEncoder::new()));
+        assert_eq!(Vec::<u8>::new(), encode(&[], RLEEncoder::new()));
+        assert_eq!(Ok(Vec::new()), decode(&[], RLEDecoder::new()));
+        assert_eq!(
+            Err(RLEError::UnexpectedEnd),
+            decode(&[3], RLEDecoder::new())
+        );
+
+        let mut decoder = RLEDecoder::new();
+        let mut buf = Vec::new();
+        assert_eq!(Err(RLEError::ZeroCount), decoder.convert(0, &mut buf));
+        assert_eq!(Ok(0), decoder.convert(b'x', &mut buf));
+        assert_eq!(Ok(0), decoder.convert(2, &mut buf));
+        assert_eq!(Ok(2), decoder.convert(b'y', &mut buf));
+        assert_eq!(Ok(()), decoder.finalize());
+        assert_eq!(b"yy".to_vec(), buf);
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::test_util::random;
+
+        // random runs of a few symbols.
+        let mut next = random();
+        for _ in 0..100 {
+            let len = next() % 600;
+            let input = (0..len)
+                .flat_map(|_| {
+                    let x = next();
+                    core::iter::repeat_n((x % 4) as u8, (x >> 8) as usize % 6 + 1)
+                })
+                .collect::<Vec<u8>>();
+            for (encoder, decoder) in [
+                (RLEEncoder::new(), RLEDecoder::new()),
+                (RLEEncoder::new().max_run(3), RLEDecoder::new()),
+                (RLEEncoder::packbits(), RLEDecoder::packbits()),
+            ] {
+                let encoded = encode(&input, encoder);
+                assert_eq!(Ok(input.clone()), decode(&encoded, decoder));
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Escaping/Unescaping Rust string literals.
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while unescaping Rust string literals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RustEscapeError {
+    /// An unknown escape sequence, like `\q`.
+    UnknownEscape(char),
+    /// An invalid character inside `\x..` or `\u{..}`.
+    InvalidDigit(char),
+    /// A value of `\x..` or `\u{..}` which is not a valid character.
+    InvalidCodepoint(u32),
+    /// The input ended inside an escape sequence.
+    Unterminated,
+}
+
+impl fmt::Display for RustEscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEscape(c) => write!(f, "unknown escape sequence: \\{}", c),
+            Self::InvalidDigit(c) => write!(f, "invalid character in escape sequence: {:?}", c),
+            Self::InvalidCodepoint(v) => write!(f, "invalid character code: {:#x}", v),
+            Self::Unterminated => write!(f, "unterminated escape sequence."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RustEscapeError {}
+
+/// An escaper for Rust string literals.
+///
+/// `\n`, `\r`, `\t`, `\\`, `"` and `\0` are escaped by their short forms, other control
+/// characters are escaped as `\u{..}`, and the rest are emitted as they are.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::rust_escape::RustStringEscapeConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "\"straße\"\n\t\u{7f}".chars();
+/// let escaped = ConvertedIterator::new(iter, RustStringEscapeConverter::new());
+///
+/// assert_eq!(Ok(String::from(r#"\"straße\"\n\t\u{7f}"#)), escaped.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RustStringEscapeConverter;
+
+impl RustStringEscapeConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for RustStringEscapeConverter {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let short = match item {
+            '\n' => 'n',
+            '\r' => 'r',
+            '\t' => 't',
+            '\\' => '\\',
+            '"' => '"',
+            '\0' => '0',
+            c if c.is_control() => {
+                // control characters are below U+00A0.
+                let code = c as u32;
+                let digits = if code < 0x10 { 1 } else { 2 };
+                let hex = |d: u32| char::from_digit(d, 16).unwrap();
+                buf.extend(['\\', 'u', '{']);
+                if digits == 2 {
+                    buf.extend([hex(code >> 4)]);
+                }
+                buf.extend([hex(code & 0xF), '}']);
+                return Ok(4 + digits);
+            }
+            c => {
+                buf.extend([c]);
+                return Ok(1);
+            }
+        };
+        buf.extend(['\\', short]);
+        Ok(2)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(6))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `\`.
+    Escape,
+    // after `\x`, with the number of digits and the value.
+    Hex(u8, u32),
+    // after `\u`.
+    UnicodeStart,
+    // after `\u{`, with the number of digits and the value.
+    Unicode(u8, u32),
+    // after `\` and a newline.
+    Whitespace,
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// An unescaper for Rust string literals.
+///
+/// Supports `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\x..` (up to `\x7f`), `\u{..}`, and line
+/// continuations (a `\` before a newline skips following whitespaces).
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::rust_escape::{RustEscapeError, RustStringUnescapeConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = r#"\"stra\u{df}e\"\x21\n\q"#.chars();
+/// let mut unescaped = ConvertedIterator::new(iter, RustStringUnescapeConverter::new());
+///
+/// assert_eq!(Some(Ok('"')), unescaped.next());
+/// assert_eq!(Some(Ok('s')), unescaped.next());
+/// assert_eq!(Some(Ok('t')), unescaped.next());
+/// assert_eq!(Some(Ok('r')), unescaped.next());
+/// assert_eq!(Some(Ok('a')), unescaped.next());
+/// assert_eq!(Some(Ok('ß')), unescaped.next());
+/// assert_eq!(Some(Ok('e')), unescaped.next());
+/// assert_eq!(Some(Ok('"')), unescaped.next());
+/// assert_eq!(Some(Ok('!')), unescaped.next());
+/// assert_eq!(Some(Ok('\n')), unescaped.next());
+/// assert_eq!(Some(Err(RustEscapeError::UnknownEscape('q'))), unescaped.next());
+/// assert_eq!(None, unescaped.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RustStringUnescapeConverter {
+    state: State,
+}
+
+impl RustStringUnescapeConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, RustEscapeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn error(&mut self, err: RustEscapeError) -> Result<usize, RustEscapeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+}
+
+impl Converter for RustStringUnescapeConverter {
+    type Item = char;
+    type Output = char;
+    type Error = RustEscapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal if item == '\\' => {
+                self.state = State::Escape;
+                Ok(0)
+            }
+            State::Normal => self.emit(item, buf),
+            State::Escape => match item {
+                'n' => self.emit('\n', buf),
+                'r' => self.emit('\r', buf),
+                't' => self.emit('\t', buf),
+                '0' => self.emit('\0', buf),
+                '\\' | '\'' | '"' => self.emit(item, buf),
+                'x' => {
+                    self.state = State::Hex(0, 0);
+                    Ok(0)
+                }
+                'u' => {
+                    self.state = State::UnicodeStart;
+                    Ok(0)
+                }
+                '\n' => {
+                    self.state = State::Whitespace;
+                    Ok(0)
+                }
+                c => self.error(RustEscapeError::UnknownEscape(c)),
+            },
+            State::Hex(digits, value) => match item.to_digit(16) {
+                Some(d) if digits == 0 => {
+                    self.state = State::Hex(1, d);
+                    Ok(0)
+                }
+                Some(d) if value <= 0x7 => self.emit((value << 4 | d) as u8 as char, buf),
+                Some(d) => self.error(RustEscapeError::InvalidCodepoint(value << 4 | d)),
+                None => self.error(RustEscapeError::InvalidDigit(item)),
+            },
+            State::UnicodeStart if item == '{' => {
+                self.state = State::Unicode(0, 0);
+                Ok(0)
+            }
+            State::UnicodeStart => self.error(RustEscapeError::InvalidDigit(item)),
+            State::Unicode(digits, value) => match item {
+                '_' if digits > 0 => Ok(0),
+                '}' if digits > 0 => match char::from_u32(value) {
+                    Some(c) => self.emit(c, buf),
+                    None => self.error(RustEscapeError::InvalidCodepoint(value)),
+                },
+                c => match c.to_digit(16) {
+                    Some(d) if digits < 6 => {
+                        self.state = State::Unicode(digits + 1, value << 4 | d);
+                        Ok(0)
+                    }
+                    _ => self.error(RustEscapeError::InvalidDigit(c)),
+                },
+            },
+            State::Whitespace if item.is_whitespace() => Ok(0),
+            State::Whitespace => {
+                self.state = State::Normal;
+                self.convert(item, buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.state {
+            State::Normal | State::Whitespace => Ok(()),
+            _ => {
+                self.state = State::Normal;
+                Err(RustEscapeError::Unterminated)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let text = "\0\x01\x1f \"quoted\" \\ \r\n\t\u{7f}\u{9f} straße 💣";
+        let escaped = ConvertedIterator::new(text.chars(), RustStringEscapeConverter::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(
+            r#"\0\u{1}\u{1f} \"quoted\" \\ \r\n\t\u{7f}\u{9f} straße 💣"#,
+            escaped
+        );
+        let unescaped = ConvertedIterator::new(escaped.chars(), RustStringUnescapeConverter::new())
+            .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(text)), unescaped);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescape() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let unescape = |s: &str| {
+            ConvertedIterator::new(s.chars(), RustStringUnescapeConverter::new())
+                .collect::<Result<String, _>>()
+        };
+        assert_eq!(
+            Ok(String::from("a'b\u{10FFFF}c")),
+            unescape(r"a\'b\u{10_ffff}c")
+        );
+        assert_eq!(Ok(String::from("ab")), unescape("a\\\n    b"));
+        assert_eq!(
+            Err(RustEscapeError::InvalidCodepoint(0x80)),
+            unescape(r"\x80")
+        );
+        assert_eq!(Err(RustEscapeError::InvalidDigit('g')), unescape(r"\x4g"));
+        assert_eq!(Err(RustEscapeError::InvalidDigit('4')), unescape(r"\u41"));
+        assert_eq!(Err(RustEscapeError::InvalidDigit('}')), unescape(r"\u{}"));
+        assert_eq!(
+            Err(RustEscapeError::InvalidDigit('0')),
+            unescape(r"\u{1000000}")
+        );
+        assert_eq!(
+            Err(RustEscapeError::InvalidCodepoint(0x110000)),
+            unescape(r"\u{110000}")
+        );
+        assert_eq!(
+            Err(RustEscapeError::InvalidCodepoint(0xD800)),
+            unescape(r"\u{D800}")
+        );
+        assert_eq!(Err(RustEscapeError::Unterminated), unescape(r"abc\u{41"));
+        assert_eq!(Err(RustEscapeError::Unterminated), unescape(r"abc\"));
+    }
+}
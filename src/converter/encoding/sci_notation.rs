@@ -0,0 +1,251 @@
+//! Formatting/Parsing numbers in the scientific notation.
+//!
+//! Numbers are formatted like `6.022e+23`, with a fixed number of decimal places, a sign of the
+//! exponent and at least two digits of the exponent, as C's `printf("%e")` does.
+
+use crate::Converter;
+use alloc::string::String;
+use core::fmt;
+
+/// An error while formatting/parsing numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SciNotationError {
+    /// A NaN, without passing non-finite values through.
+    NaN,
+    /// An infinite value, without passing non-finite values through.
+    Infinite,
+    /// A string which is not a number.
+    Invalid,
+}
+
+impl fmt::Display for SciNotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NaN => write!(f, "the value is NaN."),
+            Self::Infinite => write!(f, "the value is infinite."),
+            Self::Invalid => write!(f, "the string is not a number."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SciNotationError {}
+
+// returns an error for non-finite values, unless they are passed through.
+#[inline]
+fn check(value: f64, non_finite: bool) -> Result<f64, SciNotationError> {
+    match value {
+        _ if non_finite || value.is_finite() => Ok(value),
+        _ if value.is_nan() => Err(SciNotationError::NaN),
+        _ => Err(SciNotationError::Infinite),
+    }
+}
+
+/// A converter formatting numbers in the scientific notation.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::sci_notation::{SciNotationConverter, SciNotationError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [3.14159, 6.022e23, -0.00012];
+/// let formatted = ConvertedIterator::new(iter, SciNotationConverter::new(2));
+/// assert_eq!(
+///     Ok(vec![
+///         String::from("3.14e+00"),
+///         String::from("6.02e+23"),
+///         String::from("-1.20e-04"),
+///     ]),
+///     formatted.collect(),
+/// );
+///
+/// let mut formatted = ConvertedIterator::new([f64::NAN], SciNotationConverter::new(2));
+/// assert_eq!(Some(Err(SciNotationError::NaN)), formatted.next());
+///
+/// let converter = SciNotationConverter::new(2).non_finite(true);
+/// let formatted = ConvertedIterator::new([f64::NAN, f64::NEG_INFINITY], converter);
+/// assert_eq!(Ok(vec![String::from("nan"), String::from("-inf")]), formatted.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SciNotationConverter {
+    precision: usize,
+    non_finite: bool,
+}
+
+impl SciNotationConverter {
+    /// Create a new instance with the number of decimal places.
+    #[inline]
+    pub fn new(precision: usize) -> Self {
+        Self {
+            precision,
+            non_finite: false,
+        }
+    }
+
+    /// Setting whether to format NaN and infinite values as `"nan"`, `"inf"` and `"-inf"`,
+    /// instead of returning errors.
+    #[inline]
+    pub fn non_finite(self, non_finite: bool) -> Self {
+        Self { non_finite, ..self }
+    }
+}
+
+impl Converter for SciNotationConverter {
+    type Item = f64;
+    type Output = String;
+    type Error = SciNotationError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let value = check(item, self.non_finite)?;
+        let s = if value.is_nan() {
+            String::from("nan")
+        } else if value.is_infinite() {
+            String::from(if value < 0.0 { "-inf" } else { "inf" })
+        } else {
+            // `{:e}` omits the sign and the padding of the exponent, like `1.5e-4`.
+            let s = alloc::format!("{:.*e}", self.precision, value);
+            let (mantissa, exponent) = s.split_once('e').unwrap_or((&s, "0"));
+            let (sign, digits) = match exponent.strip_prefix('-') {
+                Some(digits) => ('-', digits),
+                None => ('+', exponent),
+            };
+            alloc::format!("{}e{}{:0>2}", mantissa, sign, digits)
+        };
+        buf.extend([s]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A converter parsing numbers, in the scientific notation or not.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::sci_notation::{ParseSciNotation, SciNotationError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = ["6.022e+23", "-1.20e-04", "42"].map(String::from);
+/// let parsed = ConvertedIterator::new(iter, ParseSciNotation::new());
+/// assert_eq!(Ok(vec![6.022e23, -1.2e-4, 42.0]), parsed.collect());
+///
+/// let mut parsed = ConvertedIterator::new([String::from("1.0e+"), String::from("inf")], ParseSciNotation::new());
+/// assert_eq!(Some(Err(SciNotationError::Invalid)), parsed.next());
+/// assert_eq!(Some(Err(SciNotationError::Infinite)), parsed.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseSciNotation {
+    non_finite: bool,
+}
+
+impl ParseSciNotation {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting whether to parse `"nan"`, `"inf"` and `"-inf"` (case-insensitive), instead of
+    /// returning errors.
+    #[inline]
+    pub fn non_finite(self, non_finite: bool) -> Self {
+        Self { non_finite }
+    }
+}
+
+impl Converter for ParseSciNotation {
+    type Item = String;
+    type Output = f64;
+    type Error = SciNotationError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let value = item
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| SciNotationError::Invalid)?;
+        buf.extend([check(value, self.non_finite)?]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let vectors = [
+            (0.0, 3, "0.000e+00"),
+            (1.0, 0, "1e+00"),
+            (-2.5, 1, "-2.5e+00"),
+            (9.995, 2, "9.99e+00"),
+            (99999.0, 2, "1.00e+05"),
+            (1.602e-19, 3, "1.602e-19"),
+            (f64::MAX, 4, "1.7977e+308"),
+            (f64::MIN_POSITIVE, 1, "2.2e-308"),
+        ];
+        for (value, precision, expected) in vectors {
+            let formatted = ConvertedIterator::new([value], SciNotationConverter::new(precision))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(expected, formatted[0]);
+        }
+
+        // the shortest precision for `f64` keeps values.
+        let values = [core::f64::consts::PI, -6.674e-11, 1e300, 5e-324];
+        let formatted = ConvertedIterator::new(values, SciNotationConverter::new(16));
+        let parsed = ConvertedIterator::new(formatted.map(Result::unwrap), ParseSciNotation::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(values.to_vec()), parsed);
+    }
+
+    #[test]
+    fn non_finite() {
+        use alloc::vec::Vec;
+
+        let mut parser = ParseSciNotation::new();
+        let mut buf = Vec::new();
+        assert_eq!(
+            Err(SciNotationError::NaN),
+            parser.convert(String::from("NaN"), &mut buf)
+        );
+        assert_eq!(
+            Err(SciNotationError::Invalid),
+            parser.convert(String::from("e+10"), &mut buf)
+        );
+
+        let mut parser = ParseSciNotation::new().non_finite(true);
+        assert_eq!(Ok(1), parser.convert(String::from("-inf"), &mut buf));
+        assert_eq!(Ok(1), parser.convert(String::from(" 1e+1 "), &mut buf));
+        assert_eq!(Ok(1), parser.convert(String::from("nan"), &mut buf));
+        assert_eq!(f64::NEG_INFINITY, buf[0]);
+        assert_eq!(10.0, buf[1]);
+        assert!(buf[2].is_nan());
+
+        let mut converter = SciNotationConverter::new(1);
+        let mut strings = Vec::new();
+        assert_eq!(
+            Err(SciNotationError::Infinite),
+            converter.convert(f64::INFINITY, &mut strings)
+        );
+        assert!(strings.is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+//! Escaping SQL string literals.
+
+use crate::Converter;
+use core::convert::Infallible;
+
+/// Ways to escape SQL string literals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SQLDialect {
+    /// Standard SQL, as PostgreSQL (with `standard_conforming_strings`) and SQLite use. `'` is
+    /// escaped as `''`, and the rest are emitted as they are.
+    #[default]
+    Standard,
+    /// MySQL, as `mysql_real_escape_string` does. `'`, `"` and `\` are escaped with a
+    /// backslash, and NUL, LF, CR and `U+001A` are escaped as `\0`, `\n`, `\r` and `\Z`.
+    MySQL,
+}
+
+/// An escaper for SQL string literals, emits the literal including the enclosing quotes.
+///
+/// The opening quote is emitted with the first character, and the closing quote is emitted at
+/// [`finish`]. (An empty input is emitted as `''`.)
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::sql_escape::{SQLDialect, SQLEscapeConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let escaped = ConvertedIterator::new(r"O'Reilly\".chars(), SQLEscapeConverter::new());
+/// assert_eq!(Ok(String::from(r"'O''Reilly\'")), escaped.collect());
+///
+/// let converter = SQLEscapeConverter::with_dialect(SQLDialect::MySQL);
+/// let escaped = ConvertedIterator::new("O'Reilly\\\n".chars(), converter);
+/// assert_eq!(Ok(String::from(r"'O\'Reilly\\\n'")), escaped.collect());
+/// ```
+///
+/// [`finish`]: crate::Converter::finish
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SQLEscapeConverter {
+    dialect: SQLDialect,
+    started: bool,
+}
+
+impl SQLEscapeConverter {
+    /// Create a new instance, escapes as standard SQL.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance with a dialect.
+    #[inline]
+    pub fn with_dialect(dialect: SQLDialect) -> Self {
+        Self {
+            dialect,
+            started: false,
+        }
+    }
+}
+
+impl Converter for SQLEscapeConverter {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        if !core::mem::replace(&mut self.started, true) {
+            buf.extend(['\'']);
+            len += 1;
+        }
+        let escaped = match (self.dialect, item) {
+            (SQLDialect::Standard, '\'') => Some('\''),
+            (SQLDialect::Standard, _) => None,
+            (SQLDialect::MySQL, '\'' | '"' | '\\') => Some(item),
+            (SQLDialect::MySQL, '\0') => Some('0'),
+            (SQLDialect::MySQL, '\n') => Some('n'),
+            (SQLDialect::MySQL, '\r') => Some('r'),
+            (SQLDialect::MySQL, '\x1a') => Some('Z'),
+            (SQLDialect::MySQL, _) => None,
+        };
+        match escaped {
+            Some(c) if self.dialect == SQLDialect::Standard => buf.extend(['\'', c]),
+            Some(c) => buf.extend(['\\', c]),
+            None => {
+                buf.extend([item]);
+                return Ok(len + 1);
+            }
+        }
+        Ok(len + 2)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if core::mem::take(&mut self.started) {
+            buf.extend(['\'']);
+            Ok(1)
+        } else {
+            buf.extend(['\'', '\'']);
+            Ok(2)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(if self.started { 1 } else { 2 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(3))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dialects() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let vectors = [
+            ("", "''", "''"),
+            ("plain", "'plain'", "'plain'"),
+            ("'", "''''", r"'\''"),
+            ("it's \"ok\"", "'it''s \"ok\"'", r#"'it\'s \"ok\"'"#),
+            ("a\\b\0\r\n\x1a", "'a\\b\0\r\n\x1a'", r"'a\\b\0\r\n\Z'"),
+            ("ß'ü", "'ß''ü'", r"'ß\'ü'"),
+        ];
+        for (input, standard, mysql) in vectors {
+            for (dialect, expected) in
+                [(SQLDialect::Standard, standard), (SQLDialect::MySQL, mysql)]
+            {
+                let escaped = ConvertedIterator::new(
+                    input.chars(),
+                    SQLEscapeConverter::with_dialect(dialect),
+                )
+                .collect::<Result<String, _>>();
+                assert_eq!(Ok(String::from(expected)), escaped, "{:?}", input);
+            }
+        }
+
+        // the converter is reused for another literal.
+        let mut converter = SQLEscapeConverter::new();
+        let mut buf = String::new();
+        assert_eq!(Ok(2), converter.convert('a', &mut buf));
+        assert_eq!(Ok(1), converter.finish(&mut buf));
+        assert_eq!(Ok(3), converter.convert('\'', &mut buf));
+        assert_eq!(Ok(1), converter.finish(&mut buf));
+        assert_eq!("'a'''''", buf);
+    }
+}
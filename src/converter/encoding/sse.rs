@@ -0,0 +1,353 @@
+//! Server-Sent Events Encoder/Decoder.
+//!
+//! Events are tuples of an event type, data, and an ID, like `(Some("update"), "1\n2", None)`.
+//! An event is encoded into `event: `, `id: ` and `data: ` lines, one data line for each line of
+//! the data, and an empty line.
+//!
+//! The decoder follows the parsing rules of `EventSource` in the HTML Living Standard: lines may
+//! end with CRLF, CR or LF, comments and unknown fields (including `retry`) are ignored, and an
+//! event without data lines is not emitted. Like `EventSource`, the ID is the last event ID, which
+//! persists until another `id` field. An event not terminated by an empty line is discarded.
+
+use crate::Converter;
+use alloc::string::String;
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// An error while encoding events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SSEError {
+    /// An event type with a line break.
+    InvalidEventType,
+    /// An ID with a line break or a NUL.
+    InvalidID,
+}
+
+impl fmt::Display for SSEError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEventType => write!(f, "the event type contains a line break."),
+            Self::InvalidID => write!(f, "the ID contains a line break or a NUL."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SSEError {}
+
+/// An encoder for events, emits characters of the wire format.
+///
+/// Line breaks of the data (CRLF, CR and LF) split data lines, so they are decoded as LF.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::sse::{SSEEncoder, SSEError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [(Some("update"), "1\n2", Some("42")), (None, "done", None)];
+/// let encoded = ConvertedIterator::new(iter, SSEEncoder::new());
+/// assert_eq!(
+///     Ok(String::from("event: update\nid: 42\ndata: 1\ndata: 2\n\ndata: done\n\n")),
+///     encoded.collect(),
+/// );
+///
+/// let mut encoded = ConvertedIterator::new([(Some("a\nb"), "", None)], SSEEncoder::new());
+/// assert_eq!(Some(Err(SSEError::InvalidEventType)), encoded.next());
+/// ```
+pub struct SSEEncoder<S> {
+    _phantom: PhantomData<S>,
+}
+
+impl<S> Clone for SSEEncoder<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for SSEEncoder<S> {}
+
+impl<S> fmt::Debug for SSEEncoder<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SSEEncoder").finish()
+    }
+}
+
+impl<S> Default for SSEEncoder<S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S> PartialEq for SSEEncoder<S> {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<S> Eq for SSEEncoder<S> {}
+
+impl<S> SSEEncoder<S> {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// emitting a field line, and returns the number of characters.
+fn field<E: Extend<char>>(name: &str, value: &str, buf: &mut E) -> usize {
+    let chars = name.chars().chain(": ".chars()).chain(value.chars());
+    let mut len = 1;
+    buf.extend(chars.inspect(|_| len += 1));
+    buf.extend(['\n']);
+    len
+}
+
+impl<S: AsRef<str>> Converter for SSEEncoder<S> {
+    type Item = (Option<S>, S, Option<S>);
+    type Output = char;
+    type Error = SSEError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (event, data, id) = item;
+        let is_break = |c| c == '\r' || c == '\n';
+        if let Some(event) = &event {
+            if event.as_ref().contains(is_break) {
+                return Err(SSEError::InvalidEventType);
+            }
+        }
+        if let Some(id) = &id {
+            if id.as_ref().contains(|c| is_break(c) || c == '\0') {
+                return Err(SSEError::InvalidID);
+            }
+        }
+
+        let mut len = 1;
+        if let Some(event) = event {
+            len += field("event", event.as_ref(), buf);
+        }
+        if let Some(id) = id {
+            len += field("id", id.as_ref(), buf);
+        }
+        let data = data.as_ref();
+        let mut rest = data;
+        loop {
+            match rest.find(is_break) {
+                Some(i) => {
+                    len += field("data", &rest[..i], buf);
+                    let skip = if rest[i..].starts_with("\r\n") { 2 } else { 1 };
+                    rest = &rest[i + skip..];
+                }
+                None => {
+                    len += field("data", rest, buf);
+                    break;
+                }
+            }
+        }
+        buf.extend(['\n']);
+        Ok(len)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (8, None)
+    }
+}
+
+/// A decoder for the wire format, emits events.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::sse::SSEDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let wire = ": comment\r\nevent: update\r\nid: 42\r\ndata: 1\r\ndata:2\r\n\r\ndata\n\n";
+/// let mut decoded = ConvertedIterator::new(wire.chars(), SSEDecoder::new());
+/// assert_eq!(
+///     Some(Ok((Some(String::from("update")), String::from("1\n2"), Some(String::from("42"))))),
+///     decoded.next(),
+/// );
+/// // the last event ID persists.
+/// assert_eq!(
+///     Some(Ok((None, String::new(), Some(String::from("42"))))),
+///     decoded.next(),
+/// );
+/// assert_eq!(None, decoded.next());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SSEDecoder {
+    started: bool,
+    after_cr: bool,
+    line: String,
+    event: Option<String>,
+    data: String,
+    id: Option<String>,
+}
+
+impl SSEDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // processing a line, and returns the number of events.
+    fn process<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<(Option<String>, String, Option<String>)>,
+    {
+        let line = core::mem::take(&mut self.line);
+        if line.is_empty() {
+            let event = self.event.take();
+            if self.data.is_empty() {
+                return 0;
+            }
+            let mut data = core::mem::take(&mut self.data);
+            data.pop();
+            buf.extend([(event, data, self.id.clone())]);
+            return 1;
+        }
+
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line.as_str(), ""),
+        };
+        match name {
+            "event" => self.event = Some(String::from(value)).filter(|s| !s.is_empty()),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.id = Some(String::from(value)).filter(|s| !s.is_empty())
+            }
+            // comments, `retry` and unknown fields.
+            _ => {}
+        }
+        0
+    }
+}
+
+impl Converter for SSEDecoder {
+    type Item = char;
+    type Output = (Option<String>, String, Option<String>);
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let started = core::mem::replace(&mut self.started, true);
+        let after_cr = core::mem::replace(&mut self.after_cr, item == '\r');
+        match item {
+            '\u{FEFF}' if !started => Ok(0),
+            '\n' if after_cr => Ok(0),
+            '\r' | '\n' => Ok(self.process(buf)),
+            c => {
+                self.line.push(c);
+                Ok(0)
+            }
+        }
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        // discarding the unterminated event.
+        *self = Self::default();
+        Ok(0)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let events = [
+            (Some("add"), "line 1\nline 2", Some("1")),
+            (None, "", Some("2")),
+            (
+                Some("status"),
+                "data: not a field\n\n: nor a comment",
+                Some("3"),
+            ),
+            (None, " leading space", Some("4")),
+        ];
+        let encoded = ConvertedIterator::new(events, SSEEncoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        let decoded = ConvertedIterator::new(encoded.chars(), SSEDecoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let expected = events
+            .iter()
+            .map(|&(event, data, id)| {
+                (
+                    event.map(String::from),
+                    String::from(data),
+                    id.map(String::from),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(expected, decoded);
+
+        // line breaks of data are normalized.
+        let encoded = ConvertedIterator::new([(None, "a\r\nb\rc", None)], SSEEncoder::new())
+            .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from("data: a\ndata: b\ndata: c\n\n")), encoded);
+    }
+
+    #[test]
+    fn parse() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let wire = "\u{FEFF}event: ignored\n\nretry: 10\nunknown\ndata:  two spaces\rid\r\r\
+                    id: bad\0\nevent\ndata\n\ndata: unterminated\n";
+        let decoded =
+            ConvertedIterator::new(wire.chars(), SSEDecoder::new()).collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            Ok(Vec::from([
+                (None, String::from(" two spaces"), None),
+                (None, String::new(), None),
+            ])),
+            decoded
+        );
+
+        let mut encoder = SSEEncoder::new();
+        let mut buf = String::new();
+        assert_eq!(
+            Err(SSEError::InvalidID),
+            encoder.convert((None, "", Some("a\0")), &mut buf)
+        );
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,377 @@
+//! Byte-stuffing framing Encoder/Decoder.
+//!
+//! Each frame is bracketed by a start byte and an end byte, and occurrences of the start, end
+//! and escape bytes in the payload are preceded by the escape byte. The escaped byte is either
+//! the byte itself ([`EscapeStyle::Duplicate`], as DLE/STX/ETX protocols do) or the byte XORed
+//! with `0x20` ([`EscapeStyle::Xor`], as HDLC and PPP do). The start byte and the end byte may be
+//! the same, like `0x7E` of HDLC.
+//!
+//! [`EscapeStyle::Duplicate`]: self::EscapeStyle::Duplicate
+//! [`EscapeStyle::Xor`]: self::EscapeStyle::Xor
+
+use crate::Converter;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// STX (start of text), the start byte of [`dle`] framing.
+///
+/// [`dle`]: self::StuffingEncoder::dle
+pub const STX: u8 = 0x02;
+/// ETX (end of text), the end byte of [`dle`] framing.
+///
+/// [`dle`]: self::StuffingEncoder::dle
+pub const ETX: u8 = 0x03;
+/// DLE (data link escape), the escape byte of [`dle`] framing.
+///
+/// [`dle`]: self::StuffingEncoder::dle
+pub const DLE: u8 = 0x10;
+
+/// How to escape bytes following the escape byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EscapeStyle {
+    /// The byte itself.
+    #[default]
+    Duplicate,
+    /// The byte XORed with `0x20`.
+    Xor,
+}
+
+/// An error while decoding byte-stuffed frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StuffingError {
+    /// A byte outside frames, without discarding noise.
+    Noise(u8),
+    /// A byte which cannot be escaped, following the escape byte.
+    InvalidEscape(u8),
+    /// The start byte inside a frame, which discards the frame and starts a new one.
+    UnexpectedStart,
+    /// The input ended inside a frame.
+    Truncated,
+}
+
+impl fmt::Display for StuffingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Noise(b) => write!(f, "unexpected byte outside frames: 0x{:02X}", b),
+            Self::InvalidEscape(b) => write!(f, "invalid escaped byte: 0x{:02X}", b),
+            Self::UnexpectedStart => write!(f, "the start byte inside a frame."),
+            Self::Truncated => write!(f, "the input ended inside a frame."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StuffingError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Sentinels {
+    start: u8,
+    end: u8,
+    escape: u8,
+    style: EscapeStyle,
+}
+
+impl Sentinels {
+    #[inline]
+    fn new(start: u8, end: u8, escape: u8) -> Self {
+        assert!(
+            escape != start && escape != end,
+            "the escape byte must differ from the start and end bytes"
+        );
+        Self {
+            start,
+            end,
+            escape,
+            style: EscapeStyle::default(),
+        }
+    }
+
+    #[inline]
+    fn is_special(&self, byte: u8) -> bool {
+        byte == self.start || byte == self.end || byte == self.escape
+    }
+}
+
+/// An encoder bracketing and escaping each message.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::stuffing::{EscapeStyle, StuffingEncoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [vec![0x41, 0x03, 0x10]];
+/// let encoded = ConvertedIterator::new(iter, StuffingEncoder::dle());
+/// assert_eq!(Ok(vec![0x02, 0x41, 0x10, 0x03, 0x10, 0x10, 0x03]), encoded.collect());
+///
+/// // HDLC-like framing.
+/// let encoder = StuffingEncoder::new(0x7E, 0x7E, 0x7D).style(EscapeStyle::Xor);
+/// let encoded = ConvertedIterator::new([vec![0x7E, 0x00]], encoder);
+/// assert_eq!(Ok(vec![0x7E, 0x7D, 0x5E, 0x00, 0x7E]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StuffingEncoder {
+    sentinels: Sentinels,
+}
+
+impl StuffingEncoder {
+    /// Create a new instance with the start, end and escape bytes, escapes bytes by duplicating.
+    ///
+    /// # Panics
+    /// Panics if `escape` is the same as `start` or `end`.
+    #[inline]
+    pub fn new(start: u8, end: u8, escape: u8) -> Self {
+        Self {
+            sentinels: Sentinels::new(start, end, escape),
+        }
+    }
+
+    /// Create a new instance for DLE/STX/ETX framing.
+    #[inline]
+    pub fn dle() -> Self {
+        Self::new(STX, ETX, DLE)
+    }
+
+    /// Setting how to escape bytes.
+    #[inline]
+    pub fn style(mut self, style: EscapeStyle) -> Self {
+        self.sentinels.style = style;
+        self
+    }
+}
+
+impl Converter for StuffingEncoder {
+    type Item = Vec<u8>;
+    type Output = u8;
+    type Error = core::convert::Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let s = self.sentinels;
+        let mut len = 2;
+        buf.extend([s.start]);
+        for b in item {
+            if !s.is_special(b) {
+                buf.extend([b]);
+                len += 1;
+                continue;
+            }
+            let escaped = match s.style {
+                EscapeStyle::Duplicate => b,
+                EscapeStyle::Xor => b ^ 0x20,
+            };
+            buf.extend([s.escape, escaped]);
+            len += 2;
+        }
+        buf.extend([s.end]);
+        Ok(len)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (2, None)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Outside,
+    Inside,
+    Escaped,
+}
+
+/// A decoder emitting payloads of frames.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::stuffing::{StuffingDecoder, StuffingError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0x02, 0x41, 0x10, 0x03, 0x10, 0x10, 0x03, 0xFF, 0x02, 0x42];
+/// let mut decoded = ConvertedIterator::new(iter, StuffingDecoder::dle());
+/// assert_eq!(Some(Ok(vec![0x41, 0x03, 0x10])), decoded.next());
+/// assert_eq!(Some(Err(StuffingError::Noise(0xFF))), decoded.next());
+/// assert_eq!(Some(Err(StuffingError::Truncated)), decoded.next());
+/// assert_eq!(None, decoded.next());
+///
+/// let iter = [0xFF, 0x02, 0x41, 0x03, 0x00];
+/// let decoded = ConvertedIterator::new(iter, StuffingDecoder::dle().discard_noise(true));
+/// assert_eq!(Ok(vec![vec![0x41]]), decoded.collect());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StuffingDecoder {
+    sentinels: Sentinels,
+    discard_noise: bool,
+    state: State,
+    frame: Vec<u8>,
+}
+
+impl StuffingDecoder {
+    /// Create a new instance with the start, end and escape bytes, unescapes duplicated bytes.
+    ///
+    /// # Panics
+    /// Panics if `escape` is the same as `start` or `end`.
+    #[inline]
+    pub fn new(start: u8, end: u8, escape: u8) -> Self {
+        Self {
+            sentinels: Sentinels::new(start, end, escape),
+            discard_noise: false,
+            state: State::Outside,
+            frame: Vec::new(),
+        }
+    }
+
+    /// Create a new instance for DLE/STX/ETX framing.
+    #[inline]
+    pub fn dle() -> Self {
+        Self::new(STX, ETX, DLE)
+    }
+
+    /// Setting how bytes are escaped.
+    #[inline]
+    pub fn style(mut self, style: EscapeStyle) -> Self {
+        self.sentinels.style = style;
+        self
+    }
+
+    /// Setting whether to discard bytes outside frames, instead of returning errors.
+    #[inline]
+    pub fn discard_noise(self, discard_noise: bool) -> Self {
+        Self {
+            discard_noise,
+            ..self
+        }
+    }
+}
+
+impl Converter for StuffingDecoder {
+    type Item = u8;
+    type Output = Vec<u8>;
+    type Error = StuffingError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let s = self.sentinels;
+        match self.state {
+            State::Outside if item == s.start => self.state = State::Inside,
+            State::Outside if self.discard_noise => {}
+            State::Outside => return Err(StuffingError::Noise(item)),
+            State::Inside if item == s.end => {
+                self.state = State::Outside;
+                buf.extend([core::mem::take(&mut self.frame)]);
+                return Ok(1);
+            }
+            State::Inside if item == s.start => {
+                self.frame.clear();
+                return Err(StuffingError::UnexpectedStart);
+            }
+            State::Inside if item == s.escape => self.state = State::Escaped,
+            State::Inside => self.frame.push(item),
+            State::Escaped => {
+                let unescaped = match s.style {
+                    EscapeStyle::Duplicate => item,
+                    EscapeStyle::Xor => item ^ 0x20,
+                };
+                if !s.is_special(unescaped) {
+                    self.state = State::Inside;
+                    return Err(StuffingError::InvalidEscape(item));
+                }
+                self.frame.push(unescaped);
+                self.state = State::Inside;
+            }
+        }
+        Ok(0)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.frame.clear();
+        match core::mem::take(&mut self.state) {
+            State::Outside => Ok(()),
+            _ => Err(StuffingError::Truncated),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+
+        // payloads full of sentinels, including the escaped forms of the XOR style.
+        let frames = vec![
+            vec![],
+            vec![STX, ETX, DLE],
+            vec![DLE, DLE, STX, 0x22, 0x23, 0x30, 0x7E, 0x7D],
+            vec![0x5E, 0x7E, 0x7D, 0x5D, 0x7E],
+        ];
+        let configs = [
+            (StuffingEncoder::dle(), StuffingDecoder::dle()),
+            (
+                StuffingEncoder::dle().style(EscapeStyle::Xor),
+                StuffingDecoder::dle().style(EscapeStyle::Xor),
+            ),
+            (
+                StuffingEncoder::new(0x7E, 0x7E, 0x7D).style(EscapeStyle::Xor),
+                StuffingDecoder::new(0x7E, 0x7E, 0x7D).style(EscapeStyle::Xor),
+            ),
+        ];
+        for (encoder, decoder) in configs {
+            let encoded = ConvertedIterator::new(frames.iter().cloned(), encoder)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let decoded = ConvertedIterator::new(encoded, decoder).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(frames.clone()), decoded);
+        }
+
+        let encoded = ConvertedIterator::new([vec![STX, DLE]], StuffingEncoder::dle())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![STX, DLE, STX, DLE, DLE, ETX]), encoded);
+        let encoder = StuffingEncoder::dle().style(EscapeStyle::Xor);
+        let encoded =
+            ConvertedIterator::new([vec![STX, DLE]], encoder).collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![STX, DLE, 0x22, DLE, 0x30, ETX]), encoded);
+    }
+
+    #[test]
+    fn errors() {
+        use alloc::vec;
+
+        let mut decoder = StuffingDecoder::dle().style(EscapeStyle::Xor);
+        let mut buf = Vec::new();
+        for b in [STX, 0x41, DLE] {
+            assert_eq!(Ok(0), decoder.convert(b, &mut buf));
+        }
+        assert_eq!(
+            Err(StuffingError::InvalidEscape(0x41)),
+            decoder.convert(0x41, &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(0x42, &mut buf));
+        // a new frame starts.
+        assert_eq!(
+            Err(StuffingError::UnexpectedStart),
+            decoder.convert(STX, &mut buf)
+        );
+        assert_eq!(Ok(0), decoder.convert(0x43, &mut buf));
+        assert_eq!(Ok(1), decoder.convert(ETX, &mut buf));
+        assert_eq!(vec![vec![0x43]], buf);
+
+        assert_eq!(Ok(0), decoder.convert(STX, &mut buf));
+        assert_eq!(Ok(0), decoder.convert(DLE, &mut buf));
+        assert_eq!(Err(StuffingError::Truncated), decoder.finalize());
+        assert_eq!(Ok(()), decoder.finalize());
+    }
+}
@@ -0,0 +1,211 @@
+//! Converters between characters and their Unicode names, like `LATIN CAPITAL LETTER A`.
+//!
+//! Names are looked up by the [`unicode_names2`] crate, following the Unicode Character Database,
+//! including names derived from code points (`CJK UNIFIED IDEOGRAPH-4E00`) and Hangul syllables
+//! (`HANGUL SYLLABLE GA`). Control characters and unassigned code points have no names, and
+//! aliases are not supported.
+//!
+//! [`unicode_names2`]: https://docs.rs/unicode_names2
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// An error while converting characters and their names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnicodeNameError {
+    /// A character without a name.
+    Unnamed(char),
+    /// A name of no characters.
+    UnknownName(String),
+}
+
+impl fmt::Display for UnicodeNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unnamed(c) => write!(f, "the character has no name: {:?}", c),
+            Self::UnknownName(name) => write!(f, "unknown character name: {:?}", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnicodeNameError {}
+
+/// A converter from characters to their names.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::unicode_name::{UnicodeNameConverter, UnicodeNameError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut names = ConvertedIterator::new("Aß가\0".chars(), UnicodeNameConverter::new());
+///
+/// assert_eq!(Some(Ok(String::from("LATIN CAPITAL LETTER A"))), names.next());
+/// assert_eq!(Some(Ok(String::from("LATIN SMALL LETTER SHARP S"))), names.next());
+/// assert_eq!(Some(Ok(String::from("HANGUL SYLLABLE GA"))), names.next());
+/// assert_eq!(Some(Err(UnicodeNameError::Unnamed('\0'))), names.next());
+/// assert_eq!(None, names.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnicodeNameConverter;
+
+impl UnicodeNameConverter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for UnicodeNameConverter {
+    type Item = char;
+    type Output = String;
+    type Error = UnicodeNameError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let name = unicode_names2::name(item).ok_or(UnicodeNameError::Unnamed(item))?;
+        buf.extend([name.to_string()]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for UnicodeNameConverter {}
+
+impl ExactSizeConverter for UnicodeNameConverter {}
+
+/// A converter from names to characters.
+///
+/// Names are case-insensitive.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::unicode_name::{UnicodeNameError, UnicodeNameToChar};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = ["Latin Capital Letter A", "CJK UNIFIED IDEOGRAPH-4E00", "NO SUCH CHARACTER"]
+///     .into_iter()
+///     .map(String::from);
+/// let mut chars = ConvertedIterator::new(iter, UnicodeNameToChar::new());
+///
+/// assert_eq!(Some(Ok('A')), chars.next());
+/// assert_eq!(Some(Ok('一')), chars.next());
+/// assert_eq!(
+///     Some(Err(UnicodeNameError::UnknownName(String::from("NO SUCH CHARACTER")))),
+///     chars.next(),
+/// );
+/// assert_eq!(None, chars.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnicodeNameToChar;
+
+impl UnicodeNameToChar {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for UnicodeNameToChar {
+    type Item = String;
+    type Output = char;
+    type Error = UnicodeNameError;
+
+    fn convert<E>(&mut self, mut item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        item.make_ascii_uppercase();
+        match unicode_names2::character(&item) {
+            Some(c) => {
+                buf.extend([c]);
+                Ok(1)
+            }
+            None => Err(UnicodeNameError::UnknownName(item)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for UnicodeNameToChar {}
+
+impl ExactSizeConverter for UnicodeNameToChar {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut names = ConvertedIterator::new(
+            (0..=0x10FFFF).filter_map(char::from_u32),
+            UnicodeNameConverter::new(),
+        );
+        let mut chars = UnicodeNameToChar::new();
+        let mut named = 0;
+        for (c, name) in (0..=0x10FFFF)
+            .filter_map(char::from_u32)
+            .zip(names.by_ref())
+        {
+            if let Ok(name) = name {
+                named += 1;
+                let mut buf = Vec::new();
+                // names are case-insensitive.
+                assert_eq!(Ok(1), chars.convert(name.to_lowercase(), &mut buf));
+                assert_eq!(vec![c], buf, "{}", name);
+            }
+        }
+        assert!(named > 140_000);
+    }
+
+    #[test]
+    fn names() {
+        use alloc::vec::Vec;
+
+        let vectors = [
+            (' ', "SPACE"),
+            ('\u{A0}', "NO-BREAK SPACE"),
+            ('\u{F0F}', "TIBETAN MARK TSHEG SHAD"),
+            ('\u{D7A3}', "HANGUL SYLLABLE HIH"),
+            ('\u{FA6D}', "CJK COMPATIBILITY IDEOGRAPH-FA6D"),
+            ('\u{1F600}', "GRINNING FACE"),
+            ('\u{E01EF}', "VARIATION SELECTOR-256"),
+        ];
+        let mut buf = Vec::new();
+        for (c, _) in vectors {
+            assert_eq!(Ok(1), UnicodeNameConverter::new().convert(c, &mut buf));
+        }
+        assert_eq!(vectors.map(|(_, name)| String::from(name)).to_vec(), buf);
+
+        for c in ['\n', '\u{FA6E}', '\u{E000}', '\u{10FFFF}'] {
+            assert_eq!(
+                Err(UnicodeNameError::Unnamed(c)),
+                UnicodeNameConverter::new().convert(c, &mut buf)
+            );
+        }
+        for unknown in ["", "CJK UNIFIED IDEOGRAPH-4DC0", "HANGUL SYLLABLE GX"] {
+            assert_eq!(
+                Err(UnicodeNameError::UnknownName(String::from(unknown))),
+                UnicodeNameToChar::new().convert(String::from(unknown), &mut Vec::new())
+            );
+        }
+    }
+}
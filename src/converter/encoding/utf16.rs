@@ -1,5 +1,9 @@
 //! UTF-16 Decoder/Encoder.
 
+use super::word::{BigEndian, BytePairToU16, LittleEndian};
+use super::Endianness;
+use crate::converter::PipedConverter;
+use crate::infallible::InfallibleConverter;
 use crate::Converter;
 use core::convert::Infallible;
 use core::fmt;
@@ -125,7 +129,7 @@ impl UTF16Encoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -150,6 +154,144 @@ impl Converter for UTF16Encoder {
     }
 }
 
+/// A 16-bit decoder for potentially ill-formed UTF-16, emits code points as `u32`.
+///
+/// Surrogate pairs are combined, but lone surrogates are emitted as they are instead of errors.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf16::UTF16ToCodepoints;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0xD834, 0xDD1E, 0x006d, 0xDD1E, 0xD834].into_iter();
+/// let decoded = ConvertedIterator::new(iter, UTF16ToCodepoints::new());
+///
+/// assert_eq!(Ok(vec![0x1D11E, 0x6d, 0xDD1E, 0xD834]), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UTF16ToCodepoints {
+    buf: Option<u16>,
+}
+
+impl UTF16ToCodepoints {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Converter for UTF16ToCodepoints {
+    type Item = u16;
+    type Output = u32;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.buf.take() {
+            Some(w) if item & 0xFC00 == 0xDC00 => {
+                buf.extend([0x10000 + (((w & 0x3FF) as u32) << 10 | (item & 0x3FF) as u32)]);
+                Ok(1)
+            }
+            Some(w) => {
+                buf.extend([w as u32]);
+                Ok(1 + self.convert(item, buf)?)
+            }
+            None if item & 0xFC00 == 0xD800 => {
+                self.buf = Some(item);
+                Ok(0)
+            }
+            None => {
+                buf.extend([item as u32]);
+                Ok(1)
+            }
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.buf.take() {
+            Some(w) => {
+                buf.extend([w as u32]);
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(usize::from(self.buf.is_some()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(2))
+    }
+}
+
+/// A 16-bit encoder for potentially ill-formed UTF-16, from code points as `u32`.
+///
+/// Code points above `0xFFFF` are split into surrogate pairs, and the others (including
+/// surrogates) are emitted as they are. Values above `0x10FFFF` are replaced with `U+FFFD`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf16::CodepointsToUTF16;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [0x1D11E, 0x6d, 0xDD1E, 0xD834].into_iter();
+/// let encoded = ConvertedIterator::new(iter, CodepointsToUTF16::new());
+///
+/// assert_eq!(Ok(vec![0xD834, 0xDD1E, 0x006d, 0xDD1E, 0xD834]), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CodepointsToUTF16;
+
+impl CodepointsToUTF16 {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for CodepointsToUTF16 {
+    type Item = u32;
+    type Output = u16;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match item {
+            0..=0xFFFF => {
+                buf.extend([item as u16]);
+                Ok(1)
+            }
+            0x10000..=0x10FFFF => {
+                let item = item - 0x10000;
+                buf.extend([0xD800 | (item >> 10) as u16, 0xDC00 | (item & 0x3FF) as u16]);
+                Ok(2)
+            }
+            _ => {
+                buf.extend([0xFFFD]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(2))
+    }
+}
+
 /// A byte decoder for UTF-16 (big-endian).
 ///
 /// # Examples
@@ -172,8 +314,7 @@ impl Converter for UTF16Encoder {
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct UTF16BEDecoder {
-    byte: Option<u8>,
-    inner: UTF16Decoder,
+    inner: PipedConverter<BytePairToU16<BigEndian>, UTF16Decoder>,
 }
 
 impl UTF16BEDecoder {
@@ -189,30 +330,19 @@ impl Converter for UTF16BEDecoder {
     type Output = char;
     type Error = UTF16EncodingError;
 
+    #[inline]
     fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
     where
         E: Extend<Self::Output>,
     {
-        match self.byte {
-            Some(b) => {
-                self.byte = None;
-                self.inner.convert(u16::from_be_bytes([b, item]), buf)
-            }
-            None => {
-                self.byte = Some(item);
-                Ok(0)
-            }
-        }
+        self.inner
+            .convert(item, buf)
+            .map_err(|_| UTF16EncodingError)
     }
 
+    #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
-        self.inner.finalize()?;
-
-        if self.byte.is_some() {
-            Ok(())
-        } else {
-            Err(UTF16EncodingError)
-        }
+        self.inner.finalize().map_err(|_| UTF16EncodingError)
     }
 
     #[inline]
@@ -240,7 +370,7 @@ impl UTF16BEEncoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -289,8 +419,7 @@ impl Converter for UTF16BEEncoder {
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct UTF16LEDecoder {
-    byte: Option<u8>,
-    inner: UTF16Decoder,
+    inner: PipedConverter<BytePairToU16<LittleEndian>, UTF16Decoder>,
 }
 
 impl UTF16LEDecoder {
@@ -306,30 +435,19 @@ impl Converter for UTF16LEDecoder {
     type Output = char;
     type Error = UTF16EncodingError;
 
+    #[inline]
     fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
     where
         E: Extend<Self::Output>,
     {
-        match self.byte {
-            Some(b) => {
-                self.byte = None;
-                self.inner.convert(u16::from_le_bytes([b, item]), buf)
-            }
-            None => {
-                self.byte = Some(item);
-                Ok(0)
-            }
-        }
+        self.inner
+            .convert(item, buf)
+            .map_err(|_| UTF16EncodingError)
     }
 
+    #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
-        self.inner.finalize()?;
-
-        if self.byte.is_none() {
-            Ok(())
-        } else {
-            Err(UTF16EncodingError)
-        }
+        self.inner.finalize().map_err(|_| UTF16EncodingError)
     }
 
     #[inline]
@@ -357,7 +475,7 @@ impl UTF16LEEncoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -383,3 +501,181 @@ impl Converter for UTF16LEEncoder {
         (2, Some(4))
     }
 }
+
+/// A byte encoder for UTF-16, with the byte order specified at runtime.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf16::UTF16ByteEncoder;
+/// use conversion::converter::encoding::Endianness;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let little_endian = true;
+/// let endianness = if little_endian { Endianness::Little } else { Endianness::Big };
+/// let encoded = ConvertedIterator::new("❤".chars(), UTF16ByteEncoder::new(endianness).with_bom());
+///
+/// assert_eq!(Ok(b"\xFF\xFE\x64\x27".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UTF16ByteEncoder {
+    endianness: Endianness,
+    // whether the BOM should be emitted before the next output.
+    bom: bool,
+}
+
+impl UTF16ByteEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(endianness: Endianness) -> Self {
+        Self {
+            endianness,
+            bom: false,
+        }
+    }
+
+    /// Emitting the byte order mark (`U+FEFF`) before the first output.
+    ///
+    /// The BOM is emitted even if the input is empty.
+    #[inline]
+    pub fn with_bom(self) -> Self {
+        Self { bom: true, ..self }
+    }
+
+    fn encode<E>(&self, c: char, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        match self.endianness {
+            Endianness::Big => UTF16BEEncoder.convert_ok(c, buf),
+            Endianness::Little => UTF16LEEncoder.convert_ok(c, buf),
+        }
+    }
+}
+
+impl Converter for UTF16ByteEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        if self.bom {
+            self.bom = false;
+            len += self.encode('\u{FEFF}', buf);
+        }
+        Ok(len + self.encode(item, buf))
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.bom {
+            self.bom = false;
+            Ok(self.encode('\u{FEFF}', buf))
+        } else {
+            Ok(0)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(if self.bom { 2 } else { 0 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (2, Some(if self.bom { 6 } else { 4 }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lone_surrogates() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let inputs: [&[u16]; 6] = [
+            &[0xD83D, 0xDE00, 0x0041],
+            &[0xD800],
+            &[0xDC00],
+            &[0x0041, 0xD800, 0x0042, 0xDFFF, 0x0043],
+            &[0xD800, 0xD800, 0xDC00],
+            &[0xDC00, 0xD800, 0xDBFF, 0xDFFF, 0xDC00],
+        ];
+        for input in inputs {
+            let codepoints =
+                ConvertedIterator::new(input.iter().cloned(), UTF16ToCodepoints::new())
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+            let encoded = ConvertedIterator::new(codepoints, CodepointsToUTF16::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(input, encoded);
+        }
+
+        let codepoints =
+            ConvertedIterator::new(inputs[4].iter().cloned(), UTF16ToCodepoints::new())
+                .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![0xD800, 0x10000]), codepoints);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn byte_encoder() {
+        use super::super::Endianness;
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let encode = |s: &str, encoder| {
+            ConvertedIterator::new(s.chars(), encoder)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        assert_eq!(
+            b"\xD8\x3D\xDE\x00",
+            &encode("😀", UTF16ByteEncoder::new(Endianness::Big))[..]
+        );
+        assert_eq!(
+            b"\xFE\xFF\x00\x41\x00\x42",
+            &encode("AB", UTF16ByteEncoder::new(Endianness::Big).with_bom())[..]
+        );
+        assert_eq!(
+            b"\xFF\xFE",
+            &encode("", UTF16ByteEncoder::new(Endianness::Little).with_bom())[..]
+        );
+        assert!(encode("", UTF16ByteEncoder::new(Endianness::Little)).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn finalize_bytes() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let decode_be = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), UTF16BEDecoder::new())
+                .collect::<Result<String, _>>()
+        };
+        let decode_le = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), UTF16LEDecoder::new())
+                .collect::<Result<String, _>>()
+        };
+
+        // an even number of bytes is complete.
+        assert_eq!(Ok(String::new()), decode_be(b""));
+        assert_eq!(Ok(String::from("AB")), decode_be(b"\x00\x41\x00\x42"));
+        assert_eq!(Ok(String::from("AB")), decode_le(b"\x41\x00\x42\x00"));
+        // a dangling byte.
+        assert_eq!(Err(UTF16EncodingError), decode_be(b"\x00\x41\x00"));
+        assert_eq!(Err(UTF16EncodingError), decode_le(b"\x41\x00\x42"));
+    }
+}
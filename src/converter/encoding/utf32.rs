@@ -1,6 +1,10 @@
 //! UTF-32 Decoder/Encoder.
 
-use crate::Converter;
+use super::word::{BigEndian, ByteQuadToU32, LittleEndian};
+use super::Endianness;
+use crate::converter::PipedConverter;
+use crate::infallible::InfallibleConverter;
+use crate::{Converter, ExactSizeConverter};
 use core::convert::Infallible;
 use core::fmt;
 
@@ -37,7 +41,7 @@ impl UTF32Decoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -65,6 +69,10 @@ impl Converter for UTF32Decoder {
     }
 }
 
+impl crate::sealed::Sealed for UTF32Decoder {}
+
+impl ExactSizeConverter for UTF32Decoder {}
+
 /// A 32-bit encoder for UTF-32.
 ///
 /// # Examples
@@ -83,7 +91,7 @@ impl UTF32Encoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -106,6 +114,10 @@ impl Converter for UTF32Encoder {
     }
 }
 
+impl crate::sealed::Sealed for UTF32Encoder {}
+
+impl ExactSizeConverter for UTF32Encoder {}
+
 /// A byte decoder for UTF-32 (big-endian).
 ///
 /// # Examples
@@ -121,8 +133,7 @@ impl Converter for UTF32Encoder {
 /// assert_eq!(None, decoded.next());
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct UTF32BEDecoder {
-    bytes: [u8; 4],
-    count: usize,
+    inner: PipedConverter<ByteQuadToU32<BigEndian>, UTF32Decoder>,
 }
 
 impl UTF32BEDecoder {
@@ -138,27 +149,19 @@ impl Converter for UTF32BEDecoder {
     type Output = char;
     type Error = UTF32EncodingError;
 
+    #[inline]
     fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
     where
         E: Extend<Self::Output>,
     {
-        self.bytes[self.count] = item;
-        if self.count == 3 {
-            self.count = 0;
-            UTF32Decoder.convert(u32::from_be_bytes(self.bytes), buf)
-        } else {
-            self.count += 1;
-            Ok(0)
-        }
+        self.inner
+            .convert(item, buf)
+            .map_err(|_| UTF32EncodingError)
     }
 
     #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
-        if self.count == 0 {
-            Ok(())
-        } else {
-            Err(UTF32EncodingError)
-        }
+        self.inner.finalize().map_err(|_| UTF32EncodingError)
     }
 
     #[inline]
@@ -185,7 +188,7 @@ impl UTF32BEEncoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -208,6 +211,10 @@ impl Converter for UTF32BEEncoder {
     }
 }
 
+impl crate::sealed::Sealed for UTF32BEEncoder {}
+
+impl ExactSizeConverter for UTF32BEEncoder {}
+
 /// A byte decoder for UTF-32 (little-endian).
 ///
 /// # Examples
@@ -223,8 +230,7 @@ impl Converter for UTF32BEEncoder {
 /// assert_eq!(None, decoded.next());
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct UTF32LEDecoder {
-    bytes: [u8; 4],
-    count: usize,
+    inner: PipedConverter<ByteQuadToU32<LittleEndian>, UTF32Decoder>,
 }
 
 impl UTF32LEDecoder {
@@ -240,27 +246,19 @@ impl Converter for UTF32LEDecoder {
     type Output = char;
     type Error = UTF32EncodingError;
 
+    #[inline]
     fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
     where
         E: Extend<Self::Output>,
     {
-        self.bytes[self.count] = item;
-        if self.count == 3 {
-            self.count = 0;
-            UTF32Decoder.convert(u32::from_le_bytes(self.bytes), buf)
-        } else {
-            self.count += 1;
-            Ok(0)
-        }
+        self.inner
+            .convert(item, buf)
+            .map_err(|_| UTF32EncodingError)
     }
 
     #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
-        if self.count == 0 {
-            Ok(())
-        } else {
-            Err(UTF32EncodingError)
-        }
+        self.inner.finalize().map_err(|_| UTF32EncodingError)
     }
 
     #[inline]
@@ -287,7 +285,7 @@ impl UTF32LEEncoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -309,3 +307,97 @@ impl Converter for UTF32LEEncoder {
         (4, Some(4))
     }
 }
+
+impl crate::sealed::Sealed for UTF32LEEncoder {}
+
+impl ExactSizeConverter for UTF32LEEncoder {}
+
+/// A byte encoder for UTF-32, with the byte order specified at runtime.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf32::UTF32ByteEncoder;
+/// use conversion::converter::encoding::Endianness;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let little_endian = true;
+/// let endianness = if little_endian { Endianness::Little } else { Endianness::Big };
+/// let encoded = ConvertedIterator::new("❤".chars(), UTF32ByteEncoder::new(endianness).with_bom());
+///
+/// assert_eq!(Ok(b"\xFF\xFE\x00\x00\x64\x27\x00\x00".to_vec()), encoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UTF32ByteEncoder {
+    endianness: Endianness,
+    // whether the BOM should be emitted before the next output.
+    bom: bool,
+}
+
+impl UTF32ByteEncoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new(endianness: Endianness) -> Self {
+        Self {
+            endianness,
+            bom: false,
+        }
+    }
+
+    /// Emitting the byte order mark (`U+FEFF`) before the first output.
+    ///
+    /// The BOM is emitted even if the input is empty.
+    #[inline]
+    pub fn with_bom(self) -> Self {
+        Self { bom: true, ..self }
+    }
+
+    fn encode<E>(&self, c: char, buf: &mut E) -> usize
+    where
+        E: Extend<u8>,
+    {
+        match self.endianness {
+            Endianness::Big => UTF32BEEncoder.convert_ok(c, buf),
+            Endianness::Little => UTF32LEEncoder.convert_ok(c, buf),
+        }
+    }
+}
+
+impl Converter for UTF32ByteEncoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        if self.bom {
+            self.bom = false;
+            len += self.encode('\u{FEFF}', buf);
+        }
+        Ok(len + self.encode(item, buf))
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.bom {
+            self.bom = false;
+            Ok(self.encode('\u{FEFF}', buf))
+        } else {
+            Ok(0)
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(if self.bom { 4 } else { 0 })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (4, Some(if self.bom { 8 } else { 4 }))
+    }
+}
@@ -1,24 +1,100 @@
-//! UTF-8 Decoder/Encoder.
+//! UTF-8 Decoder/Encoder/Validator.
 
 use crate::Converter;
 use core::convert::Infallible;
 use core::fmt;
 
 /// An error while decoding UTF-8.
+#[deprecated(note = "`UTF8Decoder` reports `UTF8DecodeError` now.")]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct UTF8EncodingError;
 
+#[allow(deprecated)]
 impl fmt::Display for UTF8EncodingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "found invalid UTF-8 sequence.")
     }
 }
 
+#[allow(deprecated)]
+impl From<UTF8DecodeError> for UTF8EncodingError {
+    #[inline]
+    fn from(_: UTF8DecodeError) -> Self {
+        Self
+    }
+}
+
 #[cfg(feature = "std")]
+#[allow(deprecated)]
 impl std::error::Error for UTF8EncodingError {}
 
+/// An error while decoding UTF-8, with the position of the invalid sequence.
+///
+/// The semantics of [`valid_up_to`] and [`error_len`] mirror [`Utf8Error`], but the offsets are
+/// counted from the first byte the decoder consumed.
+///
+/// [`valid_up_to`]: Self::valid_up_to
+/// [`error_len`]: Self::error_len
+/// [`Utf8Error`]: core::str::Utf8Error
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UTF8DecodeError {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+    byte: Option<u8>,
+}
+
+impl UTF8DecodeError {
+    /// Returns the number of bytes consumed before the invalid sequence started.
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    /// Returns the length of the invalid sequence, or [`None`] if the input ended unexpectedly.
+    ///
+    /// If a continuation byte is invalid, the length doesn't include the offending byte, so the
+    /// next sequence is considered to start from it, and the decoder decodes it again.
+    ///
+    /// [`None`]: core::option::Option::None
+    #[inline]
+    pub fn error_len(&self) -> Option<u8> {
+        self.error_len
+    }
+
+    /// Returns the offending byte, or [`None`] if the input ended unexpectedly.
+    ///
+    /// [`None`]: core::option::Option::None
+    #[inline]
+    pub fn byte(&self) -> Option<u8> {
+        self.byte
+    }
+}
+
+impl fmt::Display for UTF8DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_len {
+            Some(len) => write!(
+                f,
+                "invalid utf-8 sequence of {} bytes from index {}",
+                len, self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                self.valid_up_to
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UTF8DecodeError {}
+
 /// A decoder for UTF-8
 ///
+/// A byte which broke the previous sequence is held, and decoded with the next byte, so a call
+/// may store two characters.
+///
 /// # Examples
 /// ```
 /// use conversion::converter::encoding::utf8::UTF8Decoder;
@@ -28,15 +104,31 @@ impl std::error::Error for UTF8EncodingError {}
 /// let decoded = ConvertedIterator::new(iter, UTF8Decoder::new());
 ///
 /// assert_eq!(Ok(String::from("straße")), decoded.collect());
+///
+/// let iter = b"stra\xc3e".into_iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, UTF8Decoder::new());
+/// let err = decoded.collect::<Result<String, _>>().unwrap_err();
+///
+/// assert_eq!(4, err.valid_up_to());
+/// assert_eq!(Some(1), err.error_len());
+/// assert_eq!(Some(b'e'), err.byte());
 /// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct UTF8Decoder {
+    // number of bytes consumed before the current sequence.
+    offset: usize,
+    // number of bytes consumed in the current sequence.
+    len: u8,
     // remaining bytes to construct one character.
     remain: u8,
     // current UTF8 codepoint.
     codepoint: u32,
     // lower bound of the second, third or fourth byte.
     lower: u8,
+    // upper bound of the second, third or fourth byte.
+    upper: u8,
+    // the byte which broke the previous sequence, decoded by the next call.
+    held: Option<u8>,
 }
 
 impl UTF8Decoder {
@@ -45,74 +137,295 @@ impl UTF8Decoder {
     pub fn new() -> Self {
         Self::default()
     }
+
+    // Reports an error and skips `error_len` bytes of the current sequence.
+    fn error(&mut self, error_len: u8, byte: u8) -> UTF8DecodeError {
+        let err = UTF8DecodeError {
+            valid_up_to: self.offset,
+            error_len: Some(error_len),
+            byte: Some(byte),
+        };
+        self.offset += error_len as usize;
+        self.len = 0;
+        self.remain = 0;
+        err
+    }
+
+    // Decodes a byte, holding it if it broke the current sequence.
+    fn decode(&mut self, item: u8) -> Result<Option<char>, UTF8DecodeError> {
+        if self.remain == 0 {
+            // first byte
+            let (remain, codepoint, lower, upper) = match item {
+                0x00..=0x7F => {
+                    self.offset += 1;
+                    return Ok(Some(item as char));
+                }
+                0xC2..=0xDF => (1, (item & 0b0001_1111) as u32, 0x80, 0xBF),
+                0xE0 => (2, (item & 0b0000_1111) as u32, 0xA0, 0xBF),
+                0xED => (2, (item & 0b0000_1111) as u32, 0x80, 0x9F),
+                0xE1..=0xEF => (2, (item & 0b0000_1111) as u32, 0x80, 0xBF),
+                0xF0 => (3, (item & 0b0000_0111) as u32, 0x90, 0xBF),
+                0xF4 => (3, (item & 0b0000_0111) as u32, 0x80, 0x8F),
+                0xF1..=0xF3 => (3, (item & 0b0000_0111) as u32, 0x80, 0xBF),
+                _ => return Err(self.error(1, item)),
+            };
+            self.len = 1;
+            self.remain = remain;
+            self.codepoint = codepoint << (remain * 6);
+            self.lower = lower;
+            self.upper = upper;
+            Ok(None)
+        } else {
+            if !(self.lower..=self.upper).contains(&item) {
+                let err = self.error(self.len, item);
+                // the byte is not a part of the broken sequence, but may start the next one.
+                self.held = Some(item);
+                return Err(err);
+            }
+
+            self.len += 1;
+            self.remain -= 1;
+            self.codepoint |= ((item & 0b0011_1111) as u32) << (self.remain * 6);
+
+            if self.remain == 0 {
+                self.offset += self.len as usize;
+                self.len = 0;
+                Ok(Some(unsafe { char::from_u32_unchecked(self.codepoint) }))
+            } else {
+                self.lower = 0x80;
+                self.upper = 0xBF;
+                Ok(None)
+            }
+        }
+    }
 }
 
 impl Converter for UTF8Decoder {
     type Item = u8;
     type Output = char;
-    type Error = UTF8EncodingError;
+    type Error = UTF8DecodeError;
 
     fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
     where
         E: Extend<Self::Output>,
     {
-        if self.remain == 0 {
-            // first byte
-            match item {
-                0x00..=0x7F => {
-                    buf.extend([item as char]);
-                    Ok(1)
-                }
-                0xC2..=0xDF => {
-                    self.remain = 1;
-                    self.codepoint = ((item & 0b0001_1111) as u32) << 6;
-                    self.lower = 0x80;
-                    Ok(0)
+        let first = match self.held.take() {
+            Some(held) => match self.decode(held) {
+                Ok(c) => c,
+                Err(e) => {
+                    self.held = Some(item);
+                    return Err(e);
                 }
-                0xE0..=0xEF => {
-                    self.remain = 2;
-                    self.codepoint = ((item & 0b0000_1111) as u32) << 12;
-                    self.lower = if item == 0xE0 { 0xA0 } else { 0x80 };
-                    Ok(0)
-                }
-                0xF0..=0xF4 => {
-                    self.remain = 3;
-                    self.codepoint = ((item & 0b0000_0111) as u32) << 18;
-                    self.lower = if item == 0xF0 { 0x90 } else { 0x80 };
-                    Ok(0)
-                }
-                _ => Err(UTF8EncodingError),
+            },
+            None => None,
+        };
+
+        let state = *self;
+        match self.decode(item) {
+            Ok(second) => {
+                let len = first.is_some() as usize + second.is_some() as usize;
+                buf.extend(first.into_iter().chain(second));
+                Ok(len)
             }
-        } else {
-            self.remain -= 1;
-            if (self.lower..0xBF).contains(&item) {
-                self.codepoint |= ((item & 0b0011_1111) as u32) << (self.remain * 6);
-            } else {
-                return Err(UTF8EncodingError);
+            Err(e) if first.is_none() => Err(e),
+            Err(_) => {
+                // an error can't follow outputs, so the item is decoded by the next call.
+                *self = state;
+                self.held = Some(item);
+                buf.extend(first);
+                Ok(1)
             }
+        }
+    }
 
-            if self.remain == 0 {
-                buf.extend([unsafe { char::from_u32_unchecked(self.codepoint) }]);
-                Ok(1)
-            } else {
-                self.lower = 0x80;
-                Ok(0)
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        let mut i = 0;
+        while i < items.len() {
+            if self.remain == 0 && self.held.is_none() {
+                // extend contiguous ASCII characters at once.
+                let run = ascii_run(&items[i..]);
+                if run > 0 {
+                    buf.extend(items[i..i + run].iter().map(|&b| b as char));
+                    self.offset += run;
+                    len += run;
+                    i += run;
+                    continue;
+                }
             }
+
+            len += self.convert(items[i], buf)?;
+            i += 1;
         }
+        Ok(len)
     }
 
     #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
-        if self.remain == 0 {
-            Ok(())
-        } else {
-            Err(UTF8EncodingError)
+        self.finish(&mut Discard).map(|_| ())
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let c = match self.held.take() {
+            Some(held) => self.decode(held)?,
+            None => None,
+        };
+        if self.remain != 0 {
+            let err = UTF8DecodeError {
+                valid_up_to: self.offset,
+                error_len: None,
+                byte: None,
+            };
+            self.offset += self.len as usize;
+            self.len = 0;
+            self.remain = 0;
+            return Err(err);
         }
+        buf.extend(c);
+        Ok(c.is_some() as usize)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(self.held.is_some() as usize)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1 + self.held.is_some() as usize))
+    }
+}
+
+// A buffer discards all items.
+struct Discard;
+
+impl<T> Extend<T> for Discard {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, _iter: I) {}
+}
+
+// Returns the length of ASCII characters at the start of `bytes`, checking a word at a time.
+fn ascii_run(bytes: &[u8]) -> usize {
+    const WORD: usize = core::mem::size_of::<usize>();
+    const MASK: usize = usize::from_ne_bytes([0x80; WORD]);
+
+    let mut len = 0;
+    for chunk in bytes.chunks_exact(WORD) {
+        let word = usize::from_ne_bytes(chunk.try_into().unwrap());
+        if word & MASK != 0 {
+            break;
+        }
+        len += WORD;
+    }
+
+    len + bytes[len..]
+        .iter()
+        .position(|b| !b.is_ascii())
+        .unwrap_or(bytes.len() - len)
+}
+
+/// A validator for UTF-8, emits input bytes as they are.
+///
+/// Each byte is emitted once the sequence it belongs to is known to be valid, so at most 3 bytes
+/// of an incomplete sequence are held inside. The errors are same as [`UTF8Decoder`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Validator;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"stra\xc3\x9fe".into_iter().cloned();
+/// let validated = ConvertedIterator::new(iter, UTF8Validator::new());
+///
+/// assert_eq!(Ok(b"stra\xc3\x9fe".to_vec()), validated.collect());
+///
+/// let iter = b"stra\xc3".into_iter().cloned();
+/// let mut validated = ConvertedIterator::new(iter, UTF8Validator::new());
+///
+/// assert_eq!(Some(Ok(b's')), validated.next());
+/// assert_eq!(Some(Ok(b't')), validated.next());
+/// assert_eq!(Some(Ok(b'r')), validated.next());
+/// assert_eq!(Some(Ok(b'a')), validated.next());
+/// assert_eq!(4, validated.next().unwrap().unwrap_err().valid_up_to());
+/// assert_eq!(None, validated.next());
+/// ```
+///
+/// [`UTF8Decoder`]: self::UTF8Decoder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UTF8Validator {
+    inner: UTF8Decoder,
+    // bytes not known to be valid yet, the current sequence and the held byte of the decoder.
+    pending: [u8; 4],
+    len: u8,
+}
+
+impl UTF8Validator {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Keeps bytes still undecided by the decoder, and returns the rest.
+    fn settle(&mut self) -> ([u8; 4], usize) {
+        let undecided = self.inner.len as usize + self.inner.held.is_some() as usize;
+        let settled = self.len as usize - undecided;
+        let bytes = self.pending;
+        self.pending.copy_within(settled..self.len as usize, 0);
+        self.len = undecided as u8;
+        (bytes, settled)
+    }
+}
+
+impl Converter for UTF8Validator {
+    type Item = u8;
+    type Output = u8;
+    type Error = UTF8DecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.pending[self.len as usize] = item;
+        self.len += 1;
+        let result = self.inner.convert(item, &mut Discard);
+        // bytes of an invalid sequence are dropped, and valid ones are emitted.
+        let (bytes, settled) = self.settle();
+        result?;
+        buf.extend(bytes.into_iter().take(settled));
+        Ok(settled)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.finish(&mut Discard).map(|_| ())
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let result = self.inner.finish(&mut Discard);
+        let (bytes, settled) = self.settle();
+        result?;
+        buf.extend(bytes.into_iter().take(settled));
+        Ok(settled)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint()
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(1))
+        (0, Some(4))
     }
 }
 
@@ -135,7 +448,7 @@ impl UTF8Encoder {
     /// Create a new instance.
     #[inline]
     pub fn new() -> Self {
-        Self::default()
+        Self
     }
 }
 
@@ -154,6 +467,20 @@ impl Converter for UTF8Encoder {
         Ok(len)
     }
 
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut len = 0;
+        buf.extend(items.iter().flat_map(|c| {
+            let mut tmp_buf = [0u8; 4];
+            let l = c.encode_utf8(&mut tmp_buf).len();
+            len += l;
+            tmp_buf.into_iter().take(l)
+        }));
+        Ok(len)
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (1, Some(4))
@@ -184,18 +511,289 @@ mod test {
             ConvertedIterator::new([0x2F], UTF8Decoder::new()).collect()
         );
         assert_eq!(
-            Err(UTF8EncodingError),
+            Err(UTF8DecodeError {
+                valid_up_to: 0,
+                error_len: Some(1),
+                byte: Some(0xC0),
+            }),
             ConvertedIterator::new([0xC0, 0xAF], UTF8Decoder::new()).collect::<Result<String, _>>()
         );
         assert_eq!(
-            Err(UTF8EncodingError),
+            Err(UTF8DecodeError {
+                valid_up_to: 0,
+                error_len: Some(1),
+                byte: Some(0x80),
+            }),
             ConvertedIterator::new([0xE0, 0x80, 0xAF], UTF8Decoder::new())
                 .collect::<Result<String, _>>()
         );
         assert_eq!(
-            Err(UTF8EncodingError),
+            Err(UTF8DecodeError {
+                valid_up_to: 0,
+                error_len: Some(1),
+                byte: Some(0x80),
+            }),
             ConvertedIterator::new([0xF0, 0x80, 0x80, 0xAF], UTF8Decoder::new())
                 .collect::<Result<String, _>>()
         );
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boundaries() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        assert_eq!(
+            Ok(String::from("¿\u{FFFF}\u{10FFFF}")),
+            ConvertedIterator::new(*b"\xC2\xBF\xEF\xBF\xBF\xF4\x8F\xBF\xBF", UTF8Decoder::new())
+                .collect()
+        );
+        // surrogates
+        assert_eq!(
+            Err(UTF8DecodeError {
+                valid_up_to: 1,
+                error_len: Some(1),
+                byte: Some(0xA0),
+            }),
+            ConvertedIterator::new(*b"a\xED\xA0\x80", UTF8Decoder::new())
+                .collect::<Result<String, _>>()
+        );
+        // out of range
+        assert_eq!(
+            Err(UTF8DecodeError {
+                valid_up_to: 0,
+                error_len: Some(1),
+                byte: Some(0x90),
+            }),
+            ConvertedIterator::new(*b"\xF4\x90\x80\x80", UTF8Decoder::new())
+                .collect::<Result<String, _>>()
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn error_offsets() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        let errors = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), UTF8Decoder::new())
+                .filter_map(Result::err)
+                .map(|e| (e.valid_up_to(), e.error_len(), e.byte()))
+                .collect::<Vec<_>>()
+        };
+
+        // bad lead
+        assert_eq!(vec![(2, Some(1), Some(0x80))], errors(b"ab\x80cd"));
+        assert_eq!(vec![(2, Some(1), Some(0xFF))], errors(b"\xC3\xA0\xFF"));
+        // bad continuation
+        assert_eq!(vec![(1, Some(2), Some(b'x'))], errors(b"a\xE3\x81xb"));
+        assert_eq!(
+            vec![(0, Some(1), Some(b'x')), (4, Some(3), Some(b'y'))],
+            errors(b"\xC3xab\xF0\x9D\x84y")
+        );
+        // overlong
+        assert_eq!(
+            vec![(3, Some(1), Some(0xC1)), (4, Some(1), Some(0xBF))],
+            errors(b"abc\xC1\xBF")
+        );
+        assert_eq!(
+            vec![
+                (1, Some(1), Some(0x8F)),
+                (2, Some(1), Some(0x8F)),
+                (3, Some(1), Some(0xBF))
+            ],
+            errors(b"a\xF0\x8F\xBFb")
+        );
+        // truncated
+        assert_eq!(vec![(3, None, None)], errors(b"a\xC3\xA0\xE3\x81"));
+        assert_eq!(vec![(0, None, None)], errors(b"\xF0"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_after_errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        // the byte which broke a sequence is decoded again, like `String::from_utf8_lossy`.
+        let inputs: [&[u8]; 9] = [
+            b"stra\xc3ef",
+            b"a\xc3\xc3\xa9b",
+            b"\xC3a\xFFb",
+            b"\xC3a\xC3",
+            b"a\xE3\x81\xF0\x9D\x84\x9Eb",
+            b"a\xF0\x8F\xBFb",
+            b"a\xED\xA0\x80",
+            b"\xC3\xFFx\xE3",
+            b"\xE3\x81\xE3\x81\x82",
+        ];
+        for bytes in inputs {
+            let decoded = ConvertedIterator::new(bytes.iter().cloned(), UTF8Decoder::new())
+                .map(|r| r.unwrap_or('\u{FFFD}'))
+                .collect::<String>();
+            assert_eq!(String::from_utf8_lossy(bytes), decoded);
+
+            // the errors mirror `Utf8Error` on the rest of the input.
+            let mut expected = Vec::new();
+            let mut offset = 0;
+            while let Err(e) = core::str::from_utf8(&bytes[offset..]) {
+                let len = e.error_len();
+                expected.push((offset + e.valid_up_to(), len.map(|l| l as u8)));
+                offset += e.valid_up_to() + len.unwrap_or(bytes.len());
+                if offset >= bytes.len() {
+                    break;
+                }
+            }
+            let errors = ConvertedIterator::new(bytes.iter().cloned(), UTF8Decoder::new())
+                .filter_map(Result::err)
+                .map(|e| (e.valid_up_to(), e.error_len()))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, errors, "{:?}", bytes);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn stored_outputs() {
+        use alloc::vec::Vec;
+
+        // `convert` and `finish` return the number of stored outputs, and store nothing on errors.
+        fn check<C: Converter<Item = u8>>(mut conv: C, bytes: &[u8]) -> usize {
+            let mut buf = Vec::new();
+            let mut errors = 0;
+            for &b in bytes {
+                let before = buf.len();
+                match conv.convert(b, &mut buf) {
+                    Ok(len) => assert_eq!(before + len, buf.len(), "{:?}", bytes),
+                    Err(_) => {
+                        assert_eq!(before, buf.len(), "{:?}", bytes);
+                        errors += 1;
+                    }
+                }
+            }
+            let before = buf.len();
+            match conv.finish(&mut buf) {
+                Ok(len) => assert_eq!(before + len, buf.len(), "{:?}", bytes),
+                Err(_) => {
+                    assert_eq!(before, buf.len(), "{:?}", bytes);
+                    errors += 1;
+                }
+            }
+            errors
+        }
+
+        let inputs: [&[u8]; 8] = [
+            b"stra\xc3ef",
+            b"a\xc3\xc3\xa9b",
+            b"\xC3a\xFFb",
+            b"\xC3a\xC3",
+            b"\xE0\x80\x80a",
+            b"\xC3\xFFx\xE3",
+            b"\xE3\x81\xE3\x81\x82",
+            b"\xC3\x80",
+        ];
+        for bytes in inputs {
+            let errors = check(UTF8Decoder::new(), bytes);
+            assert_eq!(errors, check(UTF8Validator::new(), bytes));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn validate_utf8() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+        let valid = b"\x41\xC3\x80\xE3\x81\x82\xF0\x9D\x84\x9E\xC2\xBF\xF4\x8F\xBF\xBF";
+        assert_eq!(
+            Ok(valid.to_vec()),
+            ConvertedIterator::new(valid.iter().cloned(), UTF8Validator::new()).collect()
+        );
+
+        let invalid: [&[u8]; 11] = [
+            b"stra\xc3ef",
+            b"a\xc3\xc3\xa9b",
+            b"\xC0\xAF",
+            b"\xE0\x80\xAF",
+            b"\xF0\x80\x80\xAF",
+            b"a\xED\xA0\x80",
+            b"ab\x80cd",
+            b"\xC3xab\xF0\x9D\x84y",
+            b"abc\xC1\xBF",
+            b"a\xC3\xA0\xE3\x81",
+            b"\xF0",
+        ];
+        for bytes in invalid {
+            let validated = ConvertedIterator::new(bytes.iter().cloned(), UTF8Validator::new())
+                .collect::<Vec<_>>();
+            let decoded = ConvertedIterator::new(bytes.iter().cloned(), UTF8Decoder::new())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                decoded.iter().filter_map(|r| r.err()).collect::<Vec<_>>(),
+                validated.iter().filter_map(|r| r.err()).collect::<Vec<_>>(),
+            );
+
+            // valid parts are emitted as they are.
+            let mut encoded = Vec::new();
+            for c in decoded.into_iter().filter_map(Result::ok) {
+                let mut tmp = [0; 4];
+                encoded.extend_from_slice(c.encode_utf8(&mut tmp).as_bytes());
+            }
+            assert_eq!(
+                encoded,
+                validated
+                    .into_iter()
+                    .filter_map(Result::ok)
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn convert_slice() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let mut corpus = Vec::new();
+        for i in 0..40 {
+            corpus.resize(corpus.len() + i as usize * 3, b'a' + i % 26);
+            corpus.extend_from_slice(b"\xC3\x80\xE3\x81\x82\xF0\x9D\x84\x9E");
+        }
+        let expected = ConvertedIterator::new(corpus.iter().cloned(), UTF8Decoder::new())
+            .collect::<Result<String, _>>();
+        assert!(expected.is_ok());
+
+        for chunk_len in [1, 3, 7, 8, 9, 64, corpus.len()] {
+            let mut decoder = UTF8Decoder::new();
+            let mut decoded = String::new();
+            let mut len = 0;
+            for chunk in corpus.chunks(chunk_len) {
+                len += decoder.convert_slice(chunk, &mut decoded).unwrap();
+            }
+            decoder.finalize().unwrap();
+            assert_eq!(decoded.chars().count(), len);
+            assert_eq!(expected, Ok(decoded));
+        }
+
+        // errors are reported at the same positions.
+        let mut invalid = corpus.clone();
+        invalid.splice(100..100, b"\xE3\x81abcdefghijklmnop".iter().cloned());
+        let mut decoder = UTF8Decoder::new();
+        let mut expected = String::new();
+        let expected_err = invalid
+            .iter()
+            .find_map(|&b| decoder.convert(b, &mut expected).err())
+            .unwrap();
+        let mut decoded = String::new();
+        let err = UTF8Decoder::new()
+            .convert_slice(&invalid, &mut decoded)
+            .unwrap_err();
+        assert_eq!(expected, decoded);
+        assert_eq!(expected_err, err);
+        assert_eq!(100, err.valid_up_to());
+        assert_eq!(Some(2), err.error_len());
+    }
 }
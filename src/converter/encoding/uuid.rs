@@ -0,0 +1,275 @@
+//! UUID Parser/Formatter, for the textual representation described in [RFC 9562].
+//!
+//! [RFC 9562]: https://www.rfc-editor.org/rfc/rfc9562#section-4
+
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+// the length of the textual representation, and positions of hyphens in it.
+const LEN: usize = 36;
+const HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// An error while parsing UUIDs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UUIDError {
+    /// A character which is not a hexadecimal digit, or a misplaced hyphen, and its offset.
+    InvalidChar(char, usize),
+    /// The input is longer or shorter than 36 characters.
+    InvalidLength,
+}
+
+impl fmt::Display for UUIDError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar(c, offset) => {
+                write!(f, "invalid character in UUID at {}: {:?}", offset, c)
+            }
+            Self::InvalidLength => write!(f, "a UUID must be 36 characters."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UUIDError {}
+
+/// A parser for UUIDs, like `f81d4fae-7dec-11d0-a765-00a0c91e6bf6`.
+///
+/// The whole input is a UUID, which is emitted as 16 bytes at the end of the input. Hexadecimal
+/// digits are case-insensitive. After an error, the rest of the input is ignored and nothing is
+/// emitted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::uuid::{UUIDError, UUIDParser};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6".chars();
+/// let mut parsed = ConvertedIterator::new(iter, UUIDParser::new());
+/// assert_eq!(
+///     Some(Ok([
+///         0xF8, 0x1D, 0x4F, 0xAE, 0x7D, 0xEC, 0x11, 0xD0, 0xA7, 0x65, 0x00, 0xA0, 0xC9, 0x1E,
+///         0x6B, 0xF6,
+///     ])),
+///     parsed.next(),
+/// );
+///
+/// let mut parsed = ConvertedIterator::new("f81d4fae_7dec".chars(), UUIDParser::new());
+/// assert_eq!(Some(Err(UUIDError::InvalidChar('_', 8))), parsed.next());
+/// assert_eq!(None, parsed.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UUIDParser {
+    bytes: [u8; 16],
+    // the number of characters.
+    len: usize,
+    failed: bool,
+}
+
+impl UUIDParser {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn error(&mut self, err: UUIDError) -> Result<usize, UUIDError> {
+        self.failed = true;
+        Err(err)
+    }
+}
+
+impl Converter for UUIDParser {
+    type Item = char;
+    type Output = [u8; 16];
+    type Error = UUIDError;
+
+    fn convert<E>(&mut self, item: Self::Item, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.failed {
+            return Ok(0);
+        }
+
+        let offset = self.len;
+        self.len += 1;
+        if offset >= LEN {
+            return self.error(UUIDError::InvalidLength);
+        }
+        if HYPHENS.contains(&offset) {
+            return if item == '-' {
+                Ok(0)
+            } else {
+                self.error(UUIDError::InvalidChar(item, offset))
+            };
+        }
+
+        let digit = match item.to_digit(16) {
+            Some(digit) => digit as u8,
+            None => return self.error(UUIDError::InvalidChar(item, offset)),
+        };
+        // the number of digits before this one.
+        let index = offset - HYPHENS.iter().filter(|&&i| i < offset).count();
+        self.bytes[index / 2] |= if index.is_multiple_of(2) {
+            digit << 4
+        } else {
+            digit
+        };
+        Ok(0)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let Self { bytes, len, failed } = core::mem::take(self);
+        match len {
+            _ if failed => Ok(0),
+            0 => Ok(0),
+            LEN => {
+                buf.extend([bytes]);
+                Ok(1)
+            }
+            _ => Err(UUIDError::InvalidLength),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+/// A formatter for UUIDs, which converts 16 bytes into 36 lowercase characters.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::uuid::UUIDFormatter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let uuid = [
+///     0xF8, 0x1D, 0x4F, 0xAE, 0x7D, 0xEC, 0x11, 0xD0, 0xA7, 0x65, 0x00, 0xA0, 0xC9, 0x1E, 0x6B,
+///     0xF6,
+/// ];
+/// let formatted = ConvertedIterator::new([uuid], UUIDFormatter::new());
+/// assert_eq!(
+///     Ok(String::from("f81d4fae-7dec-11d0-a765-00a0c91e6bf6")),
+///     formatted.collect(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UUIDFormatter;
+
+impl UUIDFormatter {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for UUIDFormatter {
+    type Item = [u8; 16];
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut chars = [b'-'; LEN];
+        let mut digits = item
+            .iter()
+            .flat_map(|&b| [HEX[(b >> 4) as usize], HEX[(b & 0xF) as usize]]);
+        for (offset, c) in chars.iter_mut().enumerate() {
+            if !HYPHENS.contains(&offset) {
+                *c = digits.next().unwrap_or_default();
+            }
+        }
+        buf.extend(chars.iter().map(|&c| c as char));
+        Ok(LEN)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (LEN, Some(LEN))
+    }
+}
+
+impl crate::sealed::Sealed for UUIDFormatter {}
+
+impl ExactSizeConverter for UUIDFormatter {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::string::String;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        // random UUIDs.
+        let mut next = random();
+        for _ in 0..100 {
+            let mut uuid = [0; 16];
+            for chunk in uuid.chunks_mut(4) {
+                chunk.copy_from_slice(&next().to_be_bytes());
+            }
+            let formatted = ConvertedIterator::new([uuid], UUIDFormatter::new())
+                .collect::<Result<String, _>>()
+                .unwrap();
+            let parsed = ConvertedIterator::new(formatted.chars(), UUIDParser::new())
+                .collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(vec![uuid]), parsed);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let inputs = [
+            (
+                "f81d4fae-7dec-11d0-a765-00a0c91e6bf",
+                UUIDError::InvalidLength,
+            ),
+            (
+                "f81d4fae-7dec-11d0-a765-00a0c91e6bf6a",
+                UUIDError::InvalidLength,
+            ),
+            (
+                "f81d4fae7dec-11d0-a765-00a0c91e6bf6",
+                UUIDError::InvalidChar('7', 8),
+            ),
+            (
+                "f81d4fae-7dec-11d0-a765-00a0c91e6bfg",
+                UUIDError::InvalidChar('g', 35),
+            ),
+            (
+                "{f81d4fae-7dec-11d0-a765-00a0c91e6bf6}",
+                UUIDError::InvalidChar('{', 0),
+            ),
+        ];
+        for (input, err) in inputs {
+            let parsed =
+                ConvertedIterator::new(input.chars(), UUIDParser::new()).collect::<Vec<_>>();
+            assert_eq!(vec![Err(err)], parsed);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Windows-1252 Decoder/Encoder.
+//!
+//! Bytes undefined in Windows-1252 (`0x81`, `0x8D`, `0x8F`, `0x90` and `0x9D`) are mapped to the
+//! C1 control characters of the same values, like the WHATWG Encoding Standard.
+
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while encoding Windows-1252 characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Windows1252EncodingError;
+
+impl fmt::Display for Windows1252EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A character out of bound.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Windows1252EncodingError {}
+
+// characters of bytes from `0x80` to `0x9F`.
+const TABLE: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+/// A decoder for Windows-1252.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::windows1252::Windows1252Decoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"\x93stra\xdfe\x94 \x80".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, Windows1252Decoder::new());
+///
+/// assert_eq!(Ok(String::from("“straße” €")), decoded.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Windows1252Decoder;
+
+impl Windows1252Decoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for Windows1252Decoder {
+    type Item = u8;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let c = match item {
+            0x80..=0x9F => TABLE[(item - 0x80) as usize],
+            _ => item as char,
+        };
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for Windows1252Decoder {}
+
+impl ExactSizeConverter for Windows1252Decoder {}
+
+/// An encoder for Windows-1252.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::windows1252::{Windows1252Encoder, Windows1252EncodingError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "“ß” €💣".chars();
+/// let mut encoded = ConvertedIterator::new(iter, Windows1252Encoder::new());
+///
+/// assert_eq!(Some(Ok(0x93)), encoded.next());
+/// assert_eq!(Some(Ok(0xDF)), encoded.next());
+/// assert_eq!(Some(Ok(0x94)), encoded.next());
+/// assert_eq!(Some(Ok(b' ')), encoded.next());
+/// assert_eq!(Some(Ok(0x80)), encoded.next());
+/// assert_eq!(Some(Err(Windows1252EncodingError)), encoded.next());
+/// assert_eq!(None, encoded.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Windows1252Encoder;
+
+impl Windows1252Encoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Converter for Windows1252Encoder {
+    type Item = char;
+    type Output = u8;
+    type Error = Windows1252EncodingError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let byte = match item as u32 {
+            c @ (0x00..=0x7F | 0xA0..=0xFF) => c as u8,
+            _ => match TABLE.iter().position(|&c| c == item) {
+                Some(i) => 0x80 + i as u8,
+                None => return Err(Windows1252EncodingError),
+            },
+        };
+        buf.extend([byte]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl crate::sealed::Sealed for Windows1252Encoder {}
+
+impl ExactSizeConverter for Windows1252Encoder {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        let bytes = (0..=0xFF).collect::<Vec<u8>>();
+        let decoded = ConvertedIterator::new(bytes.iter().cloned(), Windows1252Decoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(256, decoded.chars().count());
+        let encoded = ConvertedIterator::new(decoded.chars(), Windows1252Encoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(bytes), encoded);
+
+        for c in ['\u{80}', '\u{9F}', '\u{100}', '\u{FFFD}'] {
+            assert_eq!(
+                Err(Windows1252EncodingError),
+                Windows1252Encoder.convert(c, &mut Vec::new())
+            );
+        }
+    }
+}
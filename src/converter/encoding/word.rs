@@ -0,0 +1,337 @@
+//! Converting bytes to fixed-size words, and vice versa.
+//!
+//! The byte order is a type parameter, one of [`BigEndian`], [`LittleEndian`] and
+//! [`NativeEndian`].
+//!
+//! [`BigEndian`]: self::BigEndian
+//! [`LittleEndian`]: self::LittleEndian
+//! [`NativeEndian`]: self::NativeEndian
+
+use super::Endianness;
+use crate::{Converter, ExactSizeConverter};
+use core::convert::Infallible;
+use core::fmt;
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+/// Byte orders as types.
+pub trait ByteOrder: Clone + Copy + fmt::Debug + Default + PartialEq + Eq + Hash {
+    /// The byte order as a value.
+    const ENDIANNESS: Endianness;
+}
+
+/// Big-endian. (the most significant byte first)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigEndian;
+
+impl ByteOrder for BigEndian {
+    const ENDIANNESS: Endianness = Endianness::Big;
+}
+
+/// Little-endian. (the least significant byte first)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LittleEndian;
+
+impl ByteOrder for LittleEndian {
+    const ENDIANNESS: Endianness = Endianness::Little;
+}
+
+/// The byte order of the target platform.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NativeEndian;
+
+impl ByteOrder for NativeEndian {
+    const ENDIANNESS: Endianness = if cfg!(target_endian = "big") {
+        Endianness::Big
+    } else {
+        Endianness::Little
+    };
+}
+
+/// An error for inputs which ended inside a word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncompleteWordError;
+
+impl fmt::Display for IncompleteWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the input ended inside a word.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IncompleteWordError {}
+
+macro_rules! word_converter {
+    (
+        $(#[$decoder_attr:meta])* $decoder:ident,
+        $(#[$encoder_attr:meta])* $encoder:ident,
+        $word:ty, $len:expr
+    ) => {
+        $(#[$decoder_attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $decoder<B> {
+            bytes: [u8; $len],
+            count: usize,
+            _order: PhantomData<B>,
+        }
+
+        impl<B> $decoder<B> {
+            /// Create a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    bytes: [0; $len],
+                    count: 0,
+                    _order: PhantomData,
+                }
+            }
+        }
+
+        impl<B: ByteOrder> Converter for $decoder<B> {
+            type Item = u8;
+            type Output = $word;
+            type Error = IncompleteWordError;
+
+            #[inline]
+            fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                self.bytes[self.count] = item;
+                self.count += 1;
+                if self.count < $len {
+                    return Ok(0);
+                }
+
+                self.count = 0;
+                buf.extend([match B::ENDIANNESS {
+                    Endianness::Big => <$word>::from_be_bytes(self.bytes),
+                    Endianness::Little => <$word>::from_le_bytes(self.bytes),
+                }]);
+                Ok(1)
+            }
+
+            #[inline]
+            fn finalize(&mut self) -> Result<(), Self::Error> {
+                if self.count == 0 {
+                    Ok(())
+                } else {
+                    self.count = 0;
+                    Err(IncompleteWordError)
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, Some(1))
+            }
+        }
+
+        $(#[$encoder_attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $encoder<B> {
+            _order: PhantomData<B>,
+        }
+
+        impl<B> $encoder<B> {
+            /// Create a new instance.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    _order: PhantomData,
+                }
+            }
+        }
+
+        impl<B: ByteOrder> Converter for $encoder<B> {
+            type Item = $word;
+            type Output = u8;
+            type Error = Infallible;
+
+            #[inline]
+            fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+            where
+                E: Extend<Self::Output>,
+            {
+                buf.extend(match B::ENDIANNESS {
+                    Endianness::Big => item.to_be_bytes(),
+                    Endianness::Little => item.to_le_bytes(),
+                });
+                Ok($len)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                ($len, Some($len))
+            }
+        }
+
+        impl<B> crate::sealed::Sealed for $encoder<B> {}
+
+        impl<B: ByteOrder> ExactSizeConverter for $encoder<B> {}
+    };
+}
+
+word_converter!(
+    /// Combining each 2 bytes into a `u16`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{BigEndian, BytePairToU16, IncompleteWordError};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\x12\x34\x56".iter().cloned();
+    /// let mut words = ConvertedIterator::new(iter, BytePairToU16::<BigEndian>::new());
+    ///
+    /// assert_eq!(Some(Ok(0x1234)), words.next());
+    /// assert_eq!(Some(Err(IncompleteWordError)), words.next());
+    /// assert_eq!(None, words.next());
+    /// ```
+    BytePairToU16,
+    /// Splitting each `u16` into 2 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{LittleEndian, U16ToBytes};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [0x1234, 0x5678].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, U16ToBytes::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\x34\x12\x78\x56".to_vec()), bytes.collect());
+    /// ```
+    U16ToBytes,
+    u16,
+    2
+);
+
+word_converter!(
+    /// Combining each 4 bytes into a `u32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{ByteQuadToU32, LittleEndian};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = b"\x78\x56\x34\x12".iter().cloned();
+    /// let words = ConvertedIterator::new(iter, ByteQuadToU32::<LittleEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![0x12345678]), words.collect());
+    /// ```
+    ByteQuadToU32,
+    /// Splitting each `u32` into 4 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{BigEndian, U32ToBytes};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [0x12345678].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, U32ToBytes::<BigEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\x12\x34\x56\x78".to_vec()), bytes.collect());
+    /// ```
+    U32ToBytes,
+    u32,
+    4
+);
+
+word_converter!(
+    /// Combining each 8 bytes into a `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{ByteOctetToU64, NativeEndian};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = 42u64.to_ne_bytes().into_iter();
+    /// let words = ConvertedIterator::new(iter, ByteOctetToU64::<NativeEndian>::new());
+    ///
+    /// assert_eq!(Ok(vec![42]), words.collect());
+    /// ```
+    ByteOctetToU64,
+    /// Splitting each `u64` into 8 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::word::{BigEndian, U64ToBytes};
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let iter = [0x0102030405060708].into_iter();
+    /// let bytes = ConvertedIterator::new(iter, U64ToBytes::<BigEndian>::new());
+    ///
+    /// assert_eq!(Ok(b"\x01\x02\x03\x04\x05\x06\x07\x08".to_vec()), bytes.collect());
+    /// ```
+    U64ToBytes,
+    u64,
+    8
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        fn check<D, C>(decoder: D, encoder: C, len: usize)
+        where
+            D: Converter<Item = u8, Error = IncompleteWordError> + Copy,
+            C: Converter<Item = D::Output, Output = u8, Error = Infallible>,
+            D::Output: core::fmt::Debug + PartialEq,
+        {
+            let bytes = (0..len as u8 * 3).collect::<Vec<_>>();
+            let words = ConvertedIterator::new(bytes.iter().cloned(), decoder)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(3, words.len());
+            let encoded = ConvertedIterator::new(words, encoder).collect::<Result<Vec<_>, _>>();
+            assert_eq!(Ok(bytes), encoded);
+
+            let words = ConvertedIterator::new(0..len as u8 + 1, decoder).collect::<Vec<_>>();
+            assert_eq!(2, words.len());
+            assert_eq!(Err(IncompleteWordError), words[1]);
+        }
+
+        check(
+            BytePairToU16::<BigEndian>::new(),
+            U16ToBytes::<BigEndian>::new(),
+            2,
+        );
+        check(
+            BytePairToU16::<LittleEndian>::new(),
+            U16ToBytes::<LittleEndian>::new(),
+            2,
+        );
+        check(
+            ByteQuadToU32::<BigEndian>::new(),
+            U32ToBytes::<BigEndian>::new(),
+            4,
+        );
+        check(
+            ByteQuadToU32::<NativeEndian>::new(),
+            U32ToBytes::<NativeEndian>::new(),
+            4,
+        );
+        check(
+            ByteOctetToU64::<LittleEndian>::new(),
+            U64ToBytes::<LittleEndian>::new(),
+            8,
+        );
+        check(
+            ByteOctetToU64::<NativeEndian>::new(),
+            U64ToBytes::<NativeEndian>::new(),
+            8,
+        );
+
+        let bytes = [1, 2, 3, 4];
+        let big = ConvertedIterator::new(bytes, ByteQuadToU32::<BigEndian>::new());
+        let little = ConvertedIterator::new(bytes, ByteQuadToU32::<LittleEndian>::new());
+        assert_eq!(Ok(vec![0x01020304]), big.collect::<Result<Vec<_>, _>>());
+        assert_eq!(Ok(vec![0x04030201]), little.collect::<Result<Vec<_>, _>>());
+    }
+}
@@ -0,0 +1,352 @@
+//! Escaping/Unescaping XML character references.
+
+use crate::Converter;
+use core::convert::Infallible;
+use core::fmt;
+
+/// An error while unescaping XML character references.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XMLEscapeError {
+    /// A named entity which is not predefined in XML, like `&nbsp;`.
+    UnknownEntity,
+    /// An unexpected character inside a reference.
+    InvalidCharacter(char),
+    /// A numeric reference which is not a valid character.
+    InvalidCodepoint(u32),
+    /// The input ended inside a reference.
+    Unterminated,
+}
+
+impl fmt::Display for XMLEscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEntity => write!(f, "unknown entity."),
+            Self::InvalidCharacter(c) => write!(f, "invalid character in reference: {:?}", c),
+            Self::InvalidCodepoint(v) => write!(f, "invalid character code: {:#x}", v),
+            Self::Unterminated => write!(f, "unterminated reference."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for XMLEscapeError {}
+
+// names and characters of the predefined entities.
+const ENTITIES: [(&str, char); 5] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+];
+
+/// An escaper for XML character references.
+///
+/// By default only `&`, `<` and `>` are escaped. With [`full`], `"` and `'` are also escaped
+/// and non-ASCII characters are escaped as hexadecimal references.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::xml_escape::XMLCharRefEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let escaped = ConvertedIterator::new("<a href='#'>ß</a>".chars(), XMLCharRefEncoder::new());
+/// assert_eq!(Ok(String::from("&lt;a href='#'&gt;ß&lt;/a&gt;")), escaped.collect());
+///
+/// let escaped = ConvertedIterator::new("<a href='#'>ß</a>".chars(), XMLCharRefEncoder::full());
+/// assert_eq!(
+///     Ok(String::from("&lt;a href=&apos;#&apos;&gt;&#xDF;&lt;/a&gt;")),
+///     escaped.collect()
+/// );
+/// ```
+///
+/// [`full`]: Self::full
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XMLCharRefEncoder {
+    full: bool,
+}
+
+impl XMLCharRefEncoder {
+    /// Create a new instance, escaping only `&`, `<` and `>`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance, escaping all of the predefined entities and non-ASCII characters.
+    #[inline]
+    pub fn full() -> Self {
+        Self { full: true }
+    }
+}
+
+impl Converter for XMLCharRefEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let entity = match item {
+            '&' | '<' | '>' => ENTITIES.iter().find(|(_, c)| *c == item),
+            '"' | '\'' if self.full => ENTITIES.iter().find(|(_, c)| *c == item),
+            _ => None,
+        };
+        if let Some((name, _)) = entity {
+            buf.extend(['&']);
+            buf.extend(name.chars());
+            buf.extend([';']);
+            return Ok(name.len() + 2);
+        }
+
+        if self.full && !item.is_ascii() {
+            let code = item as u32;
+            let digits = (32 - code.leading_zeros() as usize).div_ceil(4);
+            let hex = |i: usize| char::from_digit(code >> (i * 4) & 0xF, 16).unwrap();
+            buf.extend(['&', '#', 'x']);
+            buf.extend((0..digits).rev().map(|i| hex(i).to_ascii_uppercase()));
+            buf.extend([';']);
+            Ok(digits + 4)
+        } else {
+            buf.extend([item]);
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.full {
+            // `&#x10FFFF;`
+            (1, Some(10))
+        } else {
+            // `&amp;`
+            (1, Some(5))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `&`, with the name read so far.
+    Name([u8; 4], u8),
+    // after `&#`.
+    Numeric,
+    // after `&#`, with the value and the number of digits.
+    Decimal(u32, u8),
+    // after `&#x`, with the value and the number of digits.
+    Hex(u32, u8),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// An unescaper for XML character references.
+///
+/// Supports the predefined entities (`&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;`), decimal
+/// references (`&#DDDD;`) and hexadecimal references (`&#xHHHH;`).
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::xml_escape::{XMLCharRefDecoder, XMLEscapeError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "&lt;&#223;&#x1F4A3;&nbsp;".chars();
+/// let mut unescaped = ConvertedIterator::new(iter, XMLCharRefDecoder::new());
+///
+/// assert_eq!(Some(Ok('<')), unescaped.next());
+/// assert_eq!(Some(Ok('ß')), unescaped.next());
+/// assert_eq!(Some(Ok('💣')), unescaped.next());
+/// assert_eq!(Some(Err(XMLEscapeError::UnknownEntity)), unescaped.next());
+/// assert_eq!(None, unescaped.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XMLCharRefDecoder {
+    state: State,
+}
+
+impl XMLCharRefDecoder {
+    /// Create a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, XMLEscapeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn error(&mut self, err: XMLEscapeError) -> Result<usize, XMLEscapeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+
+    fn codepoint<E>(&mut self, code: u32, buf: &mut E) -> Result<usize, XMLEscapeError>
+    where
+        E: Extend<char>,
+    {
+        match char::from_u32(code) {
+            Some(c) if code != 0 => self.emit(c, buf),
+            _ => self.error(XMLEscapeError::InvalidCodepoint(code)),
+        }
+    }
+}
+
+impl Converter for XMLCharRefDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = XMLEscapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal if item == '&' => {
+                self.state = State::Name([0; 4], 0);
+                Ok(0)
+            }
+            State::Normal => self.emit(item, buf),
+            State::Name(_, 0) if item == '#' => {
+                self.state = State::Numeric;
+                Ok(0)
+            }
+            State::Name(name, len) if item == ';' => {
+                let name = &name[..len as usize];
+                match ENTITIES.iter().find(|(n, _)| n.as_bytes() == name) {
+                    Some(&(_, c)) => self.emit(c, buf),
+                    None => self.error(XMLEscapeError::UnknownEntity),
+                }
+            }
+            State::Name(mut name, len) if item.is_ascii_alphanumeric() => {
+                if len as usize == name.len() {
+                    self.error(XMLEscapeError::UnknownEntity)
+                } else {
+                    name[len as usize] = item as u8;
+                    self.state = State::Name(name, len + 1);
+                    Ok(0)
+                }
+            }
+            State::Numeric if item == 'x' => {
+                self.state = State::Hex(0, 0);
+                Ok(0)
+            }
+            State::Numeric => {
+                self.state = State::Decimal(0, 0);
+                self.convert(item, buf)
+            }
+            State::Decimal(value, digits) | State::Hex(value, digits) if item == ';' => {
+                if digits == 0 {
+                    self.error(XMLEscapeError::InvalidCharacter(item))
+                } else {
+                    self.codepoint(value, buf)
+                }
+            }
+            State::Decimal(value, digits) => match item.to_digit(10) {
+                Some(d) => {
+                    let value = value.saturating_mul(10).saturating_add(d);
+                    self.state = State::Decimal(value, digits.saturating_add(1));
+                    Ok(0)
+                }
+                None => self.error(XMLEscapeError::InvalidCharacter(item)),
+            },
+            State::Hex(value, digits) => match item.to_digit(16) {
+                Some(d) => {
+                    let value = value.saturating_mul(16).saturating_add(d);
+                    self.state = State::Hex(value, digits.saturating_add(1));
+                    Ok(0)
+                }
+                None => self.error(XMLEscapeError::InvalidCharacter(item)),
+            },
+            State::Name(_, _) => self.error(XMLEscapeError::InvalidCharacter(item)),
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.state {
+            State::Normal => Ok(()),
+            _ => {
+                self.state = State::Normal;
+                Err(XMLEscapeError::Unterminated)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let text = "<p class=\"a&b\">'Straße' & 💣</p>";
+        for encoder in [XMLCharRefEncoder::new(), XMLCharRefEncoder::full()] {
+            let escaped = ConvertedIterator::new(text.chars(), encoder)
+                .collect::<Result<String, _>>()
+                .unwrap();
+            assert!(!escaped.contains('<'));
+            let unescaped = ConvertedIterator::new(escaped.chars(), XMLCharRefDecoder::new())
+                .collect::<Result<String, _>>();
+            assert_eq!(Ok(String::from(text)), unescaped);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescape() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let unescape = |s: &str| {
+            ConvertedIterator::new(s.chars(), XMLCharRefDecoder::new())
+                .collect::<Result<String, _>>()
+        };
+        assert_eq!(
+            Ok(String::from("\"'&\u{10FFFF}A")),
+            unescape("&quot;&apos;&amp;&#1114111;&#x41;")
+        );
+        assert_eq!(Err(XMLEscapeError::UnknownEntity), unescape("&ampx;"));
+        assert_eq!(Err(XMLEscapeError::UnknownEntity), unescape("&foo;"));
+        assert_eq!(
+            Err(XMLEscapeError::InvalidCharacter(' ')),
+            unescape("a & b")
+        );
+        assert_eq!(Err(XMLEscapeError::InvalidCharacter(';')), unescape("&#;"));
+        assert_eq!(Err(XMLEscapeError::InvalidCharacter(';')), unescape("&#x;"));
+        assert_eq!(
+            Err(XMLEscapeError::InvalidCharacter('g')),
+            unescape("&#xg;")
+        );
+        assert_eq!(Err(XMLEscapeError::InvalidCodepoint(0)), unescape("&#0;"));
+        assert_eq!(
+            Err(XMLEscapeError::InvalidCodepoint(0xD800)),
+            unescape("&#xD800;")
+        );
+        assert_eq!(
+            Err(XMLEscapeError::InvalidCodepoint(u32::MAX)),
+            unescape("&#99999999999;")
+        );
+        assert_eq!(Err(XMLEscapeError::Unterminated), unescape("abc&lt"));
+    }
+}
@@ -0,0 +1,26 @@
+//! Converters escaping special characters for embedding text into other formats.
+
+mod html;
+mod literal;
+mod unicode;
+
+pub use html::{HtmlEscapeEncoder, HtmlUnescapeDecoder, HtmlUnescapeError, MAX_REFERENCE_LEN};
+pub use literal::{
+    LiteralDialect, LiteralEscapeEncoder, LiteralEscapeError, LiteralUnescapeDecoder,
+};
+pub use unicode::{UnicodeEscapeDecoder, UnicodeEscapeEncoder, UnicodeEscapeError};
+
+/// Escaping JSON strings, the same as [`JsonStringEscapeConverter`].
+///
+/// [`JsonStringEscapeConverter`]: super::encoding::json_escape::JsonStringEscapeConverter
+pub type JsonEscapeEncoder = super::encoding::json_escape::JsonStringEscapeConverter;
+
+/// Unescaping JSON strings, the same as [`JsonStringUnescapeConverter`].
+///
+/// [`JsonStringUnescapeConverter`]: super::encoding::json_escape::JsonStringUnescapeConverter
+pub type JsonUnescapeDecoder = super::encoding::json_escape::JsonStringUnescapeConverter;
+
+/// An error while unescaping JSON strings, the same as [`JsonEscapeError`].
+///
+/// [`JsonEscapeError`]: super::encoding::json_escape::JsonEscapeError
+pub type JsonUnescapeError = super::encoding::json_escape::JsonEscapeError;
@@ -0,0 +1,460 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::Converter;
+
+mod entities;
+
+// characters and their entities.
+const ENTITIES: [(char, &str); 5] = [
+    ('&', "amp"),
+    ('<', "lt"),
+    ('>', "gt"),
+    ('"', "quot"),
+    ('\'', "apos"),
+];
+
+/// Escaping special characters in HTML (or XML) with entities.
+///
+/// In text contexts ([`new`]) only `&`, `<` and `>` are escaped, and in attribute contexts
+/// ([`attribute`]) `"` and `'` are also escaped. With [`with_char_refs`], non-ASCII characters are
+/// escaped as hexadecimal character references.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Encoder;
+/// use conversion::converter::escape::HtmlEscapeEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let escaped = ConvertedIterator::new("a<b & c".chars(), HtmlEscapeEncoder::new());
+/// let encoded = ConvertedIterator::new(escaped.map(Result::unwrap), UTF8Encoder::new());
+/// assert_eq!(Ok(b"a&lt;b &amp; c".to_vec()), encoded.collect());
+///
+/// let escaped = ConvertedIterator::new(
+///     "\"ß\" & 'ü'".chars(),
+///     HtmlEscapeEncoder::attribute().with_char_refs(),
+/// );
+/// assert_eq!(
+///     Ok(String::from("&quot;&#xDF;&quot; &amp; &apos;&#xFC;&apos;")),
+///     escaped.collect()
+/// );
+/// ```
+///
+/// [`new`]: Self::new
+/// [`attribute`]: Self::attribute
+/// [`with_char_refs`]: Self::with_char_refs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HtmlEscapeEncoder {
+    attribute: bool,
+    char_refs: bool,
+}
+
+impl HtmlEscapeEncoder {
+    /// Creating a new instance for text contexts.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance for attribute values, also escaping quotes.
+    #[inline]
+    pub fn attribute() -> Self {
+        Self {
+            attribute: true,
+            ..Self::default()
+        }
+    }
+
+    /// Escaping non-ASCII characters as numeric character references.
+    #[inline]
+    pub fn with_char_refs(self) -> Self {
+        Self {
+            char_refs: true,
+            ..self
+        }
+    }
+}
+
+impl Converter for HtmlEscapeEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let entity = match item {
+            '&' | '<' | '>' => ENTITIES.iter().find(|(c, _)| *c == item),
+            '"' | '\'' if self.attribute => ENTITIES.iter().find(|(c, _)| *c == item),
+            _ => None,
+        };
+        if let Some((_, name)) = entity {
+            buf.extend(['&']);
+            buf.extend(name.chars());
+            buf.extend([';']);
+            return Ok(name.len() + 2);
+        }
+
+        if self.char_refs && !item.is_ascii() {
+            let code = item as u32;
+            let digits = (32 - code.leading_zeros() as usize).div_ceil(4);
+            let hex = |i: usize| char::from_digit(code >> (i * 4) & 0xF, 16).unwrap();
+            buf.extend(['&', '#', 'x']);
+            buf.extend((0..digits).rev().map(|i| hex(i).to_ascii_uppercase()));
+            buf.extend([';']);
+            Ok(digits + 4)
+        } else {
+            buf.extend([item]);
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.attribute, self.char_refs) {
+            // `&#x10FFFF;`
+            (_, true) => (1, Some(10)),
+            // `&quot;`
+            (true, false) => (1, Some(6)),
+            // `&amp;`
+            (false, false) => (1, Some(5)),
+        }
+    }
+}
+
+/// An error while unescaping HTML character references in the strict mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HtmlUnescapeError {
+    /// A reference longer than [`MAX_REFERENCE_LEN`].
+    ///
+    /// [`MAX_REFERENCE_LEN`]: self::MAX_REFERENCE_LEN
+    TooLong,
+    /// A numeric reference which is not a valid character.
+    InvalidCodepoint(u32),
+}
+
+impl fmt::Display for HtmlUnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong => write!(f, "the reference is too long."),
+            Self::InvalidCodepoint(v) => write!(f, "invalid character code: {:#x}", v),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HtmlUnescapeError {}
+
+/// The maximum length of references between `&` and `;`.
+pub const MAX_REFERENCE_LEN: usize = 32;
+
+/// Unescaping HTML character references.
+///
+/// Decimal (`&#169;`), hexadecimal (`&#x1F600;`) and named (`&amp;`) references terminated with
+/// `;` are decoded. Named references of HTML 4.01 (and `&apos;`) are supported by default, and
+/// all of HTML5 with the `html5` feature.
+///
+/// Like browsers, unknown or unterminated references are passed through verbatim, and numeric
+/// references of invalid characters are decoded into U+FFFD. References longer than
+/// [`MAX_REFERENCE_LEN`] are also passed through, or rejected in the [`strict`] mode, which also
+/// rejects invalid characters.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::escape::HtmlUnescapeDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "&lt;p&gt;&copy; &#169; &#x1F600; AT&T &unknown;".chars();
+/// let unescaped = ConvertedIterator::new(iter, HtmlUnescapeDecoder::new());
+///
+/// assert_eq!(Ok(String::from("<p>© © 😀 AT&T &unknown;")), unescaped.collect());
+/// ```
+///
+/// [`MAX_REFERENCE_LEN`]: self::MAX_REFERENCE_LEN
+/// [`strict`]: Self::strict
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HtmlUnescapeDecoder {
+    strict: bool,
+    // characters after `&`, or `None` outside references.
+    reference: Option<([u8; MAX_REFERENCE_LEN], u8)>,
+}
+
+impl HtmlUnescapeDecoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance, which returns errors for too long references and invalid
+    /// characters.
+    #[inline]
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
+    // flush `&` and buffered characters.
+    fn verbatim<E>(name: &[u8], buf: &mut E) -> usize
+    where
+        E: Extend<char>,
+    {
+        buf.extend(['&']);
+        buf.extend(name.iter().map(|&b| b as char));
+        name.len() + 1
+    }
+
+    fn resolve<E>(&self, name: &[u8], buf: &mut E) -> Result<usize, HtmlUnescapeError>
+    where
+        E: Extend<char>,
+    {
+        let numeric = match name {
+            [b'#', b'x' | b'X', digits @ ..] => Self::number(digits, 16),
+            [b'#', digits @ ..] => Self::number(digits, 10),
+            _ => None,
+        };
+        if let Some(code) = numeric {
+            return match char::from_u32(code).filter(|&c| c != '\0') {
+                Some(c) => {
+                    buf.extend([c]);
+                    Ok(1)
+                }
+                None if self.strict => Err(HtmlUnescapeError::InvalidCodepoint(code)),
+                None => {
+                    buf.extend([char::REPLACEMENT_CHARACTER]);
+                    Ok(1)
+                }
+            };
+        }
+
+        match entities::ENTITIES.binary_search_by(|(n, _)| n.as_bytes().cmp(name)) {
+            Ok(i) => {
+                let chars = entities::ENTITIES[i].1;
+                buf.extend(chars.chars());
+                Ok(chars.chars().count())
+            }
+            Err(_) => {
+                let len = Self::verbatim(name, buf);
+                buf.extend([';']);
+                Ok(len + 1)
+            }
+        }
+    }
+
+    fn number(digits: &[u8], radix: u32) -> Option<u32> {
+        if digits.is_empty() {
+            return None;
+        }
+        digits.iter().try_fold(0u32, |acc, &d| {
+            let d = (d as char).to_digit(radix)?;
+            Some(acc.saturating_mul(radix).saturating_add(d))
+        })
+    }
+}
+
+impl Converter for HtmlUnescapeDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = HtmlUnescapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (mut name, len) = match self.reference.take() {
+            None if item == '&' => {
+                self.reference = Some(([0; MAX_REFERENCE_LEN], 0));
+                return Ok(0);
+            }
+            None => {
+                buf.extend([item]);
+                return Ok(1);
+            }
+            Some(reference) => reference,
+        };
+        let len = len as usize;
+
+        if item == ';' {
+            return self.resolve(&name[..len], buf);
+        }
+        if !(item.is_ascii_alphanumeric() || (item == '#' && len == 0)) {
+            // not a reference, retrying the character as a normal one.
+            let flushed = Self::verbatim(&name[..len], buf);
+            return Ok(flushed + self.convert(item, buf)?);
+        }
+        if len == MAX_REFERENCE_LEN {
+            if self.strict {
+                return Err(HtmlUnescapeError::TooLong);
+            }
+            let flushed = Self::verbatim(&name, buf);
+            buf.extend([item]);
+            return Ok(flushed + 1);
+        }
+
+        name[len] = item as u8;
+        self.reference = Some((name, len as u8 + 1));
+        Ok(0)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        Ok(match self.reference.take() {
+            Some((name, len)) => Self::verbatim(&name[..len as usize], buf),
+            None => 0,
+        })
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(self.reference.map_or(0, |(_, len)| len as usize + 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `&` and a too long reference.
+        (0, Some(MAX_REFERENCE_LEN + 2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn contexts() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let escape = |s: &str, encoder| {
+            ConvertedIterator::new(s.chars(), encoder).collect::<Result<String, _>>()
+        };
+        let text = "<p class=\"x\">Tom & Jerry's 💣</p>";
+
+        assert_eq!(
+            Ok(String::from(
+                "&lt;p class=\"x\"&gt;Tom &amp; Jerry's 💣&lt;/p&gt;"
+            )),
+            escape(text, HtmlEscapeEncoder::new())
+        );
+        assert_eq!(
+            Ok(String::from(
+                "&lt;p class=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s 💣&lt;/p&gt;"
+            )),
+            escape(text, HtmlEscapeEncoder::attribute())
+        );
+        assert_eq!(
+            Ok(String::from(
+                "&lt;p class=\"x\"&gt;Tom &amp; Jerry's &#x1F4A3;&lt;/p&gt;"
+            )),
+            escape(text, HtmlEscapeEncoder::new().with_char_refs())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unescape() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let unescape = |s: &str, decoder| {
+            ConvertedIterator::new(s.chars(), decoder).collect::<Result<String, _>>()
+        };
+        let cases = [
+            (
+                "Tom &amp; Jerry&apos;s &lt;&quot;show&quot;&gt;",
+                "Tom & Jerry's <\"show\">",
+            ),
+            ("&eacute;t&eacute; &euro;5 &#8364;5", "été €5 €5"),
+            (
+                "&#128163; &#x1F4A3; &#X1f4a3; &#x10FFFF;",
+                "💣 💣 💣 \u{10FFFF}",
+            ),
+            (
+                "&#0; &#xD800; &#x110000; &#99999999999;",
+                "\u{FFFD} \u{FFFD} \u{FFFD} \u{FFFD}",
+            ),
+            // bare `&`s and unterminated references.
+            ("a & b && c &", "a & b && c &"),
+            (
+                "&amp &lt&gt; &#x; &#; &#12a; &;",
+                "&amp &lt> &#x; &#; &#12a; &;",
+            ),
+            ("&#169", "&#169"),
+            ("&Amp; &aMP;", "&Amp; &aMP;"),
+        ];
+        for (escaped, unescaped) in cases {
+            assert_eq!(
+                Ok(String::from(unescaped)),
+                unescape(escaped, HtmlUnescapeDecoder::new()),
+                "{}",
+                escaped
+            );
+        }
+
+        let long = "&abcdefghijklmnopqrstuvwxyz0123456789;";
+        assert_eq!(
+            Ok(String::from(long)),
+            unescape(long, HtmlUnescapeDecoder::new())
+        );
+        assert_eq!(
+            Err(HtmlUnescapeError::TooLong),
+            unescape(long, HtmlUnescapeDecoder::strict())
+        );
+        assert_eq!(
+            Err(HtmlUnescapeError::InvalidCodepoint(0xD800)),
+            unescape("&#xD800;", HtmlUnescapeDecoder::strict())
+        );
+
+        let round_trip = "<a title=\"Tom & Jerry's\">💣</a>";
+        let escaped = ConvertedIterator::new(
+            round_trip.chars(),
+            HtmlEscapeEncoder::attribute().with_char_refs(),
+        )
+        .collect::<Result<String, _>>();
+        assert_eq!(
+            Ok(String::from(round_trip)),
+            unescape(&escaped.unwrap(), HtmlUnescapeDecoder::new())
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split_calls() {
+        use alloc::string::String;
+
+        let mut decoder = HtmlUnescapeDecoder::new();
+        let mut unescaped = String::new();
+        assert_eq!(Ok(1), decoder.convert('a', &mut unescaped));
+        for c in "&#x1F60".chars() {
+            assert_eq!(Ok(0), decoder.convert(c, &mut unescaped));
+        }
+        assert_eq!(Ok(0), decoder.convert('0', &mut unescaped));
+        assert_eq!(Ok(1), decoder.convert(';', &mut unescaped));
+        for c in "&co".chars() {
+            assert_eq!(Ok(0), decoder.convert(c, &mut unescaped));
+        }
+        assert_eq!(Ok(0), decoder.convert('p', &mut unescaped));
+        assert_eq!(Ok(4), decoder.finish(&mut unescaped));
+        assert_eq!("a😀&cop", unescaped);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "html5"))]
+    #[test]
+    fn html5() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let iter = "&CounterClockwiseContourIntegral; &NotEqualTilde; &fjlig;".chars();
+        let unescaped = ConvertedIterator::new(iter, HtmlUnescapeDecoder::new());
+        assert_eq!(
+            Ok(String::from("\u{2233} \u{2242}\u{338} fj")),
+            unescaped.collect()
+        );
+    }
+}
@@ -0,0 +1,559 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::Converter;
+
+/// Dialects of escape sequences in string literals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LiteralDialect {
+    /// Rust: `\n`, `\r`, `\t`, `\0`, `\\`, `\'`, `\"`, `\x..` (two digits, up to `\x7f`),
+    /// `\u{..}`, and line continuations.
+    Rust,
+    /// C: `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`, `\\`, `\'`, `\"`, `\?`, octal escapes
+    /// (up to three digits), `\x..` (any number of digits), `\uXXXX` and `\UXXXXXXXX`.
+    ///
+    /// Octal and hexadecimal escapes end at the first non-digit character, and their values are
+    /// taken as code points.
+    C,
+    /// Only the common escapes: `\n`, `\r`, `\t`, `\0`, `\\`, `\'` and `\"`.
+    Strict,
+}
+
+/// An error while unescaping string literals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LiteralEscapeError {
+    /// An unknown escape sequence in the dialect, like `\q`.
+    UnknownEscape(char),
+    /// An invalid character inside a numeric escape sequence.
+    InvalidDigit(char),
+    /// A value of a numeric escape sequence which is not a valid character.
+    InvalidCodepoint(u32),
+    /// The input ended inside an escape sequence.
+    Unterminated,
+}
+
+impl fmt::Display for LiteralEscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownEscape(c) => write!(f, "unknown escape sequence: \\{}", c),
+            Self::InvalidDigit(c) => write!(f, "invalid character in escape sequence: {:?}", c),
+            Self::InvalidCodepoint(v) => write!(f, "invalid character code: {:#x}", v),
+            Self::Unterminated => write!(f, "unterminated escape sequence."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LiteralEscapeError {}
+
+/// Escaping string literals.
+///
+/// `\` and the chosen quotes (`"` by default, see [`with_quotes`]) are escaped, and control
+/// characters are escaped by short forms (like `\n`) if possible, or `\u{..}` in Rust and octal
+/// escapes in C. In the strict dialect, control characters without short forms are emitted as
+/// they are.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::escape::{LiteralDialect, LiteralEscapeEncoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "\"ß\"\n\u{7}".chars();
+/// let escaped = ConvertedIterator::new(iter.clone(), LiteralEscapeEncoder::new(LiteralDialect::Rust));
+/// assert_eq!(Ok(String::from(r#"\"ß\"\n\u{7}"#)), escaped.collect());
+///
+/// let escaped = ConvertedIterator::new(iter, LiteralEscapeEncoder::new(LiteralDialect::C));
+/// assert_eq!(Ok(String::from(r#"\"ß\"\n\a"#)), escaped.collect());
+/// ```
+///
+/// [`with_quotes`]: Self::with_quotes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LiteralEscapeEncoder {
+    dialect: LiteralDialect,
+    single: bool,
+    double: bool,
+}
+
+impl LiteralEscapeEncoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(dialect: LiteralDialect) -> Self {
+        Self {
+            dialect,
+            single: false,
+            double: true,
+        }
+    }
+
+    /// Setting which quotes to escape, single (`'`) and double (`"`) ones.
+    #[inline]
+    pub fn with_quotes(self, single: bool, double: bool) -> Self {
+        Self {
+            single,
+            double,
+            ..self
+        }
+    }
+}
+
+impl Converter for LiteralEscapeEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let short = match (self.dialect, item) {
+            (_, '\\') => Some('\\'),
+            (_, '\'') if self.single => Some('\''),
+            (_, '"') if self.double => Some('"'),
+            (_, '\n') => Some('n'),
+            (_, '\r') => Some('r'),
+            (_, '\t') => Some('t'),
+            (LiteralDialect::Rust | LiteralDialect::Strict, '\0') => Some('0'),
+            (LiteralDialect::C, '\x07') => Some('a'),
+            (LiteralDialect::C, '\x08') => Some('b'),
+            (LiteralDialect::C, '\x0b') => Some('v'),
+            (LiteralDialect::C, '\x0c') => Some('f'),
+            _ => None,
+        };
+        if let Some(short) = short {
+            buf.extend(['\\', short]);
+            return Ok(2);
+        }
+
+        // control characters are below U+00A0.
+        let code = item as u32;
+        let digit = |d: u32, radix: u32| char::from_digit(d, radix).unwrap();
+        match self.dialect {
+            LiteralDialect::Rust if item.is_control() => {
+                buf.extend(['\\', 'u', '{']);
+                if code >= 0x10 {
+                    buf.extend([digit(code >> 4, 16)]);
+                }
+                buf.extend([digit(code & 0xF, 16), '}']);
+                Ok(if code >= 0x10 { 6 } else { 5 })
+            }
+            // always three digits, not to be continued by following digits.
+            LiteralDialect::C if item.is_control() => {
+                buf.extend(['\\']);
+                buf.extend((0..3).rev().map(|i| digit(code >> (i * 3) & 0o7, 8)));
+                Ok(4)
+            }
+            _ => {
+                buf.extend([item]);
+                Ok(1)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.dialect {
+            // `\u{7f}`
+            LiteralDialect::Rust => (1, Some(6)),
+            // `\177`
+            LiteralDialect::C => (1, Some(4)),
+            LiteralDialect::Strict => (1, Some(2)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `\`.
+    Escape,
+    // after `\x` in Rust, with the number of digits and the value.
+    Hex(u8, u32),
+    // after `\u` in Rust.
+    BraceStart,
+    // after `\u{` in Rust, with the number of digits and the value.
+    Braced(u8, u32),
+    // after `\` and a newline in Rust.
+    Whitespace,
+    // after `\` in C, with the number of digits and the value.
+    Octal(u8, u32),
+    // after `\x` in C, with the number of digits (saturated) and the value.
+    VarHex(u8, u32),
+    // after `\u` or `\U` in C, with the number of remaining digits and the value.
+    Fixed(u8, u32),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// Unescaping string literals.
+///
+/// See [`LiteralDialect`] for supported escape sequences. Unknown escape sequences and invalid
+/// characters are errors.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::escape::{LiteralDialect, LiteralEscapeError, LiteralUnescapeDecoder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = r"\u{df}\x21".chars();
+/// let unescaped = ConvertedIterator::new(iter, LiteralUnescapeDecoder::new(LiteralDialect::Rust));
+/// assert_eq!(Ok(String::from("ß!")), unescaped.collect());
+///
+/// let iter = r"\101\x42Cß\a".chars();
+/// let unescaped = ConvertedIterator::new(iter, LiteralUnescapeDecoder::new(LiteralDialect::C));
+/// assert_eq!(Ok(String::from("A\u{42c}ß\u{7}")), unescaped.collect());
+///
+/// let iter = r"\n\x21".chars();
+/// let mut unescaped = ConvertedIterator::new(iter, LiteralUnescapeDecoder::new(LiteralDialect::Strict));
+/// assert_eq!(Some(Ok('\n')), unescaped.next());
+/// assert_eq!(Some(Err(LiteralEscapeError::UnknownEscape('x'))), unescaped.next());
+/// ```
+///
+/// [`LiteralDialect`]: self::LiteralDialect
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LiteralUnescapeDecoder {
+    dialect: LiteralDialect,
+    state: State,
+}
+
+impl LiteralUnescapeDecoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(dialect: LiteralDialect) -> Self {
+        Self {
+            dialect,
+            state: State::Normal,
+        }
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, LiteralEscapeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn emit_code<E>(&mut self, code: u32, buf: &mut E) -> Result<usize, LiteralEscapeError>
+    where
+        E: Extend<char>,
+    {
+        match char::from_u32(code) {
+            Some(c) => self.emit(c, buf),
+            None => self.error(LiteralEscapeError::InvalidCodepoint(code)),
+        }
+    }
+
+    fn error(&mut self, err: LiteralEscapeError) -> Result<usize, LiteralEscapeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+
+    // ends a variable length escape, and converts the following character.
+    fn terminate<E>(
+        &mut self,
+        code: u32,
+        item: char,
+        buf: &mut E,
+    ) -> Result<usize, LiteralEscapeError>
+    where
+        E: Extend<char>,
+    {
+        match self.emit_code(code, buf) {
+            Ok(len) => Ok(len + self.convert(item, buf)?),
+            Err(e) => {
+                self.convert(item, buf)?;
+                Err(e)
+            }
+        }
+    }
+
+    fn escape<E>(&mut self, item: char, buf: &mut E) -> Result<usize, LiteralEscapeError>
+    where
+        E: Extend<char>,
+    {
+        let short = match (self.dialect, item) {
+            (_, 'n') => '\n',
+            (_, 'r') => '\r',
+            (_, 't') => '\t',
+            (_, '\\' | '\'' | '"') => item,
+            (LiteralDialect::Rust | LiteralDialect::Strict, '0') => '\0',
+            (LiteralDialect::Rust, 'x') => {
+                self.state = State::Hex(0, 0);
+                return Ok(0);
+            }
+            (LiteralDialect::Rust, 'u') => {
+                self.state = State::BraceStart;
+                return Ok(0);
+            }
+            (LiteralDialect::Rust, '\n') => {
+                self.state = State::Whitespace;
+                return Ok(0);
+            }
+            (LiteralDialect::C, 'a') => '\x07',
+            (LiteralDialect::C, 'b') => '\x08',
+            (LiteralDialect::C, 'f') => '\x0c',
+            (LiteralDialect::C, 'v') => '\x0b',
+            (LiteralDialect::C, '?') => '?',
+            (LiteralDialect::C, '0'..='7') => {
+                self.state = State::Octal(1, item as u32 - '0' as u32);
+                return Ok(0);
+            }
+            (LiteralDialect::C, 'x') => {
+                self.state = State::VarHex(0, 0);
+                return Ok(0);
+            }
+            (LiteralDialect::C, 'u') => {
+                self.state = State::Fixed(4, 0);
+                return Ok(0);
+            }
+            (LiteralDialect::C, 'U') => {
+                self.state = State::Fixed(8, 0);
+                return Ok(0);
+            }
+            (_, c) => return self.error(LiteralEscapeError::UnknownEscape(c)),
+        };
+        self.emit(short, buf)
+    }
+}
+
+impl Converter for LiteralUnescapeDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = LiteralEscapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal if item == '\\' => {
+                self.state = State::Escape;
+                Ok(0)
+            }
+            State::Normal => self.emit(item, buf),
+            State::Escape => self.escape(item, buf),
+            State::Hex(digits, value) => match item.to_digit(16) {
+                Some(d) if digits == 0 => {
+                    self.state = State::Hex(1, d);
+                    Ok(0)
+                }
+                Some(d) if value <= 0x7 => self.emit_code(value << 4 | d, buf),
+                Some(d) => self.error(LiteralEscapeError::InvalidCodepoint(value << 4 | d)),
+                None => self.error(LiteralEscapeError::InvalidDigit(item)),
+            },
+            State::BraceStart if item == '{' => {
+                self.state = State::Braced(0, 0);
+                Ok(0)
+            }
+            State::BraceStart => self.error(LiteralEscapeError::InvalidDigit(item)),
+            State::Braced(digits, value) => match item {
+                '_' if digits > 0 => Ok(0),
+                '}' if digits > 0 => self.emit_code(value, buf),
+                c => match c.to_digit(16) {
+                    Some(d) if digits < 6 => {
+                        self.state = State::Braced(digits + 1, value << 4 | d);
+                        Ok(0)
+                    }
+                    _ => self.error(LiteralEscapeError::InvalidDigit(c)),
+                },
+            },
+            State::Whitespace if item.is_whitespace() => Ok(0),
+            State::Whitespace => {
+                self.state = State::Normal;
+                self.convert(item, buf)
+            }
+            State::Octal(digits, value) => match item.to_digit(8) {
+                Some(d) if digits < 2 => {
+                    self.state = State::Octal(digits + 1, value << 3 | d);
+                    Ok(0)
+                }
+                Some(d) => self.emit_code(value << 3 | d, buf),
+                None => self.terminate(value, item, buf),
+            },
+            State::VarHex(digits, value) => match item.to_digit(16) {
+                Some(d) => {
+                    let value = value.saturating_mul(16).saturating_add(d);
+                    self.state = State::VarHex(1, value);
+                    Ok(0)
+                }
+                None if digits > 0 => self.terminate(value, item, buf),
+                None => self.error(LiteralEscapeError::InvalidDigit(item)),
+            },
+            State::Fixed(remaining, value) => match item.to_digit(16) {
+                Some(d) if remaining > 1 => {
+                    self.state = State::Fixed(remaining - 1, value << 4 | d);
+                    Ok(0)
+                }
+                Some(d) => self.emit_code(value << 4 | d, buf),
+                None => self.error(LiteralEscapeError::InvalidDigit(item)),
+            },
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal | State::Whitespace => Ok(0),
+            // variable length escapes are complete at the end.
+            State::Octal(_, value) | State::VarHex(1, value) => self.emit_code(value, buf),
+            _ => self.error(LiteralEscapeError::Unterminated),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a variable length escape and the following character.
+        (0, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    fn unescape(
+        s: &str,
+        dialect: LiteralDialect,
+    ) -> Result<alloc::string::String, LiteralEscapeError> {
+        crate::iter::ConvertedIterator::new(s.chars(), LiteralUnescapeDecoder::new(dialect))
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rust() {
+        use alloc::string::String;
+
+        let unescape = |s| unescape(s, LiteralDialect::Rust);
+        assert_eq!(
+            Ok(String::from("\n\r\t\0\\'\"!\u{7f}ß\u{10ffff}ab")),
+            unescape(
+                r#"\n\r\t\0\\\'\"\x21\x7f\u{df}\u{10_FFFF}a\
+                     b"#
+            )
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidCodepoint(0x80)),
+            unescape(r"\x80")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidDigit('g')),
+            unescape(r"\x4g")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidDigit('g')),
+            unescape(r"\u{4g}")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidDigit('4')),
+            unescape(r"\u41")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidDigit('}')),
+            unescape(r"\u{}")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidCodepoint(0x110000)),
+            unescape(r"\u{110000}")
+        );
+        assert_eq!(Err(LiteralEscapeError::UnknownEscape('a')), unescape(r"\a"));
+        assert_eq!(Err(LiteralEscapeError::Unterminated), unescape(r"abc\u{41"));
+        assert_eq!(Err(LiteralEscapeError::Unterminated), unescape(r"abc\"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn c() {
+        use alloc::string::String;
+
+        let unescape = |s| unescape(s, LiteralDialect::C);
+        assert_eq!(
+            Ok(String::from("\x07\x08\x0c\n\r\t\x0b\\'\"?")),
+            unescape(r#"\a\b\f\n\r\t\v\\\'\"\?"#)
+        );
+        assert_eq!(
+            Ok(String::from("\0A\u{ff}8 \u{1}\u{2}")),
+            unescape(r"\0\101\3778 \1\2")
+        );
+        assert_eq!(
+            Ok(String::from("\u{ab}\u{abc}g\u{1f4a3}z ß💣")),
+            unescape(r"\xab\xabcg\x1F4a3z ß\U0001f4a3")
+        );
+        assert_eq!(Ok(String::from("\u{10ffff}")), unescape(r"\x0010ffff"));
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidCodepoint(0x110000)),
+            unescape(r"\x110000")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidCodepoint(u32::MAX)),
+            unescape(r"\xfffffffffffff")
+        );
+        assert_eq!(Err(LiteralEscapeError::InvalidDigit('g')), unescape(r"\xg"));
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidDigit('g')),
+            unescape(r"\u00g0")
+        );
+        assert_eq!(
+            Err(LiteralEscapeError::InvalidCodepoint(0xD800)),
+            unescape(r"\uD800")
+        );
+        assert_eq!(Err(LiteralEscapeError::UnknownEscape('q')), unescape(r"\q"));
+        assert_eq!(Err(LiteralEscapeError::Unterminated), unescape(r"\u00d"));
+        assert_eq!(Err(LiteralEscapeError::Unterminated), unescape(r"\x"));
+        assert_eq!(Err(LiteralEscapeError::Unterminated), unescape(r"\"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn strict() {
+        use alloc::string::String;
+
+        let unescape = |s| unescape(s, LiteralDialect::Strict);
+        assert_eq!(
+            Ok(String::from("\n\r\t\0\\'\"")),
+            unescape(r#"\n\r\t\0\\\'\""#)
+        );
+        for escape in [r"\x41", r"\u{41}", r"\101", r"\a", r"\?"] {
+            let c = escape.chars().nth(1).unwrap();
+            assert_eq!(Err(LiteralEscapeError::UnknownEscape(c)), unescape(escape));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let text = "\0\x01\x07\x1f \"quoted\" 'single' \\ \r\n\t\u{7f}\u{9f} 0123 straße 💣";
+        for dialect in [
+            LiteralDialect::Rust,
+            LiteralDialect::C,
+            LiteralDialect::Strict,
+        ] {
+            for (single, double) in [(false, true), (true, false), (true, true)] {
+                let encoder = LiteralEscapeEncoder::new(dialect).with_quotes(single, double);
+                let escaped = ConvertedIterator::new(text.chars(), encoder)
+                    .collect::<Result<String, _>>()
+                    .unwrap();
+                assert_eq!(single, !escaped.replace("\\'", "").contains('\''));
+                assert_eq!(double, !escaped.replace("\\\"", "").contains('"'));
+                assert_eq!(Ok(String::from(text)), unescape(&escaped, dialect));
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::Converter;
+
+/// An error while unescaping `\uXXXX` escapes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnicodeEscapeError {
+    /// An invalid character inside `\uXXXX`.
+    InvalidDigit(char),
+    /// A surrogate which is not a part of a surrogate pair.
+    LoneSurrogate(u16),
+    /// The input ended inside an escape.
+    Unterminated,
+}
+
+impl fmt::Display for UnicodeEscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(c) => write!(f, "invalid character in escape: {:?}", c),
+            Self::LoneSurrogate(u) => write!(f, "lone surrogate: \\u{:04X}", u),
+            Self::Unterminated => write!(f, "unterminated escape."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnicodeEscapeError {}
+
+/// Escaping non-ASCII characters as `\uXXXX`, like Java properties files and JavaScript sources.
+///
+/// Characters outside the BMP are escaped as surrogate pairs, and ASCII characters (including
+/// `\`) are emitted as they are.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::escape::UnicodeEscapeEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let escaped = ConvertedIterator::new("é=💣".chars(), UnicodeEscapeEncoder::new());
+/// assert_eq!(Ok(String::from(r"\u00E9=\uD83D\uDCA3")), escaped.collect());
+///
+/// let escaped = ConvertedIterator::new("é=💣".chars(), UnicodeEscapeEncoder::lowercase());
+/// assert_eq!(Ok(String::from(r"\u00e9=\ud83d\udca3")), escaped.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnicodeEscapeEncoder {
+    lowercase: bool,
+}
+
+impl UnicodeEscapeEncoder {
+    /// Creating a new instance, with uppercase hexadecimal digits.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance, with lowercase hexadecimal digits.
+    #[inline]
+    pub fn lowercase() -> Self {
+        Self { lowercase: true }
+    }
+}
+
+impl Converter for UnicodeEscapeEncoder {
+    type Item = char;
+    type Output = char;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item.is_ascii() {
+            buf.extend([item]);
+            return Ok(1);
+        }
+
+        let mut units = [0; 2];
+        let units = item.encode_utf16(&mut units);
+        for &unit in units.iter() {
+            let hex = |i: u16| {
+                let c = char::from_digit((unit >> (i * 4) & 0xF) as u32, 16).unwrap();
+                if self.lowercase {
+                    c
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            };
+            buf.extend(['\\', 'u']);
+            buf.extend((0..4).rev().map(hex));
+        }
+        Ok(units.len() * 6)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a surrogate pair
+        (1, Some(12))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Normal,
+    // after `\`, with the preceding high surrogate.
+    Backslash(Option<u16>),
+    // after `\u`, with the number of digits, the value and the preceding high surrogate.
+    Unicode(u8, u16, Option<u16>),
+    // after a high surrogate.
+    Surrogate(u16),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// Unescaping `\uXXXX` escapes, including surrogate pairs.
+///
+/// Other text (including other backslash sequences like `\n`) is passed through. Like Java, a
+/// `\` escaped by another `\` does not start an escape, so `\\u0041` is unchanged.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::escape::{UnicodeEscapeDecoder, UnicodeEscapeError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = r"\u00e9=\uD83D\uDCA3\n\\u0041".chars();
+/// let unescaped = ConvertedIterator::new(iter, UnicodeEscapeDecoder::new());
+/// assert_eq!(Ok(String::from(r"é=💣\n\\u0041")), unescaped.collect());
+///
+/// let mut unescaped = ConvertedIterator::new(r"\uD800!".chars(), UnicodeEscapeDecoder::new());
+/// assert_eq!(Some(Err(UnicodeEscapeError::LoneSurrogate(0xD800))), unescaped.next());
+/// assert_eq!(Some(Ok('!')), unescaped.next());
+/// assert_eq!(None, unescaped.next());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnicodeEscapeDecoder {
+    state: State,
+}
+
+impl UnicodeEscapeDecoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn emit<E>(&mut self, c: char, buf: &mut E) -> Result<usize, UnicodeEscapeError>
+    where
+        E: Extend<char>,
+    {
+        self.state = State::Normal;
+        buf.extend([c]);
+        Ok(1)
+    }
+
+    fn error(&mut self, err: UnicodeEscapeError) -> Result<usize, UnicodeEscapeError> {
+        self.state = State::Normal;
+        Err(err)
+    }
+
+    fn unit<E>(
+        &mut self,
+        unit: u16,
+        high: Option<u16>,
+        buf: &mut E,
+    ) -> Result<usize, UnicodeEscapeError>
+    where
+        E: Extend<char>,
+    {
+        match (high, unit) {
+            (None, 0xD800..=0xDBFF) => {
+                self.state = State::Surrogate(unit);
+                Ok(0)
+            }
+            (None, 0xDC00..=0xDFFF) => self.error(UnicodeEscapeError::LoneSurrogate(unit)),
+            (None, _) => self.emit(char::from_u32(unit as u32).unwrap(), buf),
+            (Some(high), 0xDC00..=0xDFFF) => {
+                let code = 0x10000 + ((high as u32 - 0xD800) << 10 | (unit as u32 - 0xDC00));
+                self.emit(char::from_u32(code).unwrap(), buf)
+            }
+            (Some(high), _) => {
+                // the second unit is still valid by itself.
+                self.unit(unit, None, buf)?;
+                Err(UnicodeEscapeError::LoneSurrogate(high))
+            }
+        }
+    }
+}
+
+impl Converter for UnicodeEscapeDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = UnicodeEscapeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal if item == '\\' => {
+                self.state = State::Backslash(None);
+                Ok(0)
+            }
+            State::Normal => self.emit(item, buf),
+            State::Backslash(high) if item == 'u' => {
+                self.state = State::Unicode(0, 0, high);
+                Ok(0)
+            }
+            State::Backslash(None) => {
+                // `\\` is emitted together, not to start an escape by the second `\`.
+                buf.extend(['\\']);
+                Ok(1 + self.emit(item, buf)?)
+            }
+            State::Backslash(Some(high)) => {
+                self.state = State::Backslash(None);
+                self.convert(item, buf)?;
+                Err(UnicodeEscapeError::LoneSurrogate(high))
+            }
+            State::Unicode(digits, value, high) => match item.to_digit(16) {
+                Some(d) if digits < 3 => {
+                    self.state = State::Unicode(digits + 1, value << 4 | d as u16, high);
+                    Ok(0)
+                }
+                Some(d) => self.unit(value << 4 | d as u16, high, buf),
+                None => self.error(UnicodeEscapeError::InvalidDigit(item)),
+            },
+            State::Surrogate(high) if item == '\\' => {
+                self.state = State::Backslash(Some(high));
+                Ok(0)
+            }
+            State::Surrogate(high) => {
+                self.state = State::Normal;
+                self.convert(item, buf)?;
+                Err(UnicodeEscapeError::LoneSurrogate(high))
+            }
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state {
+            State::Normal => Ok(0),
+            State::Backslash(None) => self.emit('\\', buf),
+            State::Backslash(Some(high)) => {
+                self.emit('\\', buf)?;
+                Err(UnicodeEscapeError::LoneSurrogate(high))
+            }
+            State::Surrogate(high) => self.error(UnicodeEscapeError::LoneSurrogate(high)),
+            State::Unicode(..) => self.error(UnicodeEscapeError::Unterminated),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `\\`
+        (0, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn properties() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let properties = "# comment\ngreeting=こんにちは, Jürgen! 💣\npath=C:\\\\temp\\n\n";
+        let escaped = ConvertedIterator::new(properties.chars(), UnicodeEscapeEncoder::new())
+            .collect::<Result<String, _>>()
+            .unwrap();
+        assert_eq!(
+            "# comment\ngreeting=\\u3053\\u3093\\u306B\\u3061\\u306F, J\\u00FCrgen! \\uD83D\\uDCA3\npath=C:\\\\temp\\n\n",
+            escaped
+        );
+        assert!(escaped.is_ascii());
+
+        let unescaped = ConvertedIterator::new(escaped.chars(), UnicodeEscapeDecoder::new())
+            .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(properties)), unescaped);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let unescape = |s: &str| {
+            ConvertedIterator::new(s.chars(), UnicodeEscapeDecoder::new()).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::LoneSurrogate(0xD800))],
+            unescape(r"\uD800")
+        );
+        assert_eq!(
+            vec![
+                Err(UnicodeEscapeError::LoneSurrogate(0xD800)),
+                Ok('\\'),
+                Ok('n')
+            ],
+            unescape(r"\uD800\n")
+        );
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::LoneSurrogate(0xD800)), Ok('A')],
+            unescape(r"\ud800\u0041")
+        );
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::LoneSurrogate(0xDC00)), Ok('a')],
+            unescape(r"\uDC00a")
+        );
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::LoneSurrogate(0xD800)), Ok('\\')],
+            unescape(r"\uD800\")
+        );
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::InvalidDigit('g')), Ok('0')],
+            unescape(r"\u0g0")
+        );
+        assert_eq!(
+            vec![Err(UnicodeEscapeError::Unterminated)],
+            unescape(r"\u00")
+        );
+        assert_eq!(vec![Ok('a'), Ok('\\')], unescape(r"a\"));
+    }
+}
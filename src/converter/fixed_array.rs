@@ -0,0 +1,213 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// An error for inputs which ended inside an array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncompleteArrayError {
+    received: usize,
+}
+
+impl IncompleteArrayError {
+    /// Returns the number of items received for the incomplete array.
+    #[inline]
+    pub fn received(&self) -> usize {
+        self.received
+    }
+}
+
+impl fmt::Display for IncompleteArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the input ended inside an array, after {} items.",
+            self.received
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IncompleteArrayError {}
+
+/// Splitting arrays into their elements.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::FixedArrayEncoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = [[1u16, 2], [3, 4]].into_iter();
+/// let elements = ConvertedIterator::new(iter, FixedArrayEncoder::new());
+///
+/// assert_eq!(Ok(vec![1, 2, 3, 4]), elements.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedArrayEncoder<T, const N: usize> {
+    _item: PhantomData<T>,
+}
+
+impl<T, const N: usize> Default for FixedArrayEncoder<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self { _item: PhantomData }
+    }
+}
+
+impl<T, const N: usize> FixedArrayEncoder<T, N> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, const N: usize> Converter for FixedArrayEncoder<T, N> {
+    type Item = [T; N];
+    type Output = T;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend(item);
+        Ok(N)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (N, Some(N))
+    }
+}
+
+impl<T, const N: usize> crate::sealed::Sealed for FixedArrayEncoder<T, N> {}
+
+impl<T, const N: usize> ExactSizeConverter for FixedArrayEncoder<T, N> {}
+
+/// Collecting each `N` items into an array.
+///
+/// This is the inverse of [`FixedArrayEncoder`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::FixedArrayDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let mut arrays = ConvertedIterator::new(*b"RIFFWAVEfm", FixedArrayDecoder::<u8, 4>::new());
+///
+/// assert_eq!(Some(Ok(*b"RIFF")), arrays.next());
+/// assert_eq!(Some(Ok(*b"WAVE")), arrays.next());
+/// assert_eq!(Some(2), arrays.next().and_then(|r| r.err()).map(|e| e.received()));
+/// assert_eq!(None, arrays.next());
+/// ```
+///
+/// [`FixedArrayEncoder`]: crate::converter::FixedArrayEncoder
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedArrayDecoder<T, const N: usize> {
+    items: [T; N],
+    count: usize,
+}
+
+impl<T: Default + Copy, const N: usize> Default for FixedArrayDecoder<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            items: [T::default(); N],
+            count: 0,
+        }
+    }
+}
+
+impl<T: Default + Copy, const N: usize> FixedArrayDecoder<T, N> {
+    /// Creating a new instance.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero.
+    #[inline]
+    pub fn new() -> Self {
+        assert!(N > 0, "arrays must not be empty.");
+        Self::default()
+    }
+}
+
+impl<T: Default + Copy, const N: usize> Converter for FixedArrayDecoder<T, N> {
+    type Item = T;
+    type Output = [T; N];
+    type Error = IncompleteArrayError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.items[self.count] = item;
+        self.count += 1;
+        if self.count < N {
+            return Ok(0);
+        }
+
+        self.count = 0;
+        buf.extend([self.items]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.count) {
+            0 => Ok(()),
+            received => Err(IncompleteArrayError { received }),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec::Vec;
+
+        let arrays = [[1u32, 2, 3], [u32::MAX, 0, 7]];
+        let elements = ConvertedIterator::new(arrays, FixedArrayEncoder::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(6, elements.len());
+        let decoded = ConvertedIterator::new(elements, FixedArrayDecoder::new())
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(arrays.to_vec()), decoded);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn incomplete() {
+        use alloc::vec::Vec;
+
+        let mut decoder = FixedArrayDecoder::<char, 3>::new();
+        let mut buf = Vec::new();
+        assert_eq!(Ok(0), decoder.convert('a', &mut buf));
+        assert_eq!(Ok(0), decoder.convert('b', &mut buf));
+        assert_eq!(
+            Err(IncompleteArrayError { received: 2 }),
+            decoder.finalize()
+        );
+        assert_eq!(Ok(()), decoder.finalize());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_array() {
+        FixedArrayDecoder::<u8, 0>::new();
+    }
+}
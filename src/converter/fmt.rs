@@ -0,0 +1,5 @@
+//! Converters formatting values for humans, like logging and debugging.
+
+mod hexdump;
+
+pub use hexdump::HexDumpConverter;
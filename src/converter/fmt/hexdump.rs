@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::Converter;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Formatting bytes as a classic hex dump.
+///
+/// Each line has the offset of its first byte, the bytes in hex, and the ASCII column, which
+/// shows non-printable bytes as `.`. Lines are emitted when they are filled, and the last partial
+/// line is emitted at [`finish`], padded to align the ASCII column.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::fmt::HexDumpConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let dump = ConvertedIterator::new(*b"Hello, World!\n", HexDumpConverter::new().bytes_per_line(8))
+///     .collect::<Result<String, _>>()
+///     .unwrap();
+/// assert_eq!(
+///     "00000000  48 65 6c 6c 6f 2c 20 57  |Hello, W|\n\
+///      00000008  6f 72 6c 64 21 0a        |orld!.|\n",
+///     dump,
+/// );
+///
+/// let dump = ConvertedIterator::new(*b"Hi", HexDumpConverter::new().ascii(false))
+///     .collect::<Result<String, _>>()
+///     .unwrap();
+/// assert_eq!("00000000  48 69\n", dump);
+/// ```
+///
+/// [`finish`]: crate::Converter::finish
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HexDumpConverter {
+    bytes_per_line: usize,
+    ascii: bool,
+    offset: usize,
+    line: Vec<u8>,
+}
+
+impl Default for HexDumpConverter {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            bytes_per_line: 16,
+            ascii: true,
+            offset: 0,
+            line: Vec::new(),
+        }
+    }
+}
+
+impl HexDumpConverter {
+    /// Creating a new instance, with 16 bytes per line and the ASCII column.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting the number of bytes per line.
+    ///
+    /// # Panics
+    /// Panics if `bytes_per_line` is zero.
+    #[inline]
+    pub fn bytes_per_line(self, bytes_per_line: usize) -> Self {
+        assert!(bytes_per_line > 0, "bytes per line must not be zero");
+        Self {
+            bytes_per_line,
+            ..self
+        }
+    }
+
+    /// Setting whether to include the ASCII column.
+    #[inline]
+    pub fn ascii(self, ascii: bool) -> Self {
+        Self { ascii, ..self }
+    }
+
+    // the number of characters of a full line.
+    #[inline]
+    fn line_len(&self) -> usize {
+        let ascii = if self.ascii {
+            self.bytes_per_line + 4
+        } else {
+            0
+        };
+        9 + self.bytes_per_line * 3 + ascii + 1
+    }
+
+    // emitting the buffered line, and returns the number of characters.
+    fn flush<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<char>,
+    {
+        let mut len = 0;
+        let mut push = |c: char| {
+            buf.extend([c]);
+            len += 1;
+        };
+
+        for shift in (0..8).rev() {
+            push(HEX[(self.offset >> (shift * 4)) & 0xF] as char);
+        }
+        push(' ');
+        // pads the last line only if the ASCII column follows.
+        let slots = if self.ascii {
+            self.bytes_per_line
+        } else {
+            self.line.len()
+        };
+        for i in 0..slots {
+            push(' ');
+            match self.line.get(i) {
+                Some(&b) => {
+                    push(HEX[(b >> 4) as usize] as char);
+                    push(HEX[(b & 0xF) as usize] as char);
+                }
+                None => {
+                    push(' ');
+                    push(' ');
+                }
+            }
+        }
+        if self.ascii {
+            push(' ');
+            push(' ');
+            push('|');
+            for &b in self.line.iter() {
+                push(if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '.'
+                });
+            }
+            push('|');
+        }
+        push('\n');
+
+        self.offset += self.line.len();
+        self.line.clear();
+        len
+    }
+}
+
+impl Converter for HexDumpConverter {
+    type Item = u8;
+    type Output = char;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.line.push(item);
+        if self.line.len() < self.bytes_per_line {
+            return Ok(0);
+        }
+        Ok(self.flush(buf))
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let len = if self.line.is_empty() {
+            0
+        } else {
+            self.flush(buf)
+        };
+        self.offset = 0;
+        Ok(len)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        if self.line.is_empty() {
+            return Some(0);
+        }
+        // the offset, a space, hexadecimal bytes and a line feed.
+        let slots = if self.ascii {
+            self.bytes_per_line
+        } else {
+            self.line.len()
+        };
+        let ascii = if self.ascii { self.line.len() + 4 } else { 0 };
+        Some(10 + slots * 3 + ascii)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.line_len()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dump() {
+        use crate::iter::ConvertedIterator;
+        use alloc::string::String;
+
+        let bytes = *b"0123456789abcdef\x00\x01\x7f\x80\xff ~\tconversion code\n";
+        let expected = concat!(
+            "00000000  30 31 32 33 34 35 36 37 38 39 61 62 63 64 65 66  |0123456789abcdef|\n",
+            "00000010  00 01 7f 80 ff 20 7e 09 63 6f 6e 76 65 72 73 69  |..... ~.conversi|\n",
+            "00000020  6f 6e 20 63 6f 64 65 0a                          |on code.|\n",
+        );
+        let dump =
+            ConvertedIterator::new(bytes, HexDumpConverter::new()).collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(expected)), dump);
+
+        let expected = concat!(
+            "00000000  30 31 32 33 34 35 36 37 38 39 61 62 63 64 65 66\n",
+            "00000010  00 01 7f 80 ff 20 7e 09 63 6f 6e 76 65 72 73 69\n",
+            "00000020  6f 6e 20 63 6f 64 65 0a\n",
+        );
+        let dump = ConvertedIterator::new(bytes, HexDumpConverter::new().ascii(false))
+            .collect::<Result<String, _>>();
+        assert_eq!(Ok(String::from(expected)), dump);
+    }
+
+    #[test]
+    fn offset() {
+        use alloc::string::String;
+
+        let mut converter = HexDumpConverter::new().bytes_per_line(4);
+        let mut buf = String::new();
+        for b in 0..5 {
+            converter.convert(b, &mut buf).unwrap();
+        }
+        let len = converter.finish(&mut buf).unwrap();
+        assert_eq!(27, len);
+        assert_eq!(
+            "00000000  00 01 02 03  |....|\n00000004  04           |.|\n",
+            buf
+        );
+        assert_eq!((0, Some(30)), converter.size_hint());
+
+        // the offset restarts after finishing.
+        buf.clear();
+        converter.convert(0xAB, &mut buf).unwrap();
+        converter.finish(&mut buf).unwrap();
+        assert_eq!("00000000  ab           |.|\n", buf);
+    }
+}
@@ -0,0 +1,33 @@
+//! Converters bracketing messages into frames of byte streams, and extracting them back.
+//!
+//! The converters are the same as the ones in [`encoding`], gathered by purpose.
+//!
+//! # Examples
+//! ```
+//! use conversion::converter::framing::length::{LengthPrefixDecoder, LengthPrefixEncoder};
+//! use conversion::iter::ConvertedIterator;
+//!
+//! let frames = [b"hi".to_vec(), Vec::new()];
+//! let bytes = ConvertedIterator::new(frames.clone(), LengthPrefixEncoder::new())
+//!     .map(Result::unwrap);
+//! let decoded = ConvertedIterator::new(bytes, LengthPrefixDecoder::new())
+//!     .collect::<Result<Vec<_>, _>>();
+//! assert_eq!(Ok(frames.to_vec()), decoded);
+//! ```
+//!
+//! [`encoding`]: super::encoding
+
+/// Frames bracketed by start and end bytes, from [`encoding::stuffing`].
+///
+/// [`encoding::stuffing`]: crate::converter::encoding::stuffing
+pub mod stuffing {
+    pub use super::super::encoding::stuffing::*;
+}
+
+/// Frames prefixed by their lengths, from [`encoding::length_prefix`].
+///
+/// [`encoding::length_prefix`]: crate::converter::encoding::length_prefix
+pub mod length {
+    pub use super::super::encoding::length_prefix::*;
+    pub use super::super::encoding::Endianness;
+}
@@ -0,0 +1,88 @@
+use crate::Converter;
+use core::convert::Infallible;
+
+/// Inserting a separator between each pair of consecutive items.
+///
+/// The separator is emitted before each item except the first one, so no separator trails the
+/// last item.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::InterleavedConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "abc".chars();
+/// let joined = ConvertedIterator::new(iter, InterleavedConverter::new(','));
+/// assert_eq!(Ok(String::from("a,b,c")), joined.collect());
+///
+/// let joined = ConvertedIterator::new("".chars(), InterleavedConverter::new(','));
+/// assert_eq!(Ok(String::new()), joined.collect());
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+pub struct InterleavedConverter<T> {
+    separator: T,
+    started: bool,
+}
+
+impl<T> InterleavedConverter<T> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(separator: T) -> Self {
+        Self {
+            separator,
+            started: false,
+        }
+    }
+}
+
+impl<T: Clone> Converter for InterleavedConverter<T> {
+    type Item = T;
+    type Output = T;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if core::mem::replace(&mut self.started, true) {
+            buf.extend([self.separator.clone(), item]);
+            Ok(2)
+        } else {
+            buf.extend([item]);
+            Ok(1)
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.started = false;
+        Ok(())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reuse() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut converter = InterleavedConverter::new(0);
+        let mut buf = Vec::new();
+        assert_eq!(Ok(1), converter.convert(1, &mut buf));
+        assert_eq!(Ok(2), converter.convert(2, &mut buf));
+        assert_eq!(Ok(0), converter.finish(&mut buf));
+        assert_eq!(Ok(1), converter.convert(3, &mut buf));
+        assert_eq!(Ok(2), converter.convert(4, &mut buf));
+        assert_eq!(vec![1, 0, 2, 3, 0, 4], buf);
+    }
+}
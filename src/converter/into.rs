@@ -1,7 +1,7 @@
 use core::fmt;
 use core::marker::PhantomData;
 
-use crate::Converter;
+use crate::{Converter, ExactSizeConverter};
 
 /// Converting values with [`TryInto`] trait.
 ///
@@ -27,7 +27,7 @@ pub struct IntoConverter<I, O> {
 impl<I, O> Clone for IntoConverter<I, O> {
     #[inline]
     fn clone(&self) -> Self {
-        Self::default()
+        *self
     }
 }
 
@@ -88,3 +88,7 @@ where
         (1, Some(1))
     }
 }
+
+impl<I, O> crate::sealed::Sealed for IntoConverter<I, O> {}
+
+impl<I, O> ExactSizeConverter for IntoConverter<I, O> where I: TryInto<O> {}
@@ -0,0 +1,5 @@
+//! Converters for mail messages.
+
+mod encoded_word;
+
+pub use encoded_word::{EncodedWordDecoder, EncodedWordError, MAX_ENCODED_WORD_LEN};
@@ -0,0 +1,370 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::converter::encoding::base64::Base64Decoder;
+use crate::converter::encoding::registry::{decoder_for, EncodingError};
+use crate::Converter;
+
+/// The maximum length of encoded words, defined by [RFC 2047].
+///
+/// [RFC 2047]: https://www.rfc-editor.org/rfc/rfc2047#section-2
+pub const MAX_ENCODED_WORD_LEN: usize = 75;
+
+/// An error while decoding encoded words.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EncodedWordError {
+    /// A charset not in the [registry].
+    ///
+    /// [registry]: crate::converter::encoding::registry
+    UnknownCharset(String),
+    /// An encoding other than `B` or `Q`.
+    UnknownEncoding(String),
+    /// An invalid Base64 or Q-encoded text.
+    InvalidText,
+    /// An invalid byte sequence in the charset.
+    Charset(EncodingError),
+}
+
+impl fmt::Display for EncodedWordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCharset(charset) => write!(f, "unknown charset: {:?}", charset),
+            Self::UnknownEncoding(encoding) => write!(f, "unknown encoding: {:?}", encoding),
+            Self::InvalidText => write!(f, "invalid encoded text."),
+            Self::Charset(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodedWordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Charset(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// decodes the text of `Q` encoding.
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut iter = text.bytes();
+    while let Some(byte) = iter.next() {
+        bytes.push(match byte {
+            b'_' => b' ',
+            b'=' => {
+                let high = (iter.next()? as char).to_digit(16)?;
+                let low = (iter.next()? as char).to_digit(16)?;
+                (high << 4 | low) as u8
+            }
+            _ => byte,
+        });
+    }
+    Some(bytes)
+}
+
+// decodes the text of `B` encoding.
+fn decode_b(text: &str) -> Option<Vec<u8>> {
+    let mut decoder = Base64Decoder::new();
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    for byte in text.bytes() {
+        decoder.convert(byte, &mut bytes).ok()?;
+    }
+    decoder.finish(&mut bytes).ok()?;
+    Some(bytes)
+}
+
+// decodes `=?charset?encoding?text?=`.
+fn decode_word(word: &str) -> Result<String, EncodedWordError> {
+    let mut parts = word[2..word.len() - 2].splitn(3, '?');
+    let (charset, encoding, text) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(charset), Some(encoding), Some(text)) => (charset, encoding, text),
+        _ => return Err(EncodedWordError::InvalidText),
+    };
+    // RFC 2231 language suffix, like `us-ascii*en`.
+    let charset = charset.split('*').next().unwrap_or_default();
+
+    let mut decoder = decoder_for(charset)
+        .ok_or_else(|| EncodedWordError::UnknownCharset(String::from(charset)))?;
+    let bytes = match encoding {
+        "B" | "b" => decode_b(text),
+        "Q" | "q" => decode_q(text),
+        _ => return Err(EncodedWordError::UnknownEncoding(String::from(encoding))),
+    }
+    .ok_or(EncodedWordError::InvalidText)?;
+
+    let mut decoded = String::new();
+    for byte in bytes {
+        decoder
+            .convert(byte, &mut decoded)
+            .map_err(EncodedWordError::Charset)?;
+    }
+    decoder
+        .finish(&mut decoded)
+        .map_err(EncodedWordError::Charset)?;
+    Ok(decoded)
+}
+
+/// Decoding encoded words (`=?charset?encoding?text?=`) in mail headers, described in
+/// [RFC 2047].
+///
+/// Charsets are looked up in the [registry], and both `B` (Base64) and `Q` encodings are
+/// supported. Whitespaces between two adjacent encoded words are removed, and other text is
+/// passed through.
+///
+/// Candidates which are not decodable (unknown charsets, invalid texts, ...) are passed through
+/// verbatim by default, or errors are returned and they are discarded in the [`strict`] mode.
+/// Candidates longer than [`MAX_ENCODED_WORD_LEN`] are always treated as plain text.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::mail::EncodedWordDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "=?ISO-8859-1?Q?Andr=E9?= Pirard, =?UTF-8?B?5pel5pys?=\r\n =?utf-8?q?=E8=AA=9E?=".chars();
+/// let decoded = ConvertedIterator::new(iter, EncodedWordDecoder::new());
+///
+/// assert_eq!(Ok(String::from("André Pirard, 日本語")), decoded.collect());
+/// ```
+///
+/// [RFC 2047]: https://www.rfc-editor.org/rfc/rfc2047
+/// [registry]: crate::converter::encoding::registry
+/// [`strict`]: Self::strict
+/// [`MAX_ENCODED_WORD_LEN`]: self::MAX_ENCODED_WORD_LEN
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EncodedWordDecoder {
+    strict: bool,
+    // the current candidate, and the number of `?` in it.
+    word: String,
+    questions: u8,
+    // whitespaces after an encoded word, which are removed before another encoded word.
+    spaces: Option<String>,
+}
+
+impl EncodedWordDecoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance, which returns errors for undecodable encoded words.
+    #[inline]
+    pub fn strict() -> Self {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
+    // flushes buffered whitespaces as plain text.
+    fn flush_spaces<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<char>,
+    {
+        match self.spaces.take() {
+            Some(spaces) => {
+                buf.extend(spaces.chars());
+                spaces.chars().count()
+            }
+            None => 0,
+        }
+    }
+
+    // flushes buffered whitespaces and the candidate as plain text.
+    fn verbatim<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<char>,
+    {
+        let len = self.flush_spaces(buf);
+        let word = core::mem::take(&mut self.word);
+        self.questions = 0;
+        buf.extend(word.chars());
+        len + word.chars().count()
+    }
+
+    fn complete<E>(&mut self, buf: &mut E) -> Result<usize, EncodedWordError>
+    where
+        E: Extend<char>,
+    {
+        match decode_word(&self.word) {
+            Ok(decoded) => {
+                self.word.clear();
+                self.questions = 0;
+                // whitespaces between encoded words are removed.
+                self.spaces = Some(String::new());
+                buf.extend(decoded.chars());
+                Ok(decoded.chars().count())
+            }
+            Err(e) if self.strict => {
+                self.word.clear();
+                self.questions = 0;
+                self.flush_spaces(buf);
+                Err(e)
+            }
+            Err(_) => Ok(self.verbatim(buf)),
+        }
+    }
+}
+
+impl Converter for EncodedWordDecoder {
+    type Item = char;
+    type Output = char;
+    type Error = EncodedWordError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.word.is_empty() {
+            return match item {
+                '=' => {
+                    self.word.push(item);
+                    Ok(0)
+                }
+                _ if item.is_ascii_whitespace() && self.spaces.is_some() => {
+                    self.spaces.as_mut().unwrap().push(item);
+                    Ok(0)
+                }
+                _ => {
+                    let len = self.flush_spaces(buf);
+                    buf.extend([item]);
+                    Ok(len + 1)
+                }
+            };
+        }
+
+        let valid = match item {
+            _ if self.word.len() >= MAX_ENCODED_WORD_LEN => false,
+            '?' if self.word.len() == 1 => true,
+            _ if self.word.len() == 1 => false,
+            '=' if self.questions == 4 => {
+                self.word.push(item);
+                return self.complete(buf);
+            }
+            _ => self.questions < 4 && item.is_ascii_graphic(),
+        };
+        if valid {
+            if item == '?' {
+                self.questions += 1;
+            }
+            self.word.push(item);
+            Ok(0)
+        } else {
+            // the item may start another candidate.
+            let len = self.verbatim(buf);
+            Ok(len + self.convert(item, buf)?)
+        }
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        Ok(self.verbatim(buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::iter::ConvertedIterator;
+
+    fn decode(s: &str) -> Result<String, EncodedWordError> {
+        ConvertedIterator::new(s.chars(), EncodedWordDecoder::new()).collect()
+    }
+
+    #[test]
+    fn rfc2047() {
+        let examples = [
+            (
+                "=?US-ASCII?Q?Keith_Moore?= <moore@cs.utk.edu>",
+                "Keith Moore <moore@cs.utk.edu>",
+            ),
+            (
+                "=?ISO-8859-1?Q?Keld_J=F8rn_Simonsen?= <keld@dkuug.dk>",
+                "Keld Jørn Simonsen <keld@dkuug.dk>",
+            ),
+            (
+                "=?ISO-8859-1?Q?Andr=E9?= Pirard <PIRARD@vm1.ulg.ac.be>",
+                "André Pirard <PIRARD@vm1.ulg.ac.be>",
+            ),
+            (
+                "=?ISO-8859-1?B?SWYgeW91IGNhbiByZWFkIHRoaXMgeW8=?=",
+                "If you can read this yo",
+            ),
+            ("(=?ISO-8859-1?Q?a?=)", "(a)"),
+            ("(=?ISO-8859-1?Q?a?= b)", "(a b)"),
+            ("(=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?b?=)", "(ab)"),
+            ("(=?ISO-8859-1?Q?a?=  =?ISO-8859-1?Q?b?=)", "(ab)"),
+            ("(=?ISO-8859-1?Q?a?=\r\n    =?ISO-8859-1?Q?b?=)", "(ab)"),
+            ("(=?ISO-8859-1?Q?a_b?=)", "(a b)"),
+            ("(=?ISO-8859-1?Q?a?= =?ISO-8859-1?Q?_b?=)", "(a b)"),
+        ];
+        for (encoded, decoded) in examples {
+            assert_eq!(Ok(String::from(decoded)), decode(encoded));
+        }
+    }
+
+    #[test]
+    fn verbatim() {
+        let texts = [
+            "a = b, x=?y, 1+1=2 ",
+            "=?utf-8?q?a b?=",
+            "=?utf-8?q?a?b?c?=",
+            "=?utf-7?B?K2E-?= =?utf-8?x?a?=",
+            "=?utf-8?B?!!!!?=",
+            "=?us-ascii?Q?=FF?=",
+        ];
+        for text in texts {
+            assert_eq!(Ok(String::from(text)), decode(text));
+        }
+
+        // a too long candidate.
+        let long = alloc::format!("=?utf-8?q?{}?=", "a".repeat(MAX_ENCODED_WORD_LEN));
+        assert_eq!(Ok(long.clone()), decode(&long));
+
+        // `=` inside plain text can start a candidate.
+        assert_eq!(Ok(String::from("=é")), decode("==?utf-8?Q?=C3=A9?="));
+        // whitespaces before a plain text are kept.
+        assert_eq!(Ok(String::from("a =?x")), decode("=?utf-8?Q?a?= =?x"));
+    }
+
+    #[test]
+    fn strict() {
+        use alloc::vec;
+
+        let decode = |s: &str| {
+            ConvertedIterator::new(s.chars(), EncodedWordDecoder::strict()).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![
+                Ok('a'),
+                Err(EncodedWordError::UnknownCharset(String::from("utf-7"))),
+                Ok(' '),
+                Ok('b')
+            ],
+            decode("=?utf-8?Q?a?= =?utf-7?B?K2E-?=b")
+        );
+        assert_eq!(
+            vec![Err(EncodedWordError::UnknownEncoding(String::from("x")))],
+            decode("=?utf-8?x?a?=")
+        );
+        assert_eq!(
+            vec![Err(EncodedWordError::InvalidText)],
+            decode("=?utf-8?Q?=4?=")
+        );
+        assert!(matches!(
+            decode("=?utf-8?B?/w==?=")[..],
+            [Err(EncodedWordError::Charset(EncodingError::UTF8(_)))]
+        ));
+    }
+}
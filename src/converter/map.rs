@@ -2,7 +2,7 @@ use core::convert::Infallible;
 use core::fmt;
 use core::marker::PhantomData;
 
-use crate::Converter;
+use crate::{Converter, ExactSizeConverter};
 
 /// Converting values with a function.
 ///
@@ -89,6 +89,10 @@ where
     }
 }
 
+impl<F, I> crate::sealed::Sealed for MapConverter<F, I> {}
+
+impl<F, I, O> ExactSizeConverter for MapConverter<F, I> where F: FnMut(I) -> O {}
+
 /// Converting values with a failable function.
 ///
 /// # Examples
@@ -177,3 +181,7 @@ where
         (1, Some(1))
     }
 }
+
+impl<F, I> crate::sealed::Sealed for TryMapConverter<F, I> {}
+
+impl<F, I, O, E> ExactSizeConverter for TryMapConverter<F, I> where F: FnMut(I) -> Result<O, E> {}
@@ -0,0 +1,106 @@
+use crate::{Converter, ExactSizeConverter};
+
+/// A converter for [`map_with_index`] method.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Decoder;
+/// use conversion::iter::ConvertedIterator;
+/// use conversion::Converter;
+///
+/// let iter = b"stra\xc3\x9fe".iter().cloned();
+/// // flipping the case of every fourth byte, counted before decoding.
+/// let conv = UTF8Decoder::new().map_with_index(|i, b: u8| match i % 4 {
+///     3 => b ^ 0x20,
+///     _ => b,
+/// });
+/// let converted = ConvertedIterator::new(iter, conv);
+///
+/// assert_eq!(Ok(String::from("strAße")), converted.collect());
+/// ```
+///
+/// [`map_with_index`]: crate::Converter::map_with_index
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct IndexedMapConverter<C, F> {
+    inner: C,
+    f: F,
+    // the number of inputs so far.
+    index: usize,
+}
+
+impl<C, F> IndexedMapConverter<C, F> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(inner: C, f: F) -> Self {
+        Self { inner, f, index: 0 }
+    }
+
+    /// Getting a reference to the inner converter.
+    #[inline]
+    pub fn get_ref(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwrapping the inner converter.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, F> Converter for IndexedMapConverter<C, F>
+where
+    C: Converter,
+    F: FnMut(usize, C::Item) -> C::Item,
+{
+    type Item = C::Item;
+    type Output = C::Output;
+    type Error = C::Error;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let item = (self.f)(self.index, item);
+        self.index += 1;
+        self.inner.convert(item, buf)
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.inner.is_ended()
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.inner.finalize()
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.inner.finish(buf)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<C, F> crate::sealed::Sealed for IndexedMapConverter<C, F> {}
+
+impl<C, F> ExactSizeConverter for IndexedMapConverter<C, F>
+where
+    C: ExactSizeConverter,
+    F: FnMut(usize, C::Item) -> C::Item,
+{
+}
@@ -0,0 +1,227 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// An error while pairing nibbles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NibbleError {
+    /// A value greater than 15.
+    OutOfRange(u8),
+    /// The input ended with an odd number of nibbles.
+    Unpaired,
+}
+
+impl fmt::Display for NibbleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange(n) => write!(f, "{} is not a nibble.", n),
+            Self::Unpaired => write!(f, "the last nibble is unpaired."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NibbleError {}
+
+/// Splitting bytes into nibbles, the same as [`ByteToNibbles`].
+///
+/// [`ByteToNibbles`]: self::ByteToNibbles
+pub type NibbleSplitter = ByteToNibbles;
+
+/// Joining nibbles into bytes, the same as [`NibblesToByte`].
+///
+/// [`NibblesToByte`]: self::NibblesToByte
+pub type NibbleJoiner = NibblesToByte;
+
+/// Orders of the two nibbles in a byte.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NibbleOrder {
+    /// The high nibble first, like hexadecimal digits.
+    #[default]
+    HighFirst,
+    /// The low nibble first, like swapped semi-octets of telephony.
+    LowFirst,
+}
+
+impl NibbleOrder {
+    // the nibbles of a byte in the order.
+    #[inline]
+    pub(crate) fn split(self, byte: u8) -> [u8; 2] {
+        match self {
+            Self::HighFirst => [byte >> 4, byte & 0xF],
+            Self::LowFirst => [byte & 0xF, byte >> 4],
+        }
+    }
+
+    // the byte of nibbles in the order.
+    #[inline]
+    pub(crate) fn join(self, first: u8, second: u8) -> u8 {
+        match self {
+            Self::HighFirst => first << 4 | second,
+            Self::LowFirst => second << 4 | first,
+        }
+    }
+}
+
+/// Splitting bytes into nibbles (4-bit values), the high nibble first by default.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{ByteToNibbles, NibbleOrder};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let nibbles = ConvertedIterator::new([0xAB, 0x05], ByteToNibbles::new());
+/// assert_eq!(Ok(vec![0xA, 0xB, 0x0, 0x5]), nibbles.collect());
+///
+/// let nibbles = ConvertedIterator::new([0xAB], ByteToNibbles::with_order(NibbleOrder::LowFirst));
+/// assert_eq!(Ok(vec![0xB, 0xA]), nibbles.collect());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteToNibbles {
+    order: NibbleOrder,
+}
+
+impl ByteToNibbles {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a nibble order.
+    #[inline]
+    pub fn with_order(order: NibbleOrder) -> Self {
+        Self { order }
+    }
+}
+
+impl Converter for ByteToNibbles {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend(self.order.split(item));
+        Ok(2)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (2, Some(2))
+    }
+}
+
+impl crate::sealed::Sealed for ByteToNibbles {}
+
+impl ExactSizeConverter for ByteToNibbles {}
+
+/// Pairing nibbles (4-bit values) into bytes, the high nibble first by default.
+///
+/// This is the inverse of [`ByteToNibbles`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{NibbleError, NibblesToByte};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let bytes = ConvertedIterator::new([0xA, 0xB, 0x0, 0x5], NibblesToByte::new());
+/// assert_eq!(Ok(vec![0xAB, 0x05]), bytes.collect());
+///
+/// let mut bytes = ConvertedIterator::new([0x1, 0x10, 0x2], NibblesToByte::new());
+/// assert_eq!(Some(Err(NibbleError::OutOfRange(0x10))), bytes.next());
+/// assert_eq!(Some(Ok(0x12)), bytes.next());
+/// ```
+///
+/// [`ByteToNibbles`]: self::ByteToNibbles
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NibblesToByte {
+    order: NibbleOrder,
+    first: Option<u8>,
+}
+
+impl NibblesToByte {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance with a nibble order.
+    #[inline]
+    pub fn with_order(order: NibbleOrder) -> Self {
+        Self { order, first: None }
+    }
+}
+
+impl Converter for NibblesToByte {
+    type Item = u8;
+    type Output = u8;
+    type Error = NibbleError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item > 0xF {
+            return Err(NibbleError::OutOfRange(item));
+        }
+
+        match self.first.take() {
+            Some(first) => {
+                buf.extend([self.order.join(first, item)]);
+                Ok(1)
+            }
+            None => {
+                self.first = Some(item);
+                Ok(0)
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match self.first.take() {
+            Some(_) => Err(NibbleError::Unpaired),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::{ConvertedIterator, ConvertedTryIterator};
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        for order in [NibbleOrder::HighFirst, NibbleOrder::LowFirst] {
+            let nibbles = ConvertedIterator::new(0..=255, NibbleSplitter::with_order(order));
+            let bytes = ConvertedTryIterator::new(nibbles, NibbleJoiner::with_order(order));
+            assert_eq!(Ok((0..=255).collect::<Vec<u8>>()), bytes.collect());
+        }
+
+        let nibbles =
+            ConvertedIterator::new([0x12], ByteToNibbles::with_order(NibbleOrder::LowFirst));
+        assert_eq!(Ok(vec![0x2, 0x1]), nibbles.collect::<Result<Vec<_>, _>>());
+
+        let bytes = ConvertedIterator::new([0x1, 0x2, 0x3], NibblesToByte::new());
+        assert_eq!(
+            vec![Ok(0x12), Err(NibbleError::Unpaired)],
+            bytes.collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::error::ChainedError;
+use crate::{Converter, ExactSizeConverter};
+
+// an `Extend` implementation passing outputs of the first converter to the second converter.
+//
+// after the second converter returns an error, the rest of outputs are discarded, even ones
+// extended later while converting the same input, so that the first error is kept and not
+// followed by outputs converted from a broken state.
+struct Pipe<'a, D: Converter, E> {
+    second: &'a mut D,
+    buf: &'a mut E,
+    // the number of outputs of the second converter, and the first error.
+    len: usize,
+    error: Option<D::Error>,
+}
+
+impl<D: Converter, E: Extend<D::Output>> Extend<D::Item> for Pipe<'_, D, E> {
+    fn extend<T: IntoIterator<Item = D::Item>>(&mut self, iter: T) {
+        if self.error.is_some() {
+            return;
+        }
+        for item in iter {
+            match self.second.convert(item, self.buf) {
+                Ok(len) => self.len += len,
+                Err(e) => {
+                    self.error.get_or_insert(e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// A converter for [`pipe`] method.
+///
+/// Bounds of [`size_hint`] are products of ones of the two converters, so it stays exact through
+/// pipelines of [`ExactSizeConverter`]s.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf32::UTF32BEEncoder;
+/// use conversion::converter::ByteToNibbles;
+/// use conversion::{Converter, ExactSizeConverter};
+///
+/// let conv = UTF32BEEncoder::new().pipe(ByteToNibbles::new());
+/// assert_eq!((8, Some(8)), conv.size_hint());
+/// assert_eq!(8, conv.output_len());
+/// ```
+///
+/// [`pipe`]: crate::Converter::pipe
+/// [`size_hint`]: crate::Converter::size_hint
+/// [`ExactSizeConverter`]: crate::ExactSizeConverter
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PipedConverter<C, D> {
+    first: C,
+    second: D,
+}
+
+impl<C, D> PipedConverter<C, D> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(first: C, second: D) -> Self {
+        Self { first, second }
+    }
+
+    /// Getting references to the inner converters.
+    #[inline]
+    pub fn get_ref(&self) -> (&C, &D) {
+        (&self.first, &self.second)
+    }
+
+    /// Unwrapping the inner converters.
+    #[inline]
+    pub fn into_inner(self) -> (C, D) {
+        (self.first, self.second)
+    }
+}
+
+impl<C, D> Converter for PipedConverter<C, D>
+where
+    C: Converter,
+    D: Converter<Item = C::Output>,
+{
+    type Item = C::Item;
+    type Output = D::Output;
+    type Error = ChainedError<C::Error, D::Error>;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut pipe = Pipe {
+            second: &mut self.second,
+            buf,
+            len: 0,
+            error: None,
+        };
+        let first = self.first.convert(item, &mut pipe);
+        match pipe.error {
+            Some(e) => Err(ChainedError::Second(e)),
+            None => first.map(|_| pipe.len).map_err(ChainedError::First),
+        }
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.first.is_ended() || self.second.is_ended()
+    }
+
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        let first = self.first.finalize();
+        let second = self.second.finalize();
+        first.map_err(ChainedError::First)?;
+        second.map_err(ChainedError::Second)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let mut pipe = Pipe {
+            second: &mut self.second,
+            buf,
+            len: 0,
+            error: None,
+        };
+        let first = self.first.finish(&mut pipe);
+        let (len, error) = (pipe.len, pipe.error);
+        let second = self.second.finish(buf);
+        if let Some(e) = error {
+            return Err(ChainedError::Second(e));
+        }
+        first.map_err(ChainedError::First)?;
+        Ok(len + second.map_err(ChainedError::Second)?)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        // outputs of the first converter are converted by the second one.
+        let (_, second_max) = self.second.size_hint();
+        self.first
+            .finish_hint()
+            .zip(second_max)
+            .zip(self.second.finish_hint())
+            .map(|((x, y), z)| x * y + z)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_min, first_max) = self.first.size_hint();
+        let (second_min, second_max) = self.second.size_hint();
+        (
+            first_min * second_min,
+            first_max.zip(second_max).map(|(x, y)| x * y),
+        )
+    }
+}
+
+impl<C, D> crate::sealed::Sealed for PipedConverter<C, D> {}
+
+impl<C, D> ExactSizeConverter for PipedConverter<C, D>
+where
+    C: ExactSizeConverter,
+    D: ExactSizeConverter<Item = C::Output>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn second_error() {
+        use crate::converter::encoding::utf8::UTF8Encoder;
+        use crate::converter::TryMapConverter;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let ascii = TryMapConverter::new(|x: u8| {
+            calls.set(calls.get() + 1);
+            if x.is_ascii() {
+                Ok(x)
+            } else {
+                Err(x)
+            }
+        });
+        let mut piped = UTF8Encoder::new().pipe(ascii);
+        let mut buf = Vec::new();
+
+        assert_eq!(Ok(1), piped.convert('a', &mut buf));
+        // bytes after `0xE2` are not passed to the second converter.
+        assert_eq!(
+            Err(ChainedError::Second(0xE2)),
+            piped.convert('€', &mut buf)
+        );
+        assert_eq!(2, calls.get());
+        assert_eq!(Ok(1), piped.convert('b', &mut buf));
+        assert_eq!(Ok(0), piped.finish(&mut buf));
+        assert_eq!(vec![b'a', b'b'], buf);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn second_error_extended_twice() {
+        use crate::converter::encoding::stuffing::StuffingEncoder;
+        use crate::converter::TryMapConverter;
+        use alloc::vec;
+        use alloc::vec::Vec;
+        use core::cell::Cell;
+
+        let calls = Cell::new(0);
+        let no_ab = TryMapConverter::new(|x: u8| {
+            calls.set(calls.get() + 1);
+            match x {
+                0x41 | 0x42 => Err(x),
+                _ => Ok(x),
+            }
+        });
+        // the encoder extends the start byte, each payload byte and the end byte separately.
+        let mut piped = StuffingEncoder::dle().pipe(no_ab);
+        let mut buf = Vec::new();
+
+        assert_eq!(
+            Err(ChainedError::Second(0x41)),
+            piped.convert(vec![0x41, 0x42, 0x43], &mut buf)
+        );
+        assert_eq!(2, calls.get());
+        assert_eq!(vec![0x02], buf);
+    }
+}
@@ -0,0 +1,240 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::Converter;
+
+/// Numbers which can be mapped between ranges by [`RangeMapConverter`].
+pub trait RangeValue: Copy + PartialOrd {
+    /// Mapping `self` in `from` linearly into `to`.
+    ///
+    /// `self` should be in `from`, and `from` should not be empty or a single value.
+    fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self;
+}
+
+macro_rules! range_value_int {
+    ($($int:ty),*) => {
+        $(
+            impl RangeValue for $int {
+                fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self {
+                    // each factor fits in 64 bits, so the product fits in `u128`.
+                    let num = (self as i128 - from.0 as i128) as u128
+                        * (to.1 as i128 - to.0 as i128).unsigned_abs();
+                    let den = (from.1 as i128 - from.0 as i128) as u128;
+                    // rounding half up.
+                    let offset = ((num + den / 2) / den) as i128;
+                    if to.0 <= to.1 {
+                        (to.0 as i128 + offset) as $int
+                    } else {
+                        (to.0 as i128 - offset) as $int
+                    }
+                }
+            }
+        )*
+    };
+}
+
+range_value_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! range_value_float {
+    ($($float:ty),*) => {
+        $(
+            impl RangeValue for $float {
+                #[inline]
+                fn map_range(self, from: (Self, Self), to: (Self, Self)) -> Self {
+                    to.0 + (self - from.0) * (to.1 - to.0) / (from.1 - from.0)
+                }
+            }
+        )*
+    };
+}
+
+range_value_float!(f32, f64);
+
+/// Handling of values outside the input range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Clamp {
+    /// Returning [`RangeMapError`]s.
+    #[default]
+    Error,
+    /// Clamping values into the range silently. (NaNs are passed through.)
+    Saturate,
+}
+
+/// An error for values outside the input range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeMapError {
+    /// A value below the range.
+    Below,
+    /// A value above the range.
+    Above,
+    /// A value which is not comparable, like NaN.
+    Unordered,
+}
+
+impl fmt::Display for RangeMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Below => write!(f, "the value is below the range."),
+            Self::Above => write!(f, "the value is above the range."),
+            Self::Unordered => write!(f, "the value is not comparable."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeMapError {}
+
+/// Mapping numbers linearly from a range to another range.
+///
+/// Integers are mapped with integer arithmetic and rounded to the nearest (half up), and floats
+/// are interpolated directly. The output range can be reversed to invert values.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{Clamp, RangeMapConverter, RangeMapError};
+/// use conversion::iter::ConvertedIterator;
+///
+/// // 10-bit ADC readings into bytes.
+/// let converter = RangeMapConverter::new(0..=1023u16, 0..=255);
+/// let mapped = ConvertedIterator::new([0, 512, 1023], converter);
+/// assert_eq!(Ok(vec![0, 128, 255]), mapped.collect());
+///
+/// let converter = RangeMapConverter::new(0.0..=255.0, -1.0..=1.0);
+/// let mut mapped = ConvertedIterator::new([127.5, 300.0], converter);
+/// assert_eq!(Some(Ok(0.0)), mapped.next());
+/// assert_eq!(Some(Err(RangeMapError::Above)), mapped.next());
+///
+/// let converter = RangeMapConverter::new(0.0..=255.0, -1.0..=1.0).clamp(Clamp::Saturate);
+/// let mapped = ConvertedIterator::new([300.0, -5.0], converter);
+/// assert_eq!(Ok(vec![1.0, -1.0]), mapped.collect());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RangeMapConverter<T> {
+    from: (T, T),
+    to: (T, T),
+    clamp: Clamp,
+}
+
+impl<T: RangeValue> RangeMapConverter<T> {
+    /// Creating a new instance, returns errors for values outside `from`.
+    ///
+    /// # Panics
+    /// Panics if `from` is empty or a single value.
+    #[inline]
+    pub fn new(from: RangeInclusive<T>, to: RangeInclusive<T>) -> Self {
+        let (from, to) = (from.into_inner(), to.into_inner());
+        assert!(
+            from.0 < from.1,
+            "the input range must have more than one value."
+        );
+        Self {
+            from,
+            to,
+            clamp: Clamp::Error,
+        }
+    }
+
+    /// Setting handling of values outside the input range.
+    #[inline]
+    pub fn clamp(self, clamp: Clamp) -> Self {
+        Self { clamp, ..self }
+    }
+}
+
+impl<T: RangeValue> Converter for RangeMapConverter<T> {
+    type Item = T;
+    type Output = T;
+    type Error = RangeMapError;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let (min, max) = self.from;
+        let item = match (self.clamp, item.partial_cmp(&min), item.partial_cmp(&max)) {
+            (_, Some(_), Some(_)) if min <= item && item <= max => item,
+            (Clamp::Saturate, None, _) | (Clamp::Saturate, _, None) => item,
+            (Clamp::Saturate, _, _) if item < min => min,
+            (Clamp::Saturate, _, _) => max,
+            (Clamp::Error, None, _) | (Clamp::Error, _, None) => {
+                return Err(RangeMapError::Unordered)
+            }
+            (Clamp::Error, _, _) if item < min => return Err(RangeMapError::Below),
+            (Clamp::Error, _, _) => return Err(RangeMapError::Above),
+        };
+        buf.extend([item.map_range(self.from, self.to)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        let vectors: [(u64, u64); 5] = [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)];
+        // [0, 4] into [0, 2], rounding half up.
+        for (input, expected) in vectors {
+            assert_eq!(expected, input.map_range((0, 4), (0, 2)));
+        }
+
+        // the full ranges of the widest types.
+        assert_eq!(u64::MAX, u64::MAX.map_range((0, u64::MAX), (0, u64::MAX)));
+        assert_eq!(
+            i64::MAX,
+            u64::MAX.map_range((0, u64::MAX), (0, i64::MAX as u64)) as i64
+        );
+        assert_eq!(
+            i64::MIN,
+            i64::MAX.map_range((i64::MIN, i64::MAX), (i64::MAX, i64::MIN))
+        );
+        assert_eq!(
+            0,
+            0i64.map_range((i64::MIN, i64::MAX), (i64::MIN, i64::MAX))
+        );
+        assert_eq!(-1, (-128i8).map_range((-128, 127), (-1, 1)));
+        assert_eq!(1, 127i8.map_range((-128, 127), (-1, 1)));
+        assert_eq!(100, 0u8.map_range((0, 10), (100, 0)));
+        assert_eq!(0, 10u8.map_range((0, 10), (100, 0)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn clamp() {
+        use alloc::vec::Vec;
+
+        let mut converter = RangeMapConverter::new(-10i32..=10, 0..=100);
+        let mut buf = Vec::new();
+        assert_eq!(Err(RangeMapError::Below), converter.convert(-11, &mut buf));
+        assert_eq!(Err(RangeMapError::Above), converter.convert(11, &mut buf));
+        assert_eq!(Ok(1), converter.convert(-10, &mut buf));
+
+        let mut converter = converter.clamp(Clamp::Saturate);
+        assert_eq!(Ok(1), converter.convert(i32::MIN, &mut buf));
+        assert_eq!(Ok(1), converter.convert(i32::MAX, &mut buf));
+        assert_eq!(&[0, 0, 100], &buf[..]);
+
+        let mut converter = RangeMapConverter::new(0.0f32..=1.0, 0.0..=2.0);
+        let mut buf = Vec::new();
+        assert_eq!(
+            Err(RangeMapError::Unordered),
+            converter.convert(f32::NAN, &mut buf)
+        );
+        let mut converter = converter.clamp(Clamp::Saturate);
+        assert_eq!(Ok(1), converter.convert(f32::NAN, &mut buf));
+        assert!(buf[0].is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_value() {
+        RangeMapConverter::new(1..=1, 0..=2);
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::{self, Write};
+use std::vec::Vec;
+
+use crate::error::CombinedError;
+use crate::Converter;
+
+/// Pushing items into a converter, and writing outputs into a [`Write`] sink immediately.
+///
+/// I/O errors are returned as [`CombinedError::Stream`], and conversion errors are returned as
+/// [`CombinedError::Conversion`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Encoder;
+/// use conversion::converter::SinkConverter;
+///
+/// let mut sink = SinkConverter::new(UTF8Encoder::new(), Vec::new());
+/// for c in "straße".chars() {
+///     sink.push(c).unwrap();
+/// }
+/// sink.flush().unwrap();
+///
+/// assert_eq!(b"stra\xc3\x9fe", &sink.into_inner().1[..]);
+/// ```
+///
+/// [`Write`]: std::io::Write
+/// [`CombinedError::Stream`]: crate::error::CombinedError::Stream
+/// [`CombinedError::Conversion`]: crate::error::CombinedError::Conversion
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SinkConverter<C, W> {
+    converter: C,
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<C, W> SinkConverter<C, W> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(converter: C, writer: W) -> Self {
+        Self {
+            converter,
+            writer,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Getting a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Unwrapping this sink, returning the converter and the writer.
+    #[inline]
+    pub fn into_inner(self) -> (C, W) {
+        (self.converter, self.writer)
+    }
+}
+
+impl<C, W> SinkConverter<C, W>
+where
+    C: Converter<Output = u8>,
+    W: Write,
+{
+    /// Converting an item, and writing outputs.
+    pub fn push(&mut self, item: C::Item) -> Result<(), CombinedError<io::Error, C::Error>> {
+        let res = self.converter.convert(item, &mut self.buffer);
+        self.write()?;
+        res.map(|_| ()).map_err(CombinedError::Conversion)
+    }
+
+    /// Finishing the conversion, writing remaining outputs, and flushing the writer.
+    pub fn flush(&mut self) -> Result<(), CombinedError<io::Error, C::Error>> {
+        let res = self.converter.finish(&mut self.buffer);
+        self.write()?;
+        res.map_err(CombinedError::Conversion)?;
+        self.writer.flush().map_err(CombinedError::Stream)
+    }
+
+    fn write(&mut self) -> Result<(), CombinedError<io::Error, C::Error>> {
+        let res = self.writer.write_all(&self.buffer);
+        self.buffer.clear();
+        res.map_err(CombinedError::Stream)
+    }
+}
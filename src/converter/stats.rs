@@ -0,0 +1,305 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// Emitting the running mean of the numbers so far, for each number.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::RunningMeanConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let means = ConvertedIterator::new([2u8, 4, 9], RunningMeanConverter::new());
+/// assert_eq!(Ok(vec![2.0, 3.0, 5.0]), means.collect());
+/// ```
+pub struct RunningMeanConverter<T> {
+    count: u64,
+    mean: f64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> RunningMeanConverter<T> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of numbers so far.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<T> Clone for RunningMeanConverter<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RunningMeanConverter<T> {}
+
+impl<T> fmt::Debug for RunningMeanConverter<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningMeanConverter")
+            .field("count", &self.count)
+            .field("mean", &self.mean)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for RunningMeanConverter<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.mean == other.mean
+    }
+}
+
+impl<T> Default for RunningMeanConverter<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Into<f64>> Converter for RunningMeanConverter<T> {
+    type Item = T;
+    type Output = f64;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.count += 1;
+        // updating incrementally, not to overflow the sum.
+        self.mean += (item.into() - self.mean) / self.count as f64;
+        buf.extend([self.mean]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        *self = Self::default();
+        Ok(())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<T> crate::sealed::Sealed for RunningMeanConverter<T> {}
+
+impl<T: Into<f64>> ExactSizeConverter for RunningMeanConverter<T> {}
+
+/// Emitting the running variance of the numbers so far, for each number.
+///
+/// The variance is computed by Welford's online algorithm, which is numerically stable. It is the
+/// population variance by default, and the sample variance (divided by `n - 1`, and `0` for the
+/// first number) with [`sample`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::RunningVarianceConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let variances = ConvertedIterator::new([1.0, 3.0, 5.0], RunningVarianceConverter::new());
+/// assert_eq!(Ok(vec![0.0, 1.0, 8.0 / 3.0]), variances.collect());
+///
+/// let sample = RunningVarianceConverter::new().sample(true);
+/// let variances = ConvertedIterator::new([1i32, 3, 5], sample);
+/// assert_eq!(Ok(vec![0.0, 2.0, 4.0]), variances.collect());
+/// ```
+///
+/// [`sample`]: Self::sample
+pub struct RunningVarianceConverter<T> {
+    sample: bool,
+    count: u64,
+    mean: f64,
+    // the sum of squared differences from the mean.
+    m2: f64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> RunningVarianceConverter<T> {
+    /// Creating a new instance, emits the population variance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Setting whether to emit the sample variance instead of the population variance.
+    #[inline]
+    pub fn sample(self, sample: bool) -> Self {
+        Self { sample, ..self }
+    }
+
+    /// Returns the number of numbers so far.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the numbers so far.
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+}
+
+impl<T> Clone for RunningVarianceConverter<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RunningVarianceConverter<T> {}
+
+impl<T> fmt::Debug for RunningVarianceConverter<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningVarianceConverter")
+            .field("sample", &self.sample)
+            .field("count", &self.count)
+            .field("mean", &self.mean)
+            .field("m2", &self.m2)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for RunningVarianceConverter<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.sample == other.sample
+            && self.count == other.count
+            && self.mean == other.mean
+            && self.m2 == other.m2
+    }
+}
+
+impl<T> Default for RunningVarianceConverter<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            sample: false,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Into<f64>> Converter for RunningVarianceConverter<T> {
+    type Item = T;
+    type Output = f64;
+    type Error = Infallible;
+
+    #[inline]
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let x = item.into();
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+
+        let divisor = match (self.sample, self.count) {
+            (true, 1) => 1,
+            (true, n) => n - 1,
+            (false, n) => n,
+        };
+        buf.extend([self.m2 / divisor as f64]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        *self = Self::default().sample(self.sample);
+        Ok(())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<T> crate::sealed::Sealed for RunningVarianceConverter<T> {}
+
+impl<T: Into<f64>> ExactSizeConverter for RunningVarianceConverter<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn welford() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::vec::Vec;
+
+        // random numbers, to compare against the two-pass computation.
+        let mut next = random();
+        let numbers = (0..1000)
+            .map(|_| {
+                // a large offset makes the naive sum of squares lose precision.
+                1e9 + (next() % 1000) as f64
+            })
+            .collect::<Vec<_>>();
+
+        let means = ConvertedIterator::new(numbers.iter().cloned(), RunningMeanConverter::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let variances =
+            ConvertedIterator::new(numbers.iter().cloned(), RunningVarianceConverter::new())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+        assert_eq!(numbers.len(), means.len());
+        assert_eq!(numbers.len(), variances.len());
+
+        for n in [1, 2, 10, 1000] {
+            let xs = &numbers[..n];
+            let mean = xs.iter().sum::<f64>() / n as f64;
+            let variance = xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+            assert!((means[n - 1] - mean).abs() < 1e-6, "{}", n);
+            assert!((variances[n - 1] - variance).abs() < 1e-3, "{}", n);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn finalize() {
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let mut variance = RunningVarianceConverter::<u8>::new().sample(true);
+        let mut buf = Vec::new();
+        for x in [1, 2, 3] {
+            assert_eq!(Ok(1), variance.convert(x, &mut buf));
+        }
+        assert_eq!(vec![0.0, 0.5, 1.0], buf);
+        assert_eq!(3, variance.count());
+        assert_eq!(2.0, variance.mean());
+
+        // the state is reset, keeping the setting.
+        variance.finalize().unwrap();
+        assert_eq!(RunningVarianceConverter::new().sample(true), variance);
+    }
+}
@@ -0,0 +1,134 @@
+use alloc::string::String;
+use core::convert::Infallible;
+
+use crate::{Converter, ExactSizeConverter};
+
+/// Converting strings with a function on the whole [`str`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::StrConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = ["  Foo ", "BAR\n"].into_iter().map(String::from);
+/// let converted = ConvertedIterator::new(iter, StrConverter::new(|s| s.trim().to_lowercase()));
+///
+/// assert_eq!(Ok(vec![String::from("foo"), String::from("bar")]), converted.collect());
+/// ```
+///
+/// [`str`]: prim@str
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StrConverter<F> {
+    f: F,
+}
+
+impl<F> From<F> for StrConverter<F> {
+    #[inline]
+    fn from(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F> StrConverter<F>
+where
+    F: FnMut(&str) -> String,
+{
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self::from(f)
+    }
+}
+
+impl<F> Converter for StrConverter<F>
+where
+    F: FnMut(&str) -> String,
+{
+    type Item = String;
+    type Output = String;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        buf.extend([(self.f)(&item)]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<F> crate::sealed::Sealed for StrConverter<F> {}
+
+impl<F> ExactSizeConverter for StrConverter<F> where F: FnMut(&str) -> String {}
+
+/// Converting strings with a failable function on the whole [`str`].
+///
+/// # Examples
+/// ```
+/// use conversion::converter::TryStrConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let strip = |s: &str| s.strip_prefix("# ").map(String::from).ok_or("not a heading");
+/// let iter = ["# Foo", "Bar", "# Baz"].into_iter().map(String::from);
+/// let mut converted = ConvertedIterator::new(iter, TryStrConverter::new(strip));
+///
+/// assert_eq!(Some(Ok(String::from("Foo"))), converted.next());
+/// assert_eq!(Some(Err("not a heading")), converted.next());
+/// assert_eq!(Some(Ok(String::from("Baz"))), converted.next());
+/// assert_eq!(None, converted.next());
+/// ```
+///
+/// [`str`]: prim@str
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryStrConverter<F> {
+    f: F,
+}
+
+impl<F> From<F> for TryStrConverter<F> {
+    #[inline]
+    fn from(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, E> TryStrConverter<F>
+where
+    F: FnMut(&str) -> Result<String, E>,
+{
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self::from(f)
+    }
+}
+
+impl<F, E> Converter for TryStrConverter<F>
+where
+    F: FnMut(&str) -> Result<String, E>,
+{
+    type Item = String;
+    type Output = String;
+    type Error = E;
+
+    fn convert<Ext>(&mut self, item: Self::Item, buf: &mut Ext) -> Result<usize, Self::Error>
+    where
+        Ext: Extend<Self::Output>,
+    {
+        buf.extend([(self.f)(&item)?]);
+        Ok(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+impl<F> crate::sealed::Sealed for TryStrConverter<F> {}
+
+impl<F, E> ExactSizeConverter for TryStrConverter<F> where F: FnMut(&str) -> Result<String, E> {}
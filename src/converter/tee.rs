@@ -0,0 +1,129 @@
+use crate::error::ChainedError;
+use crate::Converter;
+
+/// Sending each item to two converters, and merging their outputs.
+///
+/// For each item, outputs of the first converter come before ones of the second converter. Both
+/// converters receive every item even if the other one fails, and the first error is returned.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::{MapConverter, TeeConverter};
+/// use conversion::iter::ConvertedIterator;
+///
+/// let lower = MapConverter::new(|c: char| c.to_ascii_lowercase());
+/// let upper = MapConverter::new(|c: char| c.to_ascii_uppercase());
+/// let iter = "abC".chars();
+/// let converted = ConvertedIterator::new(iter, TeeConverter::new(lower, upper));
+///
+/// assert_eq!(Ok(String::from("aAbBcC")), converted.collect());
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct TeeConverter<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> TeeConverter<A, B> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Getting references to the inner converters.
+    #[inline]
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.first, &self.second)
+    }
+
+    /// Unwrapping the inner converters.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> Converter for TeeConverter<A, B>
+where
+    A: Converter,
+    A::Item: Clone,
+    B: Converter<Item = A::Item, Output = A::Output>,
+{
+    type Item = A::Item;
+    type Output = A::Output;
+    type Error = ChainedError<A::Error, B::Error>;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let first = self.first.convert(item.clone(), buf);
+        let second = self.second.convert(item, buf);
+        Ok(first.map_err(ChainedError::First)? + second.map_err(ChainedError::Second)?)
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.first.is_ended() && self.second.is_ended()
+    }
+
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        let first = self.first.finalize();
+        let second = self.second.finalize();
+        first.map_err(ChainedError::First)?;
+        second.map_err(ChainedError::Second)
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let first = self.first.finish(buf);
+        let second = self.second.finish(buf);
+        Ok(first.map_err(ChainedError::First)? + second.map_err(ChainedError::Second)?)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.first
+            .finish_hint()
+            .zip(self.second.finish_hint())
+            .map(|(x, y)| x + y)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_min, first_max) = self.first.size_hint();
+        let (second_min, second_max) = self.second.size_hint();
+        (
+            first_min + second_min,
+            first_max.zip(second_max).map(|(x, y)| x + y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn errors() {
+        use crate::converter::TryMapConverter;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let odd = TryMapConverter::new(|x: u8| if x % 2 == 1 { Ok(x) } else { Err("even") });
+        let small = TryMapConverter::new(|x: u8| if x < 10 { Ok(x * 10) } else { Err(x) });
+        let mut tee = TeeConverter::new(odd, small);
+        let mut buf = Vec::new();
+
+        assert_eq!(Ok(2), tee.convert(1, &mut buf));
+        assert_eq!(Err(ChainedError::First("even")), tee.convert(2, &mut buf));
+        assert_eq!(Err(ChainedError::Second(11)), tee.convert(11, &mut buf));
+        assert_eq!(Err(ChainedError::First("even")), tee.convert(12, &mut buf));
+        assert_eq!(Ok(()), tee.finalize());
+        assert_eq!(vec![1, 10, 20, 11], buf);
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::string::String;
+use core::convert::Infallible;
+
+use crate::Converter;
+
+/// Splitting characters into words separated by whitespaces.
+///
+/// Consecutive whitespaces are treated as one separator, so empty words are never emitted. The
+/// last word is emitted when the input ends.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Decoder;
+/// use conversion::converter::WordTokenizer;
+/// use conversion::iter::{ConvertedIterator, ConvertedTryIterator};
+///
+/// let iter = b"  stra\xc3\x9fe\tand\xe3\x80\x80\n caf\xc3\xa9".into_iter().cloned();
+/// let decoded = ConvertedIterator::new(iter, UTF8Decoder::new());
+/// let words = ConvertedTryIterator::new(decoded, WordTokenizer::new());
+///
+/// assert_eq!(
+///     Ok(vec![String::from("straße"), String::from("and"), String::from("café")]),
+///     words.collect()
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordTokenizer {
+    ascii: bool,
+    word: String,
+}
+
+impl WordTokenizer {
+    /// Creating a new instance, which treats all Unicode whitespaces as separators.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creating a new instance, which treats only ASCII whitespaces as separators.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::WordTokenizer;
+    /// use conversion::iter::ConvertedIterator;
+    ///
+    /// let words = ConvertedIterator::new("a\u{3000}b c".chars(), WordTokenizer::ascii());
+    /// assert_eq!(Ok(vec![String::from("a\u{3000}b"), String::from("c")]), words.collect());
+    /// ```
+    #[inline]
+    pub fn ascii() -> Self {
+        Self {
+            ascii: true,
+            ..Self::default()
+        }
+    }
+
+    #[inline]
+    fn is_separator(&self, c: char) -> bool {
+        if self.ascii {
+            c.is_ascii_whitespace()
+        } else {
+            c.is_whitespace()
+        }
+    }
+
+    fn emit<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<String>,
+    {
+        if self.word.is_empty() {
+            0
+        } else {
+            buf.extend([core::mem::take(&mut self.word)]);
+            1
+        }
+    }
+}
+
+impl Converter for WordTokenizer {
+    type Item = char;
+    type Output = String;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.is_separator(item) {
+            Ok(self.emit(buf))
+        } else {
+            self.word.push(item);
+            Ok(0)
+        }
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        Ok(self.emit(buf))
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(usize::from(!self.word.is_empty()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
@@ -0,0 +1,150 @@
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::Converter;
+
+/// Accumulating items into a state, and emitting outputs when they are complete.
+///
+/// `f` takes the state and an item, and returns `Some(output)` only when a complete output is
+/// ready. `g` takes the remaining state when inputs reached end, and returns the last output if
+/// exists.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::TransducerConverter;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = "foo\nbar\nbaz".chars();
+/// let lines = TransducerConverter::new(
+///     String::new(),
+///     |line: &mut String, c| {
+///         if c == '\n' {
+///             Some(core::mem::take(line))
+///         } else {
+///             line.push(c);
+///             None
+///         }
+///     },
+///     |line: String| (!line.is_empty()).then(|| line),
+/// );
+/// let converted = ConvertedIterator::new(iter, lines);
+///
+/// assert_eq!(Ok(vec![
+///     String::from("foo"),
+///     String::from("bar"),
+///     String::from("baz"),
+/// ]), converted.collect());
+/// ```
+pub struct TransducerConverter<S, F, G, I> {
+    state: Option<S>,
+    f: F,
+    g: Option<G>,
+    _phantomi: PhantomData<I>,
+}
+
+impl<S: Clone, F: Clone, G: Clone, I> Clone for TransducerConverter<S, F, G, I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            f: self.f.clone(),
+            g: self.g.clone(),
+            _phantomi: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, F: Copy, G: Copy, I> Copy for TransducerConverter<S, F, G, I> {}
+
+impl<S: fmt::Debug, F: fmt::Debug, G: fmt::Debug, I> fmt::Debug
+    for TransducerConverter<S, F, G, I>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TransducerConverter")
+            .field(&self.state)
+            .field(&self.f)
+            .field(&self.g)
+            .finish()
+    }
+}
+
+impl<S: PartialEq, F: PartialEq, G: PartialEq, I> PartialEq for TransducerConverter<S, F, G, I> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.f == other.f && self.g == other.g
+    }
+}
+
+impl<S: Eq, F: Eq, G: Eq, I> Eq for TransducerConverter<S, F, G, I> {}
+
+impl<S, F, G, I> TransducerConverter<S, F, G, I> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(state: S, f: F, g: G) -> Self {
+        Self {
+            state: Some(state),
+            f,
+            g: Some(g),
+            _phantomi: PhantomData,
+        }
+    }
+}
+
+impl<S, F, G, I, O> Converter for TransducerConverter<S, F, G, I>
+where
+    F: FnMut(&mut S, I) -> Option<O>,
+    G: FnOnce(S) -> Option<O>,
+{
+    type Item = I;
+    type Output = O;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match &mut self.state {
+            Some(state) => match (self.f)(state, item) {
+                Some(output) => {
+                    buf.extend([output]);
+                    Ok(1)
+                }
+                None => Ok(0),
+            },
+            None => Ok(0),
+        }
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.state.is_none()
+    }
+
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        match self.state.take().zip(self.g.take()) {
+            Some((state, g)) => match g(state) {
+                Some(output) => {
+                    buf.extend([output]);
+                    Ok(1)
+                }
+                None => Ok(0),
+            },
+            None => Ok(0),
+        }
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
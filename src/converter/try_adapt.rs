@@ -0,0 +1,126 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::error::ChainedError;
+use crate::{Converter, ExactSizeConverter};
+
+/// A converter for [`try_adapt_input`] method.
+///
+/// [`try_adapt_input`]: crate::Converter::try_adapt_input
+pub struct TryAdaptedConverter<C, F, I, E> {
+    inner: C,
+    f: F,
+    _phantom: PhantomData<fn(I) -> E>,
+}
+
+impl<C: Clone, F: Clone, I, E> Clone for TryAdaptedConverter<C, F, I, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            f: self.f.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Copy, F: Copy, I, E> Copy for TryAdaptedConverter<C, F, I, E> {}
+
+impl<C: fmt::Debug, F: fmt::Debug, I, E> fmt::Debug for TryAdaptedConverter<C, F, I, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryAdaptedConverter")
+            .field("inner", &self.inner)
+            .field("f", &self.f)
+            .finish()
+    }
+}
+
+impl<C: PartialEq, F: PartialEq, I, E> PartialEq for TryAdaptedConverter<C, F, I, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.f == other.f
+    }
+}
+
+impl<C: Eq, F: Eq, I, E> Eq for TryAdaptedConverter<C, F, I, E> {}
+
+impl<C, F, I, E> TryAdaptedConverter<C, F, I, E> {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new(inner: C, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Getting a reference to the inner converter.
+    #[inline]
+    pub fn get_ref(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwrapping the inner converter.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, F, I, E> Converter for TryAdaptedConverter<C, F, I, E>
+where
+    C: Converter,
+    F: FnMut(I) -> Result<C::Item, E>,
+{
+    type Item = I;
+    type Output = C::Output;
+    type Error = ChainedError<E, C::Error>;
+
+    #[inline]
+    fn convert<Ext>(&mut self, item: Self::Item, buf: &mut Ext) -> Result<usize, Self::Error>
+    where
+        Ext: Extend<Self::Output>,
+    {
+        let item = (self.f)(item).map_err(ChainedError::First)?;
+        self.inner.convert(item, buf).map_err(ChainedError::Second)
+    }
+
+    #[inline]
+    fn is_ended(&self) -> bool {
+        self.inner.is_ended()
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        self.inner.finalize().map_err(ChainedError::Second)
+    }
+
+    #[inline]
+    fn finish<Ext>(&mut self, buf: &mut Ext) -> Result<usize, Self::Error>
+    where
+        Ext: Extend<Self::Output>,
+    {
+        self.inner.finish(buf).map_err(ChainedError::Second)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        self.inner.finish_hint()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<C, F, I, E> crate::sealed::Sealed for TryAdaptedConverter<C, F, I, E> {}
+
+impl<C, F, I, E> ExactSizeConverter for TryAdaptedConverter<C, F, I, E>
+where
+    C: ExactSizeConverter,
+    F: FnMut(I) -> Result<C::Item, E>,
+{
+}
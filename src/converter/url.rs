@@ -0,0 +1,10 @@
+//! Converters for URL components.
+
+#[cfg(feature = "alloc")]
+mod form;
+mod percent;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub use form::{FormPart, FormUrlDecodeError, FormUrlDecoder};
+pub use percent::{AsciiSet, PercentDecodeError, PercentDecoder, PercentEncoder};
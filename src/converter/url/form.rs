@@ -0,0 +1,253 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::{PercentDecodeError, PercentDecoder};
+use crate::Converter;
+
+/// Parts of a key-value pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FormPart {
+    /// The key, before `=`.
+    Key,
+    /// The value, after `=`.
+    Value,
+}
+
+/// An error while decoding `application/x-www-form-urlencoded`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormUrlDecodeError {
+    index: usize,
+    part: FormPart,
+    kind: PercentDecodeError,
+}
+
+impl FormUrlDecodeError {
+    /// Returns the index of the pair where the error occurred.
+    ///
+    /// Empty pairs are not counted, so this is the same as the index of the output.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns whether the error occurred in the key or the value.
+    #[inline]
+    pub fn part(&self) -> FormPart {
+        self.part
+    }
+
+    /// Returns the error while percent-decoding.
+    #[inline]
+    pub fn kind(&self) -> PercentDecodeError {
+        self.kind
+    }
+}
+
+impl fmt::Display for FormUrlDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            FormPart::Key => "key",
+            FormPart::Value => "value",
+        };
+        write!(
+            f,
+            "{} (in the {} of the pair {})",
+            self.kind, part, self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FormUrlDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// Splitting `application/x-www-form-urlencoded` bytes into decoded key-value pairs.
+///
+/// Pairs are separated by `&`, and a key and a value are separated by the first `=`. Each part
+/// is percent-decoded (with `+` as a space), and then decoded as UTF-8, replacing invalid
+/// sequences with `U+FFFD`. Keys without `=` have empty values, and empty pairs (e.g. from
+/// consecutive `&`) are skipped.
+///
+/// If an error occurs while percent-decoding, the invalid sequence is discarded and the pair is
+/// still emitted.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::url::FormUrlDecoder;
+/// use conversion::iter::ConvertedIterator;
+///
+/// let iter = b"a=1&b=hello+world&c=%E2%9C%93".iter().cloned();
+/// let pairs = ConvertedIterator::new(iter, FormUrlDecoder::new());
+///
+/// assert_eq!(
+///     Ok(vec![
+///         (String::from("a"), String::from("1")),
+///         (String::from("b"), String::from("hello world")),
+///         (String::from("c"), String::from("✓")),
+///     ]),
+///     pairs.collect()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormUrlDecoder {
+    decoder: PercentDecoder,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    // whether the current pair is not empty.
+    started: bool,
+    part: FormPart,
+    index: usize,
+}
+
+impl Default for FormUrlDecoder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            decoder: PercentDecoder::new(true),
+            key: Vec::new(),
+            value: Vec::new(),
+            started: false,
+            part: FormPart::Key,
+            index: 0,
+        }
+    }
+}
+
+impl FormUrlDecoder {
+    /// Creating a new instance.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn error(&self, kind: PercentDecodeError) -> FormUrlDecodeError {
+        FormUrlDecodeError {
+            index: self.index,
+            part: self.part,
+            kind,
+        }
+    }
+
+    // finishes the current part.
+    fn end_part(&mut self) -> Result<(), FormUrlDecodeError> {
+        let res = self.decoder.finalize().map_err(|e| self.error(e));
+        self.part = FormPart::Value;
+        res
+    }
+
+    fn emit<E>(&mut self, buf: &mut E) -> Result<usize, FormUrlDecodeError>
+    where
+        E: Extend<(String, String)>,
+    {
+        if !self.started {
+            return Ok(0);
+        }
+
+        let res = self.end_part();
+        let decode = |bytes: Vec<u8>| {
+            String::from_utf8(bytes)
+                .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+        };
+        buf.extend([(
+            decode(core::mem::take(&mut self.key)),
+            decode(core::mem::take(&mut self.value)),
+        )]);
+        self.started = false;
+        self.part = FormPart::Key;
+        self.index += 1;
+        res.map(|_| 1)
+    }
+}
+
+impl Converter for FormUrlDecoder {
+    type Item = u8;
+    type Output = (String, String);
+    type Error = FormUrlDecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if item == b'&' {
+            return self.emit(buf);
+        }
+
+        self.started = true;
+        let res = match self.part {
+            FormPart::Key if item == b'=' => return self.end_part().map(|_| 0),
+            FormPart::Key => self.decoder.convert(item, &mut self.key),
+            FormPart::Value => self.decoder.convert(item, &mut self.value),
+        };
+        res.map(|_| 0).map_err(|e| self.error(e))
+    }
+
+    #[inline]
+    fn finish<E>(&mut self, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.emit(buf)
+    }
+
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(usize::from(self.started))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), FormUrlDecoder::new()).collect::<Vec<_>>()
+        };
+        let pair = |k: &str, v: &str| Ok((String::from(k), String::from(v)));
+
+        assert_eq!(vec![pair("a", ""), pair("b", "")], decode(b"a&b="));
+        assert_eq!(vec![pair("a", "1")], decode(b"&&a=1&&"));
+        assert_eq!(vec![pair("", ""), pair("", "x")], decode(b"=&=x"));
+        assert_eq!(vec![pair("a", "b=c")], decode(b"a=b=c"));
+        assert_eq!(vec![pair("a b", "\u{FFFD}+")], decode(b"a+b=%ff%2B"));
+        assert!(decode(b"").is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+
+        let decode = |bytes: &[u8]| {
+            ConvertedIterator::new(bytes.iter().cloned(), FormUrlDecoder::new()).collect::<Vec<_>>()
+        };
+        let error = |index, part, kind| Err(FormUrlDecodeError { index, part, kind });
+        let pair = |k: &str, v: &str| Ok((String::from(k), String::from(v)));
+
+        assert_eq!(
+            vec![
+                error(0, FormPart::Key, PercentDecodeError::Unterminated),
+                pair("a", "1"),
+                error(1, FormPart::Value, PercentDecodeError::InvalidDigit(b'g')),
+                pair("b", "2"),
+                error(2, FormPart::Value, PercentDecodeError::Unterminated),
+                pair("c", ""),
+            ],
+            decode(b"a%=1&&b=%g2&c=%4")
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use core::convert::Infallible;
+use core::fmt;
+
+use crate::Converter;
+
+const UNRESERVED: u128 = AsciiSet::EMPTY
+    .add_range(b'A', b'Z')
+    .add_range(b'a', b'z')
+    .add_range(b'0', b'9')
+    .add_str("-._~")
+    .mask;
+
+/// A set of ASCII characters which are left unescaped.
+///
+/// The predefined sets follow the grammar of [RFC 3986]. Custom sets can be built from them (or
+/// from [`EMPTY`]) with [`add`], [`add_range`], [`add_str`] and [`remove`], all of which are
+/// `const`.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::url::AsciiSet;
+///
+/// // the byte serializer of `application/x-www-form-urlencoded`, except for spaces.
+/// const FORM: AsciiSet = AsciiSet::COMPONENT.add(b'*').remove(b'~');
+///
+/// assert!(FORM.contains(b'*'));
+/// assert!(!FORM.contains(b'~'));
+/// assert!(!FORM.contains(b' '));
+/// ```
+///
+/// [RFC 3986]: https://www.rfc-editor.org/rfc/rfc3986
+/// [`EMPTY`]: Self::EMPTY
+/// [`add`]: Self::add
+/// [`add_range`]: Self::add_range
+/// [`add_str`]: Self::add_str
+/// [`remove`]: Self::remove
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AsciiSet {
+    mask: u128,
+}
+
+impl AsciiSet {
+    /// The empty set, escaping all characters.
+    pub const EMPTY: Self = Self { mask: 0 };
+
+    /// Characters allowed in a path (`pchar` and `/`).
+    pub const PATH: Self = Self::USERINFO.add_str("@/");
+
+    /// Characters allowed in a query (`pchar`, `/` and `?`).
+    pub const QUERY: Self = Self::PATH.add(b'?');
+
+    /// Characters allowed in a fragment, the same as [`QUERY`].
+    ///
+    /// [`QUERY`]: Self::QUERY
+    pub const FRAGMENT: Self = Self::QUERY;
+
+    /// Characters allowed in a user information (`unreserved`, `sub-delims` and `:`).
+    pub const USERINFO: Self = Self::COMPONENT.add_str("!$&'()*+,;=:");
+
+    /// Unreserved characters, which never have special meanings in any components.
+    ///
+    /// This is suitable for data embedded into a component, like keys and values of a query.
+    pub const COMPONENT: Self = Self { mask: UNRESERVED };
+
+    /// Adding a character to the set.
+    ///
+    /// # Panics
+    /// Panics if `byte` is not ASCII.
+    #[inline]
+    pub const fn add(self, byte: u8) -> Self {
+        assert!(byte.is_ascii(), "not an ASCII character");
+        Self {
+            mask: self.mask | 1 << byte,
+        }
+    }
+
+    /// Adding an inclusive range of characters to the set.
+    ///
+    /// # Panics
+    /// Panics if `last` is not ASCII.
+    pub const fn add_range(self, first: u8, last: u8) -> Self {
+        let mut set = self;
+        let mut byte = first;
+        while byte <= last {
+            set = set.add(byte);
+            byte += 1;
+        }
+        set
+    }
+
+    /// Adding all characters of a string to the set.
+    ///
+    /// # Panics
+    /// Panics if `s` contains non-ASCII characters.
+    pub const fn add_str(self, s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut set = self;
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.add(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+
+    /// Removing a character from the set.
+    #[inline]
+    pub const fn remove(self, byte: u8) -> Self {
+        if byte.is_ascii() {
+            Self {
+                mask: self.mask & !(1 << byte),
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Checking whether the set contains a byte.
+    ///
+    /// Non-ASCII bytes are never contained.
+    #[inline]
+    pub const fn contains(&self, byte: u8) -> bool {
+        byte.is_ascii() && self.mask & 1 << byte != 0
+    }
+}
+
+/// Percent-encoding bytes, as described in [RFC 3986].
+///
+/// Bytes in the [`AsciiSet`] are emitted as they are, and the others (including all non-ASCII
+/// bytes) are escaped as `%XX` with uppercase hexadecimal digits. Since this converter works on
+/// bytes, characters should be encoded (usually in UTF-8) beforehand.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Encoder;
+/// use conversion::converter::url::{AsciiSet, PercentEncoder};
+/// use conversion::iter::{ConvertedIterator, ConvertedTryIterator};
+///
+/// let encoded = ConvertedIterator::new("a b/ü".chars(), UTF8Encoder::new());
+/// let escaped = ConvertedTryIterator::new(encoded, PercentEncoder::new(AsciiSet::QUERY));
+/// assert_eq!(Ok(b"a%20b/%C3%BC".to_vec()), escaped.collect());
+///
+/// let encoded = ConvertedIterator::new("a b/ü".chars(), UTF8Encoder::new());
+/// let escaped = ConvertedTryIterator::new(encoded, PercentEncoder::new(AsciiSet::COMPONENT));
+/// assert_eq!(Ok(b"a%20b%2F%C3%BC".to_vec()), escaped.collect());
+/// ```
+///
+/// [RFC 3986]: https://www.rfc-editor.org/rfc/rfc3986
+/// [`AsciiSet`]: self::AsciiSet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PercentEncoder {
+    set: AsciiSet,
+}
+
+impl PercentEncoder {
+    /// Creating a new instance, leaving characters in `set` unescaped.
+    #[inline]
+    pub fn new(set: AsciiSet) -> Self {
+        Self { set }
+    }
+}
+
+impl Converter for PercentEncoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = Infallible;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        if self.set.contains(item) {
+            buf.extend([item]);
+            Ok(1)
+        } else {
+            const HEX: &[u8; 16] = b"0123456789ABCDEF";
+            buf.extend([b'%', HEX[(item >> 4) as usize], HEX[(item & 0xF) as usize]]);
+            Ok(3)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (1, Some(3))
+    }
+}
+
+/// An error while percent-decoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PercentDecodeError {
+    /// An invalid byte inside `%XX`.
+    InvalidDigit(u8),
+    /// The input ended inside `%XX`.
+    Unterminated,
+}
+
+impl fmt::Display for PercentDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDigit(b) => write!(f, "invalid byte in percent-encoding: {:#04x}", b),
+            Self::Unterminated => write!(f, "unterminated percent-encoding."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PercentDecodeError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum State {
+    Normal,
+    // after `%`.
+    Percent,
+    // after `%X`, with the value of the first digit.
+    Hex(u8),
+}
+
+impl Default for State {
+    #[inline]
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// Decoding percent-encoded bytes.
+///
+/// Bytes other than `%XX` (where `X` is a hexadecimal digit in either case) are passed through
+/// untouched, so the outputs can be decoded by other decoders like [`UTF8Decoder`]. If an invalid
+/// byte follows `%`, the error is returned and the sequence is discarded.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Decoder;
+/// use conversion::converter::url::PercentDecoder;
+/// use conversion::iter::{ConvertedIterator, ConvertedTryIterator};
+///
+/// let iter = b"a%20b+c/%c3%BC".iter().cloned();
+/// let decoded = ConvertedIterator::new(iter.clone(), PercentDecoder::new(false));
+/// let decoded = ConvertedTryIterator::new(decoded, UTF8Decoder::new());
+/// assert_eq!(Ok(String::from("a b+c/ü")), decoded.collect());
+///
+/// // `application/x-www-form-urlencoded`
+/// let decoded = ConvertedIterator::new(iter, PercentDecoder::new(true));
+/// let decoded = ConvertedTryIterator::new(decoded, UTF8Decoder::new());
+/// assert_eq!(Ok(String::from("a b c/ü")), decoded.collect());
+/// ```
+///
+/// [`UTF8Decoder`]: crate::converter::encoding::utf8::UTF8Decoder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PercentDecoder {
+    plus_as_space: bool,
+    state: State,
+}
+
+impl PercentDecoder {
+    /// Creating a new instance, decoding `+` as a space if `plus_as_space` is `true`.
+    #[inline]
+    pub fn new(plus_as_space: bool) -> Self {
+        Self {
+            plus_as_space,
+            state: State::Normal,
+        }
+    }
+}
+
+impl Converter for PercentDecoder {
+    type Item = u8;
+    type Output = u8;
+    type Error = PercentDecodeError;
+
+    fn convert<E>(&mut self, item: Self::Item, buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        let digit = (item as char).to_digit(16).map(|d| d as u8);
+        match (self.state, digit) {
+            (State::Normal, _) => match item {
+                b'%' => {
+                    self.state = State::Percent;
+                    Ok(0)
+                }
+                b'+' if self.plus_as_space => {
+                    buf.extend([b' ']);
+                    Ok(1)
+                }
+                _ => {
+                    buf.extend([item]);
+                    Ok(1)
+                }
+            },
+            (State::Percent, Some(d)) => {
+                self.state = State::Hex(d);
+                Ok(0)
+            }
+            (State::Hex(high), Some(d)) => {
+                self.state = State::Normal;
+                buf.extend([high << 4 | d]);
+                Ok(1)
+            }
+            (_, None) => {
+                self.state = State::Normal;
+                Err(PercentDecodeError::InvalidDigit(item))
+            }
+        }
+    }
+
+    #[inline]
+    fn finalize(&mut self) -> Result<(), Self::Error> {
+        match core::mem::take(&mut self.state) {
+            State::Normal => Ok(()),
+            _ => Err(PercentDecodeError::Unterminated),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GEN_DELIMS: &[u8] = b":/?#[]@";
+    const SUB_DELIMS: &[u8] = b"!$&'()*+,;=";
+
+    #[test]
+    fn rfc3986() {
+        let check = |set: AsciiSet, allowed: &[u8]| {
+            for byte in 0..=255u8 {
+                let unreserved = byte.is_ascii_alphanumeric() || b"-._~".contains(&byte);
+                let expected = unreserved || allowed.contains(&byte);
+                assert_eq!(expected, set.contains(byte), "{:?}", byte as char);
+            }
+        };
+        check(AsciiSet::COMPONENT, b"");
+        check(AsciiSet::USERINFO, b"!$&'()*+,;=:");
+        check(AsciiSet::PATH, b"!$&'()*+,;=:@/");
+        check(AsciiSet::QUERY, b"!$&'()*+,;=:@/?");
+        check(AsciiSet::FRAGMENT, b"!$&'()*+,;=:@/?");
+
+        // `#`, `[` and `]` are never allowed.
+        for set in [AsciiSet::PATH, AsciiSet::QUERY, AsciiSet::USERINFO] {
+            assert!(SUB_DELIMS.iter().all(|&b| set.contains(b)));
+            assert!(!b"#[]".iter().any(|&b| set.contains(b)));
+        }
+        assert!(!GEN_DELIMS
+            .iter()
+            .chain(SUB_DELIMS)
+            .any(|&b| AsciiSet::COMPONENT.contains(b)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode() {
+        use alloc::vec::Vec;
+
+        let mut encoder = PercentEncoder::new(AsciiSet::PATH);
+        let mut buf = Vec::new();
+        for byte in b"/a b%?#\x00\x7f\xff" {
+            encoder.convert(*byte, &mut buf).unwrap();
+        }
+        assert_eq!(b"/a%20b%25%3F%23%00%7F%FF".to_vec(), buf);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use crate::iter::ConvertedIterator;
+        use crate::test_util::random;
+        use alloc::vec::Vec;
+
+        let mut next = random();
+        for _ in 0..256 {
+            let len = next() as usize % 64;
+            let bytes: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+            for set in [AsciiSet::EMPTY, AsciiSet::COMPONENT, AsciiSet::QUERY] {
+                let encoded =
+                    ConvertedIterator::new(bytes.iter().cloned(), PercentEncoder::new(set))
+                        .collect::<Result<Vec<_>, _>>()
+                        .unwrap();
+                let decoded = ConvertedIterator::new(encoded, PercentDecoder::new(false))
+                    .collect::<Result<Vec<_>, _>>();
+                assert_eq!(Ok(bytes.clone()), decoded);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode() {
+        use crate::iter::ConvertedIterator;
+        use alloc::vec;
+        use alloc::vec::Vec;
+
+        let decode = |bytes: &[u8], plus_as_space| {
+            ConvertedIterator::new(bytes.iter().cloned(), PercentDecoder::new(plus_as_space))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Ok(b'a'), Err(PercentDecodeError::Unterminated)],
+            decode(b"a%4", false)
+        );
+        assert_eq!(
+            vec![Err(PercentDecodeError::Unterminated)],
+            decode(b"%", false)
+        );
+        assert_eq!(
+            vec![Err(PercentDecodeError::InvalidDigit(b'g')), Ok(b'1')],
+            decode(b"%4g1", false)
+        );
+        assert_eq!(
+            vec![
+                Err(PercentDecodeError::InvalidDigit(b'%')),
+                Ok(b'4'),
+                Ok(b'1')
+            ],
+            decode(b"%%41", false)
+        );
+        assert_eq!(
+            vec![Ok(b'+'), Ok(b' '), Ok(b'+')],
+            decode(b"+%20%2b", false)
+        );
+        assert_eq!(vec![Ok(b' '), Ok(b' '), Ok(b'+')], decode(b"+%20%2b", true));
+    }
+}
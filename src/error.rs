@@ -37,3 +37,41 @@ where
         }
     }
 }
+
+/// An error type for converters combining two converters, like [`ChainedConverter`],
+/// [`PipedConverter`] and [`TeeConverter`].
+///
+/// [`ChainedConverter`]: crate::converter::ChainedConverter
+/// [`PipedConverter`]: crate::converter::PipedConverter
+/// [`TeeConverter`]: crate::converter::TeeConverter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainedError<E, F> {
+    First(E),
+    Second(F),
+}
+
+impl<E: fmt::Display, F: fmt::Display> fmt::Display for ChainedError<E, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First(e) => write!(f, "{}", e),
+            Self::Second(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: InfallibleError, F: InfallibleError> InfallibleError for ChainedError<E, F> {}
+
+#[cfg(feature = "std")]
+impl<E, F> std::error::Error for ChainedError<E, F>
+where
+    E: std::error::Error + 'static,
+    F: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::First(e) => Some(e),
+            Self::Second(e) => Some(e),
+        }
+    }
+}
@@ -13,6 +13,11 @@ pub trait InfallibleConverter: Converter + sealed_converter::Sealed {
 
     /// Finalizing without any errors.
     fn finalize_ok(&mut self);
+
+    /// Finishing without any errors.
+    fn finish_ok<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<Self::Output>;
 }
 
 impl<C: Converter> InfallibleConverter for C
@@ -31,6 +36,14 @@ where
     fn finalize_ok(&mut self) {
         self.finalize().unwrap_infallible()
     }
+
+    #[inline]
+    fn finish_ok<E>(&mut self, buf: &mut E) -> usize
+    where
+        E: Extend<Self::Output>,
+    {
+        self.finish(buf).unwrap_infallible()
+    }
 }
 
 /// A marker trait for error types that will never constructed.
@@ -0,0 +1,141 @@
+use crate::converter::BatchExtend;
+use crate::Converter;
+
+/// A wrapper for [`Iterator`] like [`ConvertedIterator`], pairs each output with the index of the
+/// input item which produced it.
+///
+/// Outputs (or errors) produced while finishing the converter have the number of input items as
+/// their index, meaning "the end of the input".
+///
+/// # Example
+/// ```
+/// use conversion::converter::encoding::utf8::UTF8Decoder;
+/// use conversion::iter::EnumeratedConvertedIterator;
+///
+/// let iter = b"a\xc3\x9f\xffb\xe3".into_iter().cloned();
+/// let mut decoded = EnumeratedConvertedIterator::new(iter, UTF8Decoder::new());
+///
+/// assert_eq!(Some((0, Ok('a'))), decoded.next());
+/// assert_eq!(Some((2, Ok('ß'))), decoded.next());
+/// assert!(matches!(decoded.next(), Some((3, Err(_)))));
+/// assert_eq!(Some((4, Ok('b'))), decoded.next());
+/// assert!(matches!(decoded.next(), Some((6, Err(_)))));
+/// assert_eq!(None, decoded.next());
+/// ```
+///
+/// [`Iterator`]: core::iter::Iterator
+/// [`ConvertedIterator`]: super::ConvertedIterator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumeratedConvertedIterator<I, C, O> {
+    buffer: BatchExtend<O>,
+    iter: I,
+    converter: C,
+    // the index of the next input item.
+    index: usize,
+    // the index of the input item which produced buffered outputs.
+    current: usize,
+    finished: bool,
+}
+
+impl<I, C> EnumeratedConvertedIterator<I, C, C::Output>
+where
+    I: Iterator,
+    C: Converter<Item = I::Item>,
+{
+    /// Creating a new instance.
+    pub fn new<B>(iter: B, converter: C) -> Self
+    where
+        B: IntoIterator<IntoIter = I>,
+    {
+        let (min, max) = converter.size_hint();
+        Self {
+            buffer: BatchExtend::with_capacity(max.unwrap_or(min)),
+            iter: iter.into_iter(),
+            converter,
+            index: 0,
+            current: 0,
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.finished = true;
+        self.current = self.index;
+        match self.converter.finish(&mut self.buffer) {
+            Ok(_) => self.buffer.pop_front().map(|o| (self.current, Ok(o))),
+            Err(e) => Some((self.current, Err(e))),
+        }
+    }
+}
+
+impl<I, C> Iterator for EnumeratedConvertedIterator<I, C, C::Output>
+where
+    I: Iterator,
+    C: Converter<Item = I::Item>,
+{
+    type Item = (usize, Result<C::Output, C::Error>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.buffer.is_empty() {
+            self.buffer.pop_front().map(|o| (self.current, Ok(o)))
+        } else if self.finished {
+            None
+        } else {
+            loop {
+                match self.iter.next() {
+                    Some(item) => {
+                        self.current = self.index;
+                        self.index += 1;
+                        match self.converter.convert(item, &mut self.buffer) {
+                            Ok(0) if self.converter.is_ended() => break self.finish(),
+                            Ok(0) => continue,
+                            Ok(_) => break self.buffer.pop_front().map(|o| (self.current, Ok(o))),
+                            Err(e) => break Some((self.current, Err(e))),
+                        }
+                    }
+                    None => break self.finish(),
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // outputs already converted, but not yet emitted.
+        let buffered = self.buffer.len();
+        if self.finished {
+            return (buffered, Some(buffered));
+        }
+        let (iter_min, iter_max) = self.iter.size_hint();
+        let (converter_min, converter_max) = self.converter.size_hint();
+        // outputs stored by `finish` are added to the upper bound.
+        let finish_max = self.converter.finish_hint();
+        (
+            buffered + iter_min * converter_min,
+            iter_max
+                .zip(converter_max)
+                .zip(finish_max)
+                .map(|((x, y), z)| buffered + x * y + z),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::converter::IterConverter;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn shared_index() {
+        use alloc::vec;
+
+        let iter = "aßc".chars();
+        let converted =
+            EnumeratedConvertedIterator::new(iter, IterConverter::new(char::to_uppercase))
+                .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(0, Ok('A')), (1, Ok('S')), (1, Ok('S')), (2, Ok('C'))],
+            converted
+        );
+    }
+}
@@ -1,6 +1,6 @@
+use crate::converter::BatchExtend;
 use crate::error::CombinedError;
 use crate::Converter;
-use alloc::collections::VecDeque;
 
 /// A wrapper for [`Iterator`] whose item is [`Result`], converts its item using
 /// [`Converter`].
@@ -26,9 +26,10 @@ use alloc::collections::VecDeque;
 /// [`Converter`]: crate::Converter
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConvertedTryIterator<I, C, O> {
-    buffer: VecDeque<O>,
+    buffer: BatchExtend<O>,
     iter: I,
     converter: C,
+    finished: bool,
 }
 
 impl<I, C, T, E> ConvertedTryIterator<I, C, C::Output>
@@ -44,9 +45,18 @@ where
     {
         let (min, max) = converter.size_hint();
         Self {
-            buffer: VecDeque::with_capacity(max.unwrap_or(min)),
+            buffer: BatchExtend::with_capacity(max.unwrap_or(min)),
             iter: iter.into_iter(),
             converter,
+            finished: false,
+        }
+    }
+
+    fn finish(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.finished = true;
+        match self.converter.finish(&mut self.buffer) {
+            Ok(_) => self.buffer.pop_front().map(Ok),
+            Err(e) => Some(Err(CombinedError::Conversion(e))),
         }
     }
 }
@@ -61,34 +71,40 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         if !self.buffer.is_empty() {
             self.buffer.pop_front().map(Ok)
+        } else if self.finished {
+            None
         } else {
             loop {
                 match self.iter.next() {
                     Some(Ok(item)) => match self.converter.convert(item, &mut self.buffer) {
-                        Ok(0) if self.converter.is_ended() => match self.converter.finalize() {
-                            Ok(()) => break None,
-                            Err(e) => break Some(Err(CombinedError::Conversion(e))),
-                        },
+                        Ok(0) if self.converter.is_ended() => break self.finish(),
                         Ok(0) => continue,
                         Ok(_) => break self.buffer.pop_front().map(Ok),
                         Err(e) => break Some(Err(CombinedError::Conversion(e))),
                     },
                     Some(Err(e)) => break Some(Err(CombinedError::Stream(e))),
-                    None => match self.converter.finalize() {
-                        Ok(()) => break None,
-                        Err(e) => break Some(Err(CombinedError::Conversion(e))),
-                    },
+                    None => break self.finish(),
                 }
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        // outputs already converted, but not yet emitted.
+        let buffered = self.buffer.len();
+        if self.finished {
+            return (buffered, Some(buffered));
+        }
         let (iter_min, iter_max) = self.iter.size_hint();
         let (converter_min, converter_max) = self.converter.size_hint();
+        // outputs stored by `finish` are added to the upper bound.
+        let finish_max = self.converter.finish_hint();
         (
-            iter_min * converter_min,
-            iter_max.zip(converter_max).map(|(x, y)| x * y),
+            buffered + iter_min * converter_min,
+            iter_max
+                .zip(converter_max)
+                .zip(finish_max)
+                .map(|((x, y), z)| buffered + x * y + z),
         )
     }
 }
@@ -21,6 +21,28 @@
 //! let encoded = ConvertedTryIterator::new(uppered, UTF8Encoder::new());
 //! assert_eq!(Ok(b"STRASSE".to_vec()), encoded.collect());
 //! ```
+//!
+//! # Features
+//! - `std` (default): implementations of [`std::error::Error`], and [`SinkConverter`].
+//! - `alloc`: converters and iterators which need allocations, like [`iter`].
+//! - `async`: streams of converted items in [`stream`]. [`ConvertedStream::split`] needs `std`
+//!   too, since both halves share a [`Mutex`].
+//! - `compression`: compressing converters, in [`converter::compress`].
+//! - `cjk`: encodings of CJK characters, like ISO-2022-JP.
+//! - `html5`: all named character references of HTML5, instead of ones of HTML4.
+//! - `unicode-names`: converters between characters and their names, with `unicode_names2`.
+//! - `unicode-segmentation`: a converter into grapheme clusters, with `unicode-segmentation`.
+//! - `digest`: hash functions of the `digest` crate as digests of [`DigestConverter`].
+//! - `nightly`: documentations of required features, on nightly compilers.
+//!
+//! [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+//! [`SinkConverter`]: crate::converter::SinkConverter
+//! [`iter`]: crate::iter
+//! [`stream`]: crate::stream
+//! [`ConvertedStream::split`]: crate::stream::ConvertedStream::split
+//! [`Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+//! [`converter::compress`]: crate::converter::compress
+//! [`DigestConverter`]: crate::converter::checksum::DigestConverter
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(doc_cfg))]
 #![doc(test(attr(deny(warnings))))]
@@ -30,6 +52,8 @@ extern crate alloc;
 
 pub mod error;
 pub mod infallible;
+#[cfg(all(test, feature = "alloc"))]
+mod test_util;
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
@@ -40,7 +64,11 @@ pub mod stream;
 
 pub mod converter;
 
-use converter::ChainedConverter;
+use converter::{ChainedConverter, IndexedMapConverter, PipedConverter, TryAdaptedConverter};
+
+mod sealed {
+    pub trait Sealed {}
+}
 
 /// A trait for converters which converts N items into M outputs.
 pub trait Converter {
@@ -62,6 +90,39 @@ pub trait Converter {
     where
         E: Extend<Self::Output>;
 
+    /// Consumes items in a slice, stores outputs into `buf`, and returns the number of stored
+    /// outputs or a conversion error.
+    ///
+    /// The default implementation calls [`convert`] for each item, and stops at the first error.
+    /// (outputs stored before the error are kept in `buf`.) Converters which can process multiple
+    /// items at once should override this method with the same behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Decoder;
+    /// use conversion::Converter;
+    ///
+    /// let mut decoder = UTF8Decoder::new();
+    /// let mut buf = String::new();
+    ///
+    /// assert_eq!(Ok(4), decoder.convert_slice(b"stra\xc3", &mut buf));
+    /// assert_eq!(Ok(2), decoder.convert_slice(b"\x9fe", &mut buf));
+    /// assert_eq!("straße", buf);
+    /// ```
+    ///
+    /// [`convert`]: Self::convert
+    fn convert_slice<E>(&mut self, items: &[Self::Item], buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+        Self::Item: Copy,
+    {
+        let mut len = 0;
+        for &item in items {
+            len += self.convert(item, buf)?;
+        }
+        Ok(len)
+    }
+
     /// Returns whether the converter reached the end, or not.
     ///
     /// The default return value is `false`, and turning it `true` means the converter will no
@@ -76,17 +137,34 @@ pub trait Converter {
 
     /// Finalizing the converter.
     ///
-    /// This method will be called when input iterators or streams reached end (or the converter
-    /// indicated the end by [`is_ended`] method.). You should finalize the converter or report
-    /// remaining inputs which should be consumed inside them in this method. The default behavior
-    /// is just returning `Ok(())`.
+    /// This method will be called by the default implementation of [`finish`]. You should
+    /// finalize the converter or report remaining inputs which should be consumed inside them in
+    /// this method. The default behavior is just returning `Ok(())`.
     ///
-    /// [`is_ended`]: Self::is_ended
+    /// [`finish`]: Self::finish
     #[inline]
     fn finalize(&mut self) -> Result<(), Self::Error> {
         Ok(())
     }
 
+    /// Finalizing the converter, storing remaining outputs into `buf`.
+    ///
+    /// This method will be called when input iterators or streams reached end (or the converter
+    /// indicated the end by [`is_ended`] method.), and returns the number of stored outputs or a
+    /// conversion error. Converters which hold outputs until the end of inputs should override
+    /// this method instead of [`finalize`]. The default implementation calls [`finalize`] and
+    /// returns `Ok(0)`.
+    ///
+    /// [`is_ended`]: Self::is_ended
+    /// [`finalize`]: Self::finalize
+    #[inline]
+    fn finish<E>(&mut self, _buf: &mut E) -> Result<usize, Self::Error>
+    where
+        E: Extend<Self::Output>,
+    {
+        self.finalize().map(|()| 0)
+    }
+
     /// Returnd the estimated bounds about the numbers of outputs that one item will produce.
     ///
     /// The first element is the lower bound, and the second element is the upper bound. (if
@@ -103,6 +181,20 @@ pub trait Converter {
         (0, None)
     }
 
+    /// Returns the upper bound about the number of outputs that [`finish`] will store.
+    ///
+    /// Iterators and streams add this bound to the upper bound of their `size_hint` until the
+    /// converter finished. Converters which override [`finish`] should override this method too,
+    /// returning [`None`] if the number is unknown. The default implementation returns `Some(0)`
+    /// matches on the default implementation of [`finish`].
+    ///
+    /// [`None`]: core::option::Option::None
+    /// [`finish`]: Self::finish
+    #[inline]
+    fn finish_hint(&self) -> Option<usize> {
+        Some(0)
+    }
+
     /// Chaining two converters.
     ///
     /// If the first converter ended, the converter will provides outputs from the second
@@ -130,4 +222,159 @@ pub trait Converter {
     {
         ChainedConverter::new(self, other)
     }
+
+    /// Piping outputs of the converter into another converter.
+    ///
+    /// Errors of the first converter are returned as [`ChainedError::First`], and ones of the second
+    /// converter as [`ChainedError::Second`]. Outputs produced before an error are still passed to
+    /// the second converter. If the second converter fails, the rest of outputs the first
+    /// converter produced from the same input are discarded, and the error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::base64::Base64Decoder;
+    /// use conversion::converter::encoding::utf8::UTF8Decoder;
+    /// use conversion::iter::ConvertedIterator;
+    /// use conversion::Converter;
+    ///
+    /// let iter = b"c3RyYcOfZQ==".iter().cloned();
+    /// let decoder = Base64Decoder::new().pipe(UTF8Decoder::new());
+    /// let decoded = ConvertedIterator::new(iter, decoder);
+    ///
+    /// assert_eq!(Ok(String::from("straße")), decoded.collect());
+    /// ```
+    ///
+    /// [`ChainedError::First`]: crate::error::ChainedError::First
+    /// [`ChainedError::Second`]: crate::error::ChainedError::Second
+    #[inline]
+    fn pipe<C>(self, other: C) -> PipedConverter<Self, C>
+    where
+        C: Converter<Item = Self::Output>,
+        Self: Sized,
+    {
+        PipedConverter::new(self, other)
+    }
+
+    /// Converting inputs with a failable function before passing them to the converter.
+    ///
+    /// If `f` returns an error, it is returned as [`ChainedError::First`] without calling the
+    /// converter. Errors of the converter are returned as [`ChainedError::Second`].
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Encoder;
+    /// use conversion::error::ChainedError;
+    /// use conversion::iter::ConvertedIterator;
+    /// use conversion::Converter;
+    ///
+    /// let iter = [0x61, 0xD800, 0xDF].into_iter();
+    /// let encoder = UTF8Encoder::new().try_adapt_input(|x: u32| char::from_u32(x).ok_or(x));
+    /// let mut encoded = ConvertedIterator::new(iter, encoder);
+    ///
+    /// assert_eq!(Some(Ok(0x61)), encoded.next());
+    /// assert_eq!(Some(Err(ChainedError::First(0xD800))), encoded.next());
+    /// assert_eq!(Some(Ok(0xC3)), encoded.next());
+    /// assert_eq!(Some(Ok(0x9F)), encoded.next());
+    /// assert_eq!(None, encoded.next());
+    /// ```
+    ///
+    /// [`ChainedError::First`]: crate::error::ChainedError::First
+    /// [`ChainedError::Second`]: crate::error::ChainedError::Second
+    #[inline]
+    fn try_adapt_input<F, I, E>(self, f: F) -> TryAdaptedConverter<Self, F, I, E>
+    where
+        F: FnMut(I) -> Result<Self::Item, E>,
+        Self: Sized,
+    {
+        TryAdaptedConverter::new(self, f)
+    }
+
+    /// Mapping inputs of the converter with their indices, before converting them.
+    ///
+    /// The index counts inputs from zero, so the function can change its behavior based on
+    /// positions in the whole input.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Encoder;
+    /// use conversion::iter::ConvertedIterator;
+    /// use conversion::Converter;
+    ///
+    /// let iter = "straße".chars();
+    /// // uppercasing every other character, `ß` counts as one input.
+    /// let encoder = UTF8Encoder::new().map_with_index(|i, c: char| match i % 2 {
+    ///     0 => c.to_ascii_uppercase(),
+    ///     _ => c,
+    /// });
+    /// let encoded = ConvertedIterator::new(iter, encoder);
+    ///
+    /// assert_eq!(Ok("StRaße".as_bytes().to_vec()), encoded.collect());
+    /// ```
+    #[inline]
+    fn map_with_index<F, O>(self, f: F) -> IndexedMapConverter<Self, F>
+    where
+        F: FnMut(usize, Self::Item) -> O,
+        Self: Converter<Item = O> + Sized,
+    {
+        IndexedMapConverter::new(self, f)
+    }
+
+    /// Converting an iterator with the converter.
+    ///
+    /// This is the same as [`ConvertedIterator::new`], with the converter first.
+    ///
+    /// # Examples
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Decoder;
+    /// use conversion::Converter;
+    ///
+    /// let bytes = b"stra\xc3\x9fe".iter().cloned();
+    /// let decoded = UTF8Decoder::new().into_iter(bytes);
+    ///
+    /// assert_eq!(Ok(String::from("straße")), decoded.collect());
+    /// ```
+    ///
+    /// [`ConvertedIterator::new`]: crate::iter::ConvertedIterator::new
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn into_iter<I>(self, iter: I) -> iter::ConvertedIterator<I::IntoIter, Self, Self::Output>
+    where
+        I: IntoIterator<Item = Self::Item>,
+        Self: Sized,
+    {
+        iter::ConvertedIterator::new(iter, self)
+    }
+}
+
+/// A marker trait for converters which always produce the same number of outputs for each item,
+/// like [`ExactSizeIterator`].
+///
+/// For these converters, the lower and the upper bounds of [`size_hint`] are equal. This trait is
+/// sealed and implemented only for converters in this crate.
+///
+/// # Examples
+/// ```
+/// use conversion::converter::encoding::utf32::UTF32BEEncoder;
+/// use conversion::converter::MapConverter;
+/// use conversion::iter::ConvertedIterator;
+/// use conversion::ExactSizeConverter;
+///
+/// assert_eq!(4, UTF32BEEncoder::new().output_len());
+///
+/// let iter = [1, 2, 3].into_iter();
+/// let doubled = ConvertedIterator::new(iter, MapConverter::new(|x| 2 * x));
+/// assert_eq!((3, Some(3)), doubled.size_hint());
+/// ```
+///
+/// [`ExactSizeIterator`]: core::iter::ExactSizeIterator
+/// [`size_hint`]: Converter::size_hint
+pub trait ExactSizeConverter: Converter + sealed::Sealed {
+    /// Returns the exact number of outputs that one item will produce.
+    #[inline]
+    fn output_len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
 }
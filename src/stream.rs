@@ -1,10 +1,15 @@
 //! Asynchronous stream support.
 
+#[cfg(all(feature = "async", feature = "std"))]
+mod split;
 mod trystream;
+#[cfg(all(feature = "async", feature = "std"))]
+#[cfg_attr(feature = "nightly", doc(cfg(all(feature = "async", feature = "std"))))]
+pub use split::{ErrStream, OkStream};
 pub use trystream::ConvertedTryStream;
 
+use crate::converter::BatchExtend;
 use crate::Converter;
-use alloc::collections::VecDeque;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::{ready, Stream};
@@ -32,10 +37,11 @@ pin_project! {
     /// [`TryConverter`]: crate::TryConverter
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct ConvertedStream<S, C, O> {
-        buffer: VecDeque<O>,
+        buffer: BatchExtend<O>,
         #[pin]
         stream: S,
         converter: C,
+        finished: bool,
     }
 }
 
@@ -49,11 +55,39 @@ where
     pub fn new(stream: S, converter: C) -> Self {
         let (min, max) = converter.size_hint();
         Self {
-            buffer: VecDeque::with_capacity(max.unwrap_or(min)),
+            buffer: BatchExtend::with_capacity(max.unwrap_or(min)),
             stream,
             converter,
+            finished: false,
         }
     }
+
+    /// Splitting the stream into a stream of outputs and a stream of errors.
+    ///
+    /// Both streams can drive the inner stream, and items for the other stream are buffered until
+    /// they are polled. Items for a dropped stream are discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use conversion::converter::encoding::utf8::UTF8Decoder;
+    /// use conversion::stream::ConvertedStream;
+    /// use futures::stream::{self, StreamExt};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let stream = stream::iter(b"a\xffb\xfe".iter().cloned());
+    /// let (decoded, errors) = ConvertedStream::new(stream, UTF8Decoder::new()).split();
+    ///
+    /// let (decoded, errors) = futures::join!(decoded.collect::<String>(), errors.collect::<Vec<_>>());
+    /// assert_eq!("ab", decoded);
+    /// assert_eq!(2, errors.len());
+    /// # });
+    /// ```
+    #[cfg(all(feature = "async", feature = "std"))]
+    #[cfg_attr(feature = "nightly", doc(cfg(all(feature = "async", feature = "std"))))]
+    #[inline]
+    pub fn split(self) -> (OkStream<S, C>, ErrStream<S, C>) {
+        split::split(self)
+    }
 }
 
 impl<S, C> Stream for ConvertedStream<S, C, C::Output>
@@ -64,34 +98,73 @@ where
     type Item = Result<C::Output, C::Error>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+        let mut this = self.project();
         if !this.buffer.is_empty() {
             Poll::Ready(this.buffer.pop_front().map(Ok))
+        } else if *this.finished {
+            Poll::Ready(None)
         } else {
-            match ready!(this.stream.poll_next(cx)) {
-                Some(item) => match this.converter.convert(item, this.buffer) {
-                    Ok(0) if this.converter.is_ended() => match this.converter.finalize() {
-                        Ok(()) => Poll::Ready(None),
+            loop {
+                break match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Some(item) => match this.converter.convert(item, this.buffer) {
+                        Ok(0) if this.converter.is_ended() => {
+                            *this.finished = true;
+                            match this.converter.finish(this.buffer) {
+                                Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
+                                Err(e) => Poll::Ready(Some(Err(e))),
+                            }
+                        }
+                        Ok(0) => continue,
+                        Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
                         Err(e) => Poll::Ready(Some(Err(e))),
                     },
-                    Ok(0) => Poll::Pending,
-                    Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
-                    Err(e) => Poll::Ready(Some(Err(e))),
-                },
-                None => match this.converter.finalize() {
-                    Ok(()) => Poll::Ready(None),
-                    Err(e) => Poll::Ready(Some(Err(e))),
-                },
+                    None => {
+                        *this.finished = true;
+                        match this.converter.finish(this.buffer) {
+                            Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
+                            Err(e) => Poll::Ready(Some(Err(e))),
+                        }
+                    }
+                };
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        // outputs already converted, but not yet emitted.
+        let buffered = self.buffer.len();
+        if self.finished {
+            return (buffered, Some(buffered));
+        }
         let (stream_min, stream_max) = self.stream.size_hint();
         let (converter_min, converter_max) = self.converter.size_hint();
+        // outputs stored by `finish` are added to the upper bound.
+        let finish_max = self.converter.finish_hint();
         (
-            stream_min * converter_min,
-            stream_max.zip(converter_max).map(|(x, y)| x * y),
+            buffered + stream_min * converter_min,
+            stream_max
+                .zip(converter_max)
+                .zip(finish_max)
+                .map(|((x, y), z)| buffered + x * y + z),
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multibyte_utf8() {
+        use crate::converter::encoding::utf8::UTF8Decoder;
+        use alloc::string::String;
+        use futures::stream::{self, TryStreamExt};
+
+        // leading bytes of a sequence produce no outputs.
+        let stream = stream::iter("日本語 text".bytes());
+        let decoded = futures::executor::block_on(
+            ConvertedStream::new(stream, UTF8Decoder::new()).try_collect::<String>(),
+        );
+        assert_eq!(Ok(String::from("日本語 text")), decoded);
+    }
+}
@@ -0,0 +1,194 @@
+use super::ConvertedStream;
+use crate::Converter;
+use alloc::collections::VecDeque;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use std::sync::{Arc, Mutex};
+use std::task::Wake;
+
+const OK: usize = 0;
+const ERR: usize = 1;
+
+// wakers of both halves, since the inner stream remembers only the last one.
+#[derive(Default)]
+struct Wakers(Mutex<[Option<Waker>; 2]>);
+
+impl Wakers {
+    fn wake_half(&self, half: usize) {
+        if let Some(waker) = self.0.lock().unwrap()[half].take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for Wakers {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers = core::mem::take(&mut *self.0.lock().unwrap());
+        wakers.into_iter().flatten().for_each(Waker::wake);
+    }
+}
+
+struct Shared<S, C: Converter> {
+    stream: Pin<Box<ConvertedStream<S, C, C::Output>>>,
+    oks: VecDeque<C::Output>,
+    errs: VecDeque<C::Error>,
+    finished: bool,
+    // whether each half is dropped.
+    dropped: [bool; 2],
+}
+
+// shared parts of both halves.
+struct Inner<S, C: Converter> {
+    shared: Arc<Mutex<Shared<S, C>>>,
+    wakers: Arc<Wakers>,
+}
+
+impl<S, C> Inner<S, C>
+where
+    S: Stream,
+    C: Converter<Item = S::Item>,
+{
+    fn poll_half<T>(
+        &self,
+        half: usize,
+        cx: &mut Context<'_>,
+        pop: impl Fn(&mut Shared<S, C>) -> Option<T>,
+    ) -> Poll<Option<T>> {
+        let mut shared = self.shared.lock().unwrap();
+        loop {
+            if let Some(item) = pop(&mut shared) {
+                return Poll::Ready(Some(item));
+            } else if shared.finished {
+                return Poll::Ready(None);
+            }
+
+            self.wakers.0.lock().unwrap()[half] = Some(cx.waker().clone());
+            let waker = Waker::from(self.wakers.clone());
+            let shared = &mut *shared;
+            match shared
+                .stream
+                .as_mut()
+                .poll_next(&mut Context::from_waker(&waker))
+            {
+                Poll::Ready(Some(Ok(output))) => {
+                    if !shared.dropped[OK] {
+                        shared.oks.push_back(output);
+                        if half != OK {
+                            self.wakers.wake_half(OK);
+                        }
+                    }
+                }
+                Poll::Ready(Some(Err(err))) => {
+                    if !shared.dropped[ERR] {
+                        shared.errs.push_back(err);
+                        if half != ERR {
+                            self.wakers.wake_half(ERR);
+                        }
+                    }
+                }
+                Poll::Ready(None) => {
+                    shared.finished = true;
+                    self.wakers.wake_half(1 - half);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S, C: Converter> Inner<S, C> {
+    fn drop_half(&self, half: usize) {
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.dropped[half] = true;
+            match half {
+                OK => shared.oks.clear(),
+                _ => shared.errs.clear(),
+            }
+        }
+    }
+}
+
+/// A stream of successful outputs, returned by [`ConvertedStream::split`].
+///
+/// [`ConvertedStream::split`]: super::ConvertedStream::split
+pub struct OkStream<S, C: Converter> {
+    inner: Inner<S, C>,
+}
+
+impl<S, C> Stream for OkStream<S, C>
+where
+    S: Stream,
+    C: Converter<Item = S::Item>,
+{
+    type Item = C::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner
+            .poll_half(OK, cx, |shared| shared.oks.pop_front())
+    }
+}
+
+impl<S, C: Converter> Drop for OkStream<S, C> {
+    fn drop(&mut self) {
+        self.inner.drop_half(OK)
+    }
+}
+
+/// A stream of conversion errors, returned by [`ConvertedStream::split`].
+///
+/// [`ConvertedStream::split`]: super::ConvertedStream::split
+pub struct ErrStream<S, C: Converter> {
+    inner: Inner<S, C>,
+}
+
+impl<S, C> Stream for ErrStream<S, C>
+where
+    S: Stream,
+    C: Converter<Item = S::Item>,
+{
+    type Item = C::Error;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner
+            .poll_half(ERR, cx, |shared| shared.errs.pop_front())
+    }
+}
+
+impl<S, C: Converter> Drop for ErrStream<S, C> {
+    fn drop(&mut self) {
+        self.inner.drop_half(ERR)
+    }
+}
+
+pub(super) fn split<S, C>(
+    stream: ConvertedStream<S, C, C::Output>,
+) -> (OkStream<S, C>, ErrStream<S, C>)
+where
+    S: Stream,
+    C: Converter<Item = S::Item>,
+{
+    let shared = Arc::new(Mutex::new(Shared {
+        stream: Box::pin(stream),
+        oks: VecDeque::new(),
+        errs: VecDeque::new(),
+        finished: false,
+        dropped: [false; 2],
+    }));
+    let wakers = Arc::new(Wakers::default());
+    (
+        OkStream {
+            inner: Inner {
+                shared: shared.clone(),
+                wakers: wakers.clone(),
+            },
+        },
+        ErrStream {
+            inner: Inner { shared, wakers },
+        },
+    )
+}
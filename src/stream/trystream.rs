@@ -1,6 +1,6 @@
+use crate::converter::BatchExtend;
 use crate::error::CombinedError;
 use crate::Converter;
-use alloc::collections::VecDeque;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::{ready, Stream, TryStream};
@@ -34,10 +34,11 @@ pin_project! {
     /// [`Converter`]: crate::Converter
     #[derive(Clone, Debug, PartialEq, Eq)]
     pub struct ConvertedTryStream<S, C, O> {
-        buffer: VecDeque<O>,
+        buffer: BatchExtend<O>,
         #[pin]
         stream: S,
         converter: C,
+        finished: bool,
     }
 }
 
@@ -51,9 +52,10 @@ where
     pub fn new(stream: S, converter: C) -> Self {
         let (min, max) = converter.size_hint();
         Self {
-            buffer: VecDeque::with_capacity(max.unwrap_or(min)),
+            buffer: BatchExtend::with_capacity(max.unwrap_or(min)),
             stream,
             converter,
+            finished: false,
         }
     }
 }
@@ -66,35 +68,74 @@ where
     type Item = Result<C::Output, CombinedError<S::Error, C::Error>>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = self.project();
+        let mut this = self.project();
         if !this.buffer.is_empty() {
             Poll::Ready(this.buffer.pop_front().map(Ok))
+        } else if *this.finished {
+            Poll::Ready(None)
         } else {
-            match ready!(this.stream.try_poll_next(cx)) {
-                Some(Ok(item)) => match this.converter.convert(item, this.buffer) {
-                    Ok(0) if this.converter.is_ended() => match this.converter.finalize() {
-                        Ok(()) => Poll::Ready(None),
+            loop {
+                break match ready!(this.stream.as_mut().try_poll_next(cx)) {
+                    Some(Ok(item)) => match this.converter.convert(item, this.buffer) {
+                        Ok(0) if this.converter.is_ended() => {
+                            *this.finished = true;
+                            match this.converter.finish(this.buffer) {
+                                Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
+                                Err(e) => Poll::Ready(Some(Err(CombinedError::Conversion(e)))),
+                            }
+                        }
+                        Ok(0) => continue,
+                        Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
                         Err(e) => Poll::Ready(Some(Err(CombinedError::Conversion(e)))),
                     },
-                    Ok(0) => Poll::Pending,
-                    Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
-                    Err(e) => Poll::Ready(Some(Err(CombinedError::Conversion(e)))),
-                },
-                Some(Err(e)) => Poll::Ready(Some(Err(CombinedError::Stream(e)))),
-                None => match this.converter.finalize() {
-                    Ok(()) => Poll::Ready(None),
-                    Err(e) => Poll::Ready(Some(Err(CombinedError::Conversion(e)))),
-                },
+                    Some(Err(e)) => Poll::Ready(Some(Err(CombinedError::Stream(e)))),
+                    None => {
+                        *this.finished = true;
+                        match this.converter.finish(this.buffer) {
+                            Ok(_) => Poll::Ready(this.buffer.pop_front().map(Ok)),
+                            Err(e) => Poll::Ready(Some(Err(CombinedError::Conversion(e)))),
+                        }
+                    }
+                };
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        // outputs already converted, but not yet emitted.
+        let buffered = self.buffer.len();
+        if self.finished {
+            return (buffered, Some(buffered));
+        }
         let (stream_min, stream_max) = self.stream.size_hint();
         let (converter_min, converter_max) = self.converter.size_hint();
+        // outputs stored by `finish` are added to the upper bound.
+        let finish_max = self.converter.finish_hint();
         (
-            stream_min * converter_min,
-            stream_max.zip(converter_max).map(|(x, y)| x * y),
+            buffered + stream_min * converter_min,
+            stream_max
+                .zip(converter_max)
+                .zip(finish_max)
+                .map(|((x, y), z)| buffered + x * y + z),
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multibyte_utf8() {
+        use crate::converter::encoding::utf8::UTF8Decoder;
+        use alloc::string::String;
+        use futures::stream::{self, TryStreamExt};
+
+        // leading bytes of a sequence produce no outputs.
+        let stream = stream::iter("日本語 text".bytes().map(Ok::<_, ()>));
+        let decoded = futures::executor::block_on(
+            ConvertedTryStream::new(stream, UTF8Decoder::new()).try_collect::<String>(),
+        );
+        assert_eq!(Ok(String::from("日本語 text")), decoded);
+    }
+}
@@ -0,0 +1,20 @@
+//! Utilities shared by tests.
+
+use alloc::vec::Vec;
+
+/// Returns a generator of reproducible pseudo-random numbers, by xorshift32.
+pub(crate) fn random() -> impl FnMut() -> u32 {
+    let mut state = 0x2545_F491u32;
+    move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    }
+}
+
+/// Returns reproducible pseudo-random bytes.
+pub(crate) fn random_bytes(len: usize) -> Vec<u8> {
+    let mut next = random();
+    (0..len).map(|_| next() as u8).collect()
+}
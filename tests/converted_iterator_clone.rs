@@ -0,0 +1,80 @@
+//! Cloning `ConvertedIterator` at different points of the iteration.
+//!
+//! A clone must be independent of the original, and produce the same remaining items: ones
+//! buffered but not yet emitted, ones converted from the rest of the inner iterator, and ones
+//! emitted from the state of the converter at the end.
+#![cfg(feature = "alloc")]
+
+use conversion::converter::encoding::base64::Base64Encoder;
+use conversion::converter::encoding::utf8::{UTF8Decoder, UTF8Encoder};
+use conversion::iter::ConvertedIterator;
+
+fn remaining<I: Iterator>(iter: I) -> Vec<I::Item> {
+    iter.collect()
+}
+
+#[test]
+fn clone_before_iteration() {
+    let iter = ConvertedIterator::new("straße".chars(), UTF8Encoder::new());
+    let cloned = iter.clone();
+
+    assert_eq!(remaining(iter), remaining(cloned.clone()));
+    assert_eq!(
+        Ok("straße".as_bytes().to_vec()),
+        cloned.collect::<Result<Vec<_>, _>>()
+    );
+}
+
+#[test]
+fn clone_mid_buffer() {
+    let mut iter = ConvertedIterator::new("ß😀".chars(), UTF8Encoder::new());
+    // the rest of the bytes of `ß` are buffered.
+    assert_eq!(Some(Ok(0xC3)), iter.next());
+    let mut cloned = iter.clone();
+
+    assert_eq!(Some(Ok(0x9F)), iter.next());
+    assert_eq!(Some(Ok(0xF0)), iter.next());
+    // advancing the original does not affect the clone.
+    assert_eq!(Some(Ok(0x9F)), cloned.next());
+    assert_eq!(Some(Ok(0xF0)), cloned.next());
+    assert_eq!(vec![Ok(0x9F), Ok(0x98), Ok(0x80)], remaining(iter));
+    assert_eq!(vec![Ok(0x9F), Ok(0x98), Ok(0x80)], remaining(cloned));
+}
+
+#[test]
+fn clone_with_converter_state() {
+    // bits of `b` are left in the encoder.
+    let mut iter = ConvertedIterator::new(*b"fooba", Base64Encoder::new());
+    let first = iter.by_ref().take(5).collect::<Result<Vec<_>, _>>();
+    assert_eq!(Ok(b"Zm9vY".to_vec()), first);
+    let cloned = iter.clone();
+
+    assert_eq!(Ok(b"mE=".to_vec()), iter.collect::<Result<Vec<_>, _>>());
+    assert_eq!(Ok(b"mE=".to_vec()), cloned.collect::<Result<Vec<_>, _>>());
+}
+
+#[test]
+fn clone_after_error() {
+    let bytes = [b'a', 0xFF, 0xC3, 0x9F, 0xE2];
+    let mut iter = ConvertedIterator::new(bytes, UTF8Decoder::new());
+    assert_eq!(Some(Ok('a')), iter.next());
+    assert!(matches!(iter.next(), Some(Err(_))));
+    let cloned = iter.clone();
+
+    let rest = remaining(iter);
+    assert_eq!(Some(&Ok('ß')), rest.first());
+    assert!(matches!(rest.get(1), Some(Err(_))));
+    assert_eq!(2, rest.len());
+    assert_eq!(rest, remaining(cloned));
+}
+
+#[test]
+fn clone_after_end() {
+    let mut iter = ConvertedIterator::new("a".chars(), UTF8Encoder::new());
+    assert_eq!(Some(Ok(b'a')), iter.next());
+    assert_eq!(None, iter.next());
+
+    let mut cloned = iter.clone();
+    assert_eq!(None, cloned.next());
+    assert_eq!(None, iter.next());
+}